@@ -0,0 +1,65 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Compares the bulk primitive-array path used by `Vec<T>` against the generic
+//! element-by-element collection path used by `VecDeque<T>`.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use fory_core::fory::Fory;
+use std::collections::VecDeque;
+
+const LEN: usize = 1_000_000;
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let fory = Fory::builder().xlang(false).compatible(false).build();
+
+    let floats: Vec<f64> = (0..LEN).map(|i| i as f64 * 0.5).collect();
+    let float_deque: VecDeque<f64> = floats.iter().copied().collect();
+    let ints: Vec<i32> = (0..LEN as i32).collect();
+    let int_deque: VecDeque<i32> = ints.iter().copied().collect();
+
+    c.bench_function("Vec<f64> serialize", |b| {
+        b.iter(|| fory.serialize(black_box(&floats)).unwrap())
+    });
+    c.bench_function("VecDeque<f64> serialize", |b| {
+        b.iter(|| fory.serialize(black_box(&float_deque)).unwrap())
+    });
+    c.bench_function("Vec<i32> serialize", |b| {
+        b.iter(|| fory.serialize(black_box(&ints)).unwrap())
+    });
+    c.bench_function("VecDeque<i32> serialize", |b| {
+        b.iter(|| fory.serialize(black_box(&int_deque)).unwrap())
+    });
+
+    let floats_bin = fory.serialize(&floats).unwrap();
+    let float_deque_bin = fory.serialize(&float_deque).unwrap();
+    c.bench_function("Vec<f64> deserialize", |b| {
+        b.iter(|| {
+            fory.deserialize::<Vec<f64>>(black_box(&floats_bin))
+                .unwrap()
+        })
+    });
+    c.bench_function("VecDeque<f64> deserialize", |b| {
+        b.iter(|| {
+            fory.deserialize::<VecDeque<f64>>(black_box(&float_deque_bin))
+                .unwrap()
+        })
+    });
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
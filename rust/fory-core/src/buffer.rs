@@ -57,6 +57,11 @@ impl<'a> Writer<'a> {
         self.bf.is_empty()
     }
 
+    #[inline(always)]
+    pub fn capacity(&self) -> usize {
+        self.bf.capacity()
+    }
+
     #[inline(always)]
     pub fn reserve(&mut self, additional: usize) {
         if self.bf.capacity() - self.len() < additional {
@@ -431,11 +436,13 @@ impl<'a> Writer<'a> {
 
     // ============ Rust-specific types (i128, u128, isize, usize) ============
 
+    /// Writes an `i128` as 16 fixed bytes in little-endian two's complement order.
     #[inline(always)]
     pub fn write_i128(&mut self, value: i128) {
         self.write_u128(value as u128);
     }
 
+    /// Writes a `u128` as 16 fixed bytes, least significant byte first.
     #[inline(always)]
     pub fn write_u128(&mut self, value: u128) {
         #[cfg(target_endian = "little")]
@@ -586,13 +593,8 @@ impl<'a> Reader<'a> {
         }
     }
 
-    #[inline(always)]
-    fn read_u8_uncheck(&mut self) -> u8 {
-        let result = unsafe { self.bf.get_unchecked(self.cursor) };
-        self.move_next(1);
-        *result
-    }
-
+    /// Advances the cursor by exactly `len` bytes, failing with
+    /// [`Error::BufferOutOfBound`] and leaving the cursor unchanged if fewer remain.
     #[inline(always)]
     pub fn skip(&mut self, len: usize) -> Result<(), Error> {
         self.check_bound(len)?;
@@ -608,6 +610,17 @@ impl<'a> Reader<'a> {
         Ok(result)
     }
 
+    /// Like [`read_bytes`](Self::read_bytes), but the returned slice borrows
+    /// the underlying input rather than the reader.
+    #[inline(always)]
+    pub(crate) fn read_borrowed_bytes(&mut self, len: usize) -> Result<&'a [u8], Error> {
+        self.check_bound(len)?;
+        let bf: &'a [u8] = self.bf;
+        let result = &bf[self.cursor..self.cursor + len];
+        self.move_next(len);
+        Ok(result)
+    }
+
     #[inline(always)]
     pub fn reset_cursor_to_here(&self) -> impl FnOnce(&mut Self) {
         let raw_cursor = self.cursor;
@@ -731,6 +744,11 @@ impl<'a> Reader<'a> {
 
     // ============ VAR_UINT32 (TypeId = 12) ============
 
+    /// Reads a var-uint32 of at most 5 bytes.
+    ///
+    /// The fifth byte carries the top 4 bits and no continuation bit; any other bit set in
+    /// it means the input is not a valid 32-bit varint and yields
+    /// [`Error::MalformedVarint`] instead of a silently truncated value.
     #[inline(always)]
     pub fn read_var_u32(&mut self) -> Result<u32, Error> {
         let b0 = self.value_at(self.cursor)? as u32;
@@ -761,11 +779,26 @@ impl<'a> Reader<'a> {
         }
 
         let b4 = self.value_at(self.cursor + 4)? as u32;
+        if b4 > 0x0F {
+            return Err(Error::malformed_varint(format!(
+                "final byte {b4:#04x} of var_uint32 sets bits beyond 32"
+            )));
+        }
         encoded |= b4 << 28;
         self.move_next(5);
         Ok(encoded)
     }
 
+    /// Decodes the var-uint32 at the cursor like [`read_var_u32`](Self::read_var_u32), but
+    /// leaves the cursor where it was, whether or not the decode succeeds.
+    #[inline(always)]
+    pub fn peek_var_u32(&mut self) -> Result<u32, Error> {
+        let cursor = self.cursor;
+        let result = self.read_var_u32();
+        self.cursor = cursor;
+        result
+    }
+
     // ============ UINT64 (TypeId = 13) ============
 
     #[inline(always)]
@@ -778,6 +811,10 @@ impl<'a> Reader<'a> {
 
     // ============ VAR_UINT64 (TypeId = 14) ============
 
+    /// Reads a var-uint64 of at most 9 bytes.
+    ///
+    /// The ninth byte carries the top 8 bits in full, so every 9-byte input decodes to a
+    /// valid `u64` and reading never goes past it.
     #[inline(always)]
     pub fn read_var_u64(&mut self) -> Result<u64, Error> {
         let b0 = self.value_at(self.cursor)? as u64;
@@ -954,8 +991,7 @@ impl<'a> Reader<'a> {
         let src = &self.bf[self.cursor..self.cursor + len];
         // Rust is the only runtime that checks UTF-8 string payloads by default; other runtimes
         // preserve their platform replacement behavior for invalid byte sequences.
-        let string =
-            std::str::from_utf8(src).map_err(|_| Error::encoding_error("invalid UTF-8 string"))?;
+        let string = std::str::from_utf8(src).map_err(|_| Error::utf8("invalid UTF-8 string"))?;
         let string = string.to_owned();
         self.move_next(len);
         Ok(string)
@@ -1063,8 +1099,9 @@ impl<'a> Reader<'a> {
 
         let mut result = 0u64;
         let mut shift = 0;
-        while self.cursor < self.bf.len() {
-            let b = self.read_u8_uncheck();
+        loop {
+            // A varint cut short by the end of the buffer is an error, not a partial value.
+            let b = self.read_u8()?;
             result |= ((b & 0x7F) as u64) << shift;
             if (b & 0x80) == 0 {
                 break;
@@ -1082,3 +1119,48 @@ impl<'a> Reader<'a> {
 unsafe impl<'a> Send for Reader<'a> {}
 #[allow(clippy::needless_lifetimes)]
 unsafe impl<'a> Sync for Reader<'a> {}
+
+/// Fixed-width values whose byte order can be reversed.
+///
+/// `Writer` and `Reader` always use little-endian order; payloads written with
+/// [`Endianness::Big`](crate::config::Endianness::Big) reverse fixed-width
+/// numbers around those calls.
+pub trait ByteSwap: Copy {
+    fn reverse_bytes(self) -> Self;
+}
+
+macro_rules! impl_byte_swap_for_int {
+    ($($ty:ty),*) => {
+        $(impl ByteSwap for $ty {
+            #[inline(always)]
+            fn reverse_bytes(self) -> Self {
+                self.swap_bytes()
+            }
+        })*
+    };
+}
+
+impl_byte_swap_for_int!(i16, i32, i64, i128, u16, u32, u64, u128);
+
+macro_rules! impl_byte_swap_for_float {
+    ($($ty:ty),*) => {
+        $(impl ByteSwap for $ty {
+            #[inline(always)]
+            fn reverse_bytes(self) -> Self {
+                <$ty>::from_bits(self.to_bits().swap_bytes())
+            }
+        })*
+    };
+}
+
+impl_byte_swap_for_float!(f32, f64, float16, bfloat16);
+
+/// Reverses the bytes of every `width`-byte element in `bytes`.
+#[inline]
+pub(crate) fn reverse_element_bytes(bytes: &mut [u8], width: usize) {
+    if width > 1 {
+        for element in bytes.chunks_exact_mut(width) {
+            element.reverse();
+        }
+    }
+}
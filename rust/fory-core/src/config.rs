@@ -15,6 +15,72 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use crate::util::cipher::FieldCipher;
+
+/// Compression applied to the payload that follows the root header.
+///
+/// Compressed payloads are flagged in the header, so readers decompress them
+/// transparently regardless of their own setting. Compression is a Rust
+/// native-mode feature and is rejected in xlang mode.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Compression {
+    /// Payloads are written uncompressed.
+    #[default]
+    None,
+    /// Payloads are compressed with the LZ4 frame format.
+    #[cfg(feature = "lz4")]
+    Lz4,
+    /// Payloads are compressed with the raw Snappy format.
+    #[cfg(feature = "snappy")]
+    Snappy,
+}
+
+/// Whether payloads carry the schema of the structs they contain.
+///
+/// Selected with [`ForyBuilder::schema_mode`](crate::ForyBuilder::schema_mode), as a
+/// named alternative to `compatible(true)` and `compatible(false)`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SchemaMode {
+    /// Each payload writes the `TypeMeta` of every struct type it contains once, so
+    /// readers can evolve their structs independently. Same as `compatible(true)`.
+    #[default]
+    Compatible,
+    /// No `TypeMeta` is written. Readers decode structs with their registered local
+    /// schema, which must match the writer's. Same as `compatible(false)`.
+    None,
+}
+
+/// Integrity checksum appended to serialized payloads.
+///
+/// Checksummed payloads are flagged in the header, so readers verify them
+/// transparently regardless of their own setting, and plain payloads are still
+/// accepted. Checksums are a Rust native-mode feature and are rejected in xlang mode.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Checksum {
+    /// Payloads are written without a checksum.
+    #[default]
+    None,
+    /// A CRC-32 (IEEE) of the payload is appended as a footer.
+    Crc32,
+}
+
+/// Byte order of fixed-width numbers in a payload.
+///
+/// Applies to fixed-width integers and floats, including `encoding = fixed` fields and
+/// primitive arrays. Variable-length integers, strings and type metadata are unaffected.
+/// The header records the order, so readers detect it regardless of their own setting.
+/// Big-endian payloads are a Rust native-mode feature and are rejected in xlang mode.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Endianness {
+    /// Fixed-width numbers are written little-endian, as the specification requires.
+    #[default]
+    Little,
+    /// Fixed-width numbers are written big-endian.
+    Big,
+}
+
 /// Configuration for Fory serialization.
 ///
 /// This struct holds all the configuration options that control how Fory
@@ -32,8 +98,20 @@ pub struct Config {
     pub compress_string: bool,
     /// Whether UTF-8 string payloads are validated before constructing Rust strings.
     pub check_string_read: bool,
+    /// Whether repeated strings in a payload are written as references to their first
+    /// occurrence. Rust native mode only.
+    pub string_ref: bool,
+    /// Whether `None` fields of compatible structs are left out of the payload, with a
+    /// presence bitmap in front of the fields. Rust native mode only.
+    pub sparse_options: bool,
+    /// Whether `HashMap` entries are written in the order of their serialized keys, so
+    /// equal maps always produce identical bytes.
+    pub deterministic: bool,
     /// Maximum depth for nested dynamic object serialization.
     pub max_dyn_depth: u32,
+    /// Maximum nesting depth of struct and enum values during deserialization.
+    /// Prevents stack overflow from deeply nested recursive payloads.
+    pub max_depth: u32,
     /// Whether class version checking is enabled.
     pub check_struct_version: bool,
     /// Whether reference tracking is enabled.
@@ -46,6 +124,14 @@ pub struct Config {
     /// Maximum allowed number of elements in a collection or entries in a map.
     /// Prevents excessive memory allocation from untrusted payloads.
     pub max_collection_size: u32,
+    /// Compression applied to serialized payloads.
+    pub compression: Compression,
+    /// Integrity checksum appended to serialized payloads.
+    pub checksum: Checksum,
+    /// Byte order of fixed-width numbers in serialized payloads.
+    pub endianness: Endianness,
+    /// Key for `#[fory(encrypt)]` fields.
+    pub cipher: Option<FieldCipher>,
 }
 
 impl Default for Config {
@@ -56,11 +142,19 @@ impl Default for Config {
             share_meta: false,
             compress_string: false,
             check_string_read: true,
+            string_ref: false,
+            sparse_options: false,
+            deterministic: false,
             max_dyn_depth: 5,
+            max_depth: 64,
             check_struct_version: false,
             track_ref: false,
             max_binary_size: 64 * 1024 * 1024, // 64MB default
             max_collection_size: 1024 * 1024,  // 1M elements default
+            compression: Compression::None,
+            checksum: Checksum::None,
+            endianness: Endianness::Little,
+            cipher: None,
         }
     }
 }
@@ -101,12 +195,36 @@ impl Config {
         self.check_string_read
     }
 
+    /// Check if repeated strings are written as references.
+    #[inline(always)]
+    pub fn is_string_ref(&self) -> bool {
+        self.string_ref
+    }
+
+    /// Check if `None` struct fields are left out of compatible payloads.
+    #[inline(always)]
+    pub fn is_sparse_options(&self) -> bool {
+        self.sparse_options
+    }
+
+    /// Check if `HashMap` entries are written in a deterministic order.
+    #[inline(always)]
+    pub fn is_deterministic(&self) -> bool {
+        self.deterministic
+    }
+
     /// Get maximum dynamic depth.
     #[inline(always)]
     pub fn max_dyn_depth(&self) -> u32 {
         self.max_dyn_depth
     }
 
+    /// Get maximum struct and enum nesting depth.
+    #[inline(always)]
+    pub fn max_depth(&self) -> u32 {
+        self.max_depth
+    }
+
     /// Check if class version checking is enabled.
     #[inline(always)]
     pub fn is_check_struct_version(&self) -> bool {
@@ -130,4 +248,28 @@ impl Config {
     pub fn max_collection_size(&self) -> u32 {
         self.max_collection_size
     }
+
+    /// Get the payload compression.
+    #[inline(always)]
+    pub fn compression(&self) -> Compression {
+        self.compression
+    }
+
+    /// Get the payload checksum.
+    #[inline(always)]
+    pub fn checksum(&self) -> Checksum {
+        self.checksum
+    }
+
+    /// Get the byte order of fixed-width numbers.
+    #[inline(always)]
+    pub fn endianness(&self) -> Endianness {
+        self.endianness
+    }
+
+    /// Get the key for `#[fory(encrypt)]` fields.
+    #[inline(always)]
+    pub fn cipher(&self) -> Option<&FieldCipher> {
+        self.cipher.as_ref()
+    }
 }
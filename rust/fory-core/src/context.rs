@@ -15,8 +15,8 @@
 // specific language governing permissions and limitations
 // under the License.
 
-use crate::buffer::{Reader, Writer};
-use crate::config::Config;
+use crate::buffer::{ByteSwap, Reader, Writer};
+use crate::config::{Config, Endianness};
 use std::collections::HashMap;
 use std::mem;
 
@@ -28,6 +28,7 @@ use crate::resolver::{RefReader, RefWriter};
 use crate::resolver::{TypeInfo, TypeResolver};
 use crate::serializer::StructSerializer;
 use crate::type_id as types;
+use crate::util::cipher::FieldCipher;
 use crate::TypeId;
 use std::rc::Rc;
 
@@ -123,6 +124,11 @@ pub struct WriteContext<'a> {
     xlang: bool,
     check_struct_version: bool,
     track_ref: bool,
+    string_ref: bool,
+    sparse_options: bool,
+    deterministic: bool,
+    big_endian: bool,
+    cipher: Option<FieldCipher>,
 
     // Context-specific fields
     default_writer: Option<Writer<'a>>,
@@ -130,6 +136,7 @@ pub struct WriteContext<'a> {
     meta_resolver: MetaWriterResolver,
     meta_string_resolver: MetaStringWriterResolver,
     pub ref_writer: RefWriter,
+    string_refs: HashMap<String, u32>,
 }
 
 #[allow(clippy::needless_lifetimes)]
@@ -143,11 +150,17 @@ impl<'a> WriteContext<'a> {
             xlang: config.xlang,
             check_struct_version: config.check_struct_version,
             track_ref: config.track_ref,
+            string_ref: config.string_ref,
+            sparse_options: config.sparse_options,
+            deterministic: config.deterministic,
+            big_endian: config.endianness == Endianness::Big,
+            cipher: config.cipher,
             default_writer: None,
             writer: Writer::from_buffer(Self::get_leak_buffer()),
             meta_resolver: MetaWriterResolver::default(),
             meta_string_resolver: MetaStringWriterResolver::default(),
             ref_writer: RefWriter::new(),
+            string_refs: HashMap::new(),
         }
     }
 
@@ -215,6 +228,144 @@ impl<'a> WriteContext<'a> {
         self.track_ref
     }
 
+    /// Check if repeated strings are written as references
+    #[inline(always)]
+    pub fn is_string_ref(&self) -> bool {
+        self.string_ref
+    }
+
+    /// Check if `None` struct fields are left out of compatible payloads
+    #[inline(always)]
+    pub fn is_sparse_options(&self) -> bool {
+        self.sparse_options
+    }
+
+    /// Check if `HashMap` entries are written in a deterministic order
+    #[inline(always)]
+    pub fn is_deterministic(&self) -> bool {
+        self.deterministic
+    }
+
+    /// Returns the indices of `values` ordered by the bytes of their data.
+    ///
+    /// Each value is written on its own, as if it were the first in a fresh payload, and the
+    /// payload and the context's reference, meta and string tables are restored afterwards.
+    pub(crate) fn order_by_encoding<'v, T, I>(
+        &mut self,
+        values: I,
+        write: impl Fn(&T, &mut Self) -> Result<(), Error>,
+    ) -> Result<Vec<usize>, Error>
+    where
+        T: ?Sized + 'v,
+        I: Iterator<Item = &'v T>,
+    {
+        let start = self.writer.len();
+        let bounds = self.with_fresh_tables(|context| {
+            let mut bounds = Vec::new();
+            for value in values {
+                let offset = context.writer.len();
+                write(value, context)?;
+                bounds.push((offset, context.writer.len()));
+                context.reset();
+            }
+            Ok(bounds)
+        });
+        let bounds = match bounds {
+            Ok(bounds) => bounds,
+            Err(err) => {
+                self.writer.bf.truncate(start);
+                return Err(err);
+            }
+        };
+        let encoded = &self.writer.bf[..];
+        let mut order: Vec<usize> = (0..bounds.len()).collect();
+        order.sort_by(|&a, &b| {
+            let (a_start, a_end) = bounds[a];
+            let (b_start, b_end) = bounds[b];
+            encoded[a_start..a_end].cmp(&encoded[b_start..b_end])
+        });
+        self.writer.bf.truncate(start);
+        Ok(order)
+    }
+
+    /// Runs `write` with empty reference, meta and string tables, as if it started a fresh
+    /// payload, and restores the tables of the outer payload afterwards.
+    fn with_fresh_tables<R>(&mut self, write: impl FnOnce(&mut Self) -> R) -> R {
+        let meta_resolver = mem::take(&mut self.meta_resolver);
+        let meta_string_resolver = mem::take(&mut self.meta_string_resolver);
+        let ref_writer = mem::replace(&mut self.ref_writer, RefWriter::new());
+        let string_refs = mem::take(&mut self.string_refs);
+        let result = write(self);
+        self.meta_resolver = meta_resolver;
+        self.meta_string_resolver = meta_string_resolver;
+        self.ref_writer = ref_writer;
+        self.string_refs = string_refs;
+        result
+    }
+
+    /// Returns the index of `s` if an identical string was already written in this payload,
+    /// otherwise records it under the next index and returns `None`.
+    pub fn intern_string(&mut self, s: &str) -> Option<u32> {
+        if let Some(&index) = self.string_refs.get(s) {
+            return Some(index);
+        }
+        let index = self.string_refs.len() as u32;
+        self.string_refs.insert(s.to_owned(), index);
+        None
+    }
+
+    /// Check if fixed-width numbers are written big-endian. Always `false` without the
+    /// `big-endian` feature, so the little-endian path compiles without the check.
+    #[inline(always)]
+    pub fn is_big_endian(&self) -> bool {
+        cfg!(feature = "big-endian") && self.big_endian
+    }
+
+    /// Get the key for `#[fory(encrypt)]` fields
+    #[inline(always)]
+    pub fn get_cipher(&self) -> Option<&FieldCipher> {
+        self.cipher.as_ref()
+    }
+
+    /// Runs `write` and replaces what it wrote with its ciphertext bound to `aad`, prefixed
+    /// by its length.
+    ///
+    /// The plaintext has its own reference, meta and string tables, so a reader that skips
+    /// the ciphertext without decrypting it stays in step with the tables of the payload.
+    pub(crate) fn write_encrypted(
+        &mut self,
+        aad: &[u8],
+        write: impl FnOnce(&mut Self) -> Result<(), Error>,
+    ) -> Result<(), Error> {
+        let start = self.writer.len();
+        self.with_fresh_tables(write)?;
+        let plaintext = self.writer.bf.split_off(start);
+        let cipher = self.cipher.as_ref().ok_or_else(|| {
+            Error::not_allowed("#[fory(encrypt)] fields need a key set with ForyBuilder::cipher")
+        })?;
+        let ciphertext = cipher.encrypt(&plaintext, aad)?;
+        let len = u32::try_from(ciphertext.len()).map_err(|_| {
+            Error::encode_error(format!(
+                "encrypted field of {} bytes exceeds the u32 length prefix",
+                ciphertext.len()
+            ))
+        })?;
+        self.writer.write_var_u32(len);
+        self.writer.write_bytes(&ciphertext);
+        Ok(())
+    }
+
+    /// Writes a fixed-width number in the payload's byte order.
+    #[inline(always)]
+    pub fn write_fixed<T: ByteSwap>(&mut self, write: fn(&mut Writer<'a>, T), value: T) {
+        let value = if self.is_big_endian() {
+            value.reverse_bytes()
+        } else {
+            value
+        };
+        write(&mut self.writer, value);
+    }
+
     /// Write type meta inline using streaming protocol.
     /// Writes index marker with LSB indicating new type or reference.
     #[inline(always)]
@@ -326,6 +477,7 @@ impl<'a> WriteContext<'a> {
         self.meta_resolver.reset();
         self.meta_string_resolver.reset();
         self.ref_writer.reset();
+        self.string_refs.clear();
     }
 }
 
@@ -356,17 +508,26 @@ pub struct ReadContext<'a> {
     share_meta: bool,
     xlang: bool,
     max_dyn_depth: u32,
+    max_depth: u32,
     check_struct_version: bool,
     check_string_read: bool,
+    string_ref: bool,
+    sparse_options: bool,
+    big_endian: bool,
     max_binary_size: u32,
     max_collection_size: u32,
+    cipher: Option<FieldCipher>,
 
     // Context-specific fields
     pub reader: Reader<'a>,
     pub meta_resolver: MetaReaderResolver,
     meta_string_resolver: MetaStringReaderResolver,
     pub ref_reader: RefReader,
+    string_refs: Vec<String>,
+    projection: Option<Vec<String>>,
+    payload_version: Option<u32>,
     current_depth: u32,
+    nesting_depth: u32,
 }
 
 // Safety: ReadContext follows the same invariants as WriteContext—external orchestrators ensure
@@ -386,15 +547,24 @@ impl<'a> ReadContext<'a> {
             share_meta: config.share_meta,
             xlang: config.xlang,
             max_dyn_depth: config.max_dyn_depth,
+            max_depth: config.max_depth,
             check_struct_version: config.check_struct_version,
             check_string_read: config.check_string_read,
+            string_ref: config.string_ref,
+            sparse_options: config.sparse_options,
+            big_endian: false,
             max_binary_size: config.max_binary_size,
             max_collection_size: config.max_collection_size,
+            cipher: config.cipher,
             reader: Reader::default(),
             meta_resolver: MetaReaderResolver::default(),
             meta_string_resolver: MetaStringReaderResolver::default(),
             ref_reader: RefReader::new(),
+            string_refs: Vec::new(),
+            projection: None,
+            payload_version: None,
             current_depth: 0,
+            nesting_depth: 0,
         }
     }
 
@@ -434,12 +604,117 @@ impl<'a> ReadContext<'a> {
         self.check_string_read
     }
 
+    /// Check if strings in the payload being read can refer back to earlier ones
+    #[inline(always)]
+    pub fn is_string_ref(&self) -> bool {
+        self.string_ref
+    }
+
+    /// Sets whether the header of the payload being read marks string references.
+    #[inline(always)]
+    pub(crate) fn set_string_ref(&mut self, string_ref: bool) {
+        self.string_ref = string_ref;
+    }
+
+    /// Check if `None` struct fields are left out of the payload being read
+    #[inline(always)]
+    pub fn is_sparse_options(&self) -> bool {
+        self.sparse_options
+    }
+
+    /// Sets whether the header of the payload being read marks it as sparse.
+    #[inline(always)]
+    pub(crate) fn set_sparse_options(&mut self, sparse_options: bool) {
+        self.sparse_options = sparse_options;
+    }
+
+    /// Records a string read from the payload so later references can resolve to it.
+    pub fn record_string(&mut self, s: &str) {
+        self.string_refs.push(s.to_owned());
+    }
+
+    /// Resolves a reference to a string read earlier in this payload.
+    pub fn get_string_ref(&self, index: usize) -> Result<&String, Error> {
+        if !self.string_ref {
+            return Err(Error::invalid_data(
+                "string reference found in payload whose header doesn't mark string references",
+            ));
+        }
+        self.string_refs.get(index).ok_or_else(|| {
+            Error::invalid_data(format!(
+                "string reference {} out of range, {} strings read",
+                index,
+                self.string_refs.len()
+            ))
+        })
+    }
+
+    /// Restricts the next struct read to the fields named in `fields`.
+    #[inline(always)]
+    pub(crate) fn set_projection(&mut self, fields: Vec<String>) {
+        self.projection = Some(fields);
+    }
+
+    /// Takes the field projection set for this read, if any, so that it only applies to
+    /// the first struct read after it was set.
+    #[inline(always)]
+    pub fn take_projection(&mut self) -> Option<Vec<String>> {
+        self.projection.take()
+    }
+
+    /// Returns the schema version in the root header of the payload being read, which
+    /// payloads rooted at a `#[fory(version = N)]` struct carry.
+    #[inline(always)]
+    pub fn get_payload_version(&self) -> Option<u32> {
+        self.payload_version
+    }
+
+    /// Records the schema version read from the root header.
+    #[inline(always)]
+    pub(crate) fn set_payload_version(&mut self, version: Option<u32>) {
+        self.payload_version = version;
+    }
+
+    /// Check if the payload being read has big-endian fixed-width numbers. Always
+    /// `false` without the `big-endian` feature, so the little-endian path compiles
+    /// without the check.
+    #[inline(always)]
+    pub fn is_big_endian(&self) -> bool {
+        cfg!(feature = "big-endian") && self.big_endian
+    }
+
+    /// Sets the byte order recorded in the header of the payload being read.
+    #[inline(always)]
+    pub(crate) fn set_big_endian(&mut self, big_endian: bool) {
+        self.big_endian = big_endian;
+    }
+
+    /// Reads a fixed-width number in the payload's byte order.
+    #[inline(always)]
+    pub fn read_fixed<T: ByteSwap>(
+        &mut self,
+        read: fn(&mut Reader<'a>) -> Result<T, Error>,
+    ) -> Result<T, Error> {
+        let value = read(&mut self.reader)?;
+        Ok(if self.is_big_endian() {
+            value.reverse_bytes()
+        } else {
+            value
+        })
+    }
+
     /// Get maximum dynamic depth
     #[inline(always)]
     pub fn max_dyn_depth(&self) -> u32 {
         self.max_dyn_depth
     }
 
+    /// Get maximum struct and enum nesting depth
+    #[inline(always)]
+    pub fn max_depth(&self) -> u32 {
+        self.max_depth
+    }
+
     /// Get maximum allowed binary data size in bytes.
     #[inline(always)]
     pub fn max_binary_size(&self) -> u32 {
@@ -452,6 +727,83 @@ impl<'a> ReadContext<'a> {
         self.max_collection_size
     }
 
+    /// Get the key for `#[fory(encrypt)]` fields.
+    #[inline(always)]
+    pub fn get_cipher(&self) -> Option<&FieldCipher> {
+        self.cipher.as_ref()
+    }
+
+    /// Reads a length-prefixed ciphertext bound to `aad` and runs `read` over its
+    /// plaintext, which `read` must consume entirely. The plaintext is read with its own
+    /// reference, meta and string tables, as [`WriteContext::write_encrypted`] wrote it.
+    pub(crate) fn read_decrypted<T: 'static>(
+        &mut self,
+        aad: &[u8],
+        read: impl FnOnce(&mut ReadContext) -> Result<T, Error>,
+    ) -> Result<T, Error> {
+        let len = self.reader.read_var_u32()? as usize;
+        let ciphertext = self.reader.read_bytes(len)?;
+        let cipher = self.cipher.as_ref().ok_or_else(|| {
+            Error::decrypt_failed("payload has encrypted fields but no cipher is configured")
+        })?;
+        let plaintext = cipher.decrypt(ciphertext, aad)?;
+        let (result, consumed) =
+            self.with_scoped_reader(&plaintext, |context| context.with_fresh_tables(read));
+        let value = result?;
+        if consumed != plaintext.len() {
+            return Err(Error::invalid_data(format!(
+                "encrypted field has {} unread bytes",
+                plaintext.len() - consumed
+            )));
+        }
+        Ok(value)
+    }
+
+    /// Runs `read` with empty reference, meta and string tables and restores the tables of
+    /// the outer payload afterwards.
+    fn with_fresh_tables<R>(&mut self, read: impl FnOnce(&mut Self) -> R) -> R {
+        let meta_resolver = mem::take(&mut self.meta_resolver);
+        let meta_string_resolver = mem::take(&mut self.meta_string_resolver);
+        let ref_reader = mem::replace(&mut self.ref_reader, RefReader::new());
+        let string_refs = mem::take(&mut self.string_refs);
+        let result = read(self);
+        self.meta_resolver = meta_resolver;
+        self.meta_string_resolver = meta_string_resolver;
+        self.ref_reader = ref_reader;
+        self.string_refs = string_refs;
+        result
+    }
+
+    /// Runs `read` with the reader swapped for one over `bf`, which may be shorter-lived
+    /// than the payload, and puts the payload reader back afterwards, also on panic.
+    /// Returns the result of `read` and the number of bytes of `bf` it consumed.
+    pub(crate) fn with_scoped_reader<'b, R>(
+        &'b mut self,
+        bf: &'b [u8],
+        read: impl FnOnce(&mut ReadContext<'b>) -> R,
+    ) -> (R, usize) {
+        struct Restore<'r, 'b> {
+            context: &'r mut ReadContext<'b>,
+            outer: Reader<'b>,
+        }
+        impl Drop for Restore<'_, '_> {
+            fn drop(&mut self) {
+                mem::swap(&mut self.context.reader, &mut self.outer);
+            }
+        }
+        // Safety: `reader` is the only field that borrows the payload, so viewing the
+        // context with the shorter lifetime of `bf` can't leave a borrow of `bf` behind:
+        // `Restore` puts the payload reader back before `bf` can be dropped, and the
+        // result of `read` is tied to `bf` by the borrow checker.
+        let context = unsafe { &mut *(self as *mut ReadContext<'a>).cast::<ReadContext<'b>>() };
+        let outer = mem::replace(&mut context.reader, Reader::new(bf));
+        let restore = Restore { context, outer };
+        let result = read(restore.context);
+        let consumed = restore.context.reader.cursor;
+        drop(restore);
+        (result, consumed)
+    }
+
     #[inline(always)]
     pub fn attach_reader(&mut self, reader: Reader<'a>) {
         self.reader = reader;
@@ -490,7 +842,12 @@ impl<'a> ReadContext<'a> {
                 let user_type_id = self.reader.read_var_u32()?;
                 self.type_resolver
                     .get_user_type_info_by_id(user_type_id)
-                    .ok_or_else(|| Error::type_error("ID harness not found"))
+                    .ok_or_else(|| {
+                        Error::unknown_type_id(format!(
+                            "ID harness not found for user type id {}",
+                            user_type_id
+                        ))
+                    })
             }
             types::COMPATIBLE_STRUCT | types::NAMED_COMPATIBLE_STRUCT => {
                 // Read type meta inline using streaming protocol
@@ -514,7 +871,7 @@ impl<'a> ReadContext<'a> {
                             )
                         })
                         .ok_or_else(|| {
-                            Error::type_error(format!(
+                            Error::unknown_type_id(format!(
                                 "Name harness not found: namespace='{}', type='{}'",
                                 namespace.original, type_name.original
                             ))
@@ -524,7 +881,12 @@ impl<'a> ReadContext<'a> {
             _ => self
                 .type_resolver
                 .get_type_info_by_id(fory_type_id)
-                .ok_or_else(|| Error::type_error("ID harness not found")),
+                .ok_or_else(|| {
+                    Error::unknown_type_id(format!(
+                        "ID harness not found for type id {}",
+                        fory_type_id
+                    ))
+                }),
         }
     }
 
@@ -558,11 +920,36 @@ impl<'a> ReadContext<'a> {
         self.current_depth = self.current_depth.saturating_sub(1);
     }
 
+    /// Enters a struct or enum value. Recursive types nest only through these values, so
+    /// bounding them keeps a crafted payload from overflowing the stack.
+    #[inline(always)]
+    pub fn inc_nesting_depth(&mut self) -> Result<(), Error> {
+        self.nesting_depth += 1;
+        if self.nesting_depth > self.max_depth {
+            return Err(Error::depth_exceed(format!(
+                "Maximum struct nesting depth ({}) exceeded. Current depth: {}. \
+                    Consider increasing max_depth if this is expected.",
+                self.max_depth, self.nesting_depth
+            )));
+        }
+        Ok(())
+    }
+
+    #[inline(always)]
+    pub fn dec_nesting_depth(&mut self) {
+        self.nesting_depth = self.nesting_depth.saturating_sub(1);
+    }
+
     #[inline(always)]
     pub fn reset(&mut self) {
         self.meta_resolver.reset();
         self.meta_string_resolver.reset();
         self.ref_reader.reset();
+        self.string_refs.clear();
+        self.projection = None;
+        self.payload_version = None;
+        self.big_endian = false;
         self.current_depth = 0;
+        self.nesting_depth = 0;
     }
 }
@@ -99,6 +99,26 @@ pub const fn should_panic_on_error() -> bool {
 /// - [`Error::unsupported`] - For unsupported operations
 /// - [`Error::not_allowed`] - For disallowed operations
 /// - [`Error::unknown`] - For generic errors
+/// - [`Error::size_limit_exceeded`] - For payload lengths above configured limits
+/// - [`Error::trailing_bytes`] - For unread input after a strict deserialization
+/// - [`Error::checksum_mismatch`] - For payloads whose checksum footer doesn't match
+/// - [`Error::incomplete`] - For length-prefixed frames not yet fully received
+/// - [`Error::malformed_varint`] - For variable-length integers that overflow their width
+/// - [`Error::unknown_type_id`] - For payload type ids or names that aren't registered
+/// - [`Error::utf8`] - For string bytes that aren't valid UTF-8
+/// - [`Error::version_too_old`] - For payload versions below the root type's minimum
+/// - [`Error::decrypt_failed`] - For encrypted fields that can't be decrypted
+///
+/// ## Matching on Failure Modes
+///
+/// Callers that need to react to a specific failure can match on the variant:
+///
+/// - A truncated buffer returns [`Error::BufferOutOfBound`]
+/// - A payload whose type id differs from the requested type returns [`Error::TypeMismatch`]
+/// - A payload type that isn't registered returns [`Error::UnknownTypeId`]
+/// - Invalid UTF-8 string bytes return [`Error::Utf8`]
+/// - A collection, map or binary length above the configured limit returns
+///   [`Error::SizeLimitExceeded`]
 ///
 /// ## Debug Mode: FORY_PANIC_ON_ERROR
 ///
@@ -205,6 +225,69 @@ pub enum Error {
     /// Do not construct this variant directly; use [`Error::size_limit_exceeded`] instead.
     #[error("{0}")]
     SizeLimitExceeded(Cow<'static, str>),
+
+    /// Input bytes left over after a strict deserialization.
+    ///
+    /// Holds the number of unread bytes.
+    ///
+    /// Do not construct this variant directly; use [`Error::trailing_bytes`] instead.
+    #[error("{0} trailing bytes after deserialized value")]
+    TrailingBytes(usize),
+
+    /// Payload checksum footer doesn't match the payload.
+    ///
+    /// Holds the checksum stored in the footer and the one computed from the payload.
+    ///
+    /// Do not construct this variant directly; use [`Error::checksum_mismatch`] instead.
+    #[error("checksum mismatch: expected {0:#010x}, computed {1:#010x}")]
+    ChecksumMismatch(u32, u32),
+
+    /// Buffer ends before the length-prefixed frame it starts with.
+    ///
+    /// Holds the minimum number of additional bytes needed; wait for more input and retry.
+    ///
+    /// Do not construct this variant directly; use [`Error::incomplete`] instead.
+    #[error("incomplete frame: at least {0} more bytes needed")]
+    Incomplete(usize),
+
+    /// Variable-length integer whose encoding doesn't fit its declared width.
+    ///
+    /// Do not construct this variant directly; use [`Error::malformed_varint`] instead.
+    #[error("malformed varint: {0}")]
+    MalformedVarint(Cow<'static, str>),
+
+    /// Payload references a type id or name that isn't registered on this instance.
+    ///
+    /// Do not construct this variant directly; use [`Error::unknown_type_id`] instead.
+    #[error("{0}")]
+    UnknownTypeId(Cow<'static, str>),
+
+    /// String bytes in the payload are not valid UTF-8.
+    ///
+    /// Do not construct this variant directly; use [`Error::utf8`] instead.
+    #[error("{0}")]
+    Utf8(Cow<'static, str>),
+
+    /// Payload schema version is older than the root type accepts.
+    ///
+    /// Holds the payload version and the minimum version set with `#[fory(min_version = N)]`.
+    ///
+    /// Do not construct this variant directly; use [`Error::version_too_old`] instead.
+    #[error("payload version {0} is older than the minimum supported version {1}")]
+    VersionTooOld(u32, u32),
+
+    /// Encrypted field could not be decrypted, because the key is wrong, the ciphertext was
+    /// altered, or no cipher is configured.
+    ///
+    /// Do not construct this variant directly; use [`Error::decrypt_failed`] instead.
+    #[error("{0}")]
+    DecryptFailed(Cow<'static, str>),
+
+    /// Path can't be written because it is not valid UTF-8.
+    ///
+    /// Do not construct this variant directly; use [`Error::invalid_path`] instead.
+    #[error("{0}")]
+    InvalidPath(Cow<'static, str>),
 }
 
 impl Error {
@@ -525,6 +608,195 @@ impl Error {
         err
     }
 
+    /// Creates a new [`Error::TrailingBytes`] with the number of unread bytes.
+    ///
+    /// If `FORY_PANIC_ON_ERROR` environment variable is set, this will panic with the error message.
+    ///
+    /// # Example
+    /// ```
+    /// use fory_core::error::Error;
+    ///
+    /// let err = Error::trailing_bytes(3);
+    /// ```
+    #[inline(always)]
+    #[cold]
+    #[track_caller]
+    pub fn trailing_bytes(remaining: usize) -> Self {
+        let err = Error::TrailingBytes(remaining);
+        if PANIC_ON_ERROR {
+            panic!("FORY_PANIC_ON_ERROR: {}", err);
+        }
+        err
+    }
+
+    /// Creates a new [`Error::ChecksumMismatch`] with the stored and computed checksums.
+    ///
+    /// If `FORY_PANIC_ON_ERROR` environment variable is set, this will panic with the error message.
+    ///
+    /// # Example
+    /// ```
+    /// use fory_core::error::Error;
+    ///
+    /// let err = Error::checksum_mismatch(0xCBF4_3926, 0);
+    /// ```
+    #[inline(always)]
+    #[cold]
+    #[track_caller]
+    pub fn checksum_mismatch(expected: u32, actual: u32) -> Self {
+        let err = Error::ChecksumMismatch(expected, actual);
+        if PANIC_ON_ERROR {
+            panic!("FORY_PANIC_ON_ERROR: {}", err);
+        }
+        err
+    }
+
+    /// Creates a new [`Error::Incomplete`] with the number of bytes still missing.
+    ///
+    /// If `FORY_PANIC_ON_ERROR` environment variable is set, this will panic with the error message.
+    ///
+    /// # Example
+    /// ```
+    /// use fory_core::error::Error;
+    ///
+    /// let err = Error::incomplete(4);
+    /// ```
+    #[inline(always)]
+    #[cold]
+    #[track_caller]
+    pub fn incomplete(needed: usize) -> Self {
+        let err = Error::Incomplete(needed);
+        if PANIC_ON_ERROR {
+            panic!("FORY_PANIC_ON_ERROR: {}", err);
+        }
+        err
+    }
+
+    /// Creates a new [`Error::MalformedVarint`] from a string or static message.
+    ///
+    /// If `FORY_PANIC_ON_ERROR` environment variable is set, this will panic with the error message.
+    ///
+    /// # Example
+    /// ```
+    /// use fory_core::error::Error;
+    ///
+    /// let err = Error::malformed_varint("final byte 0x80 of var_uint32 sets bits beyond 32");
+    /// ```
+    #[inline(always)]
+    #[cold]
+    #[track_caller]
+    pub fn malformed_varint<S: Into<Cow<'static, str>>>(s: S) -> Self {
+        let err = Error::MalformedVarint(s.into());
+        if PANIC_ON_ERROR {
+            panic!("FORY_PANIC_ON_ERROR: {}", err);
+        }
+        err
+    }
+
+    /// Creates a new [`Error::UnknownTypeId`] from a string or static message.
+    ///
+    /// If `FORY_PANIC_ON_ERROR` environment variable is set, this will panic with the error message.
+    ///
+    /// # Example
+    /// ```
+    /// use fory_core::error::Error;
+    ///
+    /// let err = Error::unknown_type_id(format!("user type id {} is not registered", 100));
+    /// ```
+    #[inline(always)]
+    #[cold]
+    #[track_caller]
+    pub fn unknown_type_id<S: Into<Cow<'static, str>>>(s: S) -> Self {
+        let err = Error::UnknownTypeId(s.into());
+        if PANIC_ON_ERROR {
+            panic!("FORY_PANIC_ON_ERROR: {}", err);
+        }
+        err
+    }
+
+    /// Creates a new [`Error::Utf8`] from a string or static message.
+    ///
+    /// If `FORY_PANIC_ON_ERROR` environment variable is set, this will panic with the error message.
+    ///
+    /// # Example
+    /// ```
+    /// use fory_core::error::Error;
+    ///
+    /// let err = Error::utf8("invalid UTF-8 string");
+    /// ```
+    #[inline(always)]
+    #[cold]
+    #[track_caller]
+    pub fn utf8<S: Into<Cow<'static, str>>>(s: S) -> Self {
+        let err = Error::Utf8(s.into());
+        if PANIC_ON_ERROR {
+            panic!("FORY_PANIC_ON_ERROR: {}", err);
+        }
+        err
+    }
+
+    /// Creates a new [`Error::VersionTooOld`] with the payload version and the minimum accepted.
+    ///
+    /// If `FORY_PANIC_ON_ERROR` environment variable is set, this will panic with the error message.
+    ///
+    /// # Example
+    /// ```
+    /// use fory_core::error::Error;
+    ///
+    /// let err = Error::version_too_old(1, 3);
+    /// ```
+    #[inline(always)]
+    #[cold]
+    #[track_caller]
+    pub fn version_too_old(version: u32, min_version: u32) -> Self {
+        let err = Error::VersionTooOld(version, min_version);
+        if PANIC_ON_ERROR {
+            panic!("FORY_PANIC_ON_ERROR: {}", err);
+        }
+        err
+    }
+
+    /// Creates a new [`Error::DecryptFailed`] from a string or static message.
+    ///
+    /// If `FORY_PANIC_ON_ERROR` environment variable is set, this will panic with the error message.
+    ///
+    /// # Example
+    /// ```
+    /// use fory_core::error::Error;
+    ///
+    /// let err = Error::decrypt_failed("field 'ssn' failed authentication");
+    /// ```
+    #[inline(always)]
+    #[cold]
+    #[track_caller]
+    pub fn decrypt_failed<S: Into<Cow<'static, str>>>(s: S) -> Self {
+        let err = Error::DecryptFailed(s.into());
+        if PANIC_ON_ERROR {
+            panic!("FORY_PANIC_ON_ERROR: {}", err);
+        }
+        err
+    }
+
+    /// Creates a new [`Error::InvalidPath`] from a string or static message.
+    ///
+    /// If `FORY_PANIC_ON_ERROR` environment variable is set, this will panic with the error message.
+    ///
+    /// # Example
+    /// ```
+    /// use fory_core::error::Error;
+    ///
+    /// let err = Error::invalid_path("path is not valid UTF-8");
+    /// ```
+    #[inline(always)]
+    #[cold]
+    #[track_caller]
+    pub fn invalid_path<S: Into<Cow<'static, str>>>(s: S) -> Self {
+        let err = Error::InvalidPath(s.into());
+        if PANIC_ON_ERROR {
+            panic!("FORY_PANIC_ON_ERROR: {}", err);
+        }
+        err
+    }
+
     /// Enhances a [`Error::TypeError`] with additional type name information.
     ///
     /// If the error is a `TypeError`, appends the type name to the message.
@@ -552,6 +824,22 @@ impl Error {
     }
 }
 
+impl From<std::str::Utf8Error> for Error {
+    #[cold]
+    #[track_caller]
+    fn from(err: std::str::Utf8Error) -> Self {
+        Error::utf8(format!("invalid UTF-8 string: {err}"))
+    }
+}
+
+impl From<std::string::FromUtf8Error> for Error {
+    #[cold]
+    #[track_caller]
+    fn from(err: std::string::FromUtf8Error) -> Self {
+        Error::utf8(format!("invalid UTF-8 string: {}", err.utf8_error()))
+    }
+}
+
 #[cold]
 #[inline(never)]
 pub(crate) fn unsupported_send_sync_type<T>() -> Error
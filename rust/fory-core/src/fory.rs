@@ -16,20 +16,30 @@
 // under the License.
 
 use crate::buffer::{Reader, Writer};
-use crate::config::Config;
+use crate::config::{Checksum, Compression, Config, Endianness, SchemaMode};
 use crate::context::{ContextCache, ReadContext, WriteContext};
 use crate::ensure;
 use crate::error::Error;
-use crate::resolver::RefMode;
+use crate::meta::{CompatibilityReport, TypeMeta};
 use crate::resolver::TypeResolver;
-use crate::serializer::ForyDefault;
+use crate::resolver::{RefFlag, RefMode};
+use crate::serializer::external::ExternalSerializer;
+use crate::serializer::{diff, dump, FieldDiff};
+use crate::serializer::{
+    estimate_slice_size, write_slice, write_slice_type_info, BorrowDeserialize, ForyDefault,
+};
 use crate::serializer::{Serializer, StructSerializer};
-use crate::type_id::config_flags::{IS_CROSS_LANGUAGE_FLAG, IS_OUT_OF_BAND_FLAG};
-use crate::type_id::SIZE_OF_REF_AND_TYPE;
+use crate::type_id::config_flags::{
+    HAS_NATIVE_OPTIONS_FLAG, IS_BIG_ENDIAN_FLAG, IS_CHECKSUMMED_FLAG, IS_COMPRESSED_FLAG,
+    IS_CROSS_LANGUAGE_FLAG, IS_OUT_OF_BAND_FLAG, IS_VERSIONED_FLAG,
+};
+use crate::type_id::native_options::{SPARSE_OPTIONS_FLAG, STRING_REF_FLAG};
+use crate::type_id::{self, SIZE_OF_REF_AND_TYPE};
+use crate::util::{checksum, compression};
 use std::cell::UnsafeCell;
 use std::mem;
 use std::sync::atomic::{AtomicU64, Ordering};
-use std::sync::OnceLock;
+use std::sync::{Arc, OnceLock};
 
 /// Global counter to assign unique IDs to each Fory instance.
 static FORY_ID_COUNTER: AtomicU64 = AtomicU64::new(0);
@@ -105,6 +115,26 @@ impl ForyBuilder {
         self
     }
 
+    /// Selects whether payloads carry struct schemas.
+    ///
+    /// [`SchemaMode::None`] skips `TypeMeta` emission entirely and reads structs with the
+    /// locally registered schema, which gives the smallest payloads when both ends are built
+    /// from the same struct definitions. [`SchemaMode::Compatible`] is the default and
+    /// supports schema evolution. This is the same switch as
+    /// [`compatible`](Self::compatible), and the later call wins.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fory_core::{Fory, SchemaMode};
+    ///
+    /// let fory = Fory::builder().schema_mode(SchemaMode::None).build();
+    /// assert!(!fory.is_compatible());
+    /// ```
+    pub fn schema_mode(self, mode: SchemaMode) -> Self {
+        self.compatible(mode == SchemaMode::Compatible)
+    }
+
     /// Enables or disables xlang mode.
     ///
     /// # Arguments
@@ -192,6 +222,126 @@ impl ForyBuilder {
         self
     }
 
+    /// Trusts UTF-8 string payloads and builds `String`s without validating them.
+    ///
+    /// The name states the guarantee the caller makes: every UTF-8 string in every payload
+    /// this instance reads is valid. It removes the validation pass from string reads, which
+    /// is worthwhile when payloads come from a trusted producer. It is an alias of
+    /// [`check_string_read`](Self::check_string_read): `trust_utf8(true)` is the same as
+    /// `check_string_read(false)`.
+    ///
+    /// # Warning
+    ///
+    /// Never enable this for payloads from untrusted sources. Reading an invalid UTF-8
+    /// string produces a `String` that breaks its invariant, which is undefined behavior.
+    /// [`deserialize_borrowed`](Fory::deserialize_borrowed) still validates strings.
+    ///
+    /// # Default
+    ///
+    /// The default value is `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fory_core::Fory;
+    ///
+    /// let fory = Fory::builder().trust_utf8(true).build();
+    /// assert!(!fory.is_check_string_read());
+    /// ```
+    pub fn trust_utf8(mut self, trust_utf8: bool) -> Self {
+        self.config.check_string_read = !trust_utf8;
+        self
+    }
+
+    /// Enables or disables string references.
+    ///
+    /// When enabled, each distinct string is written once per payload and later identical
+    /// strings are written as a short back-reference to the first occurrence. This shrinks
+    /// payloads that repeat the same strings many times, such as map keys, at the cost of
+    /// hashing every string on write and keeping a copy of every string on read.
+    ///
+    /// The payload header records the option, so readers resolve string references whatever
+    /// their own setting. String references are a Rust native-mode feature; serializing with
+    /// them in xlang mode returns an error.
+    ///
+    /// # Default
+    ///
+    /// The default value is `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fory_core::Fory;
+    ///
+    /// let fory = Fory::builder().xlang(false).string_ref(true).build();
+    /// let keys = vec!["status".to_string(); 100];
+    /// let bytes = fory.serialize(&keys).unwrap();
+    /// assert_eq!(fory.deserialize::<Vec<String>>(&bytes).unwrap(), keys);
+    /// ```
+    pub fn string_ref(mut self, string_ref: bool) -> Self {
+        self.config.string_ref = string_ref;
+        self
+    }
+
+    /// Enables or disables sparse `Option` fields in compatible structs.
+    ///
+    /// When enabled, every struct written in compatible mode starts with a bitmap of its
+    /// absent fields, and fields that are `None` are left out of the payload. Readers rebuild
+    /// absent fields as `None`. This shrinks structs with many optional fields that are
+    /// mostly unset. Only the first 64 fields of a struct can be left out; later fields are
+    /// always written.
+    ///
+    /// The payload header records the option, so readers handle sparse payloads whatever
+    /// their own setting. It only applies in compatible mode and is ignored otherwise. Sparse
+    /// options are a Rust native-mode feature; serializing with them in xlang mode returns
+    /// an error.
+    ///
+    /// # Default
+    ///
+    /// The default value is `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fory_core::Fory;
+    ///
+    /// let fory = Fory::builder()
+    ///     .xlang(false)
+    ///     .compatible(true)
+    ///     .sparse_options(true)
+    ///     .build();
+    /// assert!(fory.is_sparse_options());
+    /// ```
+    pub fn sparse_options(mut self, sparse_options: bool) -> Self {
+        self.config.sparse_options = sparse_options;
+        self
+    }
+
+    /// Writes `HashMap` entries sorted by the bytes of their serialized keys.
+    ///
+    /// `HashMap` iteration order depends on the hasher's random state, so serializing equal
+    /// maps can produce different bytes. With this option the entries are ordered by key
+    /// encoding first, which makes the output reproducible for hashing, caching or
+    /// signatures. Only the order changes: the wire format stays the same and any reader can
+    /// decode the payload. Each key is encoded twice, so writing maps is slower.
+    ///
+    /// # Default
+    ///
+    /// The default value is `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fory_core::Fory;
+    ///
+    /// let fory = Fory::builder().deterministic(true).build();
+    /// assert!(fory.is_deterministic());
+    /// ```
+    pub fn deterministic(mut self, deterministic: bool) -> Self {
+        self.config.deterministic = deterministic;
+        self
+    }
+
     /// Enables or disables schema hash checking for same-schema payloads.
     ///
     /// # Arguments
@@ -298,6 +448,36 @@ impl ForyBuilder {
         self
     }
 
+    /// Sets the maximum nesting depth of struct and enum values during deserialization.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_depth` - The maximum number of struct or enum values that may be nested inside
+    ///   each other. Recursive types such as `struct Node { children: Vec<Node> }` can only
+    ///   nest through these values, so this bounds the recursion a payload can trigger.
+    ///   Exceeding it causes a `DepthExceed` error instead of a stack overflow.
+    ///
+    /// # Returns
+    ///
+    /// Returns `self` for method chaining.
+    ///
+    /// # Default
+    ///
+    /// The default value is `64`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fory_core::Fory;
+    ///
+    /// // Allow deeper trees for recursive data models
+    /// let fory = Fory::builder().max_depth(256).build();
+    /// ```
+    pub fn max_depth(mut self, max_depth: u32) -> Self {
+        self.config.max_depth = max_depth;
+        self
+    }
+
     /// Sets the maximum allowed size for binary data during deserialization.
     ///
     /// # Arguments
@@ -357,6 +537,121 @@ impl ForyBuilder {
         self
     }
 
+    /// Sets the compression applied to serialized payloads.
+    ///
+    /// The payload after the root header is compressed, and the header records
+    /// the algorithm, so any Fory instance built with the matching cargo feature
+    /// decompresses it transparently. Compression is only available in native
+    /// mode; serializing with compression in xlang mode returns an error.
+    /// Decompressed payloads are bounded by `max_binary_size`.
+    ///
+    /// # Default
+    ///
+    /// The default value is [`Compression::None`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust, ignore
+    /// use fory_core::{Compression, Fory};
+    ///
+    /// // Requires the `lz4` feature
+    /// let fory = Fory::builder().xlang(false).compression(Compression::Lz4).build();
+    /// ```
+    pub fn compression(mut self, compression: Compression) -> Self {
+        self.config.compression = compression;
+        self
+    }
+
+    /// Sets the integrity checksum appended to serialized payloads.
+    ///
+    /// With [`Checksum::Crc32`], a CRC-32 of the message is written as a footer
+    /// and the header records its presence, so any Fory instance verifies it
+    /// before decoding and returns [`Error::ChecksumMismatch`] on corruption.
+    /// Plain payloads are still accepted. The checksum covers the compressed
+    /// bytes when compression is also enabled. Checksums are only available in
+    /// native mode; serializing with a checksum in xlang mode returns an error.
+    ///
+    /// # Default
+    ///
+    /// The default value is [`Checksum::None`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fory_core::{Checksum, Error, Fory};
+    ///
+    /// let fory = Fory::builder().xlang(false).checksum(Checksum::Crc32).build();
+    /// let mut bytes = fory.serialize(&"data".to_string()).unwrap();
+    /// assert_eq!(fory.deserialize::<String>(&bytes).unwrap(), "data");
+    ///
+    /// bytes[3] ^= 1;
+    /// let err = fory.deserialize::<String>(&bytes).unwrap_err();
+    /// assert!(matches!(err, Error::ChecksumMismatch(..)));
+    /// ```
+    pub fn checksum(mut self, checksum: Checksum) -> Self {
+        self.config.checksum = checksum;
+        self
+    }
+
+    /// Sets the byte order of fixed-width numbers in serialized payloads.
+    ///
+    /// Fixed-width integers and floats, including `encoding = fixed` fields and
+    /// primitive arrays, are written in this order. Variable-length integers,
+    /// strings and type metadata keep their usual encoding. The header records
+    /// the order, so any Fory instance reads the payload regardless of its own
+    /// setting. Big-endian payloads are only available in native mode;
+    /// serializing with [`Endianness::Big`] in xlang mode returns an error.
+    ///
+    /// Requires the `big-endian` feature, which is also needed to read big-endian
+    /// payloads. Without it, fixed-width numbers are handled with no byte-order check.
+    ///
+    /// # Default
+    ///
+    /// The default value is [`Endianness::Little`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fory_core::{Endianness, Fory};
+    ///
+    /// let fory = Fory::builder().xlang(false).endianness(Endianness::Big).build();
+    /// let bytes = fory.serialize(&1.5f64).unwrap();
+    /// assert_eq!(Fory::builder().xlang(false).build().deserialize::<f64>(&bytes).unwrap(), 1.5);
+    /// ```
+    #[cfg(feature = "big-endian")]
+    pub fn endianness(mut self, endianness: Endianness) -> Self {
+        self.config.endianness = endianness;
+        self
+    }
+
+    /// Sets the AES-256-GCM key for fields marked `#[fory(encrypt)]`.
+    ///
+    /// Each encrypted field is written as a length-prefixed blob holding a random
+    /// nonce, the ciphertext of the field and its authentication tag; all other
+    /// fields stay in clear. Reading an encrypted field with a different key
+    /// returns [`Error::DecryptFailed`]. Writing an encrypted field without a key
+    /// returns an error. Requires the `encryption` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```rust, ignore
+    /// use fory::{Fory, ForyStruct};
+    ///
+    /// #[derive(ForyStruct)]
+    /// struct Patient {
+    ///     id: u64,
+    ///     #[fory(encrypt)]
+    ///     ssn: String,
+    /// }
+    ///
+    /// let fory = Fory::builder().xlang(false).cipher([7u8; 32]).build();
+    /// ```
+    #[cfg(feature = "encryption")]
+    pub fn cipher(mut self, key: [u8; 32]) -> Self {
+        self.config.cipher = Some(crate::util::cipher::FieldCipher::new(key));
+        self
+    }
+
     fn finish_config(self) -> Config {
         let mut config = self.config;
         if !self.compatible_set {
@@ -364,6 +659,7 @@ impl ForyBuilder {
             config.compatible = true;
             config.check_struct_version = false;
         }
+        config.sparse_options &= config.compatible;
         config
     }
 
@@ -426,7 +722,8 @@ pub struct Fory {
     config: Config,
     type_resolver: TypeResolver,
     /// Lazy-initialized final type resolver (thread-safe, one-time initialization).
-    final_type_resolver: OnceLock<Result<TypeResolver, Error>>,
+    /// Shared with instances created by [`Fory::clone_config`].
+    final_type_resolver: OnceLock<Result<Arc<TypeResolver>, Error>>,
 }
 
 impl Default for Fory {
@@ -481,6 +778,40 @@ impl Fory {
         self.config.check_string_read
     }
 
+    /// Returns whether repeated strings are written as references.
+    pub fn is_string_ref(&self) -> bool {
+        self.config.string_ref
+    }
+
+    /// Returns whether `None` struct fields are left out of compatible payloads.
+    pub fn is_sparse_options(&self) -> bool {
+        self.config.sparse_options
+    }
+
+    /// Returns whether `HashMap` entries are written in a deterministic order.
+    pub fn is_deterministic(&self) -> bool {
+        self.config.deterministic
+    }
+
+    /// Returns the integrity checksum appended to serialized payloads.
+    pub fn get_checksum(&self) -> Checksum {
+        self.config.checksum
+    }
+
+    /// Returns the byte order of fixed-width numbers in serialized payloads.
+    pub fn get_endianness(&self) -> Endianness {
+        self.config.endianness
+    }
+
+    /// Returns whether payloads carry struct schemas.
+    pub fn get_schema_mode(&self) -> SchemaMode {
+        if self.config.compatible {
+            SchemaMode::Compatible
+        } else {
+            SchemaMode::None
+        }
+    }
+
     /// Returns whether metadata sharing is enabled.
     ///
     /// # Returns
@@ -495,6 +826,11 @@ impl Fory {
         self.config.max_dyn_depth
     }
 
+    /// Returns the maximum nesting depth of struct and enum values during deserialization.
+    pub fn get_max_depth(&self) -> u32 {
+        self.config.max_depth
+    }
+
     /// Returns the maximum allowed binary data size in bytes.
     pub fn get_max_binary_size(&self) -> u32 {
         self.config.max_binary_size
@@ -519,6 +855,33 @@ impl Fory {
         &self.config
     }
 
+    /// Returns the schema hash of struct `T`, for fingerprinting and caching schemas.
+    ///
+    /// The hash is computed at compile time from the names (or ids) and types of the
+    /// struct's fields. It is the same in every process and build for the same definition
+    /// and changes when a field is added, removed, renamed or retyped. It does not depend
+    /// on the type's name or registration, and matches the version hash written when
+    /// `check_struct_version` is enabled. Enums return `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust, ignore
+    /// use fory_core::Fory;
+    /// use fory_derive::ForyStruct;
+    ///
+    /// #[derive(ForyStruct)]
+    /// struct Point {
+    ///     x: i32,
+    ///     y: i32,
+    /// }
+    ///
+    /// let fory = Fory::default();
+    /// let fingerprint = fory.type_hash::<Point>();
+    /// ```
+    pub fn type_hash<T: StructSerializer>(&self) -> u32 {
+        T::fory_struct_hash() as u32
+    }
+
     /// Checks whether the final type resolver has already been initialized.
     ///
     /// If it has, further type registrations would be silently ignored (the frozen
@@ -540,6 +903,38 @@ impl Fory {
         Ok(())
     }
 
+    /// Creates a new `Fory` with this instance's configuration and registered types.
+    ///
+    /// The registrations are frozen into a read-only snapshot that both instances share
+    /// through an `Arc`, so cloning does not repeat the `register` calls. Each clone keeps
+    /// its own thread-local context pools, which makes it a cheap handle to move into a
+    /// worker thread.
+    ///
+    /// Taking the snapshot ends the registration phase just like the first `serialize` or
+    /// `deserialize` call does: later `register*` calls on either instance return
+    /// [`Error::NotAllowed`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the registered types cannot be built into a type resolver.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fory_core::Fory;
+    ///
+    /// let fory = Fory::default();
+    /// let clone = fory.clone_config().unwrap();
+    /// let bytes = clone.serialize(&"shared".to_string()).unwrap();
+    /// assert_eq!(fory.deserialize::<String>(&bytes).unwrap(), "shared");
+    /// ```
+    pub fn clone_config(&self) -> Result<Fory, Error> {
+        let type_resolver = Arc::clone(self.get_shared_final_type_resolver()?);
+        let mut clone = Fory::from_config(self.config.clone());
+        clone.final_type_resolver = OnceLock::from(Ok(type_resolver));
+        Ok(clone)
+    }
+
     /// Serializes a value of type `T` into a byte vector.
     ///
     /// # Type Parameters
@@ -569,8 +964,11 @@ impl Fory {
     /// let bytes = fory.serialize(&point).unwrap();
     /// ```
     pub fn serialize<T: Serializer>(&self, record: &T) -> Result<Vec<u8>, Error> {
-        self.with_write_context(
-            |context| match self.serialize_with_context(record, context) {
+        self.with_write_context(|context| {
+            match self
+                .serialize_with_context(record, context)
+                .and_then(|_| self.finish_message(context.writer.bf, 0))
+            {
                 Ok(_) => {
                     let result = context.writer.dump();
                     context.writer.reset();
@@ -580,8 +978,58 @@ impl Fory {
                     context.writer.reset();
                     Err(err)
                 }
-            },
-        )
+            }
+        })
+    }
+
+    /// Serializes a slice with the wire format of `Vec<T>`, without copying it into a `Vec`.
+    ///
+    /// The output is byte-for-byte what [`serialize`](Self::serialize) produces for a
+    /// `Vec<T>` holding the same elements, so receivers deserialize it as a `Vec<T>`
+    /// (or any type that reads the `Vec<T>` format, such as `Box<[T]>`). There is no
+    /// matching read method, since a slice can't own the decoded elements.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fory_core::Fory;
+    ///
+    /// let fory = Fory::default();
+    /// let items = vec![1i32, 2, 3, 4];
+    /// let bytes = fory.serialize_slice(&items[1..]).unwrap();
+    /// let decoded: Vec<i32> = fory.deserialize(&bytes).unwrap();
+    /// assert_eq!(decoded, [2, 3, 4]);
+    /// ```
+    pub fn serialize_slice<T: Serializer>(&self, items: &[T]) -> Result<Vec<u8>, Error> {
+        self.with_write_context(|context| {
+            let result = self.serialize_slice_with_context(items, context);
+            context.reset();
+            match result.and_then(|_| self.finish_message(context.writer.bf, 0)) {
+                Ok(_) => {
+                    let result = context.writer.dump();
+                    context.writer.reset();
+                    Ok(result)
+                }
+                Err(err) => {
+                    context.writer.reset();
+                    Err(err)
+                }
+            }
+        })
+    }
+
+    /// Writes `items` as a root `Vec<T>`: `Vec<T>` keeps the default `fory_write`, which
+    /// writes a not-null flag and the type info before the data.
+    fn serialize_slice_with_context<T: Serializer>(
+        &self,
+        items: &[T],
+        context: &mut WriteContext,
+    ) -> Result<(), Error> {
+        self.check_write_config(None)?;
+        self.write_head_sized(estimate_slice_size(items), None, &mut context.writer);
+        context.writer.write_i8(RefFlag::NotNullValue as i8);
+        write_slice_type_info::<T>(context)?;
+        write_slice(items, context, false)
     }
 
     /// Serializes a value of type `T` into the provided byte buffer.
@@ -715,7 +1163,9 @@ impl Fory {
             // So it's safe to make buf live to the end of this method.
             let outlive_buffer = unsafe { mem::transmute::<&mut Vec<u8>, &mut Vec<u8>>(buf) };
             context.attach_writer(Writer::from_buffer(outlive_buffer));
-            let result = self.serialize_with_context(record, context);
+            let result = self
+                .serialize_with_context(record, context)
+                .and_then(|_| self.finish_message(context.writer.bf, start));
             let written_size = context.writer.len() - start;
             context.detach_writer();
             match result {
@@ -725,23 +1175,219 @@ impl Fory {
         })
     }
 
-    /// Gets the final type resolver, building it lazily on first access.
-    #[inline(always)]
-    fn get_final_type_resolver(&self) -> Result<&TypeResolver, Error> {
-        let result = self
-            .final_type_resolver
-            .get_or_init(|| self.type_resolver.build_final_type_resolver());
-        result
-            .as_ref()
-            .map_err(|e| Error::type_error(format!("Failed to build type resolver: {}", e)))
+    /// Serializes a value of type `T` into a caller-owned [`Writer`], replacing its contents.
+    ///
+    /// The writer is cleared before encoding, so its buffer capacity is reused across calls
+    /// and a hot loop allocates only until the buffer reaches its steady-state size.
+    ///
+    /// # Returns
+    ///
+    /// The serialized bytes, borrowed from the writer's buffer until the next call.
+    ///
+    /// # Examples
+    ///
+    /// ```rust, ignore
+    /// use fory_core::buffer::Writer;
+    /// use fory_core::Fory;
+    ///
+    /// let fory = Fory::default();
+    /// let mut buf = Vec::new();
+    /// let mut writer = Writer::from_buffer(&mut buf);
+    /// for i in 0..3 {
+    ///     let bytes = fory.serialize_into(&mut writer, &i).unwrap();
+    ///     assert_eq!(fory.deserialize::<i32>(bytes).unwrap(), i);
+    /// }
+    /// ```
+    pub fn serialize_into<'w, T: Serializer>(
+        &self,
+        writer: &'w mut Writer,
+        record: &T,
+    ) -> Result<&'w [u8], Error> {
+        writer.reset();
+        if let Err(err) = self.serialize_to(writer.bf, record) {
+            writer.reset();
+            return Err(err);
+        }
+        Ok(writer.bf.as_slice())
     }
 
-    /// Executes a closure with mutable access to a WriteContext for this Fory instance.
-    /// The context is stored in thread-local storage, eliminating all lock contention.
-    /// Uses fast path caching for O(1) access when using the same Fory instance repeatedly.
-    #[inline(always)]
-    fn with_write_context<R>(
-        &self,
+    /// Serializes a value of type `T` into an [`std::io::Write`] sink.
+    ///
+    /// The value is encoded into the reusable buffer of the thread-local write
+    /// context and then written to `writer` with a single `write_all`, so no
+    /// intermediate `Vec<u8>` is allocated per call. The whole payload is
+    /// buffered before writing because the encoder back-patches earlier bytes
+    /// (e.g. map chunk headers).
+    ///
+    /// # Returns
+    ///
+    /// The number of bytes written to `writer` on success. I/O failures are
+    /// reported as [`Error::EncodeError`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust, ignore
+    /// use fory_core::Fory;
+    /// use std::fs::File;
+    ///
+    /// let fory = Fory::default();
+    /// let mut file = File::create("value.bin").unwrap();
+    /// let bytes_written = fory.serialize_to_writer(&mut file, &vec![1, 2, 3]).unwrap();
+    /// ```
+    pub fn serialize_to_writer<T: Serializer, W: std::io::Write>(
+        &self,
+        writer: &mut W,
+        record: &T,
+    ) -> Result<usize, Error> {
+        self.with_write_context(|context| {
+            let result = self
+                .serialize_with_context(record, context)
+                .and_then(|_| self.finish_message(context.writer.bf, 0))
+                .and_then(|_| {
+                    writer.write_all(context.writer.bf).map_err(|e| {
+                        Error::encode_error(format!("failed to write serialized data: {e}"))
+                    })
+                })
+                .map(|_| context.writer.len());
+            context.writer.reset();
+            result
+        })
+    }
+
+    /// Returns the exact number of bytes [`serialize`](Self::serialize) would produce for
+    /// `record`, without handing out an output buffer.
+    ///
+    /// The value is encoded into the reusable buffer of the thread-local write context,
+    /// which is cleared again before returning, so steady-state calls do not allocate. A
+    /// count-only writer can't give the exact size: the encoder back-patches earlier bytes
+    /// and compression and checksums run over the encoded payload.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fory_core::Fory;
+    ///
+    /// let fory = Fory::default();
+    /// let value = vec![1, 2, 3];
+    /// let size = fory.serialized_size(&value).unwrap();
+    /// assert_eq!(size, fory.serialize(&value).unwrap().len());
+    /// ```
+    pub fn serialized_size<T: Serializer>(&self, record: &T) -> Result<usize, Error> {
+        self.with_write_context(|context| {
+            let result = self
+                .serialize_with_context(record, context)
+                .and_then(|_| self.finish_message(context.writer.bf, 0))
+                .map(|_| context.writer.len());
+            context.writer.reset();
+            result
+        })
+    }
+
+    /// Serializes a value of type `T` as a self-delimiting frame.
+    ///
+    /// The frame is a var-uint32 payload length followed by the payload produced by
+    /// [`serialize`](Self::serialize), so frames can be concatenated on a byte stream and
+    /// split again with [`deserialize_framed`](Self::deserialize_framed). Payloads that
+    /// don't fit the prefix, 4 GiB and over, return [`Error::EncodeError`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fory_core::Fory;
+    ///
+    /// let fory = Fory::default();
+    /// let frame = fory.serialize_framed(&42i32).unwrap();
+    /// let (value, consumed) = fory.deserialize_framed::<i32>(&frame).unwrap();
+    /// assert_eq!(value, 42);
+    /// assert_eq!(consumed, frame.len());
+    /// ```
+    pub fn serialize_framed<T: Serializer>(&self, record: &T) -> Result<Vec<u8>, Error> {
+        let payload = self.serialize(record)?;
+        let len = u32::try_from(payload.len()).map_err(|_| {
+            Error::encode_error(format!(
+                "payload of {} bytes exceeds the u32 frame length prefix",
+                payload.len()
+            ))
+        })?;
+        let mut frame = Vec::with_capacity(5 + payload.len());
+        Writer::from_buffer(&mut frame).write_var_u32(len);
+        frame.extend_from_slice(&payload);
+        Ok(frame)
+    }
+
+    /// Serializes a value of type `T` as one length-prefixed frame on an async sink.
+    ///
+    /// The frame is the one built by [`serialize_framed`](Self::serialize_framed). Encoding
+    /// stays synchronous and finishes before the first write, so the thread-local context
+    /// is never held across an `.await`; only the writes to `writer` are async. Read frames
+    /// back with [`deserialize_async`](Self::deserialize_async).
+    ///
+    /// # Returns
+    ///
+    /// The number of bytes written to `writer`, prefix included. I/O failures are
+    /// reported as [`Error::EncodeError`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust, ignore
+    /// use fory_core::Fory;
+    ///
+    /// let fory = Fory::default();
+    /// let (mut client, mut server) = tokio::io::duplex(1024);
+    /// fory.serialize_async(&mut client, &vec![1, 2, 3]).await.unwrap();
+    /// let value: Vec<i32> = fory.deserialize_async(&mut server).await.unwrap();
+    /// ```
+    #[cfg(feature = "tokio")]
+    pub async fn serialize_async<T, W>(&self, writer: &mut W, record: &T) -> Result<usize, Error>
+    where
+        T: Serializer,
+        W: tokio::io::AsyncWrite + Unpin,
+    {
+        use tokio::io::AsyncWriteExt;
+
+        let frame = self.serialize_framed(record)?;
+        let write_frame = async {
+            writer.write_all(&frame).await?;
+            writer.flush().await
+        };
+        write_frame
+            .await
+            .map_err(|e| Error::encode_error(format!("failed to write serialized data: {e}")))?;
+        Ok(frame.len())
+    }
+
+    /// Compresses and then checksums the message that starts at `buf[start]`, as
+    /// configured.
+    #[inline(always)]
+    fn finish_message(&self, buf: &mut Vec<u8>, start: usize) -> Result<(), Error> {
+        compression::compress_message(self.config.compression, buf, start)?;
+        checksum::checksum_message(self.config.checksum, buf, start)
+    }
+
+    /// Gets the final type resolver, building it lazily on first access.
+    #[inline(always)]
+    fn get_final_type_resolver(&self) -> Result<&TypeResolver, Error> {
+        self.get_shared_final_type_resolver()
+            .map(|type_resolver| type_resolver.as_ref())
+    }
+
+    /// Gets the `Arc` holding the final type resolver, building it lazily on first access.
+    #[inline(always)]
+    fn get_shared_final_type_resolver(&self) -> Result<&Arc<TypeResolver>, Error> {
+        let result = self
+            .final_type_resolver
+            .get_or_init(|| self.type_resolver.build_final_type_resolver().map(Arc::new));
+        result
+            .as_ref()
+            .map_err(|e| Error::type_error(format!("Failed to build type resolver: {}", e)))
+    }
+
+    /// Executes a closure with mutable access to a WriteContext for this Fory instance.
+    /// The context is stored in thread-local storage, eliminating all lock contention.
+    /// Uses fast path caching for O(1) access when using the same Fory instance repeatedly.
+    #[inline(always)]
+    fn with_write_context<R>(
+        &self,
         f: impl FnOnce(&mut WriteContext) -> Result<R, Error>,
     ) -> Result<R, Error> {
         // SAFETY: Thread-local storage is only accessed from the current thread.
@@ -754,7 +1400,7 @@ impl Fory {
             let context = cache.get_or_insert_result(id, || {
                 // Only fetch type resolver when creating a new context
                 let type_resolver = self.get_final_type_resolver()?;
-                Ok(Box::new(WriteContext::new(
+                Ok::<_, Error>(Box::new(WriteContext::new(
                     type_resolver.clone(),
                     self.config.clone(),
                 )))
@@ -781,7 +1427,8 @@ impl Fory {
         record: &T,
         context: &mut WriteContext,
     ) -> Result<(), Error> {
-        self.write_head::<T>(&mut context.writer);
+        self.check_write_config(T::fory_version())?;
+        self.write_head_for(record, &mut context.writer);
         // Use RefMode based on config:
         // - If track_ref is enabled, use RefMode::Tracking for the root object
         // - Otherwise, use RefMode::NullOnly which writes NOT_NULL_VALUE_FLAG
@@ -795,6 +1442,29 @@ impl Fory {
         Ok(())
     }
 
+    /// Rejects native-only options in xlang mode. `version` is the root type's
+    /// [`Serializer::fory_version`].
+    #[inline(always)]
+    fn check_write_config(&self, version: Option<u32>) -> Result<(), Error> {
+        ensure!(
+            !(self.config.string_ref && self.config.xlang),
+            Error::not_allowed("string_ref is only supported in Rust native mode")
+        );
+        ensure!(
+            !(self.config.sparse_options && self.config.xlang),
+            Error::not_allowed("sparse_options is only supported in Rust native mode")
+        );
+        ensure!(
+            !(self.config.endianness == Endianness::Big && self.config.xlang),
+            Error::not_allowed("big-endian payloads are only supported in Rust native mode")
+        );
+        ensure!(
+            !(version.is_some() && self.config.xlang),
+            Error::not_allowed("versioned structs are only supported in Rust native mode")
+        );
+        Ok(())
+    }
+
     /// Registers a struct type with a numeric type ID for serialization.
     ///
     /// # Type Parameters
@@ -830,6 +1500,72 @@ impl Fory {
         self.type_resolver.register::<T>(id)
     }
 
+    /// Registers an additional numeric type ID that deserializes as an already registered type.
+    ///
+    /// Payloads tagged with `id` are read as `T`, while `T` is still written with the ID it was
+    /// registered with. This lets one process read the same struct from peers that assign it
+    /// different IDs.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The extra type ID to accept on the read path.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `T` has not been registered by ID, or if `id` is already in use.
+    ///
+    /// # Examples
+    ///
+    /// ```rust, ignore
+    /// use fory::{Fory, ForyStruct};
+    ///
+    /// #[derive(ForyStruct)]
+    /// struct Order { id: u64 }
+    ///
+    /// let mut fory = Fory::default();
+    /// fory.register::<Order>(999).unwrap();
+    /// fory.register_alias::<Order>(1001).unwrap();
+    /// ```
+    pub fn register_alias<T: 'static + Serializer>(&mut self, id: u32) -> Result<(), Error> {
+        self.check_registration_allowed()?;
+        self.type_resolver.register_alias::<T>(id)
+    }
+
+    /// Registers a `ForyEnum` type with a numeric type ID.
+    ///
+    /// Behaves like [`register`](Self::register) but fails if `T` is not an enum, which keeps
+    /// enum registrations apart from struct ones. Derive with `#[fory(stable_ordinals)]` to
+    /// encode variants by their explicit discriminants so that adding a variant never shifts
+    /// the ordinals of existing ones.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `T` is not an enum or if `id` is already in use.
+    ///
+    /// # Examples
+    ///
+    /// ```rust, ignore
+    /// use fory::{Fory, ForyEnum};
+    ///
+    /// #[derive(ForyEnum, Default)]
+    /// #[fory(stable_ordinals)]
+    /// enum Status {
+    ///     #[default]
+    ///     Active = 0,
+    ///     Archived = 1,
+    /// }
+    ///
+    /// let mut fory = Fory::default();
+    /// fory.register_enum::<Status>(100).unwrap();
+    /// ```
+    pub fn register_enum<T: 'static + StructSerializer + Serializer + ForyDefault>(
+        &mut self,
+        id: u32,
+    ) -> Result<(), Error> {
+        self.check_registration_allowed()?;
+        self.type_resolver.register_enum::<T>(id)
+    }
+
     /// Register a union type with a numeric type ID.
     ///
     /// This is intended for union-compatible enums generated by the compiler.
@@ -951,17 +1687,95 @@ impl Fory {
         self.type_resolver.register_serializer_by_name::<T>(name)
     }
 
+    /// Registers closures that serialize a type which can't implement `Serializer`.
+    ///
+    /// The orphan rule prevents implementing [`Serializer`] for types from other crates.
+    /// This stores `write_fn` and `read_fn` in the type resolver instead, and registers
+    /// `T` as an ext type under `id`. `write_fn` writes the value's data and `read_fn`
+    /// reads it back; ref flags and type info are handled by Fory.
+    ///
+    /// Derived structs serialize fields marked `#[fory(external)]` through the registered
+    /// closures, looking them up at runtime. Elsewhere, wrap values in [`External`](crate::External).
+    /// `T` must implement `Default`, which is used when a field is missing from the payload.
+    ///
+    /// # Examples
+    ///
+    /// ```rust, ignore
+    /// use fory::{External, Fory, ForyStruct};
+    /// use geo::Point;
+    ///
+    /// #[derive(ForyStruct)]
+    /// struct Place {
+    ///     name: String,
+    ///     #[fory(external)]
+    ///     location: Point,
+    /// }
+    ///
+    /// let mut fory = Fory::builder().xlang(false).build();
+    /// fory.register_external::<Point>(
+    ///     200,
+    ///     |point, context| {
+    ///         context.writer.write_f64(point.x());
+    ///         context.writer.write_f64(point.y());
+    ///         Ok(())
+    ///     },
+    ///     |context| Ok(Point::new(context.reader.read_f64()?, context.reader.read_f64()?)),
+    /// )?;
+    /// fory.register::<Place>(201)?;
+    ///
+    /// let origin = fory.serialize(&External(Point::new(0.0, 0.0)))?;
+    /// ```
+    pub fn register_external<T: 'static + Default>(
+        &mut self,
+        id: u32,
+        write_fn: impl Fn(&T, &mut WriteContext) -> Result<(), Error> + Send + Sync + 'static,
+        read_fn: impl Fn(&mut ReadContext) -> Result<T, Error> + Send + Sync + 'static,
+    ) -> Result<(), Error> {
+        self.check_registration_allowed()?;
+        self.type_resolver.register_external::<T>(
+            id,
+            ExternalSerializer {
+                write: Box::new(write_fn),
+                read: Box::new(read_fn),
+            },
+        )
+    }
+
     /// Writes the serialization header to the writer.
     #[inline(always)]
     pub fn write_head<T: Serializer>(&self, writer: &mut Writer) {
+        self.write_head_sized(T::fory_reserved_space(), T::fory_version(), writer);
+    }
+
+    /// Writes the serialization header for `record` to the writer.
+    ///
+    /// Unlike [`Fory::write_head`], the writer is reserved for the whole message, sized
+    /// from [`Serializer::fory_estimate_size`] of `record`, so values with long strings or
+    /// collections are written without growing the buffer mid-write.
+    #[inline(always)]
+    pub fn write_head_for<T: Serializer>(&self, record: &T, writer: &mut Writer) {
+        self.write_head_sized(record.fory_estimate_size(), T::fory_version(), writer);
+    }
+
+    #[inline(always)]
+    fn write_head_sized(&self, estimate: usize, version: Option<u32>, writer: &mut Writer) {
         const HEAD_SIZE: usize = 10;
-        writer.reserve(T::fory_reserved_space() + SIZE_OF_REF_AND_TYPE + HEAD_SIZE);
-        let bitmap = if self.config.xlang {
-            IS_CROSS_LANGUAGE_FLAG
-        } else {
-            0
-        };
+        writer.reserve(estimate + SIZE_OF_REF_AND_TYPE + HEAD_SIZE);
+        let mut bitmap = self.mode_bitmap();
+        if self.config.endianness == Endianness::Big {
+            bitmap |= IS_BIG_ENDIAN_FLAG;
+        }
+        if version.is_some() {
+            bitmap |= IS_VERSIONED_FLAG;
+        }
         writer.write_u8(bitmap);
+        let options = self.native_options();
+        if options != 0 {
+            writer.write_u8(options);
+        }
+        if let Some(version) = version {
+            writer.write_var_u32(version);
+        }
     }
 
     /// Deserializes data from a byte slice into a value of type `T`.
@@ -1073,6 +1887,560 @@ impl Fory {
         })
     }
 
+    /// Deserializes a value from the front of `bf` and returns it together with
+    /// the number of bytes consumed.
+    ///
+    /// Use this to walk a buffer of concatenated messages without managing a
+    /// [`Reader`] yourself; see [`deserialize_from`](Self::deserialize_from).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fory_core::Fory;
+    ///
+    /// let fory = Fory::default();
+    /// let mut buf = Vec::new();
+    /// fory.serialize_to(&mut buf, &1i32).unwrap();
+    /// fory.serialize_to(&mut buf, &2i32).unwrap();
+    ///
+    /// let (first, len) = fory.deserialize_with_len::<i32>(&buf).unwrap();
+    /// let (second, _) = fory.deserialize_with_len::<i32>(&buf[len..]).unwrap();
+    /// assert_eq!((first, second), (1, 2));
+    /// ```
+    pub fn deserialize_with_len<T: Serializer + ForyDefault>(
+        &self,
+        bf: &[u8],
+    ) -> Result<(T, usize), Error> {
+        let mut reader = Reader::new(bf);
+        let value = self.deserialize_from(&mut reader)?;
+        Ok((value, reader.get_cursor()))
+    }
+
+    /// Deserializes a value that must occupy all of `bf`.
+    ///
+    /// Unlike [`deserialize`](Self::deserialize), which ignores anything after the
+    /// value, this returns [`Error::TrailingBytes`] when bytes remain, surfacing
+    /// framing bugs where a buffer holds more than one message.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fory_core::{Error, Fory};
+    ///
+    /// let fory = Fory::default();
+    /// let mut buf = fory.serialize(&7i32).unwrap();
+    /// assert_eq!(fory.deserialize_strict::<i32>(&buf).unwrap(), 7);
+    ///
+    /// buf.push(0);
+    /// let err = fory.deserialize_strict::<i32>(&buf).unwrap_err();
+    /// assert!(matches!(err, Error::TrailingBytes(1)));
+    /// ```
+    pub fn deserialize_strict<T: Serializer + ForyDefault>(&self, bf: &[u8]) -> Result<T, Error> {
+        let (value, len) = self.deserialize_with_len(bf)?;
+        if len != bf.len() {
+            return Err(Error::trailing_bytes(bf.len() - len));
+        }
+        Ok(value)
+    }
+
+    /// Deserializes the frame at the start of `bf`, as written by
+    /// [`serialize_framed`](Self::serialize_framed).
+    ///
+    /// # Returns
+    ///
+    /// The value and the number of bytes the frame occupies, prefix included; anything
+    /// after that belongs to the next frame.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Incomplete`] with the number of missing bytes when `bf` ends inside
+    /// the frame, so a stream reader can buffer more input and retry. A frame longer than
+    /// `max_binary_size` returns [`Error::SizeLimitExceeded`] instead, before any of its
+    /// payload is needed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fory_core::{Error, Fory};
+    ///
+    /// let fory = Fory::default();
+    /// let mut stream = fory.serialize_framed(&1i32).unwrap();
+    /// stream.extend(fory.serialize_framed(&2i32).unwrap());
+    ///
+    /// let (first, used) = fory.deserialize_framed::<i32>(&stream).unwrap();
+    /// let (second, _) = fory.deserialize_framed::<i32>(&stream[used..]).unwrap();
+    /// assert_eq!((first, second), (1, 2));
+    ///
+    /// let err = fory.deserialize_framed::<i32>(&stream[..used - 1]).unwrap_err();
+    /// assert!(matches!(err, Error::Incomplete(1)));
+    /// ```
+    pub fn deserialize_framed<T: Serializer + ForyDefault>(
+        &self,
+        bf: &[u8],
+    ) -> Result<(T, usize), Error> {
+        let prefix_len = match bf.iter().take(5).position(|b| b & 0x80 == 0) {
+            Some(pos) => pos + 1,
+            None if bf.len() < 5 => return Err(Error::incomplete(1)),
+            None => 5,
+        };
+        let len = Reader::new(&bf[..prefix_len]).read_var_u32()? as usize;
+        self.check_frame_len(len)?;
+        let end = prefix_len + len;
+        if bf.len() < end {
+            return Err(Error::incomplete(end - bf.len()));
+        }
+        let value = self.deserialize(&bf[prefix_len..end])?;
+        Ok((value, end))
+    }
+
+    /// Reads one frame written by [`serialize_async`](Self::serialize_async) from an
+    /// async source and deserializes its payload.
+    ///
+    /// The payload is read in full before decoding, which then runs synchronously as in
+    /// [`deserialize`](Self::deserialize). Bytes after the frame are left in `reader`, so
+    /// consecutive calls read consecutive frames.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidData`] when reading fails or the source ends inside a
+    /// frame, and [`Error::SizeLimitExceeded`] when the frame is longer than
+    /// `max_binary_size`, besides any error from decoding the payload.
+    ///
+    /// # Examples
+    ///
+    /// See [`serialize_async`](Self::serialize_async).
+    #[cfg(feature = "tokio")]
+    pub async fn deserialize_async<T, R>(&self, reader: &mut R) -> Result<T, Error>
+    where
+        T: Serializer + ForyDefault,
+        R: tokio::io::AsyncRead + Unpin,
+    {
+        use tokio::io::AsyncReadExt;
+
+        let read_error =
+            |e: std::io::Error| Error::invalid_data(format!("failed to read serialized data: {e}"));
+        let mut prefix = [0u8; 5];
+        let mut prefix_len = 0;
+        while prefix_len < prefix.len() {
+            let byte = reader.read_u8().await.map_err(read_error)?;
+            prefix[prefix_len] = byte;
+            prefix_len += 1;
+            if byte < 0x80 {
+                break;
+            }
+        }
+        let len = Reader::new(&prefix[..prefix_len]).read_var_u32()? as usize;
+        self.check_frame_len(len)?;
+        // Grow the buffer as bytes arrive instead of trusting the prefix for the allocation.
+        let mut payload = Vec::new();
+        reader
+            .take(len as u64)
+            .read_to_end(&mut payload)
+            .await
+            .map_err(read_error)?;
+        if payload.len() != len {
+            return Err(Error::invalid_data(format!(
+                "frame ended after {} of {len} payload bytes",
+                payload.len()
+            )));
+        }
+        self.deserialize(&payload)
+    }
+
+    /// Deserializes `bf` into an existing value instead of returning a new one.
+    ///
+    /// Derived structs are read field by field in place, so `Vec` and `HashMap` fields keep
+    /// their allocations and a hot loop decoding same-shaped messages stops allocating once
+    /// the buffers have grown. Types without an in-place reader are replaced by a freshly
+    /// read value. In compatible mode, payloads written with a different schema are also
+    /// read into a fresh value. If an error is returned, `target` is left in an
+    /// unspecified but valid state.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fory_core::Fory;
+    ///
+    /// let fory = Fory::default();
+    /// let mut values: Vec<i64> = Vec::with_capacity(16);
+    /// let ptr = values.as_ptr();
+    ///
+    /// fory.deserialize_into(&fory.serialize(&vec![1i64, 2, 3]).unwrap(), &mut values)
+    ///     .unwrap();
+    /// assert_eq!(values, [1, 2, 3]);
+    /// assert_eq!(values.as_ptr(), ptr);
+    /// ```
+    pub fn deserialize_into<T: Serializer + ForyDefault>(
+        &self,
+        bf: &[u8],
+        target: &mut T,
+    ) -> Result<(), Error> {
+        self.with_read_context(|context| {
+            let outlive_buffer = unsafe { mem::transmute::<&[u8], &[u8]>(bf) };
+            context.attach_reader(Reader::new(outlive_buffer));
+            let result = self.deserialize_into_with_context(context, target);
+            context.reset();
+            context.detach_reader();
+            result
+        })
+    }
+
+    /// Deserializes only the named fields of the root struct in `bf`.
+    ///
+    /// The payload's inline schema gives the type of every written field, so fields that are
+    /// not in `fields` are skipped over without being materialized. They keep their default
+    /// value in the returned struct, as do requested fields the payload doesn't contain.
+    /// Nested values of a requested field are read in full. Names are the Rust field names
+    /// of `T`, which are matched through their `#[fory(rename = "...")]` name if they have
+    /// one; an unknown name returns [`Error::TypeError`].
+    ///
+    /// Projection relies on the inline schema, so it requires compatible mode, and the root
+    /// value must be a struct rather than an enum.
+    ///
+    /// # Examples
+    ///
+    /// ```rust, ignore
+    /// use fory_core::Fory;
+    /// use fory_derive::ForyStruct;
+    ///
+    /// #[derive(ForyStruct, Debug, Default)]
+    /// struct Order {
+    ///     id: i64,
+    ///     customer: String,
+    ///     lines: Vec<String>,
+    /// }
+    ///
+    /// let mut fory = Fory::builder().compatible(true).build();
+    /// fory.register::<Order>(1).unwrap();
+    /// let order = Order { id: 7, customer: "ada".into(), lines: vec!["tea".into()] };
+    /// let bytes = fory.serialize(&order).unwrap();
+    ///
+    /// let projected: Order = fory.deserialize_projection(&bytes, &["id"]).unwrap();
+    /// assert_eq!(projected.id, 7);
+    /// assert!(projected.lines.is_empty());
+    /// ```
+    pub fn deserialize_projection<T: StructSerializer + ForyDefault>(
+        &self,
+        bf: &[u8],
+        fields: &[&str],
+    ) -> Result<T, Error> {
+        ensure!(
+            self.config.compatible,
+            Error::not_allowed("deserialize_projection requires compatible mode")
+        );
+        self.with_read_context(|context| {
+            let outlive_buffer = unsafe { mem::transmute::<&[u8], &[u8]>(bf) };
+            context.attach_reader(Reader::new(outlive_buffer));
+            let projection = fields
+                .iter()
+                .map(|name| T::fory_meta_field_name(name).unwrap_or(name).to_string())
+                .collect();
+            context.set_projection(projection);
+            let result = self.deserialize_with_context_inner::<T>(context);
+            // Only compatible struct reads take the projection; enum roots leave it unused.
+            let unprojected = context.take_projection().is_some();
+            context.reset();
+            context.detach_reader();
+            ensure!(
+                result.is_err() || !unprojected,
+                Error::type_error("only the fields of a struct root can be projected")
+            );
+            result
+        })
+    }
+
+    /// Deserializes a value and returns it with the schema version in the payload header.
+    ///
+    /// Payloads rooted at a struct annotated with `#[fory(version = N)]` carry `N` in their
+    /// header, and the reader gets it back regardless of its own version, so it can adapt to
+    /// payloads from newer or older writers. A payload older than the `#[fory(min_version = N)]`
+    /// of `T` returns [`Error::VersionTooOld`], as with [`deserialize`](Self::deserialize).
+    /// Payloads without a version return [`Error::InvalidData`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust, ignore
+    /// use fory_core::Fory;
+    /// use fory_derive::ForyStruct;
+    ///
+    /// #[derive(ForyStruct, Debug, PartialEq)]
+    /// #[fory(version = 3, min_version = 2)]
+    /// struct Settings {
+    ///     retries: u32,
+    /// }
+    ///
+    /// let mut fory = Fory::builder().xlang(false).build();
+    /// fory.register::<Settings>(1).unwrap();
+    /// let bytes = fory.serialize(&Settings { retries: 5 }).unwrap();
+    ///
+    /// let (settings, version) = fory.deserialize_versioned::<Settings>(&bytes).unwrap();
+    /// assert_eq!(settings.retries, 5);
+    /// assert_eq!(version, 3);
+    /// ```
+    pub fn deserialize_versioned<T: Serializer + ForyDefault>(
+        &self,
+        bf: &[u8],
+    ) -> Result<(T, u32), Error> {
+        self.with_read_context(|context| {
+            let outlive_buffer = unsafe { mem::transmute::<&[u8], &[u8]>(bf) };
+            context.attach_reader(Reader::new(outlive_buffer));
+            let result = self.deserialize_with_context_inner::<T>(context);
+            let version = context.get_payload_version();
+            context.reset();
+            context.detach_reader();
+            let value = result?;
+            let version =
+                version.ok_or_else(|| Error::invalid_data("payload carries no schema version"))?;
+            Ok((value, version))
+        })
+    }
+
+    /// Reads the [`TypeMeta`] of the root struct in `bf` without deserializing it.
+    ///
+    /// Compatible-mode payloads carry each struct's schema inline. This parses only the
+    /// root struct's schema (its user type id or name, and the name and type of every
+    /// written field), which helps diagnose fields that are skipped or defaulted when the
+    /// writer's and reader's structs diverge. Payloads whose root value carries no inline
+    /// schema, such as non-struct roots or structs written outside compatible mode, return
+    /// [`Error::TypeError`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust, ignore
+    /// use fory_core::Fory;
+    /// use fory_derive::ForyStruct;
+    ///
+    /// #[derive(ForyStruct)]
+    /// struct Point {
+    ///     x: i32,
+    ///     y: i32,
+    /// }
+    ///
+    /// let mut fory = Fory::builder().compatible(true).build();
+    /// fory.register::<Point>(1).unwrap();
+    /// let bytes = fory.serialize(&Point { x: 1, y: 2 }).unwrap();
+    ///
+    /// let meta = fory.peek_type_meta(&bytes).unwrap();
+    /// assert_eq!(meta.get_user_type_id(), 1);
+    /// let names: Vec<_> = meta.get_field_infos().iter().map(|f| f.field_name.as_str()).collect();
+    /// assert_eq!(names, ["x", "y"]);
+    /// ```
+    pub fn peek_type_meta(&self, bf: &[u8]) -> Result<TypeMeta, Error> {
+        let mut reader = Reader::new(bf);
+        if self.read_head(&mut reader)?.wrapped {
+            let message = self.unwrap_message(&mut reader)?;
+            return self.peek_type_meta(&message);
+        }
+        let ref_flag = reader.read_i8()?;
+        if ref_flag != RefFlag::NotNullValue as i8 && ref_flag != RefFlag::RefValue as i8 {
+            return Err(Error::invalid_data(format!(
+                "root value with ref flag {} has no type meta",
+                ref_flag
+            )));
+        }
+        let remote_type_id = reader.read_u8()? as u32;
+        let inline_meta = match remote_type_id {
+            type_id::COMPATIBLE_STRUCT | type_id::NAMED_COMPATIBLE_STRUCT => true,
+            type_id::NAMED_STRUCT
+            | type_id::NAMED_ENUM
+            | type_id::NAMED_EXT
+            | type_id::NAMED_UNION => self.config.share_meta,
+            _ => false,
+        };
+        ensure!(
+            inline_meta,
+            Error::type_error(format!(
+                "root value of type {} carries no inline type meta",
+                type_id::format_type_id(remote_type_id)
+            ))
+        );
+        // The root is the first type in the message, so it can't reference an earlier one.
+        let index_marker = reader.read_var_u32()?;
+        ensure!(
+            index_marker & 1 == 0,
+            Error::invalid_data("root type meta is encoded as a back reference")
+        );
+        TypeMeta::from_bytes(&mut reader, self.get_final_type_resolver()?)
+    }
+
+    /// Compares two versions of a struct schema field by field.
+    ///
+    /// The report lists fields only in `new_meta` as added, fields only in `old_meta` as
+    /// removed, and fields whose type changed as retyped. Fields are matched by name, or by
+    /// `#[fory(id = N)]` when the metadata was written without names. A retyped field is
+    /// flagged as breaking unless a reader using `new_meta` decodes every old value
+    /// unchanged, as with `i32` to `i64` or a field that becomes nullable. Conversions
+    /// that compatible mode would attempt but that can fail or alter the value, such as
+    /// `i64` to `String`, count as breaking.
+    ///
+    /// Metadata usually comes from [`Fory::peek_type_meta`] on a payload written by each
+    /// schema version.
+    ///
+    /// # Examples
+    ///
+    /// ```rust, ignore
+    /// use fory_core::Fory;
+    /// use fory_derive::ForyStruct;
+    ///
+    /// #[derive(ForyStruct, Default)]
+    /// struct PointV1 {
+    ///     x: i32,
+    ///     y: i64,
+    /// }
+    ///
+    /// #[derive(ForyStruct, Default)]
+    /// struct PointV2 {
+    ///     x: i64,
+    ///     y: String,
+    /// }
+    ///
+    /// let mut v1 = Fory::builder().compatible(true).build();
+    /// v1.register::<PointV1>(1).unwrap();
+    /// let mut v2 = Fory::builder().compatible(true).build();
+    /// v2.register::<PointV2>(1).unwrap();
+    ///
+    /// let old_meta = v1.peek_type_meta(&v1.serialize(&PointV1::default()).unwrap()).unwrap();
+    /// let new_meta = v2.peek_type_meta(&v2.serialize(&PointV2::default()).unwrap()).unwrap();
+    /// let report = Fory::check_compatibility(&old_meta, &new_meta);
+    /// assert!(report.is_breaking());
+    /// let breaking: Vec<_> = report.breaking_fields().map(|f| f.field_name.as_str()).collect();
+    /// assert_eq!(breaking, ["y"]);
+    /// ```
+    pub fn check_compatibility(old_meta: &TypeMeta, new_meta: &TypeMeta) -> CompatibilityReport {
+        crate::meta::check_compatibility(old_meta, new_meta)
+    }
+
+    /// Renders the structure of a serialized payload as an indented JSON tree, for
+    /// debugging wire issues.
+    ///
+    /// Each value becomes an object holding its ref flag (`"ref"`), its type id and type
+    /// name, and its content: `"value"` for scalars and arrays, `"items"` for lists and
+    /// sets, `"entries"` of key/value pairs for maps, and `"fields"` by name for structs.
+    /// Struct fields are named from the inline type meta in compatible mode and from the
+    /// registered types otherwise, so the payload must have been written by a `Fory` with
+    /// the same mode and registrations. Outside compatible mode, struct fields whose type is
+    /// registered by name can't be resolved and return [`Error::TypeError`]. Ext values are
+    /// skipped without being rendered.
+    ///
+    /// The dump is read-only and does not resolve shared references: a repeated value
+    /// shows up as `"ref": "ref"` with the `"ref_id"` of its first occurrence.
+    ///
+    /// # Examples
+    ///
+    /// ```rust, ignore
+    /// use fory_core::Fory;
+    /// use fory_derive::ForyStruct;
+    ///
+    /// #[derive(ForyStruct)]
+    /// struct Point {
+    ///     x: i32,
+    ///     y: i32,
+    /// }
+    ///
+    /// let mut fory = Fory::builder().compatible(true).build();
+    /// fory.register::<Point>(1).unwrap();
+    /// let bytes = fory.serialize(&Point { x: 1, y: 2 }).unwrap();
+    ///
+    /// let dump = fory.debug_dump(&bytes).unwrap();
+    /// assert!(dump.contains("\"x\": {"));
+    /// println!("{dump}");
+    /// ```
+    pub fn debug_dump(&self, bf: &[u8]) -> Result<String, Error> {
+        self.dump_tree(bf).map(|node| node.to_json())
+    }
+
+    /// Compares two serialized payloads structurally and returns the values that differ.
+    ///
+    /// Both payloads are walked the same way as [`debug_dump`](Self::debug_dump), so the
+    /// same requirements on mode and registrations apply. Values are compared rather than
+    /// bytes: struct fields are matched by name, and map entries and set elements by key,
+    /// so a `HashMap` iterated in a different order yields no differences. A value whose
+    /// type or ref flag differs is reported as a whole instead of being compared further.
+    ///
+    /// # Examples
+    ///
+    /// ```rust, ignore
+    /// use fory_core::Fory;
+    /// use fory_derive::ForyStruct;
+    ///
+    /// #[derive(ForyStruct)]
+    /// struct Point {
+    ///     x: i32,
+    ///     y: i32,
+    /// }
+    ///
+    /// let mut fory = Fory::builder().compatible(true).build();
+    /// fory.register::<Point>(1).unwrap();
+    /// let a = fory.serialize(&Point { x: 1, y: 2 }).unwrap();
+    /// let b = fory.serialize(&Point { x: 1, y: 3 }).unwrap();
+    ///
+    /// let diffs = fory.diff(&a, &b).unwrap();
+    /// assert_eq!(diffs.len(), 1);
+    /// assert_eq!(diffs[0].path, "y");
+    /// assert_eq!(diffs[0].left.as_deref(), Some("2"));
+    /// assert_eq!(diffs[0].right.as_deref(), Some("3"));
+    /// ```
+    pub fn diff(&self, a: &[u8], b: &[u8]) -> Result<Vec<FieldDiff>, Error> {
+        let left = self.dump_tree(a)?;
+        let right = self.dump_tree(b)?;
+        Ok(diff::diff_root(&left, &right))
+    }
+
+    fn dump_tree(&self, bf: &[u8]) -> Result<dump::Node, Error> {
+        self.with_read_context(|context| {
+            let outlive_buffer = unsafe { mem::transmute::<&[u8], &[u8]>(bf) };
+            context.attach_reader(Reader::new(outlive_buffer));
+            let result = self.read_message(context, |context, _| dump::dump_root(context));
+            context.reset();
+            context.detach_reader();
+            result
+        })
+    }
+
+    /// Deserializes a value that borrows from `bf` instead of copying out of it.
+    ///
+    /// This is the zero-copy counterpart of [`deserialize`](Self::deserialize) for
+    /// types implementing [`BorrowDeserialize`], such as `&str` (read from a
+    /// serialized `String`) and `&[u8]` (read from a serialized `Vec<u8>`).
+    /// Strings are always UTF-8 validated, regardless of `check_string_read`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fory_core::Fory;
+    ///
+    /// let fory = Fory::default();
+    /// let bytes = fory.serialize(&"hello".to_string()).unwrap();
+    /// let s: &str = fory.deserialize_borrowed(&bytes).unwrap();
+    /// assert_eq!(s, "hello");
+    /// ```
+    pub fn deserialize_borrowed<'de, T: BorrowDeserialize<'de>>(
+        &self,
+        bf: &'de [u8],
+    ) -> Result<T, Error> {
+        let mut reader = Reader::new(bf);
+        ensure!(
+            !self.read_head(&mut reader)?.wrapped,
+            Error::unsupported(
+                "compressed or checksummed payloads can't be deserialized by borrowing"
+            )
+        );
+        let ref_flag = reader.read_i8()?;
+        if ref_flag != RefFlag::NotNullValue as i8 && ref_flag != RefFlag::RefValue as i8 {
+            return Err(Error::invalid_data(format!(
+                "can't borrow root value with ref flag {}",
+                ref_flag
+            )));
+        }
+        let local_type_id = T::fory_borrowed_type_id() as u32;
+        let remote_type_id = reader.read_var_u32()?;
+        ensure!(
+            local_type_id == remote_type_id,
+            Error::type_mismatch(local_type_id, remote_type_id)
+        );
+        T::fory_read_borrowed_data(&mut reader)
+    }
+
     /// Executes a closure with mutable access to a ReadContext for this Fory instance.
     /// The context is stored in thread-local storage, eliminating all lock contention.
     /// Uses fast path caching for O(1) access when using the same Fory instance repeatedly.
@@ -1091,7 +2459,7 @@ impl Fory {
             let context = cache.get_or_insert_result(id, || {
                 // Only fetch type resolver when creating a new context
                 let type_resolver = self.get_final_type_resolver()?;
-                Ok(Box::new(ReadContext::new(
+                Ok::<_, Error>(Box::new(ReadContext::new(
                     type_resolver.clone(),
                     self.config.clone(),
                 )))
@@ -1115,7 +2483,46 @@ impl Fory {
         &self,
         context: &mut ReadContext,
     ) -> Result<T, Error> {
-        self.read_head(&mut context.reader)?;
+        self.read_message(context, |context, ref_mode| {
+            Self::check_root_version::<T>(context)?;
+            // TypeMeta is read inline during deserialization (streaming protocol)
+            let result = <T as Serializer>::fory_read(context, ref_mode, true);
+            context.ref_reader.resolve_callbacks();
+            result
+        })
+    }
+
+    #[inline(always)]
+    fn deserialize_into_with_context<T: Serializer + ForyDefault>(
+        &self,
+        context: &mut ReadContext,
+        target: &mut T,
+    ) -> Result<(), Error> {
+        self.read_message(context, |context, ref_mode| {
+            Self::check_root_version::<T>(context)?;
+            let result = target.fory_read_into(context, ref_mode, true);
+            context.ref_reader.resolve_callbacks();
+            result
+        })
+    }
+
+    /// Reads the root header into `context` and calls `read` with the ref mode of the
+    /// root value. Compressed or checksummed messages are unwrapped first, and `read`
+    /// then runs over the message they wrap.
+    #[inline(always)]
+    fn read_message<R>(
+        &self,
+        context: &mut ReadContext,
+        read: impl FnOnce(&mut ReadContext, RefMode) -> Result<R, Error>,
+    ) -> Result<R, Error> {
+        let head = self.read_head(&mut context.reader)?;
+        if head.wrapped {
+            return self.read_wrapped_message(context, read);
+        }
+        context.set_big_endian(head.big_endian);
+        context.set_payload_version(head.version);
+        context.set_sparse_options(head.sparse_options);
+        context.set_string_ref(head.string_ref);
         // Use RefMode based on config:
         // - If track_ref is enabled, use RefMode::Tracking for the root object
         // - Otherwise, use RefMode::NullOnly
@@ -1124,30 +2531,145 @@ impl Fory {
         } else {
             RefMode::NullOnly
         };
-        // TypeMeta is read inline during deserialization (streaming protocol)
-        let result = <T as Serializer>::fory_read(context, ref_mode, true);
-        context.ref_reader.resolve_callbacks();
-        result
+        read(context, ref_mode)
+    }
+
+    #[cold]
+    #[inline(never)]
+    fn read_wrapped_message<R>(
+        &self,
+        context: &mut ReadContext,
+        read: impl FnOnce(&mut ReadContext, RefMode) -> Result<R, Error>,
+    ) -> Result<R, Error> {
+        let message = self.unwrap_message(&mut context.reader)?;
+        context
+            .with_scoped_reader(&message, |context| self.read_message(context, read))
+            .0
+    }
+
+    /// Rejects payloads older than the [`Serializer::fory_min_version`] of the root type.
+    #[inline(always)]
+    fn check_root_version<T: Serializer>(context: &ReadContext) -> Result<(), Error> {
+        let min_version = T::fory_min_version();
+        if min_version == 0 {
+            return Ok(());
+        }
+        let version = context.get_payload_version().unwrap_or(0);
+        ensure!(
+            version >= min_version,
+            Error::version_too_old(version, min_version)
+        );
+        Ok(())
+    }
+
+    /// Rejects a frame whose length prefix exceeds `max_binary_size` before its payload
+    /// is read.
+    fn check_frame_len(&self, len: usize) -> Result<(), Error> {
+        let max_size = self.config.max_binary_size as usize;
+        if len > max_size {
+            return Err(Error::size_limit_exceeded(format!(
+                "Frame size {} exceeds limit {}",
+                len, max_size
+            )));
+        }
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn is_checksummed(bf: &[u8]) -> bool {
+        bf.first()
+            .is_some_and(|bitmap| bitmap & IS_CHECKSUMMED_FLAG != 0)
+    }
+
+    /// Verifies the checksum or decompresses the message at the reader's cursor and
+    /// returns the message it wraps, which may itself still be compressed.
+    fn unwrap_message(&self, reader: &mut Reader) -> Result<Vec<u8>, Error> {
+        if Self::is_checksummed(reader.slice_after_cursor()) {
+            return checksum::verify_message(reader);
+        }
+        let max_size = self.config.max_binary_size as usize;
+        compression::decompress_message(reader, max_size)
     }
 
+    /// Reads the root header, including the schema version that follows it in
+    /// versioned payloads. For a compressed or checksummed message the reader is left
+    /// at the header, ready for [`unwrap_message`](Self::unwrap_message).
     #[inline(always)]
-    fn read_head(&self, reader: &mut Reader) -> Result<(), Error> {
+    fn read_head(&self, reader: &mut Reader) -> Result<RootHead, Error> {
         let bitmap = reader.read_u8()?;
-        let expected = if self.config.xlang {
-            IS_CROSS_LANGUAGE_FLAG
+        let expected = self.mode_bitmap();
+        if bitmap != expected {
+            return self.read_head_slow(bitmap, expected, reader);
+        }
+        let options = if bitmap & HAS_NATIVE_OPTIONS_FLAG != 0 {
+            Self::read_native_options(reader)?
         } else {
             0
         };
-        if bitmap != expected {
-            return self.read_head_slow(bitmap, expected);
+        Ok(RootHead {
+            sparse_options: options & SPARSE_OPTIONS_FLAG != 0,
+            string_ref: options & STRING_REF_FLAG != 0,
+            ..RootHead::default()
+        })
+    }
+
+    /// Header bits of the messages this instance writes, apart from the byte order and
+    /// the version.
+    #[inline(always)]
+    fn mode_bitmap(&self) -> u8 {
+        if self.config.xlang {
+            // Native-only options are rejected on write, and their bits must stay clear.
+            return IS_CROSS_LANGUAGE_FLAG;
+        }
+        if self.native_options() != 0 {
+            HAS_NATIVE_OPTIONS_FLAG
+        } else {
+            0
         }
-        Ok(())
+    }
+
+    /// The native options byte of the messages this instance writes, or 0 when the
+    /// header carries none.
+    #[inline(always)]
+    fn native_options(&self) -> u8 {
+        if self.config.xlang {
+            return 0;
+        }
+        let mut options = 0;
+        if self.config.sparse_options {
+            options |= SPARSE_OPTIONS_FLAG;
+        }
+        if self.config.string_ref {
+            options |= STRING_REF_FLAG;
+        }
+        options
+    }
+
+    fn read_native_options(reader: &mut Reader) -> Result<u8, Error> {
+        const KNOWN_OPTIONS: u8 = SPARSE_OPTIONS_FLAG | STRING_REF_FLAG;
+        let options = reader.read_u8()?;
+        ensure!(
+            options & !KNOWN_OPTIONS == 0,
+            Error::invalid_data(format!("unknown native options: {:#04x}", options))
+        );
+        Ok(options)
     }
 
     #[cold]
     #[inline(never)]
-    fn read_head_slow(&self, bitmap: u8, expected: u8) -> Result<(), Error> {
-        const KNOWN_FLAGS: u8 = IS_CROSS_LANGUAGE_FLAG | IS_OUT_OF_BAND_FLAG;
+    fn read_head_slow(
+        &self,
+        bitmap: u8,
+        expected: u8,
+        reader: &mut Reader,
+    ) -> Result<RootHead, Error> {
+        const WRAPPED_FLAGS: u8 = IS_COMPRESSED_FLAG | IS_CHECKSUMMED_FLAG;
+        const KNOWN_FLAGS: u8 = IS_CROSS_LANGUAGE_FLAG
+            | IS_OUT_OF_BAND_FLAG
+            | IS_BIG_ENDIAN_FLAG
+            | IS_VERSIONED_FLAG
+            | HAS_NATIVE_OPTIONS_FLAG
+            | WRAPPED_FLAGS;
         ensure!(
             (bitmap & !KNOWN_FLAGS) == 0 && (bitmap & IS_OUT_OF_BAND_FLAG) == 0,
             Error::invalid_data("unsupported root header bitmap")
@@ -1156,10 +2678,71 @@ impl Fory {
             (bitmap & IS_CROSS_LANGUAGE_FLAG) == (expected & IS_CROSS_LANGUAGE_FLAG),
             Error::invalid_data("header bitmap mismatch at xlang bit")
         );
-        Ok(())
+        if bitmap & WRAPPED_FLAGS != 0 {
+            ensure!(
+                bitmap & IS_CROSS_LANGUAGE_FLAG == 0,
+                Error::invalid_data(
+                    "compressed or checksummed payloads are not valid in xlang mode"
+                )
+            );
+            reader.set_cursor(reader.get_cursor() - 1);
+            return Ok(RootHead {
+                wrapped: true,
+                ..RootHead::default()
+            });
+        }
+        let big_endian = bitmap & IS_BIG_ENDIAN_FLAG != 0;
+        ensure!(
+            !(big_endian && bitmap & IS_CROSS_LANGUAGE_FLAG != 0),
+            Error::invalid_data("big-endian payloads are not valid in xlang mode")
+        );
+        ensure!(
+            !big_endian || cfg!(feature = "big-endian"),
+            Error::unsupported("reading big-endian payloads requires the `big-endian` feature")
+        );
+        let versioned = bitmap & IS_VERSIONED_FLAG != 0;
+        ensure!(
+            !(versioned && bitmap & IS_CROSS_LANGUAGE_FLAG != 0),
+            Error::invalid_data("versioned payloads are not valid in xlang mode")
+        );
+        let has_options = bitmap & HAS_NATIVE_OPTIONS_FLAG != 0;
+        ensure!(
+            !(has_options && bitmap & IS_CROSS_LANGUAGE_FLAG != 0),
+            Error::invalid_data("native options are not valid in xlang mode")
+        );
+        let options = if has_options {
+            Self::read_native_options(reader)?
+        } else {
+            0
+        };
+        let version = if versioned {
+            Some(reader.read_var_u32()?)
+        } else {
+            None
+        };
+        Ok(RootHead {
+            big_endian,
+            version,
+            sparse_options: options & SPARSE_OPTIONS_FLAG != 0,
+            string_ref: options & STRING_REF_FLAG != 0,
+            wrapped: false,
+        })
     }
 }
 
+/// Root header fields beyond the mode bits checked while reading it.
+#[derive(Default)]
+struct RootHead {
+    big_endian: bool,
+    version: Option<u32>,
+    /// Structs in the message start with a presence bitmap.
+    sparse_options: bool,
+    /// Strings in the message can refer back to earlier ones.
+    string_ref: bool,
+    /// The message is compressed or checksummed and has to be unwrapped before decoding.
+    wrapped: bool,
+}
+
 #[cfg(test)]
 mod tests {
     use super::Fory;
@@ -200,14 +200,18 @@ pub mod util;
 pub use paste;
 
 pub use crate::buffer::{Reader, Writer};
-pub use crate::config::Config;
+pub use crate::config::{Checksum, Compression, Config, Endianness, SchemaMode};
 pub use crate::context::{ReadContext, WriteContext};
 pub use crate::error::Error;
 pub use crate::fory::{Fory, ForyBuilder};
-pub use crate::meta::{compute_field_hash, compute_struct_hash};
+pub use crate::meta::{compute_field_hash, compute_struct_hash, CompatibilityReport, RetypedField};
 pub use crate::resolver::{RefFlag, RefMode, TypeInfo, TypeResolver};
-pub use crate::serializer::{read_data, write_data, ForyDefault, Serializer, StructSerializer};
+pub use crate::serializer::{
+    read_data, write_data, BorrowDeserialize, External, FieldDiff, ForyDefault, PresentFields,
+    Serializer, StructSerializer,
+};
 pub use crate::type_id::TypeId;
 pub use crate::types::bfloat16::bfloat16 as BFloat16;
 pub use crate::types::float16::float16 as Float16;
 pub use crate::types::{ArcWeak, Date, Decimal, Duration, RcWeak, Timestamp, UnknownCase};
+pub use crate::util::cipher::FieldCipher;
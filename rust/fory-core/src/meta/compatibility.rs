@@ -0,0 +1,155 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::meta::type_meta::normalize_type_id_for_eq;
+use crate::meta::{FieldInfo, FieldType, TypeMeta};
+use crate::serializer::codec::compatible_field_pair;
+use crate::type_id;
+use crate::util::to_snake_case;
+
+/// Field-level differences between two versions of a struct schema, as produced by
+/// [`Fory::check_compatibility`](crate::Fory::check_compatibility).
+///
+/// Added and removed fields are tolerated by compatible mode: readers fill missing fields
+/// with defaults and skip unknown ones. A retyped field is breaking when a reader of the
+/// new schema cannot decode the old type, or can only do so through a conversion that may
+/// fail or change the value, such as `i64` to `String` or `i64` to `i32`.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct CompatibilityReport {
+    /// Fields only present in the new schema.
+    pub added: Vec<FieldInfo>,
+    /// Fields only present in the old schema.
+    pub removed: Vec<FieldInfo>,
+    /// Fields present in both schemas with different types.
+    pub retyped: Vec<RetypedField>,
+}
+
+/// A field whose type differs between the old and the new schema.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetypedField {
+    pub field_name: String,
+    pub old_type: FieldType,
+    pub new_type: FieldType,
+    pub breaking: bool,
+}
+
+impl CompatibilityReport {
+    /// Returns `true` if any retyped field is breaking.
+    pub fn is_breaking(&self) -> bool {
+        self.retyped.iter().any(|field| field.breaking)
+    }
+
+    /// Returns `true` if the two schemas have the same fields with the same types.
+    pub fn is_unchanged(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.retyped.is_empty()
+    }
+
+    /// Iterates over the breaking field changes.
+    pub fn breaking_fields(&self) -> impl Iterator<Item = &RetypedField> {
+        self.retyped.iter().filter(|field| field.breaking)
+    }
+}
+
+pub(crate) fn check_compatibility(old_meta: &TypeMeta, new_meta: &TypeMeta) -> CompatibilityReport {
+    let mut report = CompatibilityReport::default();
+    let old_fields = old_meta.get_field_infos();
+    let mut matched_old = vec![false; old_fields.len()];
+    for new_field in new_meta.get_field_infos() {
+        let old_index = (0..old_fields.len())
+            .find(|&i| !matched_old[i] && same_field(&old_fields[i], new_field));
+        let Some(old_index) = old_index else {
+            report.added.push(new_field.clone());
+            continue;
+        };
+        matched_old[old_index] = true;
+        let old_type = &old_fields[old_index].field_type;
+        let new_type = &new_field.field_type;
+        if old_type.exact_shape_match(new_type) {
+            continue;
+        }
+        report.retyped.push(RetypedField {
+            field_name: new_field.field_name.clone(),
+            old_type: old_type.clone(),
+            new_type: new_type.clone(),
+            breaking: !compatible_field_pair(new_type, old_type) || !widens(old_type, new_type),
+        });
+    }
+    report.removed = old_fields
+        .iter()
+        .zip(matched_old)
+        .filter(|(_, matched)| !matched)
+        .map(|(field, _)| field.clone())
+        .collect();
+    report
+}
+
+/// Fields match by name, or by `#[fory(id = N)]` when the metadata carries no name.
+fn same_field(old_field: &FieldInfo, new_field: &FieldInfo) -> bool {
+    if old_field.field_name.is_empty() || new_field.field_name.is_empty() {
+        return old_field.field_id >= 0 && old_field.field_id == new_field.field_id;
+    }
+    to_snake_case(&old_field.field_name) == to_snake_case(&new_field.field_name)
+}
+
+/// Returns `true` if every value of `old` reads into `new` unchanged.
+fn widens(old: &FieldType, new: &FieldType) -> bool {
+    if (old.nullable && !new.nullable) || old.track_ref != new.track_ref {
+        return false;
+    }
+    if normalize_type_id_for_eq(old.type_id) != normalize_type_id_for_eq(new.type_id)
+        && !scalar_widens(old.type_id, new.type_id)
+    {
+        return false;
+    }
+    old.generics.len() == new.generics.len()
+        && old
+            .generics
+            .iter()
+            .zip(new.generics.iter())
+            .all(|(old, new)| widens(old, new))
+}
+
+fn scalar_widens(old: u32, new: u32) -> bool {
+    if let (Some((old_signed, old_bits)), Some((new_signed, new_bits))) =
+        (integer_range(old), integer_range(new))
+    {
+        return (old_signed == new_signed && new_bits >= old_bits)
+            || (!old_signed && new_signed && new_bits > old_bits);
+    }
+    match old {
+        type_id::FLOAT16 | type_id::BFLOAT16 => {
+            matches!(new, type_id::FLOAT32 | type_id::FLOAT64)
+        }
+        type_id::FLOAT32 => new == type_id::FLOAT64,
+        _ => false,
+    }
+}
+
+/// Signedness and bit width of an integer type id, independent of its encoding.
+fn integer_range(type_id: u32) -> Option<(bool, u8)> {
+    match type_id {
+        type_id::INT8 => Some((true, 8)),
+        type_id::INT16 => Some((true, 16)),
+        type_id::INT32 | type_id::VARINT32 => Some((true, 32)),
+        type_id::INT64 | type_id::VARINT64 | type_id::TAGGED_INT64 => Some((true, 64)),
+        type_id::UINT8 => Some((false, 8)),
+        type_id::UINT16 => Some((false, 16)),
+        type_id::UINT32 | type_id::VAR_UINT32 => Some((false, 32)),
+        type_id::UINT64 | type_id::VAR_UINT64 | type_id::TAGGED_UINT64 => Some((false, 64)),
+        _ => None,
+    }
+}
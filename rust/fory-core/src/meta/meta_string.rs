@@ -496,7 +496,7 @@ impl MetaStringDecoder {
                     }
                     Encoding::Utf8 => std::str::from_utf8(encoded_data)
                         .map(str::to_owned)
-                        .map_err(|_| Error::encoding_error("invalid UTF-8 meta string")),
+                        .map_err(|_| Error::utf8("invalid UTF-8 meta string")),
                 }
             }
         }?;
@@ -15,9 +15,12 @@
 // specific language governing permissions and limitations
 // under the License.
 
+mod compatibility;
 mod meta_string;
 mod type_meta;
 
+pub(crate) use compatibility::check_compatibility;
+pub use compatibility::{CompatibilityReport, RetypedField};
 pub use meta_string::{
     Encoding, MetaString, MetaStringDecoder, MetaStringEncoder, FIELD_NAME_DECODER,
     FIELD_NAME_ENCODER, NAMESPACE_DECODER, NAMESPACE_ENCODER, TYPE_NAME_DECODER, TYPE_NAME_ENCODER,
@@ -23,8 +23,8 @@ use crate::meta::{
 };
 use crate::resolver::{TypeInfo, TypeResolver};
 use crate::type_id::{
-    TypeId, BINARY, COMPATIBLE_STRUCT, ENUM, EXT, NAMED_COMPATIBLE_STRUCT, NAMED_ENUM, NAMED_EXT,
-    NAMED_STRUCT, NAMED_UNION, STRUCT, TYPED_UNION, UINT8_ARRAY, UNION, UNKNOWN,
+    TypeId, BINARY, COMPATIBLE_STRUCT, ENCRYPTED, ENUM, EXT, NAMED_COMPATIBLE_STRUCT, NAMED_ENUM,
+    NAMED_EXT, NAMED_STRUCT, NAMED_UNION, STRUCT, TYPED_UNION, UINT8_ARRAY, UNION, UNKNOWN,
 };
 use crate::util::{murmurhash3_x64_128, to_snake_case};
 
@@ -35,7 +35,7 @@ use crate::util::{murmurhash3_x64_128, to_snake_case};
 /// Similarly for ENUM, EXT, and UNION variants. Dense byte arrays stay distinct here because schema
 /// equality and schema hashes must not turn compatibility-only byte-sequence assignment into
 /// same-schema equality.
-fn normalize_type_id_for_eq(type_id: u32) -> u32 {
+pub(super) fn normalize_type_id_for_eq(type_id: u32) -> u32 {
     match type_id {
         // All struct variants and UNKNOWN normalize to STRUCT
         _ if type_id == STRUCT
@@ -848,6 +848,14 @@ pub fn assign_remote_field_ids(
                         field.field_name, local_info.field_name
                     )));
                 }
+                if local_info.field_type.type_id == ENCRYPTED
+                    && field.field_type.type_id != ENCRYPTED
+                {
+                    return Err(Error::decrypt_failed(format!(
+                        "Remote field {} was written in clear but local field {} is encrypted",
+                        field.field_name, local_info.field_name
+                    )));
+                }
                 let exact_field = local_info.field_type.exact_shape_match(&field.field_type);
                 if !exact_field
                     && !crate::serializer::codec::compatible_field_pair(
@@ -1137,8 +1145,6 @@ impl TypeMeta {
         let mut num_fields = 0usize;
         let type_id;
         let mut user_type_id = NO_USER_TYPE_ID;
-        let namespace;
-        let type_name;
         if is_struct {
             register_by_name = (meta_header & REGISTER_BY_NAME_FLAG) != 0;
             let compatible = (meta_header & COMPATIBLE_TYPEDEF_FLAG) != 0;
@@ -1170,15 +1176,13 @@ impl TypeMeta {
             type_id = non_struct_type_id(meta_header & 0b1111)?;
             register_by_name = is_named_type_def_kind(type_id);
         }
-        if register_by_name {
-            namespace = Self::read_namespace(reader)?;
-            type_name = Self::read_type_name(reader)?;
+        let (namespace, type_name) = if register_by_name {
+            (Self::read_namespace(reader)?, Self::read_type_name(reader)?)
         } else {
             user_type_id = reader.read_var_u32()?;
             let empty_name = MetaString::default();
-            namespace = empty_name.clone();
-            type_name = empty_name;
-        }
+            (empty_name.clone(), empty_name)
+        };
 
         let mut field_infos = Vec::with_capacity(num_fields);
         for _ in 0..num_fields {
@@ -1257,7 +1261,6 @@ impl TypeMeta {
         assign_remote_field_ids(local_field_infos, field_infos)
     }
 
-    #[allow(dead_code)]
     pub(crate) fn from_bytes(
         reader: &mut Reader,
         type_resolver: &TypeResolver,
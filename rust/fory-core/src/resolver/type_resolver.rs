@@ -22,6 +22,7 @@ use crate::meta::{
     TYPE_NAME_ENCODINGS,
 };
 use crate::resolver::RefMode;
+use crate::serializer::external::{External, ExternalSerializer};
 use crate::serializer::{ForyDefault, Serializer, StructSerializer};
 use crate::type_id::{get_ext_actual_type_id, is_enum_type_id};
 use crate::types::{Date, Duration, Timestamp};
@@ -29,6 +30,7 @@ use crate::TypeId;
 #[cfg(feature = "chrono")]
 use chrono::{Duration as ChronoDuration, NaiveDate, NaiveDateTime};
 use std::rc::Rc;
+use std::sync::Arc;
 use std::vec;
 
 use std::{any::Any, collections::HashMap};
@@ -211,7 +213,7 @@ impl TypeInfo {
         harness: Harness,
     ) -> Result<TypeInfo, Error> {
         let type_id = TypeId::try_from(type_id as u8)
-            .map_err(|_| Error::type_error(format!("Unknown type id {}", type_id)))?;
+            .map_err(|_| Error::unknown_type_id(format!("Unknown type id {}", type_id)))?;
         let namespace_meta_string =
             NAMESPACE_ENCODER.encode_with_encodings(namespace, NAMESPACE_ENCODINGS)?;
         let type_name_meta_string =
@@ -232,7 +234,7 @@ impl TypeInfo {
     fn new_with_type_meta(type_meta: Rc<TypeMeta>, harness: Harness) -> Result<TypeInfo, Error> {
         let type_id_raw = type_meta.get_type_id();
         let type_id = TypeId::try_from(type_id_raw as u8)
-            .map_err(|_| Error::type_error(format!("Unknown type id {}", type_id_raw)))?;
+            .map_err(|_| Error::unknown_type_id(format!("Unknown type id {}", type_id_raw)))?;
         let user_type_id = type_meta.get_user_type_id();
         let namespace = type_meta.get_namespace();
         let type_name = type_meta.get_type_name();
@@ -575,6 +577,10 @@ pub struct TypeResolver {
     rust_type_id_by_index: Vec<Option<std::any::TypeId>>,
     // Fast lookup by type index for TypeMeta
     type_meta_by_index: Vec<Option<Rc<crate::meta::TypeMeta>>>,
+    // Closures registered for external types, each an `ExternalSerializer<T>` keyed by `T`
+    external_serializers: HashMap<std::any::TypeId, Arc<dyn Any + Send + Sync>>,
+    // Read-only user type ids added by `register_alias`, mapped to the canonical id
+    user_type_id_aliases: HashMap<u32, u32>,
     compatible: bool,
     xlang: bool,
 }
@@ -600,6 +606,8 @@ impl Default for TypeResolver {
             rust_type_id_by_index: Vec::new(),
             type_meta_by_index: Vec::new(),
             partial_type_infos: HashMap::new(),
+            external_serializers: HashMap::new(),
+            user_type_id_aliases: HashMap::new(),
             compatible: false,
             xlang: false,
         };
@@ -637,6 +645,16 @@ impl TypeResolver {
         self.user_type_info_by_id.get(&user_type_id).cloned()
     }
 
+    /// Returns the id a type is written with when `user_type_id` was added by
+    /// `register_alias`, otherwise `user_type_id` itself.
+    #[inline(always)]
+    pub fn canonical_user_type_id(&self, user_type_id: u32) -> u32 {
+        self.user_type_id_aliases
+            .get(&user_type_id)
+            .copied()
+            .unwrap_or(user_type_id)
+    }
+
     #[inline(always)]
     pub fn get_type_info_by_name(&self, namespace: &str, type_name: &str) -> Option<Rc<TypeInfo>> {
         self.type_info_map_by_name
@@ -791,7 +809,49 @@ impl TypeResolver {
     }
 
     fn register_builtin_types(&mut self) -> Result<(), Error> {
+        // `Atomic*` values are written as their loaded value, and must also come before the
+        // plain types they share ids with.
+        self.register_internal_serializer::<std::sync::atomic::AtomicBool>(TypeId::BOOL)?;
         self.register_internal_serializer::<bool>(TypeId::BOOL)?;
+        // `NonZero*` integers share their inner integer's type id. Registering them first lets
+        // the plain integers own the ids for dynamic reads.
+        self.register_internal_serializer::<std::num::NonZeroI8>(TypeId::INT8)?;
+        self.register_internal_serializer::<std::num::NonZeroI16>(TypeId::INT16)?;
+        self.register_internal_serializer::<std::num::NonZeroI32>(TypeId::VARINT32)?;
+        self.register_internal_serializer::<std::num::NonZeroI64>(TypeId::VARINT64)?;
+        self.register_internal_serializer::<std::num::NonZeroIsize>(TypeId::ISIZE)?;
+        self.register_internal_serializer::<std::num::NonZeroI128>(TypeId::INT128)?;
+        self.register_internal_serializer::<std::num::NonZeroU8>(TypeId::UINT8)?;
+        self.register_internal_serializer::<std::num::NonZeroU16>(TypeId::UINT16)?;
+        self.register_internal_serializer::<std::num::NonZeroU32>(TypeId::VAR_UINT32)?;
+        self.register_internal_serializer::<std::num::NonZeroU64>(TypeId::VAR_UINT64)?;
+        self.register_internal_serializer::<std::num::NonZeroUsize>(TypeId::USIZE)?;
+        self.register_internal_serializer::<std::num::NonZeroU128>(TypeId::U128)?;
+        // `Wrapping<T>` integers are written as `T` too.
+        self.register_internal_serializer::<std::num::Wrapping<i8>>(TypeId::INT8)?;
+        self.register_internal_serializer::<std::num::Wrapping<i16>>(TypeId::INT16)?;
+        self.register_internal_serializer::<std::num::Wrapping<i32>>(TypeId::VARINT32)?;
+        self.register_internal_serializer::<std::num::Wrapping<i64>>(TypeId::VARINT64)?;
+        self.register_internal_serializer::<std::num::Wrapping<isize>>(TypeId::ISIZE)?;
+        self.register_internal_serializer::<std::num::Wrapping<i128>>(TypeId::INT128)?;
+        self.register_internal_serializer::<std::num::Wrapping<u8>>(TypeId::UINT8)?;
+        self.register_internal_serializer::<std::num::Wrapping<u16>>(TypeId::UINT16)?;
+        self.register_internal_serializer::<std::num::Wrapping<u32>>(TypeId::VAR_UINT32)?;
+        self.register_internal_serializer::<std::num::Wrapping<u64>>(TypeId::VAR_UINT64)?;
+        self.register_internal_serializer::<std::num::Wrapping<usize>>(TypeId::USIZE)?;
+        self.register_internal_serializer::<std::num::Wrapping<u128>>(TypeId::U128)?;
+        self.register_internal_serializer::<std::sync::atomic::AtomicI8>(TypeId::INT8)?;
+        self.register_internal_serializer::<std::sync::atomic::AtomicI16>(TypeId::INT16)?;
+        self.register_internal_serializer::<std::sync::atomic::AtomicI32>(TypeId::VARINT32)?;
+        #[cfg(target_has_atomic = "64")]
+        self.register_internal_serializer::<std::sync::atomic::AtomicI64>(TypeId::VARINT64)?;
+        self.register_internal_serializer::<std::sync::atomic::AtomicIsize>(TypeId::ISIZE)?;
+        self.register_internal_serializer::<std::sync::atomic::AtomicU8>(TypeId::UINT8)?;
+        self.register_internal_serializer::<std::sync::atomic::AtomicU16>(TypeId::UINT16)?;
+        self.register_internal_serializer::<std::sync::atomic::AtomicU32>(TypeId::VAR_UINT32)?;
+        #[cfg(target_has_atomic = "64")]
+        self.register_internal_serializer::<std::sync::atomic::AtomicU64>(TypeId::VAR_UINT64)?;
+        self.register_internal_serializer::<std::sync::atomic::AtomicUsize>(TypeId::USIZE)?;
         self.register_internal_serializer::<i8>(TypeId::INT8)?;
         self.register_internal_serializer::<i16>(TypeId::INT16)?;
         self.register_internal_serializer::<i32>(TypeId::VARINT32)?;
@@ -800,6 +860,8 @@ impl TypeResolver {
         self.register_internal_serializer::<i128>(TypeId::INT128)?;
         self.register_internal_serializer::<f32>(TypeId::FLOAT32)?;
         self.register_internal_serializer::<f64>(TypeId::FLOAT64)?;
+        #[cfg(feature = "half")]
+        self.register_internal_serializer::<half::f16>(TypeId::FLOAT16)?;
         self.register_internal_serializer::<crate::types::float16::float16>(TypeId::FLOAT16)?;
         self.register_internal_serializer::<crate::types::bfloat16::bfloat16>(TypeId::BFLOAT16)?;
         self.register_internal_serializer::<u8>(TypeId::UINT8)?;
@@ -808,7 +870,10 @@ impl TypeResolver {
         self.register_internal_serializer::<u64>(TypeId::VAR_UINT64)?;
         self.register_internal_serializer::<usize>(TypeId::USIZE)?;
         self.register_internal_serializer::<u128>(TypeId::U128)?;
+        self.register_internal_serializer::<char>(TypeId::CHAR)?;
         self.register_internal_serializer::<String>(TypeId::STRING)?;
+        self.register_internal_serializer::<std::time::Duration>(TypeId::DURATION)?;
+        self.register_internal_serializer::<std::time::SystemTime>(TypeId::TIMESTAMP)?;
         #[cfg(feature = "chrono")]
         {
             self.register_internal_serializer::<ChronoDuration>(TypeId::DURATION)?;
@@ -818,7 +883,16 @@ impl TypeResolver {
         self.register_internal_serializer::<Duration>(TypeId::DURATION)?;
         self.register_internal_serializer::<Timestamp>(TypeId::TIMESTAMP)?;
         self.register_internal_serializer::<Date>(TypeId::DATE)?;
+        #[cfg(feature = "decimal")]
+        self.register_internal_serializer::<rust_decimal::Decimal>(TypeId::DECIMAL)?;
         self.register_internal_serializer::<crate::types::Decimal>(TypeId::DECIMAL)?;
+        #[cfg(feature = "uuid")]
+        self.register_internal_serializer::<uuid::Uuid>(TypeId::UUID)?;
+        self.register_internal_serializer::<std::net::Ipv4Addr>(TypeId::IPV4_ADDR)?;
+        self.register_internal_serializer::<std::net::Ipv6Addr>(TypeId::IPV6_ADDR)?;
+        self.register_internal_serializer::<std::net::IpAddr>(TypeId::IP_ADDR)?;
+        self.register_internal_serializer::<std::net::SocketAddr>(TypeId::SOCKET_ADDR)?;
+        self.register_internal_serializer::<std::ffi::OsString>(TypeId::OS_STRING)?;
 
         self.register_internal_serializer::<Vec<bool>>(TypeId::BOOL_ARRAY)?;
         self.register_internal_serializer::<Vec<i8>>(TypeId::INT8_ARRAY)?;
@@ -827,12 +901,19 @@ impl TypeResolver {
         self.register_internal_serializer::<Vec<i64>>(TypeId::INT64_ARRAY)?;
         self.register_internal_serializer::<Vec<f32>>(TypeId::FLOAT32_ARRAY)?;
         self.register_internal_serializer::<Vec<f64>>(TypeId::FLOAT64_ARRAY)?;
+        #[cfg(feature = "half")]
+        self.register_internal_serializer::<Vec<half::f16>>(TypeId::FLOAT16_ARRAY)?;
         self.register_internal_serializer::<Vec<crate::types::float16::float16>>(
             TypeId::FLOAT16_ARRAY,
         )?;
         self.register_internal_serializer::<Vec<crate::types::bfloat16::bfloat16>>(
             TypeId::BFLOAT16_ARRAY,
         )?;
+        // Registered before `Vec<u8>` so the plain vector owns the id for dynamic reads.
+        #[cfg(feature = "bytes")]
+        self.register_internal_serializer::<bytes::Bytes>(TypeId::BINARY)?;
+        #[cfg(feature = "bytes")]
+        self.register_internal_serializer::<bytes::BytesMut>(TypeId::BINARY)?;
         self.register_internal_serializer::<Vec<u8>>(TypeId::BINARY)?;
         self.register_internal_serializer::<Vec<u16>>(TypeId::UINT16_ARRAY)?;
         self.register_internal_serializer::<Vec<u32>>(TypeId::UINT32_ARRAY)?;
@@ -852,6 +933,16 @@ impl TypeResolver {
         self.register_struct_type::<T>(id, &EMPTY_STRING, &EMPTY_STRING, true)
     }
 
+    pub fn register_enum<T: 'static + StructSerializer + Serializer + ForyDefault>(
+        &mut self,
+        id: u32,
+    ) -> Result<(), Error> {
+        if T::fory_static_type_id() != TypeId::ENUM {
+            return Err(Error::not_allowed("register_enum requires an enum type"));
+        }
+        self.register_struct_type::<T>(id, &EMPTY_STRING, &EMPTY_STRING, true)
+    }
+
     pub fn register_union<T: 'static + StructSerializer + Serializer + ForyDefault>(
         &mut self,
         id: u32,
@@ -885,6 +976,33 @@ impl TypeResolver {
         self.register_struct_type::<T>(0, namespace, type_name, true)
     }
 
+    pub fn register_alias<T: 'static + Serializer>(&mut self, id: u32) -> Result<(), Error> {
+        if id > MAX_USER_TYPE_ID {
+            return Err(Error::not_allowed(format!(
+                "type id must be in range [0, 0xfffffffe], got {}",
+                id
+            )));
+        }
+        let type_info = match self.type_info_map.get(&std::any::TypeId::of::<T>()) {
+            Some(info) if info.user_type_id != NO_USER_TYPE_ID => info.clone(),
+            _ => {
+                return Err(Error::not_allowed(format!(
+                    "register_alias requires {} to be registered by id first",
+                    std::any::type_name::<T>()
+                )));
+            }
+        };
+        if self.user_type_info_by_id.contains_key(&id) {
+            return Err(Error::type_error(format!(
+                "Type ID {} conflicts with already registered type. Please use a different type ID.",
+                id
+            )));
+        }
+        self.user_type_id_aliases.insert(id, type_info.user_type_id);
+        self.user_type_info_by_id.insert(id, type_info);
+        Ok(())
+    }
+
     fn register_struct_type<T: StructSerializer + Serializer + ForyDefault>(
         &mut self,
         id: u32,
@@ -1092,8 +1210,11 @@ impl TypeResolver {
             self.user_type_info_by_id
                 .insert(user_type_id, Rc::new(type_info.clone()));
         }
-        self.type_info_map
-            .insert(rs_type_id, Rc::new(type_info.clone()));
+        let type_info_rc = match self.internal_type_info_by_id.get(actual_type_id as usize) {
+            Some(Some(info)) if crate::type_id::is_internal_type(actual_type_id) => info.clone(),
+            _ => Rc::new(type_info.clone()),
+        };
+        self.type_info_map.insert(rs_type_id, type_info_rc);
         self.partial_type_infos.insert(rs_type_id, type_info);
 
         Ok(())
@@ -1128,6 +1249,41 @@ impl TypeResolver {
         self.register_serializer_type::<T>(0, actual_type_id, namespace, type_name)
     }
 
+    /// Registers closures that serialize `T` as an ext type, for types that can't
+    /// implement [`Serializer`] themselves. Values are written through [`External`].
+    pub(crate) fn register_external<T: 'static + Default>(
+        &mut self,
+        id: u32,
+        serializer: ExternalSerializer<T>,
+    ) -> Result<(), Error> {
+        let actual_type_id = get_ext_actual_type_id(id, false);
+        self.register_serializer_type::<External<T>>(
+            id,
+            actual_type_id,
+            &EMPTY_STRING,
+            &EMPTY_STRING,
+        )?;
+        self.external_serializers
+            .insert(std::any::TypeId::of::<T>(), Arc::new(serializer));
+        Ok(())
+    }
+
+    /// Returns the closures registered for the external type `T`.
+    pub(crate) fn get_external_serializer<T: 'static>(
+        &self,
+    ) -> Result<Arc<ExternalSerializer<T>>, Error> {
+        self.external_serializers
+            .get(&std::any::TypeId::of::<T>())
+            .cloned()
+            .and_then(|serializer| serializer.downcast::<ExternalSerializer<T>>().ok())
+            .ok_or_else(|| {
+                Error::type_error(format!(
+                    "no external serializer registered for {}",
+                    std::any::type_name::<T>()
+                ))
+            })
+    }
+
     fn register_internal_serializer<T: Serializer + ForyDefault>(
         &mut self,
         type_id: TypeId,
@@ -1311,8 +1467,11 @@ impl TypeResolver {
             self.user_type_info_by_id
                 .insert(user_type_id, Rc::new(type_info.clone()));
         }
-        self.type_info_map
-            .insert(rs_type_id, Rc::new(type_info.clone()));
+        let type_info_rc = match self.internal_type_info_by_id.get(actual_type_id as usize) {
+            Some(Some(info)) if crate::type_id::is_internal_type(actual_type_id) => info.clone(),
+            _ => Rc::new(type_info.clone()),
+        };
+        self.type_info_map.insert(rs_type_id, type_info_rc);
         self.partial_type_infos.insert(rs_type_id, type_info);
         Ok(())
     }
@@ -1360,7 +1519,7 @@ impl TypeResolver {
         let user_type_id_index = self.user_type_id_index.clone();
 
         // Iterate over partial_type_infos and complete them
-        for (_rust_type_id, partial_type_info) in self.partial_type_infos.iter() {
+        for partial_type_info in self.partial_type_infos.values() {
             let harness = &partial_type_info.harness;
             // Call build_type_infos to get all type infos (main + enum variants)
             let type_infos = (harness.build_type_infos)(self)?;
@@ -1370,7 +1529,16 @@ impl TypeResolver {
                 // Insert into id maps
                 if crate::type_id::is_internal_type(type_info.type_id as u32) {
                     let index = type_info.type_id as usize;
-                    if index < internal_type_info_by_id.len() {
+                    // Several Rust types may share an internal id; the last one registered
+                    // owns it, which registration recorded by sharing the same `Rc`.
+                    let owns_id = match (
+                        self.internal_type_info_by_id.get(index),
+                        self.type_info_map.get(type_rust_id),
+                    ) {
+                        (Some(Some(by_id)), Some(by_type)) => Rc::ptr_eq(by_id, by_type),
+                        _ => true,
+                    };
+                    if owns_id && index < internal_type_info_by_id.len() {
                         internal_type_info_by_id[index] = Some(Rc::new(type_info.clone()));
                     }
                 } else if type_info.user_type_id != NO_USER_TYPE_ID {
@@ -1398,6 +1566,13 @@ impl TypeResolver {
             }
         }
 
+        // Point aliases at the completed type info of the type they stand for
+        for (alias, canonical) in self.user_type_id_aliases.iter() {
+            if let Some(type_info) = user_type_info_by_id.get(canonical).cloned() {
+                user_type_info_by_id.insert(*alias, type_info);
+            }
+        }
+
         let type_meta_by_index: Vec<Option<Rc<crate::meta::TypeMeta>>> = rust_type_id_by_index
             .iter()
             .map(|id| {
@@ -1416,6 +1591,8 @@ impl TypeResolver {
             user_type_id_index,
             rust_type_id_by_index,
             type_meta_by_index,
+            external_serializers: self.external_serializers.clone(),
+            user_type_id_aliases: self.user_type_id_aliases.clone(),
             compatible: self.compatible,
             xlang: self.xlang,
         })
@@ -1515,6 +1692,8 @@ impl TypeResolver {
             user_type_id_index: self.user_type_id_index.clone(),
             rust_type_id_by_index: self.rust_type_id_by_index.clone(),
             type_meta_by_index,
+            external_serializers: self.external_serializers.clone(),
+            user_type_id_aliases: self.user_type_id_aliases.clone(),
             compatible: self.compatible,
             xlang: self.xlang,
         }
@@ -19,7 +19,8 @@ use crate::context::{ReadContext, WriteContext};
 use crate::error::Error;
 use crate::resolver::{RefFlag, RefMode};
 use crate::resolver::{TypeInfo, TypeResolver};
-use crate::serializer::{ForyDefault, Serializer};
+use crate::serializer::string::{estimate_str_size, write_str_data};
+use crate::serializer::{primitive_list, ForyDefault, Serializer};
 use crate::type_id::TypeId;
 use std::rc::Rc;
 use std::sync::Arc;
@@ -231,3 +232,130 @@ impl<T: ForyDefault> ForyDefault for Arc<T> {
         Arc::new(T::fory_default())
     }
 }
+
+/// `Arc<str>` is an immutable string value rather than a shared reference: it shares the
+/// wire format of `String` and is never ref-tracked.
+impl Serializer for Arc<str> {
+    #[inline(always)]
+    fn fory_write_data(&self, context: &mut WriteContext) -> Result<(), Error> {
+        write_str_data(self, context)
+    }
+
+    #[inline(always)]
+    fn fory_read_data(context: &mut ReadContext) -> Result<Self, Error> {
+        Ok(Arc::from(String::fory_read_data(context)?))
+    }
+
+    #[inline(always)]
+    fn fory_reserved_space() -> usize {
+        String::fory_reserved_space()
+    }
+
+    #[inline(always)]
+    fn fory_estimate_size(&self) -> usize {
+        estimate_str_size(self)
+    }
+
+    #[inline(always)]
+    fn fory_get_type_id(_: &TypeResolver) -> Result<TypeId, Error> {
+        Ok(TypeId::STRING)
+    }
+
+    #[inline(always)]
+    fn fory_type_id_dyn(&self, _: &TypeResolver) -> Result<TypeId, Error> {
+        Ok(TypeId::STRING)
+    }
+
+    #[inline(always)]
+    fn fory_static_type_id() -> TypeId
+    where
+        Self: Sized,
+    {
+        TypeId::STRING
+    }
+
+    #[inline(always)]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline(always)]
+    fn fory_write_type_info(context: &mut WriteContext) -> Result<(), Error> {
+        String::fory_write_type_info(context)
+    }
+
+    #[inline(always)]
+    fn fory_read_type_info(context: &mut ReadContext) -> Result<(), Error> {
+        String::fory_read_type_info(context)
+    }
+}
+
+impl ForyDefault for Arc<str> {
+    #[inline(always)]
+    fn fory_default() -> Self {
+        Arc::from("")
+    }
+}
+
+/// `Arc<[u8]>` shares the wire format of `Vec<u8>` and is never ref-tracked.
+impl Serializer for Arc<[u8]> {
+    #[inline(always)]
+    fn fory_write_data(&self, context: &mut WriteContext) -> Result<(), Error> {
+        primitive_list::fory_write_data(self.as_ref(), context)
+    }
+
+    #[inline(always)]
+    fn fory_read_data(context: &mut ReadContext) -> Result<Self, Error> {
+        Ok(Arc::from(Vec::<u8>::fory_read_data(context)?))
+    }
+
+    #[inline(always)]
+    fn fory_reserved_space() -> usize {
+        Vec::<u8>::fory_reserved_space()
+    }
+
+    #[inline(always)]
+    fn fory_estimate_size(&self) -> usize {
+        primitive_list::fory_estimate_size::<u8>(self)
+    }
+
+    #[inline(always)]
+    fn fory_get_type_id(_: &TypeResolver) -> Result<TypeId, Error> {
+        Ok(TypeId::BINARY)
+    }
+
+    #[inline(always)]
+    fn fory_type_id_dyn(&self, _: &TypeResolver) -> Result<TypeId, Error> {
+        Ok(TypeId::BINARY)
+    }
+
+    #[inline(always)]
+    fn fory_static_type_id() -> TypeId
+    where
+        Self: Sized,
+    {
+        TypeId::BINARY
+    }
+
+    #[inline(always)]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline(always)]
+    fn fory_write_type_info(context: &mut WriteContext) -> Result<(), Error> {
+        Vec::<u8>::fory_write_type_info(context)
+    }
+
+    #[inline(always)]
+    fn fory_read_type_info(context: &mut ReadContext) -> Result<(), Error> {
+        Vec::<u8>::fory_read_type_info(context)
+    }
+}
+
+impl ForyDefault for Arc<[u8]> {
+    #[inline(always)]
+    fn fory_default() -> Self {
+        Arc::from(Vec::new())
+    }
+}
@@ -15,6 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use crate::buffer::reverse_element_bytes;
 use crate::context::ReadContext;
 use crate::context::WriteContext;
 use crate::error::Error;
@@ -82,6 +83,10 @@ where
         let dst_ptr = arr.as_mut_ptr() as *mut u8;
         let src = context.reader.read_bytes(size_bytes)?;
         std::ptr::copy_nonoverlapping(src.as_ptr(), dst_ptr, size_bytes);
+        if context.is_big_endian() {
+            let bytes = std::slice::from_raw_parts_mut(dst_ptr, size_bytes);
+            reverse_element_bytes(bytes, elem_size);
+        }
     }
     // Safety: all elements are now initialized with data from the reader
     Ok(unsafe { assume_array_init(&arr) })
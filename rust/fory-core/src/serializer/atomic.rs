@@ -0,0 +1,124 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! `Atomic*` values are written as a `SeqCst` load of the value and read into a new atomic. They
+//! share the type id and wire format of their inner integer or `bool`, so they interoperate with
+//! plain fields.
+
+use crate::context::ReadContext;
+use crate::context::WriteContext;
+use crate::error::Error;
+use crate::resolver::TypeResolver;
+use crate::serializer::{ForyDefault, Serializer};
+use crate::type_id::TypeId;
+use std::sync::atomic::{
+    AtomicBool, AtomicI16, AtomicI32, AtomicI8, AtomicIsize, AtomicU16, AtomicU32, AtomicU8,
+    AtomicUsize, Ordering,
+};
+#[cfg(target_has_atomic = "64")]
+use std::sync::atomic::{AtomicI64, AtomicU64};
+
+macro_rules! impl_atomic_serializer {
+    ($ty:ty, $inner:ty) => {
+        impl Serializer for $ty {
+            #[inline(always)]
+            fn fory_write_data(&self, context: &mut WriteContext) -> Result<(), Error> {
+                self.load(Ordering::SeqCst).fory_write_data(context)
+            }
+
+            #[inline(always)]
+            fn fory_read_data(context: &mut ReadContext) -> Result<Self, Error> {
+                <$inner as Serializer>::fory_read_data(context).map(<$ty>::new)
+            }
+
+            #[inline]
+            fn fory_read_data_as_send_sync_any(
+                context: &mut ReadContext,
+            ) -> Result<Box<dyn std::any::Any + Send + Sync>, Error>
+            where
+                Self: Sized + ForyDefault,
+            {
+                Ok(crate::serializer::box_send_sync(Self::fory_read_data(
+                    context,
+                )?))
+            }
+
+            #[inline(always)]
+            fn fory_reserved_space() -> usize {
+                <$inner as Serializer>::fory_reserved_space()
+            }
+
+            #[inline(always)]
+            fn fory_get_type_id(type_resolver: &TypeResolver) -> Result<TypeId, Error> {
+                <$inner as Serializer>::fory_get_type_id(type_resolver)
+            }
+
+            #[inline(always)]
+            fn fory_type_id_dyn(&self, type_resolver: &TypeResolver) -> Result<TypeId, Error> {
+                <$inner as Serializer>::fory_get_type_id(type_resolver)
+            }
+
+            #[inline(always)]
+            fn fory_static_type_id() -> TypeId {
+                <$inner as Serializer>::fory_static_type_id()
+            }
+
+            /// Keeps `Vec<Atomic*>` off the primitive array path, which copies the values as
+            /// plain memory instead of loading them.
+            #[inline(always)]
+            fn fory_is_wrapper_type() -> bool {
+                true
+            }
+
+            #[inline(always)]
+            fn as_any(&self) -> &dyn std::any::Any {
+                self
+            }
+
+            #[inline(always)]
+            fn fory_write_type_info(context: &mut WriteContext) -> Result<(), Error> {
+                <$inner as Serializer>::fory_write_type_info(context)
+            }
+
+            #[inline(always)]
+            fn fory_read_type_info(context: &mut ReadContext) -> Result<(), Error> {
+                <$inner as Serializer>::fory_read_type_info(context)
+            }
+        }
+
+        impl ForyDefault for $ty {
+            #[inline(always)]
+            fn fory_default() -> Self {
+                <$ty>::new(<$inner as ForyDefault>::fory_default())
+            }
+        }
+    };
+}
+
+impl_atomic_serializer!(AtomicBool, bool);
+impl_atomic_serializer!(AtomicI8, i8);
+impl_atomic_serializer!(AtomicI16, i16);
+impl_atomic_serializer!(AtomicI32, i32);
+#[cfg(target_has_atomic = "64")]
+impl_atomic_serializer!(AtomicI64, i64);
+impl_atomic_serializer!(AtomicIsize, isize);
+impl_atomic_serializer!(AtomicU8, u8);
+impl_atomic_serializer!(AtomicU16, u16);
+impl_atomic_serializer!(AtomicU32, u32);
+#[cfg(target_has_atomic = "64")]
+impl_atomic_serializer!(AtomicU64, u64);
+impl_atomic_serializer!(AtomicUsize, usize);
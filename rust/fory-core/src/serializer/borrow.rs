@@ -0,0 +1,73 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Zero-copy deserialization of values that borrow from the input buffer.
+//!
+//! See [`Fory::deserialize_borrowed`](crate::fory::Fory::deserialize_borrowed).
+
+use crate::buffer::Reader;
+use crate::error::Error;
+use crate::type_id::TypeId;
+
+/// A type that can be deserialized by borrowing from the input buffer.
+///
+/// Implementations read the same wire format as their owned counterparts:
+/// `&str` reads `String` payloads and `&[u8]` reads `Vec<u8>` payloads.
+/// Composite types can implement this trait by reading their fields in wire
+/// order with [`fory_read_borrowed_data`](Self::fory_read_borrowed_data).
+pub trait BorrowDeserialize<'de>: Sized {
+    /// The type id written in front of the root value.
+    fn fory_borrowed_type_id() -> TypeId;
+
+    /// Reads the value payload, without ref flag or type info.
+    fn fory_read_borrowed_data(reader: &mut Reader<'de>) -> Result<Self, Error>;
+}
+
+impl<'de> BorrowDeserialize<'de> for &'de str {
+    #[inline(always)]
+    fn fory_borrowed_type_id() -> TypeId {
+        TypeId::STRING
+    }
+
+    fn fory_read_borrowed_data(reader: &mut Reader<'de>) -> Result<Self, Error> {
+        let bitor = reader.read_var_u36_small()?;
+        let len = (bitor >> 2) as usize;
+        let encoding = bitor & 0b11;
+        let bytes = reader.read_borrowed_bytes(len)?;
+        match encoding {
+            // Latin1 payloads are only valid UTF-8 when they are pure ASCII.
+            0 if bytes.is_ascii() => Ok(unsafe { std::str::from_utf8_unchecked(bytes) }),
+            2 => std::str::from_utf8(bytes).map_err(|_| Error::utf8("invalid UTF-8 string")),
+            _ => Err(Error::encoding_error(format!(
+                "string with encoding {} can't be borrowed as &str",
+                encoding
+            ))),
+        }
+    }
+}
+
+impl<'de> BorrowDeserialize<'de> for &'de [u8] {
+    #[inline(always)]
+    fn fory_borrowed_type_id() -> TypeId {
+        TypeId::BINARY
+    }
+
+    fn fory_read_borrowed_data(reader: &mut Reader<'de>) -> Result<Self, Error> {
+        let len = reader.read_var_u32()? as usize;
+        reader.read_borrowed_bytes(len)
+    }
+}
@@ -52,6 +52,11 @@ impl<T: Serializer + ForyDefault> Serializer for Box<T> {
         T::fory_reserved_space()
     }
 
+    #[inline(always)]
+    fn fory_estimate_size(&self) -> usize {
+        T::fory_estimate_size(self.as_ref())
+    }
+
     #[inline(always)]
     fn fory_get_type_id(type_resolver: &TypeResolver) -> Result<TypeId, Error> {
         T::fory_get_type_id(type_resolver)
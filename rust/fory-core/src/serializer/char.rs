@@ -0,0 +1,105 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::context::ReadContext;
+use crate::context::WriteContext;
+use crate::error::Error;
+use crate::resolver::TypeResolver;
+use crate::serializer::util::read_basic_type_info;
+use crate::serializer::{ForyDefault, Serializer};
+use crate::type_id::TypeId;
+use std::mem;
+
+/// `char` is written as the var_uint32 of its Unicode scalar value.
+///
+/// `char` has no xlang type, so it uses the Rust-specific `CHAR` type id and is only
+/// supported in native mode.
+impl Serializer for char {
+    #[inline(always)]
+    fn fory_write_data(&self, context: &mut WriteContext) -> Result<(), Error> {
+        if context.is_xlang() {
+            return Err(Error::not_allowed(
+                "char is not supported in cross-language mode",
+            ));
+        }
+        context.writer.write_var_u32(*self as u32);
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn fory_read_data(context: &mut ReadContext) -> Result<Self, Error> {
+        let code_point = context.reader.read_var_u32()?;
+        char::from_u32(code_point).ok_or_else(|| {
+            Error::invalid_data(format!("invalid char code point: {:#x}", code_point))
+        })
+    }
+
+    #[inline]
+    fn fory_read_data_as_send_sync_any(
+        context: &mut ReadContext,
+    ) -> Result<Box<dyn std::any::Any + Send + Sync>, Error>
+    where
+        Self: Sized + ForyDefault,
+    {
+        Ok(crate::serializer::box_send_sync(Self::fory_read_data(
+            context,
+        )?))
+    }
+
+    #[inline(always)]
+    fn fory_reserved_space() -> usize {
+        mem::size_of::<char>()
+    }
+
+    #[inline(always)]
+    fn fory_get_type_id(_: &TypeResolver) -> Result<TypeId, Error> {
+        Ok(TypeId::CHAR)
+    }
+
+    #[inline(always)]
+    fn fory_type_id_dyn(&self, _: &TypeResolver) -> Result<TypeId, Error> {
+        Ok(TypeId::CHAR)
+    }
+
+    #[inline(always)]
+    fn fory_static_type_id() -> TypeId {
+        TypeId::CHAR
+    }
+
+    #[inline(always)]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline(always)]
+    fn fory_write_type_info(context: &mut WriteContext) -> Result<(), Error> {
+        context.writer.write_var_u32(TypeId::CHAR as u32);
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn fory_read_type_info(context: &mut ReadContext) -> Result<(), Error> {
+        read_basic_type_info::<Self>(context)
+    }
+}
+
+impl ForyDefault for char {
+    #[inline(always)]
+    fn fory_default() -> Self {
+        '\0'
+    }
+}
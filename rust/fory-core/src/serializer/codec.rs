@@ -22,19 +22,25 @@
 //! nested collection configuration without creating wrapper value types.
 
 use super::collection::{
-    compatible_list_array_field, read_primitive_array_vec_compatible_mismatch,
-    read_vec_compatible_mismatch, CompatibleListArrayElement,
+    check_collection_len, compatible_list_array_field,
+    read_primitive_array_vec_compatible_mismatch, read_vec_compatible_mismatch,
+    CompatibleListArrayElement,
 };
+use super::map::{check_map_len, sorted_entries};
+use crate::buffer::{Reader, Writer};
 use crate::context::{ReadContext, WriteContext};
 use crate::error::Error;
 use crate::meta::{FieldInfo, FieldType};
 use crate::resolver::{RefFlag, RefMode, TypeResolver};
-use crate::serializer::{primitive_list, ForyDefault, Serializer};
+use crate::serializer::{primitive_list, External, ForyDefault, Serializer};
 use crate::type_id::{self, need_to_write_type_for_field, TypeId, SIZE_OF_REF_AND_TYPE, UNKNOWN};
+use crate::util::cipher::field_aad;
+use crate::util::murmurhash3_x64_128;
 use std::any::Any;
 use std::collections::HashMap;
-use std::hash::Hash;
+use std::hash::{BuildHasher, Hash};
 use std::marker::PhantomData;
+use std::num::Wrapping;
 use std::rc::Rc;
 use std::sync::Arc;
 
@@ -361,13 +367,46 @@ compatible_scalar_reader!(
     read_bfloat16_option_target,
     crate::types::bfloat16::bfloat16
 );
-compatible_scalar_reader!(
-    read_decimal_compatible_scalar,
-    read_decimal_option_compatible_scalar,
-    read_decimal_target,
-    read_decimal_option_target,
-    crate::types::Decimal
-);
+
+/// Decimal carriers that compatible-mode scalar conversion can produce.
+///
+/// Conversion always goes through [`crate::types::Decimal`]; carriers with a
+/// narrower range reject values they cannot represent exactly.
+#[doc(hidden)]
+pub trait DecimalCarrier: Sized {
+    fn from_decimal(value: crate::types::Decimal) -> Result<Self, Error>;
+}
+
+impl DecimalCarrier for crate::types::Decimal {
+    #[inline(always)]
+    fn from_decimal(value: crate::types::Decimal) -> Result<Self, Error> {
+        Ok(value)
+    }
+}
+
+#[inline(always)]
+pub fn read_decimal_compatible_scalar<T: DecimalCarrier>(
+    context: &mut ReadContext,
+    local_type: u32,
+    remote_field: &FieldInfo,
+) -> Result<T, Error> {
+    T::from_decimal(super::scalar_conversion::read_decimal_target(
+        context,
+        local_type,
+        remote_field,
+    )?)
+}
+
+#[inline(always)]
+pub fn read_decimal_option_compatible_scalar<T: DecimalCarrier>(
+    context: &mut ReadContext,
+    local_type: u32,
+    remote_field: &FieldInfo,
+) -> Result<Option<T>, Error> {
+    super::scalar_conversion::read_decimal_option_target(context, local_type, remote_field)?
+        .map(T::from_decimal)
+        .transpose()
+}
 
 #[inline(always)]
 pub(super) fn generic_field_type<'a>(
@@ -474,10 +513,25 @@ pub trait Codec<T: 'static>: 'static {
         std::mem::size_of::<T>()
     }
 
+    /// O(1) size hint for `value`, which codecs for strings override to add their length
+    /// and codecs for collections to scale their reserved space by their length.
+    #[inline(always)]
+    fn estimate_size(_value: &T) -> usize {
+        Self::reserved_space()
+    }
+
     fn write_field(value: &T, context: &mut WriteContext) -> Result<(), Error>;
 
     fn read_field(context: &mut ReadContext) -> Result<T, Error>;
 
+    /// Reads a field into an existing value. Codecs for types that own buffers override
+    /// this to reuse the allocation.
+    #[inline(always)]
+    fn read_field_into(value: &mut T, context: &mut ReadContext) -> Result<(), Error> {
+        *value = Self::read_field(context)?;
+        Ok(())
+    }
+
     #[inline(always)]
     fn read_compatible(
         context: &mut ReadContext,
@@ -615,6 +669,11 @@ where
         T::fory_reserved_space() + SIZE_OF_REF_AND_TYPE
     }
 
+    #[inline(always)]
+    fn estimate_size(value: &T) -> usize {
+        value.fory_estimate_size() + SIZE_OF_REF_AND_TYPE
+    }
+
     #[inline(always)]
     fn write_field(value: &T, context: &mut WriteContext) -> Result<(), Error> {
         T::fory_write(
@@ -635,6 +694,16 @@ where
         )
     }
 
+    #[inline(always)]
+    fn read_field_into(value: &mut T, context: &mut ReadContext) -> Result<(), Error> {
+        let read_type_info = serializer_read_type_info::<T>(context);
+        value.fory_read_into(
+            context,
+            serializer_ref_mode::<T, NULLABLE, TRACK_REF>(),
+            read_type_info,
+        )
+    }
+
     #[inline(always)]
     fn write_data(value: &T, context: &mut WriteContext) -> Result<(), Error> {
         T::fory_write_data_generic(value, context, false)
@@ -763,65 +832,70 @@ where
     }
 }
 
-pub struct CollectionSerializerCodec<
-    T,
-    E,
-    EC,
-    const TYPE_ID: u8,
-    const NULLABLE: bool,
-    const TRACK_REF: bool,
->(PhantomData<(T, E, EC)>);
+/// Codec for `#[fory(external)]` values, written through [`External`] with the closures
+/// registered by [`Fory::register_external`](crate::Fory::register_external).
+pub struct ExternalCodec<T, const NULLABLE: bool, const TRACK_REF: bool>(PhantomData<T>);
 
-impl<T, E, EC, const TYPE_ID: u8, const NULLABLE: bool, const TRACK_REF: bool> Codec<T>
-    for CollectionSerializerCodec<T, E, EC, TYPE_ID, NULLABLE, TRACK_REF>
+impl<T, const NULLABLE: bool, const TRACK_REF: bool> Codec<T>
+    for ExternalCodec<T, NULLABLE, TRACK_REF>
 where
-    T: Serializer + ForyDefault,
-    E: 'static,
-    EC: Codec<E>,
+    T: 'static + Default,
 {
     #[inline(always)]
     fn field_type(type_resolver: &TypeResolver) -> Result<FieldType, Error> {
-        Ok(FieldType::new_with_ref(
-            TYPE_ID as u32,
-            NULLABLE,
-            TRACK_REF,
-            vec![EC::field_type(type_resolver)?],
-        ))
+        <ExternalSerializerCodec<T, NULLABLE, TRACK_REF>>::field_type(type_resolver)
     }
 
     #[inline(always)]
     fn reserved_space() -> usize {
-        T::fory_reserved_space() + SIZE_OF_REF_AND_TYPE
+        <ExternalSerializerCodec<T, NULLABLE, TRACK_REF>>::reserved_space()
     }
 
     #[inline(always)]
     fn write_field(value: &T, context: &mut WriteContext) -> Result<(), Error> {
-        T::fory_write(
-            value,
+        <ExternalSerializerCodec<T, NULLABLE, TRACK_REF>>::write_field(
+            External::from_ref(value),
             context,
-            serializer_ref_mode::<T, NULLABLE, TRACK_REF>(),
-            field_write_type_info::<T>(context),
-            true,
         )
     }
 
     #[inline(always)]
     fn read_field(context: &mut ReadContext) -> Result<T, Error> {
-        T::fory_read(
-            context,
-            serializer_ref_mode::<T, NULLABLE, TRACK_REF>(),
-            serializer_read_type_info::<T>(context),
-        )
+        <ExternalSerializerCodec<T, NULLABLE, TRACK_REF>>::read_field(context)
+            .map(External::into_inner)
     }
 
     #[inline(always)]
     fn write_data(value: &T, context: &mut WriteContext) -> Result<(), Error> {
-        T::fory_write_data_generic(value, context, true)
+        <ExternalSerializerCodec<T, NULLABLE, TRACK_REF>>::write_data(
+            External::from_ref(value),
+            context,
+        )
     }
 
     #[inline(always)]
     fn read_data(context: &mut ReadContext) -> Result<T, Error> {
-        T::fory_read_data(context)
+        <ExternalSerializerCodec<T, NULLABLE, TRACK_REF>>::read_data(context)
+            .map(External::into_inner)
+    }
+
+    #[inline(always)]
+    fn read_data_with_type_info(
+        context: &mut ReadContext,
+        type_info: &Rc<crate::TypeInfo>,
+    ) -> Result<T, Error> {
+        <ExternalSerializerCodec<T, NULLABLE, TRACK_REF>>::read_data_with_type_info(
+            context, type_info,
+        )
+        .map(External::into_inner)
+    }
+
+    #[inline(always)]
+    fn type_info_exact(
+        context: &ReadContext,
+        type_info: &Rc<crate::TypeInfo>,
+    ) -> Result<bool, Error> {
+        <ExternalSerializerCodec<T, NULLABLE, TRACK_REF>>::type_info_exact(context, type_info)
     }
 
     #[inline(always)]
@@ -829,11 +903,11 @@ where
         context: &mut ReadContext,
         remote_field_type: &FieldType,
     ) -> Result<T, Error> {
-        T::fory_read(
+        <ExternalSerializerCodec<T, NULLABLE, TRACK_REF>>::read_field_with_type(
             context,
-            field_ref_mode(remote_field_type),
-            field_read_type_info::<T>(context, remote_field_type),
+            remote_field_type,
         )
+        .map(External::into_inner)
     }
 
     #[inline(always)]
@@ -842,9 +916,15 @@ where
         context: &mut WriteContext,
         ref_mode: RefMode,
         write_type_info: bool,
-        _has_generics: bool,
+        has_generics: bool,
     ) -> Result<(), Error> {
-        T::fory_write(value, context, ref_mode, write_type_info, true)
+        <ExternalSerializerCodec<T, NULLABLE, TRACK_REF>>::write_with_mode(
+            External::from_ref(value),
+            context,
+            ref_mode,
+            write_type_info,
+            has_generics,
+        )
     }
 
     #[inline(always)]
@@ -853,7 +933,12 @@ where
         ref_mode: RefMode,
         read_type_info: bool,
     ) -> Result<T, Error> {
-        T::fory_read(context, ref_mode, read_type_info)
+        <ExternalSerializerCodec<T, NULLABLE, TRACK_REF>>::read_with_mode(
+            context,
+            ref_mode,
+            read_type_info,
+        )
+        .map(External::into_inner)
     }
 
     #[inline(always)]
@@ -862,58 +947,222 @@ where
         ref_mode: RefMode,
         type_info: std::rc::Rc<crate::TypeInfo>,
     ) -> Result<T, Error> {
-        T::fory_read_with_type_info(context, ref_mode, type_info)
+        <ExternalSerializerCodec<T, NULLABLE, TRACK_REF>>::read_with_type_info(
+            context, ref_mode, type_info,
+        )
+        .map(External::into_inner)
     }
 
     #[inline(always)]
     fn default_value() -> T {
-        T::fory_default()
+        T::default()
     }
 
     #[inline(always)]
     fn write_type_info(context: &mut WriteContext) -> Result<(), Error> {
-        context.writer.write_u8(TYPE_ID);
-        Ok(())
+        <ExternalSerializerCodec<T, NULLABLE, TRACK_REF>>::write_type_info(context)
     }
 
     #[inline(always)]
     fn read_type_info(context: &mut ReadContext) -> Result<(), Error> {
-        let remote = context.reader.read_u8()?;
-        if remote != TYPE_ID {
-            return Err(Error::type_mismatch(TYPE_ID as u32, remote as u32));
+        <ExternalSerializerCodec<T, NULLABLE, TRACK_REF>>::read_type_info(context)
+    }
+
+    #[inline(always)]
+    fn read_type_info_value(context: &mut ReadContext) -> Result<CodecReadType, Error> {
+        <ExternalSerializerCodec<T, NULLABLE, TRACK_REF>>::read_type_info_value(context)
+    }
+
+    #[inline(always)]
+    fn static_type_id() -> TypeId {
+        <ExternalSerializerCodec<T, NULLABLE, TRACK_REF>>::static_type_id()
+    }
+
+    #[inline(always)]
+    fn concrete_type_id(_value: &T) -> std::any::TypeId {
+        std::any::TypeId::of::<External<T>>()
+    }
+}
+
+type ExternalSerializerCodec<T, const NULLABLE: bool, const TRACK_REF: bool> =
+    SerializerCodec<External<T>, NULLABLE, TRACK_REF>;
+
+/// Codec for `#[fory(encrypt)]` fields. The field is written with `C` as usual, then
+/// replaced by its AES-GCM ciphertext using the key set with
+/// [`ForyBuilder::cipher`](crate::ForyBuilder::cipher). The ciphertext is bound to the
+/// struct `S` and to `FIELD_ID`, the field id or the hash of the field name.
+pub struct EncryptedCodec<T, C, S, const FIELD_ID: u64>(PhantomData<(T, C, S)>);
+
+impl<T, C, S, const FIELD_ID: u64> EncryptedCodec<T, C, S, FIELD_ID>
+where
+    T: 'static,
+    C: Codec<T>,
+    S: 'static,
+{
+    fn aad(type_resolver: &TypeResolver) -> Result<[u8; 16], Error> {
+        let type_info = type_resolver.get_type_info(&std::any::TypeId::of::<S>())?;
+        let type_hash = if type_info.is_registered_by_name() {
+            let name = format!(
+                "{}.{}",
+                type_info.get_namespace().original,
+                type_info.get_type_name().original
+            );
+            murmurhash3_x64_128(name.as_bytes(), 47).0
+        } else {
+            murmurhash3_x64_128(&type_info.get_user_type_id().to_le_bytes(), 47).0
+        };
+        Ok(field_aad(type_hash, FIELD_ID))
+    }
+
+    fn write_encrypted(value: &T, context: &mut WriteContext) -> Result<(), Error> {
+        if context.is_xlang() {
+            return Err(Error::not_allowed(
+                "#[fory(encrypt)] fields are not supported in cross-language mode",
+            ));
+        }
+        let aad = Self::aad(context.get_type_resolver())?;
+        context.write_encrypted(&aad, |context| C::write_field(value, context))
+    }
+}
+
+impl<T, C, S, const FIELD_ID: u64> Codec<T> for EncryptedCodec<T, C, S, FIELD_ID>
+where
+    T: 'static,
+    C: Codec<T>,
+    S: 'static,
+{
+    #[inline(always)]
+    fn field_type(_type_resolver: &TypeResolver) -> Result<FieldType, Error> {
+        Ok(FieldType::new(type_id::ENCRYPTED, false, Vec::new()))
+    }
+
+    #[inline(always)]
+    fn reserved_space() -> usize {
+        // Length prefix, 12-byte nonce and 16-byte tag.
+        C::reserved_space() + 33
+    }
+
+    #[inline(always)]
+    fn estimate_size(value: &T) -> usize {
+        C::estimate_size(value) + 33
+    }
+
+    #[inline(always)]
+    fn write_field(value: &T, context: &mut WriteContext) -> Result<(), Error> {
+        Self::write_encrypted(value, context)
+    }
+
+    #[inline(always)]
+    fn read_field(context: &mut ReadContext) -> Result<T, Error> {
+        let aad = Self::aad(context.get_type_resolver())?;
+        context.read_decrypted(&aad, C::read_field)
+    }
+
+    /// Rejects a remote field written in clear, so a writer can't strip the encryption.
+    fn read_compatible(
+        context: &mut ReadContext,
+        _local_field_type: &FieldType,
+        remote_field_type: &FieldType,
+    ) -> Result<Option<T>, Error> {
+        if remote_field_type.type_id != type_id::ENCRYPTED {
+            return Err(Error::decrypt_failed(
+                "#[fory(encrypt)] field was written in clear",
+            ));
         }
+        Self::read_field(context).map(Some)
+    }
+
+    #[inline(always)]
+    fn write_data(value: &T, context: &mut WriteContext) -> Result<(), Error> {
+        Self::write_encrypted(value, context)
+    }
+
+    #[inline(always)]
+    fn read_data(context: &mut ReadContext) -> Result<T, Error> {
+        Self::read_field(context)
+    }
+
+    #[inline(always)]
+    fn read_field_with_type(
+        context: &mut ReadContext,
+        _remote_field_type: &FieldType,
+    ) -> Result<T, Error> {
+        Self::read_field(context)
+    }
+
+    #[inline(always)]
+    fn write_with_mode(
+        value: &T,
+        context: &mut WriteContext,
+        _ref_mode: RefMode,
+        _write_type_info: bool,
+        _has_generics: bool,
+    ) -> Result<(), Error> {
+        Self::write_encrypted(value, context)
+    }
+
+    #[inline(always)]
+    fn read_with_mode(
+        context: &mut ReadContext,
+        _ref_mode: RefMode,
+        _read_type_info: bool,
+    ) -> Result<T, Error> {
+        Self::read_field(context)
+    }
+
+    #[inline(always)]
+    fn read_with_type_info(
+        context: &mut ReadContext,
+        _ref_mode: RefMode,
+        _type_info: std::rc::Rc<crate::TypeInfo>,
+    ) -> Result<T, Error> {
+        Self::read_field(context)
+    }
+
+    #[inline(always)]
+    fn default_value() -> T {
+        C::default_value()
+    }
+
+    #[inline(always)]
+    fn write_type_info(_context: &mut WriteContext) -> Result<(), Error> {
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn read_type_info(_context: &mut ReadContext) -> Result<(), Error> {
         Ok(())
     }
 
     #[inline(always)]
     fn static_type_id() -> TypeId {
-        TypeId::try_from(TYPE_ID).unwrap_or(TypeId::UNKNOWN)
+        TypeId::ENCRYPTED
     }
 }
 
-pub struct MapSerializerCodec<T, K, V, KC, VC, const NULLABLE: bool, const TRACK_REF: bool>(
-    PhantomData<(T, K, V, KC, VC)>,
-);
+pub struct CollectionSerializerCodec<
+    T,
+    E,
+    EC,
+    const TYPE_ID: u8,
+    const NULLABLE: bool,
+    const TRACK_REF: bool,
+>(PhantomData<(T, E, EC)>);
 
-impl<T, K, V, KC, VC, const NULLABLE: bool, const TRACK_REF: bool> Codec<T>
-    for MapSerializerCodec<T, K, V, KC, VC, NULLABLE, TRACK_REF>
+impl<T, E, EC, const TYPE_ID: u8, const NULLABLE: bool, const TRACK_REF: bool> Codec<T>
+    for CollectionSerializerCodec<T, E, EC, TYPE_ID, NULLABLE, TRACK_REF>
 where
     T: Serializer + ForyDefault,
-    K: 'static,
-    V: 'static,
-    KC: Codec<K>,
-    VC: Codec<V>,
+    E: 'static,
+    EC: Codec<E>,
 {
     #[inline(always)]
     fn field_type(type_resolver: &TypeResolver) -> Result<FieldType, Error> {
         Ok(FieldType::new_with_ref(
-            TypeId::MAP as u32,
+            TYPE_ID as u32,
             NULLABLE,
             TRACK_REF,
-            vec![
-                KC::field_type(type_resolver)?,
-                VC::field_type(type_resolver)?,
-            ],
+            vec![EC::field_type(type_resolver)?],
         ))
     }
 
@@ -922,6 +1171,11 @@ where
         T::fory_reserved_space() + SIZE_OF_REF_AND_TYPE
     }
 
+    #[inline(always)]
+    fn estimate_size(value: &T) -> usize {
+        value.fory_estimate_size() + SIZE_OF_REF_AND_TYPE
+    }
+
     #[inline(always)]
     fn write_field(value: &T, context: &mut WriteContext) -> Result<(), Error> {
         T::fory_write(
@@ -942,6 +1196,16 @@ where
         )
     }
 
+    #[inline(always)]
+    fn read_field_into(value: &mut T, context: &mut ReadContext) -> Result<(), Error> {
+        let read_type_info = serializer_read_type_info::<T>(context);
+        value.fory_read_into(
+            context,
+            serializer_ref_mode::<T, NULLABLE, TRACK_REF>(),
+            read_type_info,
+        )
+    }
+
     #[inline(always)]
     fn write_data(value: &T, context: &mut WriteContext) -> Result<(), Error> {
         T::fory_write_data_generic(value, context, true)
@@ -1000,26 +1264,186 @@ where
 
     #[inline(always)]
     fn write_type_info(context: &mut WriteContext) -> Result<(), Error> {
-        context.writer.write_u8(TypeId::MAP as u8);
+        context.writer.write_u8(TYPE_ID);
         Ok(())
     }
 
     #[inline(always)]
     fn read_type_info(context: &mut ReadContext) -> Result<(), Error> {
-        let remote = context.reader.read_u8()? as u32;
-        if remote != TypeId::MAP as u32 {
-            return Err(Error::type_mismatch(TypeId::MAP as u32, remote));
+        let remote = context.reader.read_u8()?;
+        if remote != TYPE_ID {
+            return Err(Error::type_mismatch(TYPE_ID as u32, remote as u32));
         }
         Ok(())
     }
 
     #[inline(always)]
     fn static_type_id() -> TypeId {
-        TypeId::MAP
+        TypeId::try_from(TYPE_ID).unwrap_or(TypeId::UNKNOWN)
     }
 }
 
-pub struct OptionCodec<T, C, const TRACK_REF: bool>(PhantomData<(T, C)>);
+pub struct MapSerializerCodec<T, K, V, KC, VC, const NULLABLE: bool, const TRACK_REF: bool>(
+    PhantomData<(T, K, V, KC, VC)>,
+);
+
+impl<T, K, V, KC, VC, const NULLABLE: bool, const TRACK_REF: bool> Codec<T>
+    for MapSerializerCodec<T, K, V, KC, VC, NULLABLE, TRACK_REF>
+where
+    T: Serializer + ForyDefault,
+    K: 'static,
+    V: 'static,
+    KC: Codec<K>,
+    VC: Codec<V>,
+{
+    #[inline(always)]
+    fn field_type(type_resolver: &TypeResolver) -> Result<FieldType, Error> {
+        Ok(FieldType::new_with_ref(
+            TypeId::MAP as u32,
+            NULLABLE,
+            TRACK_REF,
+            vec![
+                KC::field_type(type_resolver)?,
+                VC::field_type(type_resolver)?,
+            ],
+        ))
+    }
+
+    #[inline(always)]
+    fn reserved_space() -> usize {
+        T::fory_reserved_space() + SIZE_OF_REF_AND_TYPE
+    }
+
+    #[inline(always)]
+    fn estimate_size(value: &T) -> usize {
+        value.fory_estimate_size() + SIZE_OF_REF_AND_TYPE
+    }
+
+    #[inline(always)]
+    fn write_field(value: &T, context: &mut WriteContext) -> Result<(), Error> {
+        T::fory_write(
+            value,
+            context,
+            serializer_ref_mode::<T, NULLABLE, TRACK_REF>(),
+            field_write_type_info::<T>(context),
+            true,
+        )
+    }
+
+    #[inline(always)]
+    fn read_field(context: &mut ReadContext) -> Result<T, Error> {
+        T::fory_read(
+            context,
+            serializer_ref_mode::<T, NULLABLE, TRACK_REF>(),
+            serializer_read_type_info::<T>(context),
+        )
+    }
+
+    #[inline(always)]
+    fn read_field_into(value: &mut T, context: &mut ReadContext) -> Result<(), Error> {
+        let read_type_info = serializer_read_type_info::<T>(context);
+        value.fory_read_into(
+            context,
+            serializer_ref_mode::<T, NULLABLE, TRACK_REF>(),
+            read_type_info,
+        )
+    }
+
+    #[inline(always)]
+    fn write_data(value: &T, context: &mut WriteContext) -> Result<(), Error> {
+        T::fory_write_data_generic(value, context, true)
+    }
+
+    #[inline(always)]
+    fn read_data(context: &mut ReadContext) -> Result<T, Error> {
+        T::fory_read_data(context)
+    }
+
+    #[inline(always)]
+    fn read_field_with_type(
+        context: &mut ReadContext,
+        remote_field_type: &FieldType,
+    ) -> Result<T, Error> {
+        T::fory_read(
+            context,
+            field_ref_mode(remote_field_type),
+            field_read_type_info::<T>(context, remote_field_type),
+        )
+    }
+
+    #[inline(always)]
+    fn write_with_mode(
+        value: &T,
+        context: &mut WriteContext,
+        ref_mode: RefMode,
+        write_type_info: bool,
+        _has_generics: bool,
+    ) -> Result<(), Error> {
+        T::fory_write(value, context, ref_mode, write_type_info, true)
+    }
+
+    #[inline(always)]
+    fn read_with_mode(
+        context: &mut ReadContext,
+        ref_mode: RefMode,
+        read_type_info: bool,
+    ) -> Result<T, Error> {
+        T::fory_read(context, ref_mode, read_type_info)
+    }
+
+    #[inline(always)]
+    fn read_with_type_info(
+        context: &mut ReadContext,
+        ref_mode: RefMode,
+        type_info: std::rc::Rc<crate::TypeInfo>,
+    ) -> Result<T, Error> {
+        T::fory_read_with_type_info(context, ref_mode, type_info)
+    }
+
+    #[inline(always)]
+    fn default_value() -> T {
+        T::fory_default()
+    }
+
+    #[inline(always)]
+    fn write_type_info(context: &mut WriteContext) -> Result<(), Error> {
+        context.writer.write_u8(TypeId::MAP as u8);
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn read_type_info(context: &mut ReadContext) -> Result<(), Error> {
+        let remote = context.reader.read_u8()? as u32;
+        if remote != TypeId::MAP as u32 {
+            return Err(Error::type_mismatch(TypeId::MAP as u32, remote));
+        }
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn static_type_id() -> TypeId {
+        TypeId::MAP
+    }
+}
+
+pub struct OptionCodec<T, C, const TRACK_REF: bool>(PhantomData<(T, C)>);
+
+impl<T, C, const TRACK_REF: bool> OptionCodec<T, C, TRACK_REF>
+where
+    T: 'static,
+    C: Codec<T>,
+{
+    /// Ref mode for the value inside `Some`. A nested option writes its own
+    /// null flag after the outer one, so `None` and `Some(None)` stay distinct.
+    #[inline(always)]
+    fn inner_ref_mode() -> RefMode {
+        if C::is_option() {
+            RefMode::NullOnly
+        } else {
+            RefMode::None
+        }
+    }
+}
 
 impl<T, C, const TRACK_REF: bool> Codec<Option<T>> for OptionCodec<T, C, TRACK_REF>
 where
@@ -1039,6 +1463,13 @@ where
         C::reserved_space() + 1
     }
 
+    #[inline(always)]
+    fn estimate_size(value: &Option<T>) -> usize {
+        value
+            .as_ref()
+            .map_or(1, |value| C::estimate_size(value) + 1)
+    }
+
     #[inline(always)]
     fn write_field(value: &Option<T>, context: &mut WriteContext) -> Result<(), Error> {
         Self::write_with_mode(
@@ -1072,12 +1503,21 @@ where
         let value = value.as_ref().ok_or_else(|| {
             Error::invalid_data("Option::None cannot be written as non-null data")
         })?;
-        C::write_data(value, context)
+        if C::is_option() {
+            // Nested options keep their own null flag so `Some(None)` survives.
+            C::write_with_mode(value, context, RefMode::NullOnly, false, false)
+        } else {
+            C::write_data(value, context)
+        }
     }
 
     #[inline(always)]
     fn read_data(context: &mut ReadContext) -> Result<Option<T>, Error> {
-        Ok(Some(C::read_data(context)?))
+        if C::is_option() {
+            Ok(Some(C::read_with_mode(context, RefMode::NullOnly, false)?))
+        } else {
+            Ok(Some(C::read_data(context)?))
+        }
     }
 
     #[inline(always)]
@@ -1085,7 +1525,11 @@ where
         context: &mut ReadContext,
         remote_data_type: &FieldType,
     ) -> Result<Option<T>, Error> {
-        Ok(Some(C::read_data_with_type(context, remote_data_type)?))
+        if C::is_option() {
+            Ok(Some(C::read_with_mode(context, RefMode::NullOnly, false)?))
+        } else {
+            Ok(Some(C::read_data_with_type(context, remote_data_type)?))
+        }
     }
 
     #[inline(always)]
@@ -1101,7 +1545,9 @@ where
                 return Ok(None);
             }
             if ref_mode == RefMode::Tracking {
-                context.reader.move_back(1);
+                if !C::is_option() {
+                    context.reader.move_back(1);
+                }
                 return Ok(Some(C::read_with_mode(
                     context,
                     RefMode::Tracking,
@@ -1109,7 +1555,13 @@ where
                 )?));
             }
         }
-        if read_type_info || C::is_polymorphic() || C::is_shared_ref() {
+        if C::is_option() {
+            Ok(Some(C::read_with_mode(
+                context,
+                RefMode::NullOnly,
+                read_type_info,
+            )?))
+        } else if read_type_info || C::is_polymorphic() || C::is_shared_ref() {
             Ok(Some(C::read_with_mode(
                 context,
                 RefMode::None,
@@ -1146,12 +1598,31 @@ where
         write_type_info: bool,
         has_generics: bool,
     ) -> Result<(), Error> {
+        let inner_ref_mode = Self::inner_ref_mode();
         match ref_mode {
+            RefMode::None if C::is_option() => {
+                let value = value.as_ref().ok_or_else(|| {
+                    Error::invalid_data("Option::None cannot be written as non-null data")
+                })?;
+                C::write_with_mode(
+                    value,
+                    context,
+                    inner_ref_mode,
+                    write_type_info,
+                    has_generics,
+                )
+            }
             RefMode::None => Self::write_data(value, context),
             RefMode::NullOnly => {
                 if let Some(value) = value {
                     context.writer.write_i8(RefFlag::NotNullValue as i8);
-                    C::write_with_mode(value, context, RefMode::None, write_type_info, has_generics)
+                    C::write_with_mode(
+                        value,
+                        context,
+                        inner_ref_mode,
+                        write_type_info,
+                        has_generics,
+                    )
                 } else {
                     context.writer.write_i8(RefFlag::Null as i8);
                     Ok(())
@@ -1159,6 +1630,9 @@ where
             }
             RefMode::Tracking => {
                 if let Some(value) = value {
+                    if C::is_option() {
+                        context.writer.write_i8(RefFlag::NotNullValue as i8);
+                    }
                     C::write_with_mode(
                         value,
                         context,
@@ -1183,7 +1657,7 @@ where
         match ref_mode {
             RefMode::None => Ok(Some(C::read_with_mode(
                 context,
-                RefMode::None,
+                Self::inner_ref_mode(),
                 read_type_info,
             )?)),
             RefMode::NullOnly => {
@@ -1193,7 +1667,7 @@ where
                 }
                 Ok(Some(C::read_with_mode(
                     context,
-                    RefMode::None,
+                    Self::inner_ref_mode(),
                     read_type_info,
                 )?))
             }
@@ -1202,7 +1676,9 @@ where
                 if ref_flag == RefFlag::Null as i8 {
                     return Ok(None);
                 }
-                context.reader.move_back(1);
+                if !C::is_option() {
+                    context.reader.move_back(1);
+                }
                 Ok(Some(C::read_with_mode(
                     context,
                     RefMode::Tracking,
@@ -1221,7 +1697,7 @@ where
         match ref_mode {
             RefMode::None => Ok(Some(C::read_with_type_info(
                 context,
-                RefMode::None,
+                Self::inner_ref_mode(),
                 type_info,
             )?)),
             RefMode::NullOnly => {
@@ -1231,7 +1707,7 @@ where
                 }
                 Ok(Some(C::read_with_type_info(
                     context,
-                    RefMode::None,
+                    Self::inner_ref_mode(),
                     type_info,
                 )?))
             }
@@ -1240,7 +1716,9 @@ where
                 if ref_flag == RefFlag::Null as i8 {
                     return Ok(None);
                 }
-                context.reader.move_back(1);
+                if !C::is_option() {
+                    context.reader.move_back(1);
+                }
                 Ok(Some(C::read_with_type_info(
                     context,
                     RefMode::Tracking,
@@ -1291,6 +1769,165 @@ where
     }
 }
 
+/// Codec for `Wrapping<T>` fields, which have the field type and wire format of `T`.
+pub struct WrappingCodec<T, C>(PhantomData<(T, C)>);
+
+impl<T, C> Codec<Wrapping<T>> for WrappingCodec<T, C>
+where
+    T: 'static,
+    C: Codec<T>,
+{
+    #[inline(always)]
+    fn field_type(type_resolver: &TypeResolver) -> Result<FieldType, Error> {
+        C::field_type(type_resolver)
+    }
+
+    #[inline(always)]
+    fn reserved_space() -> usize {
+        C::reserved_space()
+    }
+
+    #[inline(always)]
+    fn estimate_size(value: &Wrapping<T>) -> usize {
+        C::estimate_size(&value.0)
+    }
+
+    #[inline(always)]
+    fn write_field(value: &Wrapping<T>, context: &mut WriteContext) -> Result<(), Error> {
+        C::write_field(&value.0, context)
+    }
+
+    #[inline(always)]
+    fn read_field(context: &mut ReadContext) -> Result<Wrapping<T>, Error> {
+        C::read_field(context).map(Wrapping)
+    }
+
+    #[inline(always)]
+    fn read_field_into(value: &mut Wrapping<T>, context: &mut ReadContext) -> Result<(), Error> {
+        C::read_field_into(&mut value.0, context)
+    }
+
+    #[inline(always)]
+    fn read_compatible(
+        context: &mut ReadContext,
+        local_field_type: &FieldType,
+        remote_field_type: &FieldType,
+    ) -> Result<Option<Wrapping<T>>, Error> {
+        Ok(C::read_compatible(context, local_field_type, remote_field_type)?.map(Wrapping))
+    }
+
+    #[inline(always)]
+    fn write_data(value: &Wrapping<T>, context: &mut WriteContext) -> Result<(), Error> {
+        C::write_data(&value.0, context)
+    }
+
+    #[inline(always)]
+    fn read_data(context: &mut ReadContext) -> Result<Wrapping<T>, Error> {
+        C::read_data(context).map(Wrapping)
+    }
+
+    #[inline(always)]
+    fn read_data_with_type(
+        context: &mut ReadContext,
+        remote_data_type: &FieldType,
+    ) -> Result<Wrapping<T>, Error> {
+        C::read_data_with_type(context, remote_data_type).map(Wrapping)
+    }
+
+    #[inline(always)]
+    fn read_data_with_type_info(
+        context: &mut ReadContext,
+        type_info: &Rc<crate::TypeInfo>,
+    ) -> Result<Wrapping<T>, Error> {
+        C::read_data_with_type_info(context, type_info).map(Wrapping)
+    }
+
+    #[inline(always)]
+    fn type_info_exact(
+        context: &ReadContext,
+        type_info: &Rc<crate::TypeInfo>,
+    ) -> Result<bool, Error> {
+        C::type_info_exact(context, type_info)
+    }
+
+    #[inline(always)]
+    fn read_field_with_type(
+        context: &mut ReadContext,
+        remote_field_type: &FieldType,
+    ) -> Result<Wrapping<T>, Error> {
+        C::read_field_with_type(context, remote_field_type).map(Wrapping)
+    }
+
+    #[inline(always)]
+    fn write_with_mode(
+        value: &Wrapping<T>,
+        context: &mut WriteContext,
+        ref_mode: RefMode,
+        write_type_info: bool,
+        has_generics: bool,
+    ) -> Result<(), Error> {
+        C::write_with_mode(&value.0, context, ref_mode, write_type_info, has_generics)
+    }
+
+    #[inline(always)]
+    fn read_with_mode(
+        context: &mut ReadContext,
+        ref_mode: RefMode,
+        read_type_info: bool,
+    ) -> Result<Wrapping<T>, Error> {
+        C::read_with_mode(context, ref_mode, read_type_info).map(Wrapping)
+    }
+
+    #[inline(always)]
+    fn read_with_type_info(
+        context: &mut ReadContext,
+        ref_mode: RefMode,
+        type_info: Rc<crate::TypeInfo>,
+    ) -> Result<Wrapping<T>, Error> {
+        C::read_with_type_info(context, ref_mode, type_info).map(Wrapping)
+    }
+
+    #[inline(always)]
+    fn default_value() -> Wrapping<T> {
+        Wrapping(C::default_value())
+    }
+
+    #[inline(always)]
+    fn write_type_info(context: &mut WriteContext) -> Result<(), Error> {
+        C::write_type_info(context)
+    }
+
+    #[inline(always)]
+    fn read_type_info(context: &mut ReadContext) -> Result<(), Error> {
+        C::read_type_info(context)
+    }
+
+    #[inline(always)]
+    fn read_type_info_value(context: &mut ReadContext) -> Result<CodecReadType, Error> {
+        C::read_type_info_value(context)
+    }
+
+    #[inline(always)]
+    fn read_type_info_as_field_type(context: &mut ReadContext) -> Result<FieldType, Error> {
+        C::read_type_info_as_field_type(context)
+    }
+
+    #[inline(always)]
+    fn static_type_id() -> TypeId {
+        C::static_type_id()
+    }
+
+    #[inline(always)]
+    fn is_polymorphic() -> bool {
+        C::is_polymorphic()
+    }
+
+    #[inline(always)]
+    fn is_shared_ref() -> bool {
+        C::is_shared_ref()
+    }
+}
+
 macro_rules! signed_int_codec {
     ($name:ident, $ty:ty, $default_type:expr, $fixed_type:expr, $tagged_type:expr, $write_fixed:ident, $read_fixed:ident, $write_var:ident, $read_var:ident, $write_tagged:ident, $read_tagged:ident) => {
         pub struct $name<const WIRE_TYPE_ID: u8, const NULLABLE: bool, const TRACK_REF: bool>;
@@ -1335,7 +1972,7 @@ macro_rules! signed_int_codec {
             #[inline(always)]
             fn write_data(value: &$ty, context: &mut WriteContext) -> Result<(), Error> {
                 match WIRE_TYPE_ID as u32 {
-                    x if x == $fixed_type => context.writer.$write_fixed(*value),
+                    x if x == $fixed_type => context.write_fixed(Writer::$write_fixed, *value),
                     x if x == $tagged_type => context.writer.$write_tagged(*value),
                     _ => context.writer.$write_var(*value),
                 }
@@ -1345,7 +1982,7 @@ macro_rules! signed_int_codec {
             #[inline(always)]
             fn read_data(context: &mut ReadContext) -> Result<$ty, Error> {
                 match WIRE_TYPE_ID as u32 {
-                    x if x == $fixed_type => context.reader.$read_fixed(),
+                    x if x == $fixed_type => context.read_fixed(Reader::$read_fixed),
                     x if x == $tagged_type => context.reader.$read_tagged(),
                     _ => context.reader.$read_var(),
                 }
@@ -1357,7 +1994,7 @@ macro_rules! signed_int_codec {
                 remote_data_type: &FieldType,
             ) -> Result<$ty, Error> {
                 match remote_data_type.type_id {
-                    x if x == $fixed_type => context.reader.$read_fixed(),
+                    x if x == $fixed_type => context.read_fixed(Reader::$read_fixed),
                     x if x == $tagged_type => context.reader.$read_tagged(),
                     _ => context.reader.$read_var(),
                 }
@@ -1375,7 +2012,7 @@ macro_rules! signed_int_codec {
                     }
                 }
                 match remote_field_type.type_id {
-                    x if x == $fixed_type => context.reader.$read_fixed(),
+                    x if x == $fixed_type => context.read_fixed(Reader::$read_fixed),
                     x if x == $tagged_type => context.reader.$read_tagged(),
                     _ => context.reader.$read_var(),
                 }
@@ -1518,9 +2155,99 @@ signed_int_codec!(
 
 pub struct VecCodec<T, C, const NULLABLE: bool, const TRACK_REF: bool>(PhantomData<(T, C)>);
 
+impl<T, C, const NULLABLE: bool, const TRACK_REF: bool> VecCodec<T, C, NULLABLE, TRACK_REF>
+where
+    T: 'static,
+    C: Codec<T>,
+{
+    /// Reads list data into `vec`, keeping its allocation unless the elements are dynamic.
+    fn read_data_reusing(context: &mut ReadContext, mut vec: Vec<T>) -> Result<Vec<T>, Error> {
+        vec.clear();
+        let len = context.reader.read_var_u32()?;
+        if len == 0 {
+            return Ok(vec);
+        }
+        let max = context.max_collection_size();
+        if len > max {
+            return Err(Error::size_limit_exceeded(format!(
+                "Collection size {} exceeds limit {}",
+                len, max
+            )));
+        }
+        check_collection_len::<T>(context, len)?;
+        let header = context.reader.read_u8()?;
+        if C::is_polymorphic() || C::is_shared_ref() {
+            let field_type = Self::field_type(context.get_type_resolver())?;
+            return read_vec_dynamic_items::<T, C>(context, len, header, &field_type);
+        }
+        if (header & IS_SAME_TYPE) == 0 {
+            return Err(Error::type_error(
+                "Type inconsistent, target collection element type is not polymorphic",
+            ));
+        }
+        let read_type = if (header & DECL_ELEMENT_TYPE) == 0 {
+            let codec_read_type = C::read_type_info_value(context)?;
+            Some(element_read_type::<T, C>(context, codec_read_type)?)
+        } else {
+            None
+        };
+        let has_null = (header & HAS_NULL) != 0;
+        read_vec_items::<T, C>(context, vec, len, has_null, read_type)
+    }
+
+    /// Writes list data from a slice, shared by `Vec<T>` and `Box<[T]>` fields.
+    fn write_slice_data(value: &[T], context: &mut WriteContext) -> Result<(), Error> {
+        let len = value.len();
+        context.writer.write_var_u32(len as u32);
+        if len == 0 {
+            return Ok(());
+        }
+        if C::is_polymorphic() || C::is_shared_ref() {
+            return write_vec_dynamic::<T, C>(value, context);
+        }
+        let mut header = IS_SAME_TYPE;
+        let mut has_null = false;
+        if C::is_option() {
+            for item in value {
+                if C::is_none(item) {
+                    has_null = true;
+                    break;
+                }
+            }
+        }
+        if has_null {
+            header |= HAS_NULL;
+        }
+        if !need_to_write_type_for_field(C::static_type_id()) {
+            header |= DECL_ELEMENT_TYPE;
+            context.writer.write_u8(header);
+        } else {
+            context.writer.write_u8(header);
+            C::write_type_info(context)?;
+        }
+        context.writer.reserve(len * C::reserved_space());
+        if has_null {
+            for item in value {
+                if C::is_none(item) {
+                    context.writer.write_i8(RefFlag::Null as i8);
+                    continue;
+                }
+                context.writer.write_i8(RefFlag::NotNullValue as i8);
+                C::write_data(item, context)?;
+            }
+        } else {
+            for item in value {
+                C::write_data(item, context)?;
+            }
+        }
+        Ok(())
+    }
+}
+
 #[inline(always)]
 fn read_vec_items<T, C>(
     context: &mut ReadContext,
+    mut vec: Vec<T>,
     len: u32,
     has_null: bool,
     read_type: Option<ElementReadType>,
@@ -1529,7 +2256,7 @@ where
     T: 'static,
     C: Codec<T>,
 {
-    let mut vec = Vec::with_capacity(len as usize);
+    vec.reserve_exact(len as usize);
     match read_type {
         None | Some(ElementReadType::Direct) => {
             if has_null {
@@ -1605,6 +2332,11 @@ where
         std::mem::size_of::<u32>() + SIZE_OF_REF_AND_TYPE
     }
 
+    #[inline(always)]
+    fn estimate_size(value: &Vec<T>) -> usize {
+        Self::reserved_space() + value.len() * C::reserved_space()
+    }
+
     #[inline(always)]
     fn write_field(value: &Vec<T>, context: &mut WriteContext) -> Result<(), Error> {
         if NULLABLE {
@@ -1624,6 +2356,19 @@ where
         Self::read_data(context)
     }
 
+    #[inline(always)]
+    fn read_field_into(value: &mut Vec<T>, context: &mut ReadContext) -> Result<(), Error> {
+        if NULLABLE {
+            let ref_flag = context.reader.read_i8()?;
+            if ref_flag == RefFlag::Null as i8 {
+                value.clear();
+                return Ok(());
+            }
+        }
+        *value = Self::read_data_reusing(context, std::mem::take(value))?;
+        Ok(())
+    }
+
     #[inline(always)]
     fn read_compatible(
         context: &mut ReadContext,
@@ -1639,88 +2384,18 @@ where
             && collection_type_with_fallback_generics(local_field_type.type_id)
             && (local_field_type.generics.is_empty() || remote_field_type.generics.is_empty())
         {
-            return Self::read_field_with_type(context, remote_field_type).map(Some);
-        }
-        read_vec_compatible_mismatch::<T, C>(context, local_field_type, remote_field_type)
-    }
-
-    fn write_data(value: &Vec<T>, context: &mut WriteContext) -> Result<(), Error> {
-        let len = value.len();
-        context.writer.write_var_u32(len as u32);
-        if len == 0 {
-            return Ok(());
-        }
-        if C::is_polymorphic() || C::is_shared_ref() {
-            return write_vec_dynamic::<T, C>(value, context);
-        }
-        let mut header = IS_SAME_TYPE;
-        let mut has_null = false;
-        if C::is_option() {
-            for item in value {
-                if C::is_none(item) {
-                    has_null = true;
-                    break;
-                }
-            }
-        }
-        if has_null {
-            header |= HAS_NULL;
-        }
-        if !need_to_write_type_for_field(C::static_type_id()) {
-            header |= DECL_ELEMENT_TYPE;
-            context.writer.write_u8(header);
-        } else {
-            context.writer.write_u8(header);
-            C::write_type_info(context)?;
-        }
-        context.writer.reserve(len * C::reserved_space());
-        if has_null {
-            for item in value {
-                if C::is_none(item) {
-                    context.writer.write_i8(RefFlag::Null as i8);
-                    continue;
-                }
-                context.writer.write_i8(RefFlag::NotNullValue as i8);
-                C::write_data(item, context)?;
-            }
-        } else {
-            for item in value {
-                C::write_data(item, context)?;
-            }
-        }
-        Ok(())
-    }
-
-    fn read_data(context: &mut ReadContext) -> Result<Vec<T>, Error> {
-        let len = context.reader.read_var_u32()?;
-        if len == 0 {
-            return Ok(Vec::new());
-        }
-        let max = context.max_collection_size();
-        if len > max {
-            return Err(Error::size_limit_exceeded(format!(
-                "Collection size {} exceeds limit {}",
-                len, max
-            )));
-        }
-        let header = context.reader.read_u8()?;
-        if C::is_polymorphic() || C::is_shared_ref() {
-            let field_type = Self::field_type(context.get_type_resolver())?;
-            return read_vec_dynamic_items::<T, C>(context, len, header, &field_type);
-        }
-        if (header & IS_SAME_TYPE) == 0 {
-            return Err(Error::type_error(
-                "Type inconsistent, target collection element type is not polymorphic",
-            ));
-        }
-        let read_type = if (header & DECL_ELEMENT_TYPE) == 0 {
-            let codec_read_type = C::read_type_info_value(context)?;
-            Some(element_read_type::<T, C>(context, codec_read_type)?)
-        } else {
-            None
-        };
-        let has_null = (header & HAS_NULL) != 0;
-        read_vec_items::<T, C>(context, len, has_null, read_type)
+            return Self::read_field_with_type(context, remote_field_type).map(Some);
+        }
+        read_vec_compatible_mismatch::<T, C>(context, local_field_type, remote_field_type)
+    }
+
+    #[inline(always)]
+    fn write_data(value: &Vec<T>, context: &mut WriteContext) -> Result<(), Error> {
+        Self::write_slice_data(value, context)
+    }
+
+    fn read_data(context: &mut ReadContext) -> Result<Vec<T>, Error> {
+        Self::read_data_reusing(context, Vec::new())
     }
 
     fn read_data_with_type(
@@ -1738,6 +2413,7 @@ where
                 len, max
             )));
         }
+        check_collection_len::<T>(context, len)?;
         let header = context.reader.read_u8()?;
         let has_null = (header & HAS_NULL) != 0;
         let is_same_type = (header & IS_SAME_TYPE) != 0;
@@ -1756,7 +2432,7 @@ where
             let codec_read_type = C::read_type_info_value(context)?;
             element_read_type::<T, C>(context, codec_read_type)?
         };
-        read_vec_items::<T, C>(context, len, has_null, Some(read_type))
+        read_vec_items::<T, C>(context, Vec::new(), len, has_null, Some(read_type))
     }
 
     #[inline(always)]
@@ -1843,6 +2519,142 @@ where
     }
 }
 
+/// Codec for `Box<[T]>` fields, which have the field type and wire format of `Vec<T>` fields.
+pub struct BoxedSliceCodec<T, C, const NULLABLE: bool, const TRACK_REF: bool>(PhantomData<(T, C)>);
+
+impl<T, C, const NULLABLE: bool, const TRACK_REF: bool> Codec<Box<[T]>>
+    for BoxedSliceCodec<T, C, NULLABLE, TRACK_REF>
+where
+    T: 'static,
+    C: Codec<T>,
+{
+    #[inline(always)]
+    fn field_type(type_resolver: &TypeResolver) -> Result<FieldType, Error> {
+        VecCodec::<T, C, NULLABLE, TRACK_REF>::field_type(type_resolver)
+    }
+
+    #[inline(always)]
+    fn reserved_space() -> usize {
+        VecCodec::<T, C, NULLABLE, TRACK_REF>::reserved_space()
+    }
+
+    #[inline(always)]
+    fn estimate_size(value: &Box<[T]>) -> usize {
+        Self::reserved_space() + value.len() * C::reserved_space()
+    }
+
+    #[inline(always)]
+    fn write_field(value: &Box<[T]>, context: &mut WriteContext) -> Result<(), Error> {
+        if NULLABLE {
+            context.writer.write_i8(RefFlag::NotNullValue as i8);
+        }
+        Self::write_data(value, context)
+    }
+
+    #[inline(always)]
+    fn read_field(context: &mut ReadContext) -> Result<Box<[T]>, Error> {
+        VecCodec::<T, C, NULLABLE, TRACK_REF>::read_field(context).map(Vec::into_boxed_slice)
+    }
+
+    #[inline(always)]
+    fn read_compatible(
+        context: &mut ReadContext,
+        local_field_type: &FieldType,
+        remote_field_type: &FieldType,
+    ) -> Result<Option<Box<[T]>>, Error> {
+        Ok(VecCodec::<T, C, NULLABLE, TRACK_REF>::read_compatible(
+            context,
+            local_field_type,
+            remote_field_type,
+        )?
+        .map(Vec::into_boxed_slice))
+    }
+
+    #[inline(always)]
+    fn write_data(value: &Box<[T]>, context: &mut WriteContext) -> Result<(), Error> {
+        VecCodec::<T, C, NULLABLE, TRACK_REF>::write_slice_data(value, context)
+    }
+
+    #[inline(always)]
+    fn read_data(context: &mut ReadContext) -> Result<Box<[T]>, Error> {
+        VecCodec::<T, C, NULLABLE, TRACK_REF>::read_data(context).map(Vec::into_boxed_slice)
+    }
+
+    #[inline(always)]
+    fn read_data_with_type(
+        context: &mut ReadContext,
+        remote_field_type: &FieldType,
+    ) -> Result<Box<[T]>, Error> {
+        VecCodec::<T, C, NULLABLE, TRACK_REF>::read_data_with_type(context, remote_field_type)
+            .map(Vec::into_boxed_slice)
+    }
+
+    #[inline(always)]
+    fn read_field_with_type(
+        context: &mut ReadContext,
+        remote_field_type: &FieldType,
+    ) -> Result<Box<[T]>, Error> {
+        VecCodec::<T, C, NULLABLE, TRACK_REF>::read_field_with_type(context, remote_field_type)
+            .map(Vec::into_boxed_slice)
+    }
+
+    #[inline(always)]
+    fn write_with_mode(
+        value: &Box<[T]>,
+        context: &mut WriteContext,
+        ref_mode: RefMode,
+        write_type_info: bool,
+        _has_generics: bool,
+    ) -> Result<(), Error> {
+        if ref_mode != RefMode::None {
+            context.writer.write_i8(RefFlag::NotNullValue as i8);
+        }
+        if write_type_info {
+            Self::write_type_info(context)?;
+        }
+        Self::write_data(value, context)
+    }
+
+    #[inline(always)]
+    fn read_with_mode(
+        context: &mut ReadContext,
+        ref_mode: RefMode,
+        read_type_info: bool,
+    ) -> Result<Box<[T]>, Error> {
+        VecCodec::<T, C, NULLABLE, TRACK_REF>::read_with_mode(context, ref_mode, read_type_info)
+            .map(Vec::into_boxed_slice)
+    }
+
+    #[inline(always)]
+    fn read_with_type_info(
+        context: &mut ReadContext,
+        ref_mode: RefMode,
+        _type_info: std::rc::Rc<crate::TypeInfo>,
+    ) -> Result<Box<[T]>, Error> {
+        Self::read_with_mode(context, ref_mode, false)
+    }
+
+    #[inline(always)]
+    fn default_value() -> Box<[T]> {
+        Box::default()
+    }
+
+    #[inline(always)]
+    fn write_type_info(context: &mut WriteContext) -> Result<(), Error> {
+        VecCodec::<T, C, NULLABLE, TRACK_REF>::write_type_info(context)
+    }
+
+    #[inline(always)]
+    fn read_type_info(context: &mut ReadContext) -> Result<(), Error> {
+        VecCodec::<T, C, NULLABLE, TRACK_REF>::read_type_info(context)
+    }
+
+    #[inline(always)]
+    fn static_type_id() -> TypeId {
+        TypeId::LIST
+    }
+}
+
 pub struct PrimitiveArrayVecCodec<T, const TYPE_ID: u8, const NULLABLE: bool, const TRACK_REF: bool>(
     PhantomData<T>,
 );
@@ -1867,6 +2679,11 @@ where
         primitive_list::fory_reserved_space::<T>() + SIZE_OF_REF_AND_TYPE
     }
 
+    #[inline(always)]
+    fn estimate_size(value: &Vec<T>) -> usize {
+        primitive_list::fory_estimate_size(value) + SIZE_OF_REF_AND_TYPE
+    }
+
     #[inline(always)]
     fn write_field(value: &Vec<T>, context: &mut WriteContext) -> Result<(), Error> {
         if TRACK_REF || NULLABLE {
@@ -1886,6 +2703,18 @@ where
         Self::read_data(context)
     }
 
+    #[inline(always)]
+    fn read_field_into(value: &mut Vec<T>, context: &mut ReadContext) -> Result<(), Error> {
+        if TRACK_REF || NULLABLE {
+            let ref_flag = context.reader.read_i8()?;
+            if ref_flag == RefFlag::Null as i8 {
+                value.clear();
+                return Ok(());
+            }
+        }
+        primitive_list::fory_read_data_into(value, context)
+    }
+
     fn read_compatible(
         context: &mut ReadContext,
         local_field_type: &FieldType,
@@ -1993,7 +2822,7 @@ where
     }
 }
 
-fn write_vec_dynamic<T, C>(value: &Vec<T>, context: &mut WriteContext) -> Result<(), Error>
+fn write_vec_dynamic<T, C>(value: &[T], context: &mut WriteContext) -> Result<(), Error>
 where
     T: 'static,
     C: Codec<T>,
@@ -2130,63 +2959,55 @@ pub struct HashMapCodec<K, V, KC, VC, const NULLABLE: bool, const TRACK_REF: boo
     PhantomData<(K, V, KC, VC)>,
 );
 
-impl<K, V, KC, VC, const NULLABLE: bool, const TRACK_REF: bool> Codec<HashMap<K, V>>
-    for HashMapCodec<K, V, KC, VC, NULLABLE, TRACK_REF>
+impl<K, V, KC, VC, const NULLABLE: bool, const TRACK_REF: bool>
+    HashMapCodec<K, V, KC, VC, NULLABLE, TRACK_REF>
 where
     K: Eq + Hash + 'static,
     V: 'static,
     KC: Codec<K>,
     VC: Codec<V>,
 {
-    #[inline(always)]
-    fn field_type(type_resolver: &TypeResolver) -> Result<FieldType, Error> {
-        Ok(FieldType::new_with_ref(
-            TypeId::MAP as u32,
-            NULLABLE,
-            TRACK_REF,
-            vec![
-                KC::field_type(type_resolver)?,
-                VC::field_type(type_resolver)?,
-            ],
-        ))
-    }
-
-    #[inline(always)]
-    fn reserved_space() -> usize {
-        std::mem::size_of::<u32>() + SIZE_OF_REF_AND_TYPE
-    }
-
-    #[inline(always)]
-    fn write_field(value: &HashMap<K, V>, context: &mut WriteContext) -> Result<(), Error> {
-        if NULLABLE {
-            context.writer.write_i8(RefFlag::NotNullValue as i8);
+    /// Reads map data into `map`, keeping its allocation unless the entries are dynamic.
+    fn read_data_reusing<S: BuildHasher + Default + 'static>(
+        context: &mut ReadContext,
+        mut map: HashMap<K, V, S>,
+    ) -> Result<HashMap<K, V, S>, Error> {
+        map.clear();
+        let len = context.reader.read_var_u32()?;
+        if len == 0 {
+            return Ok(map);
         }
-        Self::write_data(value, context)
-    }
-
-    #[inline(always)]
-    fn read_field(context: &mut ReadContext) -> Result<HashMap<K, V>, Error> {
-        if NULLABLE {
-            let ref_flag = context.reader.read_i8()?;
-            if ref_flag == RefFlag::Null as i8 {
-                return Ok(HashMap::new());
-            }
+        let max = context.max_collection_size();
+        if len > max {
+            return Err(Error::size_limit_exceeded(format!(
+                "Map size {} exceeds limit {}",
+                len, max
+            )));
         }
-        Self::read_data(context)
+        check_map_len(context, len)?;
+        if KC::is_polymorphic()
+            || KC::is_shared_ref()
+            || VC::is_polymorphic()
+            || VC::is_shared_ref()
+        {
+            let field_type =
+                <Self as Codec<HashMap<K, V, S>>>::field_type(context.get_type_resolver())?;
+            return read_map_dynamic::<K, V, S, KC, VC>(context, len, &field_type);
+        }
+        read_map_static::<K, V, S, KC, VC>(context, map, len)
     }
 
-    fn write_data(value: &HashMap<K, V>, context: &mut WriteContext) -> Result<(), Error> {
-        let len = value.len();
-        context.writer.write_var_u32(len as u32);
-        if len == 0 {
-            return Ok(());
-        }
+    /// Writes the chunks of non-empty map data, after the length.
+    fn write_entries<'a>(
+        entries: impl Iterator<Item = (&'a K, &'a V)>,
+        context: &mut WriteContext,
+    ) -> Result<(), Error> {
         if KC::is_polymorphic()
             || KC::is_shared_ref()
             || VC::is_polymorphic()
             || VC::is_shared_ref()
         {
-            return write_map_dynamic::<K, V, KC, VC>(value, context);
+            return write_map_dynamic::<K, V, KC, VC>(entries, context);
         }
         let key_declared = !need_to_write_type_for_field(KC::static_type_id());
         let value_declared = !need_to_write_type_for_field(VC::static_type_id());
@@ -2194,7 +3015,7 @@ where
         let mut pair_counter: u8 = 0;
         let mut need_write_header = true;
 
-        for (key, value) in value {
+        for (key, value) in entries {
             let key_is_none = KC::is_none(key);
             let value_is_none = VC::is_none(value);
             if key_is_none || value_is_none {
@@ -2274,37 +3095,100 @@ where
         }
         Ok(())
     }
+}
 
-    fn read_data(context: &mut ReadContext) -> Result<HashMap<K, V>, Error> {
-        let len = context.reader.read_var_u32()?;
-        if len == 0 {
-            return Ok(HashMap::new());
+impl<K, V, S, KC, VC, const NULLABLE: bool, const TRACK_REF: bool> Codec<HashMap<K, V, S>>
+    for HashMapCodec<K, V, KC, VC, NULLABLE, TRACK_REF>
+where
+    K: Eq + Hash + 'static,
+    V: 'static,
+    S: BuildHasher + Default + 'static,
+    KC: Codec<K>,
+    VC: Codec<V>,
+{
+    #[inline(always)]
+    fn field_type(type_resolver: &TypeResolver) -> Result<FieldType, Error> {
+        Ok(FieldType::new_with_ref(
+            TypeId::MAP as u32,
+            NULLABLE,
+            TRACK_REF,
+            vec![
+                KC::field_type(type_resolver)?,
+                VC::field_type(type_resolver)?,
+            ],
+        ))
+    }
+
+    #[inline(always)]
+    fn reserved_space() -> usize {
+        std::mem::size_of::<u32>() + SIZE_OF_REF_AND_TYPE
+    }
+
+    #[inline(always)]
+    fn estimate_size(value: &HashMap<K, V, S>) -> usize {
+        <Self as Codec<HashMap<K, V, S>>>::reserved_space()
+            + value.len() * (KC::reserved_space() + VC::reserved_space() + 1)
+    }
+
+    #[inline(always)]
+    fn write_field(value: &HashMap<K, V, S>, context: &mut WriteContext) -> Result<(), Error> {
+        if NULLABLE {
+            context.writer.write_i8(RefFlag::NotNullValue as i8);
         }
-        let max = context.max_collection_size();
-        if len > max {
-            return Err(Error::size_limit_exceeded(format!(
-                "Map size {} exceeds limit {}",
-                len, max
-            )));
+        Self::write_data(value, context)
+    }
+
+    #[inline(always)]
+    fn read_field(context: &mut ReadContext) -> Result<HashMap<K, V, S>, Error> {
+        if NULLABLE {
+            let ref_flag = context.reader.read_i8()?;
+            if ref_flag == RefFlag::Null as i8 {
+                return Ok(HashMap::default());
+            }
         }
-        if KC::is_polymorphic()
-            || KC::is_shared_ref()
-            || VC::is_polymorphic()
-            || VC::is_shared_ref()
-        {
-            let field_type = Self::field_type(context.get_type_resolver())?;
-            return read_map_dynamic::<K, V, KC, VC>(context, len, &field_type);
+        Self::read_data(context)
+    }
+
+    #[inline(always)]
+    fn read_field_into(
+        value: &mut HashMap<K, V, S>,
+        context: &mut ReadContext,
+    ) -> Result<(), Error> {
+        if NULLABLE {
+            let ref_flag = context.reader.read_i8()?;
+            if ref_flag == RefFlag::Null as i8 {
+                value.clear();
+                return Ok(());
+            }
         }
-        read_map_static::<K, V, KC, VC>(context, len)
+        *value = Self::read_data_reusing(context, std::mem::take(value))?;
+        Ok(())
+    }
+
+    fn write_data(value: &HashMap<K, V, S>, context: &mut WriteContext) -> Result<(), Error> {
+        let len = value.len();
+        context.writer.write_var_u32(len as u32);
+        if len == 0 {
+            return Ok(());
+        }
+        if context.is_deterministic() && len > 1 {
+            let entries = sorted_entries(value, context, KC::write_data)?;
+            return Self::write_entries(entries.into_iter(), context);
+        }
+        Self::write_entries(value.iter(), context)
+    }
+
+    fn read_data(context: &mut ReadContext) -> Result<HashMap<K, V, S>, Error> {
+        Self::read_data_reusing(context, HashMap::default())
     }
 
     fn read_data_with_type(
         context: &mut ReadContext,
         remote_field_type: &FieldType,
-    ) -> Result<HashMap<K, V>, Error> {
+    ) -> Result<HashMap<K, V, S>, Error> {
         let len = context.reader.read_var_u32()?;
         if len == 0 {
-            return Ok(HashMap::new());
+            return Ok(HashMap::default());
         }
         let max = context.max_collection_size();
         if len > max {
@@ -2313,14 +3197,15 @@ where
                 len, max
             )));
         }
+        check_map_len(context, len)?;
         if KC::is_polymorphic()
             || KC::is_shared_ref()
             || VC::is_polymorphic()
             || VC::is_shared_ref()
         {
-            return read_map_dynamic::<K, V, KC, VC>(context, len, remote_field_type);
+            return read_map_dynamic::<K, V, S, KC, VC>(context, len, remote_field_type);
         }
-        let mut map = HashMap::with_capacity(len as usize);
+        let mut map = HashMap::with_capacity_and_hasher(len as usize, S::default());
         let mut len_counter = 0;
         while len_counter < len {
             let header = context.reader.read_u8()?;
@@ -2374,11 +3259,11 @@ where
     fn read_field_with_type(
         context: &mut ReadContext,
         remote_field_type: &FieldType,
-    ) -> Result<HashMap<K, V>, Error> {
+    ) -> Result<HashMap<K, V, S>, Error> {
         if field_ref_mode(remote_field_type) != RefMode::None {
             let ref_flag = context.reader.read_i8()?;
             if ref_flag == RefFlag::Null as i8 {
-                return Ok(HashMap::new());
+                return Ok(HashMap::default());
             }
         }
         Self::read_data_with_type(context, remote_field_type)
@@ -2386,7 +3271,7 @@ where
 
     #[inline(always)]
     fn write_with_mode(
-        value: &HashMap<K, V>,
+        value: &HashMap<K, V, S>,
         context: &mut WriteContext,
         ref_mode: RefMode,
         write_type_info: bool,
@@ -2396,7 +3281,7 @@ where
             context.writer.write_i8(RefFlag::NotNullValue as i8);
         }
         if write_type_info {
-            Self::write_type_info(context)?;
+            <Self as Codec<HashMap<K, V, S>>>::write_type_info(context)?;
         }
         Self::write_data(value, context)
     }
@@ -2406,15 +3291,15 @@ where
         context: &mut ReadContext,
         ref_mode: RefMode,
         read_type_info: bool,
-    ) -> Result<HashMap<K, V>, Error> {
+    ) -> Result<HashMap<K, V, S>, Error> {
         if ref_mode != RefMode::None {
             let ref_flag = context.reader.read_i8()?;
             if ref_flag == RefFlag::Null as i8 {
-                return Ok(HashMap::new());
+                return Ok(HashMap::default());
             }
         }
         if read_type_info {
-            Self::read_type_info(context)?;
+            <Self as Codec<HashMap<K, V, S>>>::read_type_info(context)?;
         }
         Self::read_data(context)
     }
@@ -2424,13 +3309,13 @@ where
         context: &mut ReadContext,
         ref_mode: RefMode,
         _type_info: std::rc::Rc<crate::TypeInfo>,
-    ) -> Result<HashMap<K, V>, Error> {
+    ) -> Result<HashMap<K, V, S>, Error> {
         Self::read_with_mode(context, ref_mode, false)
     }
 
     #[inline(always)]
-    fn default_value() -> HashMap<K, V> {
-        HashMap::new()
+    fn default_value() -> HashMap<K, V, S> {
+        HashMap::default()
     }
 
     #[inline(always)]
@@ -2459,17 +3344,19 @@ struct MapEntryReadType {
     type_info: Option<std::rc::Rc<crate::TypeInfo>>,
 }
 
-fn read_map_static<K, V, KC, VC>(
+fn read_map_static<K, V, S, KC, VC>(
     context: &mut ReadContext,
+    mut map: HashMap<K, V, S>,
     len: u32,
-) -> Result<HashMap<K, V>, Error>
+) -> Result<HashMap<K, V, S>, Error>
 where
     K: Eq + Hash + 'static,
     V: 'static,
+    S: BuildHasher,
     KC: Codec<K>,
     VC: Codec<V>,
 {
-    let mut map = HashMap::with_capacity(len as usize);
+    map.reserve(len as usize);
     let mut len_counter = 0u32;
     while len_counter < len {
         let header = context.reader.read_u8()?;
@@ -2564,18 +3451,19 @@ where
     }
 }
 
-fn read_map_dynamic<K, V, KC, VC>(
+fn read_map_dynamic<K, V, S, KC, VC>(
     context: &mut ReadContext,
     len: u32,
     remote_field_type: &FieldType,
-) -> Result<HashMap<K, V>, Error>
+) -> Result<HashMap<K, V, S>, Error>
 where
     K: Eq + Hash + 'static,
     V: 'static,
+    S: BuildHasher + Default,
     KC: Codec<K>,
     VC: Codec<V>,
 {
-    let mut map = HashMap::with_capacity(len as usize);
+    let mut map = HashMap::with_capacity_and_hasher(len as usize, S::default());
     let mut len_counter = 0u32;
     while len_counter < len {
         let header = context.reader.read_u8()?;
@@ -2692,8 +3580,8 @@ where
     C::read_data_with_type(context, field_type)
 }
 
-fn write_map_dynamic<K, V, KC, VC>(
-    value: &HashMap<K, V>,
+fn write_map_dynamic<'a, K, V, KC, VC>(
+    entries: impl Iterator<Item = (&'a K, &'a V)>,
     context: &mut WriteContext,
 ) -> Result<(), Error>
 where
@@ -2714,7 +3602,7 @@ where
     let mut pair_counter: u8 = 0;
     let mut need_write_header = true;
 
-    for (key, value) in value {
+    for (key, value) in entries {
         let key_is_none = KC::is_none(key);
         let value_is_none = VC::is_none(value);
         if key_is_none || value_is_none {
@@ -2916,6 +3804,11 @@ macro_rules! any_codec {
                 <$ty as Serializer>::fory_reserved_space() + SIZE_OF_REF_AND_TYPE
             }
 
+            #[inline(always)]
+            fn estimate_size(value: &$ty) -> usize {
+                <$ty as Serializer>::fory_estimate_size(value) + SIZE_OF_REF_AND_TYPE
+            }
+
             #[inline(always)]
             fn write_field(value: &$ty, context: &mut WriteContext) -> Result<(), Error> {
                 <$ty as Serializer>::fory_write(
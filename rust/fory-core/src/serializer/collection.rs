@@ -16,6 +16,7 @@
 // under the License.
 
 use super::codec::{field_ref_mode, generic_field_type, same_numeric_family, Codec};
+use crate::buffer::{reverse_element_bytes, Reader};
 use crate::context::ReadContext;
 use crate::context::WriteContext;
 use crate::ensure;
@@ -41,7 +42,9 @@ fn collection_size_limit_exceeded(len: u32, max: u32) -> Error {
     Error::size_limit_exceeded(format!("Collection size {} exceeds limit {}", len, max))
 }
 
-fn check_collection_len<T: Serializer>(context: &ReadContext, len: u32) -> Result<(), Error> {
+/// Every non-zero-sized element takes at least one byte on the wire, so a declared length
+/// larger than the remaining buffer is rejected before anything is allocated for it.
+pub(crate) fn check_collection_len<T>(context: &ReadContext, len: u32) -> Result<(), Error> {
     if std::mem::size_of::<T>() == 0 {
         return Ok(());
     }
@@ -62,6 +65,13 @@ pub fn write_collection_type_info(
     Ok(())
 }
 
+/// Size hint for a collection of `len` elements: the length and element headers plus the
+/// reserved space of each element. Elements aren't visited, so the hint is O(1).
+#[inline(always)]
+pub fn estimate_collection_size<T: Serializer>(len: usize) -> usize {
+    std::mem::size_of::<u64>() + type_id::SIZE_OF_REF_AND_TYPE + len * T::fory_reserved_space()
+}
+
 pub fn write_collection_data<'a, T, I>(
     iter: I,
     context: &mut WriteContext,
@@ -296,16 +306,28 @@ pub fn read_vec_data<T>(context: &mut ReadContext) -> Result<Vec<T>, Error>
 where
     T: Serializer + ForyDefault,
 {
+    let mut vec = Vec::new();
+    read_vec_data_into(&mut vec, context)?;
+    Ok(vec)
+}
+
+/// Reads a list into `vec`, reusing its allocation when the capacity suffices.
+pub fn read_vec_data_into<T>(vec: &mut Vec<T>, context: &mut ReadContext) -> Result<(), Error>
+where
+    T: Serializer + ForyDefault,
+{
+    vec.clear();
     let len = context.reader.read_var_u32()?;
     if len == 0 {
-        return Ok(Vec::new());
+        return Ok(());
     }
     let max = context.max_collection_size();
     if len > max {
         return Err(collection_size_limit_exceeded(len, max));
     }
     if T::fory_is_polymorphic() || T::fory_is_shared_ref() {
-        return read_vec_data_dyn_ref(context, len);
+        *vec = read_vec_data_dyn_ref(context, len)?;
+        return Ok(());
     }
     let header = context.reader.read_u8()?;
     let declared = (header & DECL_ELEMENT_TYPE) != 0;
@@ -318,7 +340,7 @@ where
         Error::type_error("Type inconsistent, target type is not polymorphic")
     );
     check_collection_len::<T>(context, len)?;
-    let mut vec = Vec::with_capacity(len as usize);
+    vec.reserve_exact(len as usize);
     if !has_null {
         for _ in 0..len {
             vec.push(T::fory_read_data(context)?);
@@ -333,7 +355,7 @@ where
             }
         }
     }
-    Ok(vec)
+    Ok(())
 }
 
 #[inline(always)]
@@ -518,6 +540,10 @@ fn read_primitive_array_data_bulk<T: 'static>(
         unsafe {
             std::ptr::copy_nonoverlapping(src.as_ptr(), vec.as_mut_ptr() as *mut u8, size_bytes);
             vec.set_len(len);
+            if context.is_big_endian() {
+                let bytes = std::slice::from_raw_parts_mut(vec.as_mut_ptr() as *mut u8, size_bytes);
+                reverse_element_bytes(bytes, std::mem::size_of::<T>());
+            }
         }
         Some(Ok(vec))
     }
@@ -621,7 +647,16 @@ pub(super) trait CompatibleListArrayElement: Serializer + ForyDefault {
 }
 
 macro_rules! compatible_exact_element {
+    (fixed $ty:ty, $type_id:expr, $reader:ident) => {
+        compatible_exact_element!(@impl $ty, $type_id, |context: &mut ReadContext| context
+            .read_fixed(Reader::$reader));
+    };
     ($ty:ty, $type_id:expr, $reader:ident) => {
+        compatible_exact_element!(@impl $ty, $type_id, |context: &mut ReadContext| context
+            .reader
+            .$reader());
+    };
+    (@impl $ty:ty, $type_id:expr, $reader:expr) => {
         impl CompatibleListArrayElement for $ty {
             #[inline(always)]
             fn read_list_array_element(
@@ -629,7 +664,7 @@ macro_rules! compatible_exact_element {
                 remote_type_id: u32,
             ) -> Result<Self, Error> {
                 if remote_type_id == $type_id {
-                    context.reader.$reader()
+                    ($reader)(context)
                 } else {
                     Err(Error::type_mismatch(
                         <$ty as Serializer>::fory_static_type_id() as u32,
@@ -650,7 +685,7 @@ macro_rules! compatible_integer_element {
                 remote_type_id: u32,
             ) -> Result<Self, Error> {
                 match remote_type_id {
-                    x if x == $fixed_type => context.reader.$fixed_reader(),
+                    x if x == $fixed_type => context.read_fixed(Reader::$fixed_reader),
                     x if x == $var_type => context.reader.$var_reader(),
                     _ => Err(Error::type_mismatch(
                         <$ty as Serializer>::fory_static_type_id() as u32,
@@ -679,7 +714,7 @@ macro_rules! compatible_tagged_integer_element {
                 remote_type_id: u32,
             ) -> Result<Self, Error> {
                 match remote_type_id {
-                    x if x == $fixed_type => context.reader.$fixed_reader(),
+                    x if x == $fixed_type => context.read_fixed(Reader::$fixed_reader),
                     x if x == $var_type => context.reader.$var_reader(),
                     x if x == $tagged_type => context.reader.$tagged_reader(),
                     _ => Err(Error::type_mismatch(
@@ -707,7 +742,7 @@ impl CompatibleListArrayElement for bool {
 }
 
 compatible_exact_element!(i8, type_id::INT8, read_i8);
-compatible_exact_element!(i16, type_id::INT16, read_i16);
+compatible_exact_element!(fixed i16, type_id::INT16, read_i16);
 compatible_integer_element!(
     i32,
     type_id::INT32,
@@ -725,7 +760,7 @@ compatible_tagged_integer_element!(
     read_tagged_i64
 );
 compatible_exact_element!(u8, type_id::UINT8, read_u8);
-compatible_exact_element!(u16, type_id::UINT16, read_u16);
+compatible_exact_element!(fixed u16, type_id::UINT16, read_u16);
 compatible_integer_element!(
     u32,
     type_id::UINT32,
@@ -742,12 +777,12 @@ compatible_tagged_integer_element!(
     read_var_u64,
     read_tagged_u64
 );
-compatible_exact_element!(float16, type_id::FLOAT16, read_f16);
-compatible_exact_element!(bfloat16, type_id::BFLOAT16, read_bf16);
-compatible_exact_element!(f32, type_id::FLOAT32, read_f32);
-compatible_exact_element!(f64, type_id::FLOAT64, read_f64);
-compatible_exact_element!(i128, type_id::INT128, read_i128);
-compatible_exact_element!(u128, TypeId::U128 as u32, read_u128);
+compatible_exact_element!(fixed float16, type_id::FLOAT16, read_f16);
+compatible_exact_element!(fixed bfloat16, type_id::BFLOAT16, read_bf16);
+compatible_exact_element!(fixed f32, type_id::FLOAT32, read_f32);
+compatible_exact_element!(fixed f64, type_id::FLOAT64, read_f64);
+compatible_exact_element!(fixed i128, type_id::INT128, read_i128);
+compatible_exact_element!(fixed u128, TypeId::U128 as u32, read_u128);
 compatible_exact_element!(isize, type_id::ISIZE, read_isize);
 compatible_exact_element!(usize, type_id::USIZE, read_usize);
 
@@ -791,6 +826,7 @@ where
             "array-compatible list must declare element type",
         ));
     }
+    check_collection_len::<T>(context, len)?;
     let mut vec = Vec::with_capacity(len as usize);
     for _ in 0..len {
         vec.push(T::read_list_array_element(context, element_type.type_id)?);
@@ -890,6 +890,55 @@ pub trait Serializer: 'static {
     where
         Self: Sized + ForyDefault;
 
+    /// Deserialize into an existing value, reusing its allocations where possible.
+    ///
+    /// This is the in-place counterpart of [`fory_read`] and reads exactly the same bytes.
+    /// The value is left in an unspecified but valid state if an error is returned.
+    ///
+    /// # Default Implementation
+    ///
+    /// The default implementation reads a fresh value and assigns it:
+    ///
+    /// ```rust,ignore
+    /// *self = Self::fory_read(context, ref_mode, read_type_info)?;
+    /// ```
+    ///
+    /// # When to Override
+    ///
+    /// Override this method for types that own reusable buffers, such as `Vec<T>` and
+    /// `HashMap<K, V>`, and for structs whose fields can be read in place. Derived structs
+    /// override it automatically.
+    ///
+    /// [`fory_read`]: Serializer::fory_read
+    #[inline(always)]
+    fn fory_read_into(
+        &mut self,
+        context: &mut ReadContext,
+        ref_mode: RefMode,
+        read_type_info: bool,
+    ) -> Result<(), Error>
+    where
+        Self: Sized + ForyDefault,
+    {
+        *self = Self::fory_read(context, ref_mode, read_type_info)?;
+        Ok(())
+    }
+
+    /// Deserialize the type's data into an existing value, reusing its allocations where possible.
+    ///
+    /// This is the in-place counterpart of [`fory_read_data`]. The default implementation
+    /// reads a fresh value with [`fory_read_data`] and assigns it.
+    ///
+    /// [`fory_read_data`]: Serializer::fory_read_data
+    #[inline(always)]
+    fn fory_read_data_into(&mut self, context: &mut ReadContext) -> Result<(), Error>
+    where
+        Self: Sized + ForyDefault,
+    {
+        *self = Self::fory_read_data(context)?;
+        Ok(())
+    }
+
     /// Deserialize data for dynamic send-sync carriers.
     ///
     /// This method must construct the `Box<dyn Any + Send + Sync>` from the
@@ -1048,6 +1097,41 @@ pub trait Serializer: 'static {
         Self::fory_is_shared_ref()
     }
 
+    /// Get the schema version written into the root header of payloads rooted at this type.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(version)` for structs annotated with `#[fory(version = N)]`
+    /// - `None` for all other types (default)
+    ///
+    /// # Implementation Notes
+    ///
+    /// - Implemented automatically by `#[derive(ForyStruct)]` macro
+    /// - Only the root value's version is written; nested values carry none
+    #[inline(always)]
+    fn fory_version() -> Option<u32>
+    where
+        Self: Sized,
+    {
+        None
+    }
+
+    /// Get the oldest payload version this type accepts as a root value.
+    ///
+    /// Reading a payload with an older version, or with no version when this is
+    /// greater than zero, returns [`Error::VersionTooOld`].
+    ///
+    /// # Returns
+    ///
+    /// The `#[fory(min_version = N)]` of the struct, or `0` by default.
+    #[inline(always)]
+    fn fory_min_version() -> u32
+    where
+        Self: Sized,
+    {
+        0
+    }
+
     /// Get the static Fory type ID for this type.
     ///
     /// Type IDs are Fory's internal type identification system, separate from
@@ -1225,6 +1309,26 @@ pub trait Serializer: 'static {
         std::mem::size_of::<Self>()
     }
 
+    /// Size hint for this particular value.
+    ///
+    /// Unlike [`fory_reserved_space`](Serializer::fory_reserved_space), which only sees the
+    /// type, this can account for the length of strings and collections, so a value with
+    /// large variable-length content is written into a buffer reserved once, up front.
+    ///
+    /// # Implementation Notes
+    ///
+    /// - Default returns `Self::fory_reserved_space()`
+    /// - Strings add their length; collections and maps multiply their length by the
+    ///   reserved space of their elements, so the estimate is O(1) and never walks them
+    /// - Derived structs sum the estimates of their fields
+    #[inline(always)]
+    fn fory_estimate_size(&self) -> usize
+    where
+        Self: Sized,
+    {
+        Self::fory_reserved_space()
+    }
+
     /// **[USER IMPLEMENTATION REQUIRED]** Downcast to `&dyn Any` for dynamic type checking.
     ///
     /// This method enables runtime type checking and downcasting, required for
@@ -1413,6 +1517,43 @@ pub trait StructSerializer: Serializer + 'static {
         &[]
     }
 
+    /// Get the type metadata name of the field named `field` in Rust.
+    ///
+    /// Field names in type metadata are snake_cased and follow `#[fory(rename = "...")]`,
+    /// so they can differ from the Rust identifier.
+    ///
+    /// # Returns
+    ///
+    /// The metadata name when it differs from `field`, or `None` by default.
+    ///
+    /// # Implementation Notes
+    ///
+    /// - Implemented automatically by `#[derive(ForyStruct)]` macro
+    /// - Used to resolve the field names given to `Fory::deserialize_projection`
+    /// - **Do not implement** for user types with custom serialization (EXT types)
+    fn fory_meta_field_name(field: &str) -> Option<&'static str> {
+        let _ = field;
+        None
+    }
+
+    /// Get the schema hash of this struct's fields.
+    ///
+    /// This is the version hash written when `check_struct_version` is enabled. It is
+    /// computed at compile time from each field's name (or id) and type, sorted so that
+    /// field order does not matter, and does not depend on the type name or registration.
+    ///
+    /// # Returns
+    ///
+    /// The struct hash, or `0` for enums and by default.
+    ///
+    /// # Implementation Notes
+    ///
+    /// - Implemented automatically by `#[derive(ForyStruct)]` macro
+    /// - **Do not implement** for user types with custom serialization (EXT types)
+    fn fory_struct_hash() -> i32 {
+        0
+    }
+
     /// Deserialize a struct with schema compatibility support.
     ///
     /// This method enables reading structs even when the reader's schema differs
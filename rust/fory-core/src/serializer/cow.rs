@@ -0,0 +1,152 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! `Cow<'static, str>` and `Cow<'static, [u8]>` share the wire format of
+//! `String` and `Vec<u8>`. Deserialized values are always `Cow::Owned`.
+
+use crate::context::ReadContext;
+use crate::context::WriteContext;
+use crate::error::Error;
+use crate::resolver::TypeResolver;
+use crate::serializer::string::{estimate_str_size, write_str_data};
+use crate::serializer::{primitive_list, ForyDefault, Serializer};
+use crate::type_id::TypeId;
+use std::borrow::Cow;
+
+impl Serializer for Cow<'static, str> {
+    #[inline(always)]
+    fn fory_write_data(&self, context: &mut WriteContext) -> Result<(), Error> {
+        write_str_data(self, context)
+    }
+
+    #[inline(always)]
+    fn fory_read_data(context: &mut ReadContext) -> Result<Self, Error> {
+        Ok(Cow::Owned(String::fory_read_data(context)?))
+    }
+
+    #[inline(always)]
+    fn fory_reserved_space() -> usize {
+        String::fory_reserved_space()
+    }
+
+    #[inline(always)]
+    fn fory_estimate_size(&self) -> usize {
+        estimate_str_size(self)
+    }
+
+    #[inline(always)]
+    fn fory_get_type_id(_: &TypeResolver) -> Result<TypeId, Error> {
+        Ok(TypeId::STRING)
+    }
+
+    #[inline(always)]
+    fn fory_type_id_dyn(&self, _: &TypeResolver) -> Result<TypeId, Error> {
+        Ok(TypeId::STRING)
+    }
+
+    #[inline(always)]
+    fn fory_static_type_id() -> TypeId
+    where
+        Self: Sized,
+    {
+        TypeId::STRING
+    }
+
+    #[inline(always)]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline(always)]
+    fn fory_write_type_info(context: &mut WriteContext) -> Result<(), Error> {
+        String::fory_write_type_info(context)
+    }
+
+    #[inline(always)]
+    fn fory_read_type_info(context: &mut ReadContext) -> Result<(), Error> {
+        String::fory_read_type_info(context)
+    }
+}
+
+impl ForyDefault for Cow<'static, str> {
+    #[inline(always)]
+    fn fory_default() -> Self {
+        Cow::Borrowed("")
+    }
+}
+
+impl Serializer for Cow<'static, [u8]> {
+    #[inline(always)]
+    fn fory_write_data(&self, context: &mut WriteContext) -> Result<(), Error> {
+        primitive_list::fory_write_data(self.as_ref(), context)
+    }
+
+    #[inline(always)]
+    fn fory_read_data(context: &mut ReadContext) -> Result<Self, Error> {
+        Ok(Cow::Owned(Vec::<u8>::fory_read_data(context)?))
+    }
+
+    #[inline(always)]
+    fn fory_reserved_space() -> usize {
+        Vec::<u8>::fory_reserved_space()
+    }
+
+    #[inline(always)]
+    fn fory_estimate_size(&self) -> usize {
+        primitive_list::fory_estimate_size::<u8>(self)
+    }
+
+    #[inline(always)]
+    fn fory_get_type_id(_: &TypeResolver) -> Result<TypeId, Error> {
+        Ok(TypeId::BINARY)
+    }
+
+    #[inline(always)]
+    fn fory_type_id_dyn(&self, _: &TypeResolver) -> Result<TypeId, Error> {
+        Ok(TypeId::BINARY)
+    }
+
+    #[inline(always)]
+    fn fory_static_type_id() -> TypeId
+    where
+        Self: Sized,
+    {
+        TypeId::BINARY
+    }
+
+    #[inline(always)]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline(always)]
+    fn fory_write_type_info(context: &mut WriteContext) -> Result<(), Error> {
+        Vec::<u8>::fory_write_type_info(context)
+    }
+
+    #[inline(always)]
+    fn fory_read_type_info(context: &mut ReadContext) -> Result<(), Error> {
+        Vec::<u8>::fory_read_type_info(context)
+    }
+}
+
+impl ForyDefault for Cow<'static, [u8]> {
+    #[inline(always)]
+    fn fory_default() -> Self {
+        Cow::Borrowed(&[])
+    }
+}
@@ -158,3 +158,116 @@ fn encode_zigzag64(value: i64) -> u64 {
 fn decode_zigzag64(value: u64) -> i64 {
     ((value >> 1) as i64) ^ -((value & 1) as i64)
 }
+
+#[cfg(feature = "decimal")]
+mod rust_decimal_support {
+    use super::*;
+    use crate::serializer::codec::DecimalCarrier;
+    use num_traits::{Signed, Zero};
+
+    /// Largest scale `rust_decimal::Decimal` can hold.
+    const MAX_SCALE: i32 = 28;
+
+    impl DecimalCarrier for rust_decimal::Decimal {
+        fn from_decimal(value: Decimal) -> Result<Self, Error> {
+            let Decimal {
+                mut unscaled,
+                mut scale,
+            } = value;
+            if unscaled.is_zero() {
+                return Ok(rust_decimal::Decimal::new(
+                    0,
+                    scale.clamp(0, MAX_SCALE) as u32,
+                ));
+            }
+            if scale < 0 {
+                // Compared before negating, which overflows for `i32::MIN`.
+                if scale < -MAX_SCALE {
+                    return Err(out_of_range(&unscaled, scale));
+                }
+                unscaled *= BigInt::from(10).pow((-scale) as u32);
+                scale = 0;
+            }
+            let ten = BigInt::from(10);
+            while scale > MAX_SCALE && (&unscaled % &ten).is_zero() {
+                unscaled /= &ten;
+                scale -= 1;
+            }
+            if scale > MAX_SCALE || unscaled.abs().bits() > 96 {
+                return Err(out_of_range(&unscaled, scale));
+            }
+            let mantissa = i128::try_from(&unscaled).map_err(|_| out_of_range(&unscaled, scale))?;
+            rust_decimal::Decimal::try_from_i128_with_scale(mantissa, scale as u32)
+                .map_err(|_| out_of_range(&unscaled, scale))
+        }
+    }
+
+    #[cold]
+    fn out_of_range(unscaled: &BigInt, scale: i32) -> Error {
+        Error::invalid_data(format!(
+            "decimal with unscaled value {} and scale {} is out of rust_decimal::Decimal range",
+            unscaled, scale
+        ))
+    }
+
+    impl Serializer for rust_decimal::Decimal {
+        #[inline(always)]
+        fn fory_write_data(&self, context: &mut WriteContext) -> Result<(), Error> {
+            context.writer.write_var_i32(self.scale() as i32);
+            write_decimal_unscaled(&BigInt::from(self.mantissa()), &mut context.writer)
+        }
+
+        #[inline(always)]
+        fn fory_read_data(context: &mut ReadContext) -> Result<Self, Error> {
+            Self::from_decimal(Decimal::fory_read_data(context)?)
+        }
+        #[inline]
+        fn fory_read_data_as_send_sync_any(
+            context: &mut ReadContext,
+        ) -> Result<Box<dyn std::any::Any + Send + Sync>, Error>
+        where
+            Self: Sized + ForyDefault,
+        {
+            Ok(crate::serializer::box_send_sync(Self::fory_read_data(
+                context,
+            )?))
+        }
+
+        #[inline(always)]
+        fn fory_get_type_id(_: &TypeResolver) -> Result<TypeId, Error> {
+            Ok(TypeId::DECIMAL)
+        }
+
+        #[inline(always)]
+        fn fory_type_id_dyn(&self, _: &TypeResolver) -> Result<TypeId, Error> {
+            Ok(TypeId::DECIMAL)
+        }
+
+        #[inline(always)]
+        fn fory_static_type_id() -> TypeId {
+            TypeId::DECIMAL
+        }
+
+        #[inline(always)]
+        fn as_any(&self) -> &dyn std::any::Any {
+            self
+        }
+
+        #[inline(always)]
+        fn fory_write_type_info(context: &mut WriteContext) -> Result<(), Error> {
+            Decimal::fory_write_type_info(context)
+        }
+
+        #[inline(always)]
+        fn fory_read_type_info(context: &mut ReadContext) -> Result<(), Error> {
+            read_basic_type_info::<Self>(context)
+        }
+    }
+
+    impl ForyDefault for rust_decimal::Decimal {
+        #[inline(always)]
+        fn fory_default() -> Self {
+            rust_decimal::Decimal::ZERO
+        }
+    }
+}
@@ -0,0 +1,227 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Structural comparison of two serialized values for [`Fory::diff`](crate::Fory::diff).
+//!
+//! Both payloads are walked into [`dump`](super::dump) trees, which are then compared
+//! value by value rather than byte by byte.
+
+use crate::serializer::dump::{Entries, Node};
+use crate::type_id as types;
+
+/// A value that differs between the two payloads compared by
+/// [`Fory::diff`](crate::Fory::diff).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldDiff {
+    /// Where the value sits under the root value. Struct fields are joined with `.`, list
+    /// items are indexed as `[0]`, and map values and set elements are keyed by their
+    /// rendered key, as in `["level"]`. The root value itself has an empty path.
+    pub path: String,
+    /// The value in the first payload, rendered as single-line JSON, or `None` if only the
+    /// second payload has it.
+    pub left: Option<String>,
+    /// The value in the second payload, rendered as single-line JSON, or `None` if only
+    /// the first payload has it.
+    pub right: Option<String>,
+}
+
+/// Compares two dump trees, in the order their values appear in the first one.
+pub(crate) fn diff_root(left: &Node, right: &Node) -> Vec<FieldDiff> {
+    let mut diffs = Vec::new();
+    diff_value("", left, right, &mut diffs);
+    diffs
+}
+
+fn push(diffs: &mut Vec<FieldDiff>, path: String, left: Option<&Node>, right: Option<&Node>) {
+    diffs.push(FieldDiff {
+        path,
+        left: left.map(Node::to_compact_json),
+        right: right.map(Node::to_compact_json),
+    });
+}
+
+/// Reports a value present on one side only, rendered like a map key.
+fn push_missing(
+    diffs: &mut Vec<FieldDiff>,
+    path: String,
+    left: Option<&Node>,
+    right: Option<&Node>,
+) {
+    diffs.push(FieldDiff {
+        path,
+        left: left.map(label),
+        right: right.map(label),
+    });
+}
+
+fn field_path(path: &str, name: &str) -> String {
+    if path.is_empty() {
+        name.to_string()
+    } else {
+        format!("{path}.{name}")
+    }
+}
+
+fn get<'a>(entries: &'a Entries, key: &str) -> Option<&'a Node> {
+    entries
+        .iter()
+        .find(|(name, _)| name == key)
+        .map(|(_, node)| node)
+}
+
+/// Entries holding nested values, which are compared child by child. Every other entry,
+/// such as the ref flag, the type id or a scalar `"value"`, describes the node itself.
+fn is_nested(key: &str, node: &Node) -> bool {
+    matches!(key, "fields" | "items" | "entries") || matches!(node, Node::Object(_))
+}
+
+fn diff_value(path: &str, left: &Node, right: &Node, diffs: &mut Vec<FieldDiff>) {
+    if left == right {
+        return;
+    }
+    let (Node::Object(left_entries), Node::Object(right_entries)) = (left, right) else {
+        push(diffs, path.to_string(), Some(left), Some(right));
+        return;
+    };
+    let own = |entries: &'_ Entries| -> Vec<(String, String)> {
+        entries
+            .iter()
+            .filter(|(key, node)| !is_nested(key, node))
+            .map(|(key, node)| (key.clone(), node.to_compact_json()))
+            .collect()
+    };
+    let nested_keys = |entries: &'_ Entries| -> Vec<String> {
+        entries
+            .iter()
+            .filter(|(key, node)| is_nested(key, node))
+            .map(|(key, _)| key.clone())
+            .collect()
+    };
+    let (left_own, right_own) = (own(left_entries), own(right_entries));
+    if left_own != right_own || nested_keys(left_entries) != nested_keys(right_entries) {
+        // Report a changed scalar by its value alone; anything else, such as a changed
+        // type or ref flag, by the whole node.
+        let only_value_differs = left_own.len() == right_own.len()
+            && left_own
+                .iter()
+                .zip(&right_own)
+                .all(|(l, r)| l.0 == r.0 && (l.0 == "value" || l.1 == r.1));
+        match (get(left_entries, "value"), get(right_entries, "value")) {
+            (Some(l), Some(r)) if only_value_differs => {
+                push(diffs, path.to_string(), Some(l), Some(r))
+            }
+            _ => push(diffs, path.to_string(), Some(left), Some(right)),
+        }
+        return;
+    }
+    let is_set = matches!(
+        get(left_entries, "type_id"),
+        Some(Node::Number(type_id)) if *type_id == types::SET.to_string()
+    );
+    for (key, left_node) in left_entries.iter() {
+        let Some(right_node) = get(right_entries, key) else {
+            continue;
+        };
+        match (key.as_str(), left_node, right_node) {
+            ("fields", Node::Object(l), Node::Object(r)) => diff_fields(path, l, r, diffs),
+            ("items", Node::Array(l), Node::Array(r)) if is_set => {
+                diff_keyed(path, set_elements(l), set_elements(r), diffs)
+            }
+            ("items", Node::Array(l), Node::Array(r)) => diff_items(path, l, r, diffs),
+            ("entries", Node::Array(l), Node::Array(r)) => {
+                diff_keyed(path, map_entries(l), map_entries(r), diffs)
+            }
+            (key, l, r) if is_nested(key, l) => diff_value(&field_path(path, key), l, r, diffs),
+            _ => {}
+        }
+    }
+}
+
+fn diff_fields(path: &str, left: &Entries, right: &Entries, diffs: &mut Vec<FieldDiff>) {
+    for (name, left_node) in left {
+        let field_path = field_path(path, name);
+        match get(right, name) {
+            Some(right_node) => diff_value(&field_path, left_node, right_node, diffs),
+            None => push_missing(diffs, field_path, Some(left_node), None),
+        }
+    }
+    for (name, right_node) in right {
+        if get(left, name).is_none() {
+            push_missing(diffs, field_path(path, name), None, Some(right_node));
+        }
+    }
+}
+
+fn diff_items(path: &str, left: &[Node], right: &[Node], diffs: &mut Vec<FieldDiff>) {
+    for i in 0..left.len().max(right.len()) {
+        let item_path = format!("{path}[{i}]");
+        match (left.get(i), right.get(i)) {
+            (Some(l), Some(r)) => diff_value(&item_path, l, r, diffs),
+            (l, r) => push_missing(diffs, item_path, l, r),
+        }
+    }
+}
+
+/// Renders a scalar by its value alone and any other node as a whole. Map keys and set
+/// elements are identified by this rendering.
+fn label(node: &Node) -> String {
+    match node {
+        Node::Object(entries) => match get(entries, "value") {
+            Some(value) if !matches!(value, Node::Object(_)) => value.to_compact_json(),
+            _ => node.to_compact_json(),
+        },
+        node => node.to_compact_json(),
+    }
+}
+
+/// Labels dumped map entries by their key.
+fn map_entries(entries: &[Node]) -> Vec<(String, &Node)> {
+    entries
+        .iter()
+        .filter_map(|entry| match entry {
+            Node::Object(pair) => Some((label(get(pair, "key")?), get(pair, "value")?)),
+            _ => None,
+        })
+        .collect()
+}
+
+fn set_elements(items: &[Node]) -> Vec<(String, &Node)> {
+    items.iter().map(|item| (label(item), item)).collect()
+}
+
+/// Matches map entries and set elements by key, so that iteration order alone, as with
+/// `HashMap` and `HashSet`, is not reported as a difference.
+fn diff_keyed(
+    path: &str,
+    left: Vec<(String, &Node)>,
+    mut right: Vec<(String, &Node)>,
+    diffs: &mut Vec<FieldDiff>,
+) {
+    for (label, left_value) in left {
+        let entry_path = format!("{path}[{label}]");
+        match right.iter().position(|(key, _)| *key == label) {
+            Some(index) => {
+                let (_, right_value) = right.remove(index);
+                diff_value(&entry_path, left_value, right_value, diffs);
+            }
+            None => push_missing(diffs, entry_path, Some(left_value), None),
+        }
+    }
+    for (label, right_value) in right {
+        push_missing(diffs, format!("{path}[{label}]"), None, Some(right_value));
+    }
+}
@@ -0,0 +1,602 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Read-only walk of a serialized value for [`Fory::debug_dump`](crate::Fory::debug_dump)
+//! and [`Fory::diff`](crate::Fory::diff).
+//!
+//! The walk follows [`skip`](super::skip), but records the ref flags, type ids, struct
+//! field names and values it reads instead of discarding them.
+
+use crate::context::ReadContext;
+use crate::ensure;
+use crate::error::Error;
+use crate::meta::{FieldInfo, FieldType};
+use crate::resolver::TypeInfo;
+use crate::serializer::collection::{DECL_ELEMENT_TYPE, HAS_NULL, IS_SAME_TYPE};
+use crate::serializer::map::{DECL_KEY_TYPE, DECL_VALUE_TYPE, KEY_NULL, VALUE_NULL};
+use crate::serializer::skip::{read_any_type, unknown_field_type};
+use crate::serializer::Serializer;
+use crate::serializer::{struct_, util};
+use crate::type_id as types;
+use crate::types::bfloat16::bfloat16;
+use crate::types::float16::float16;
+use crate::types::{Date, Duration, Timestamp};
+use crate::RefFlag;
+use std::fmt::Write;
+use std::rc::Rc;
+
+/// A node of the dump tree, rendered as indented JSON.
+#[derive(PartialEq)]
+pub(crate) enum Node {
+    Null,
+    Bool(bool),
+    Number(String),
+    Str(String),
+    Array(Vec<Node>),
+    Object(Vec<(String, Node)>),
+}
+
+pub(crate) type Entries = Vec<(String, Node)>;
+
+impl Node {
+    fn number(value: impl ToString) -> Node {
+        Node::Number(value.to_string())
+    }
+
+    fn str(value: impl ToString) -> Node {
+        Node::Str(value.to_string())
+    }
+
+    /// Non-finite floats have no JSON number form, so they are rendered as strings.
+    fn float(value: impl ToString, finite: bool) -> Node {
+        if finite {
+            Node::number(value)
+        } else {
+            Node::str(value)
+        }
+    }
+
+    fn numbers<T: ToString>(values: Vec<T>) -> Node {
+        Node::Array(values.into_iter().map(Node::number).collect())
+    }
+
+    /// Renders the node as indented JSON.
+    pub(crate) fn to_json(&self) -> String {
+        let mut out = String::new();
+        self.render(0, &mut out);
+        out
+    }
+
+    /// Renders the node as JSON on a single line.
+    pub(crate) fn to_compact_json(&self) -> String {
+        let mut out = String::new();
+        self.render_compact(&mut out);
+        out
+    }
+
+    fn render_compact(&self, out: &mut String) {
+        match self {
+            Node::Array(items) => {
+                out.push('[');
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        out.push_str(", ");
+                    }
+                    item.render_compact(out);
+                }
+                out.push(']');
+            }
+            Node::Object(entries) => {
+                out.push('{');
+                for (i, (key, value)) in entries.iter().enumerate() {
+                    if i > 0 {
+                        out.push_str(", ");
+                    }
+                    render_str(key, out);
+                    out.push_str(": ");
+                    value.render_compact(out);
+                }
+                out.push('}');
+            }
+            _ => self.render(0, out),
+        }
+    }
+
+    fn render(&self, indent: usize, out: &mut String) {
+        match self {
+            Node::Null => out.push_str("null"),
+            Node::Bool(value) => out.push_str(if *value { "true" } else { "false" }),
+            Node::Number(value) => out.push_str(value),
+            Node::Str(value) => render_str(value, out),
+            Node::Array(items) if items.is_empty() => out.push_str("[]"),
+            Node::Array(items) => {
+                out.push_str("[\n");
+                for (i, item) in items.iter().enumerate() {
+                    push_indent(indent + 1, out);
+                    item.render(indent + 1, out);
+                    out.push_str(if i + 1 < items.len() { ",\n" } else { "\n" });
+                }
+                push_indent(indent, out);
+                out.push(']');
+            }
+            Node::Object(entries) if entries.is_empty() => out.push_str("{}"),
+            Node::Object(entries) => {
+                out.push_str("{\n");
+                for (i, (key, value)) in entries.iter().enumerate() {
+                    push_indent(indent + 1, out);
+                    render_str(key, out);
+                    out.push_str(": ");
+                    value.render(indent + 1, out);
+                    out.push_str(if i + 1 < entries.len() { ",\n" } else { "\n" });
+                }
+                push_indent(indent, out);
+                out.push('}');
+            }
+        }
+    }
+}
+
+fn push_indent(indent: usize, out: &mut String) {
+    for _ in 0..indent {
+        out.push_str("  ");
+    }
+}
+
+fn render_str(value: &str, out: &mut String) {
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+/// Dumps the root value that follows the payload header.
+pub(crate) fn dump_root(context: &mut ReadContext) -> Result<Node, Error> {
+    dump_value(context, &unknown_field_type(), true, &None)
+}
+
+/// Records the ref flag and returns whether value data follows it.
+fn read_ref_flag(context: &mut ReadContext, entries: &mut Entries) -> Result<bool, Error> {
+    let ref_flag = context.reader.read_i8()?;
+    let (name, has_data) = match ref_flag {
+        flag if flag == RefFlag::Null as i8 => ("null", false),
+        flag if flag == RefFlag::Ref as i8 => ("ref", false),
+        flag if flag == RefFlag::NotNullValue as i8 => ("not_null", true),
+        flag if flag == RefFlag::RefValue as i8 => ("ref_value", true),
+        _ => {
+            return Err(Error::invalid_data(format!(
+                "invalid ref flag {}",
+                ref_flag
+            )))
+        }
+    };
+    entries.push(("ref".into(), Node::str(name)));
+    if ref_flag == RefFlag::Ref as i8 {
+        let ref_id = context.reader.read_var_u32()?;
+        entries.push(("ref_id".into(), Node::number(ref_id)));
+    }
+    Ok(has_data)
+}
+
+fn dump_value(
+    context: &mut ReadContext,
+    field_type: &FieldType,
+    read_ref: bool,
+    type_info: &Option<Rc<TypeInfo>>,
+) -> Result<Node, Error> {
+    let mut entries = Entries::new();
+    if read_ref && !read_ref_flag(context, &mut entries)? {
+        return Ok(Node::Object(entries));
+    }
+    if field_type.type_id == types::UNKNOWN {
+        let (field_type, type_info) = read_any_type(context)?;
+        dump_data(context, &field_type, &type_info, &mut entries)?;
+    } else {
+        dump_data(context, field_type, type_info, &mut entries)?;
+    }
+    Ok(Node::Object(entries))
+}
+
+fn dump_data(
+    context: &mut ReadContext,
+    field_type: &FieldType,
+    type_info: &Option<Rc<TypeInfo>>,
+    entries: &mut Entries,
+) -> Result<(), Error> {
+    let type_id = field_type.type_id;
+    entries.push(("type_id".into(), Node::number(type_id)));
+    entries.push(("type".into(), Node::str(types::format_type_id(type_id))));
+    match type_id {
+        types::STRUCT
+        | types::COMPATIBLE_STRUCT
+        | types::NAMED_STRUCT
+        | types::NAMED_COMPATIBLE_STRUCT => dump_struct(context, type_id, type_info, entries)?,
+        types::ENUM | types::NAMED_ENUM => {
+            let ordinal = context.reader.read_var_u32()?;
+            entries.push(("ordinal".into(), Node::number(ordinal)));
+        }
+        types::UNION | types::TYPED_UNION | types::NAMED_UNION => {
+            let case = context.reader.read_var_u32()?;
+            entries.push(("case".into(), Node::number(case)));
+            let value = dump_value(context, &unknown_field_type(), true, &None)?;
+            entries.push(("value".into(), value));
+        }
+        types::RESULT => {
+            let arm = match context.reader.read_u8()? {
+                0 => "ok",
+                1 => "err",
+                tag => return Err(Error::invalid_data(format!("invalid Result tag: {tag}"))),
+            };
+            let value = dump_value(context, &unknown_field_type(), true, &None)?;
+            entries.push((arm.into(), value));
+        }
+        types::RANGE | types::RANGE_INCLUSIVE => {
+            let start = dump_value(context, &unknown_field_type(), true, &None)?;
+            entries.push(("start".into(), start));
+            let end = dump_value(context, &unknown_field_type(), true, &None)?;
+            entries.push(("end".into(), end));
+        }
+        types::ENCRYPTED => {
+            // Dumps never decrypt, so the plaintext stays out of logs.
+            let len = context.reader.read_var_u32()?;
+            context.reader.skip(len as usize)?;
+            entries.push(("ciphertext_len".into(), Node::number(len)));
+        }
+        types::EXT | types::NAMED_EXT => {
+            let type_info = match type_info {
+                Some(type_info) => type_info.clone(),
+                None => read_remote_type_info(context, type_id)?,
+            };
+            push_type_name(&type_info, entries);
+            type_info.get_harness().get_read_data_fn()(context)?;
+            entries.push(("value".into(), Node::str("<ext>")));
+        }
+        types::LIST | types::SET => {
+            let items = dump_collection(context, field_type)?;
+            entries.push(("items".into(), items));
+        }
+        types::MAP => {
+            let map_entries = dump_map(context, field_type)?;
+            entries.push(("entries".into(), map_entries));
+        }
+        types::NONE => {}
+        _ => {
+            let value = read_scalar(context, type_id)?;
+            entries.push(("value".into(), value));
+        }
+    }
+    Ok(())
+}
+
+fn read_remote_type_info(context: &mut ReadContext, type_id: u32) -> Result<Rc<TypeInfo>, Error> {
+    let remote_type_info = context.read_any_type_info()?;
+    let remote_type_id = remote_type_info.get_type_id() as u32;
+    if type_id != types::UNKNOWN && remote_type_id != types::UNKNOWN {
+        ensure!(
+            type_id == remote_type_id,
+            Error::type_mismatch(type_id, remote_type_id)
+        );
+    }
+    Ok(remote_type_info)
+}
+
+fn push_type_name(type_info: &TypeInfo, entries: &mut Entries) {
+    if type_info.is_registered_by_name() {
+        let namespace = &type_info.get_namespace().original;
+        let type_name = &type_info.get_type_name().original;
+        let name = if namespace.is_empty() {
+            type_name.clone()
+        } else {
+            format!("{}.{}", namespace, type_name)
+        };
+        entries.push(("type_name".into(), Node::Str(name)));
+    } else {
+        entries.push((
+            "user_type_id".into(),
+            Node::number(type_info.get_user_type_id()),
+        ));
+    }
+}
+
+fn dump_struct(
+    context: &mut ReadContext,
+    type_id: u32,
+    type_info: &Option<Rc<TypeInfo>>,
+    entries: &mut Entries,
+) -> Result<(), Error> {
+    let type_info = match type_info {
+        Some(type_info) => type_info.clone(),
+        None => read_remote_type_info(context, type_id)?,
+    };
+    push_type_name(&type_info, entries);
+    if context.is_check_struct_version() {
+        let version = context.reader.read_i32()?;
+        entries.push(("version".into(), Node::number(version)));
+    }
+    let type_meta = type_info.get_type_meta();
+    let presence = struct_::read_field_presence(context, type_meta.get_field_infos())?;
+    let mut fields = Entries::new();
+    context.inc_depth()?;
+    for (index, field_info) in type_meta.get_field_infos().iter().enumerate() {
+        if !presence.is_present(index) {
+            fields.push((field_info.field_name.clone(), Node::Null));
+            continue;
+        }
+        let field_type = &field_info.field_type;
+        let read_ref = field_type.track_ref
+            || util::field_need_write_ref_into(field_type.type_id, field_type.nullable);
+        let field_type_info = field_type_info(context, field_info)?;
+        let value = dump_value(context, field_type, read_ref, &field_type_info)?;
+        fields.push((field_info.field_name.clone(), value));
+    }
+    context.dec_depth();
+    entries.push(("fields".into(), Node::Object(fields)));
+    Ok(())
+}
+
+/// Outside compatible mode, only polymorphic struct fields carry inline type info, so
+/// struct and ext fields are resolved from the registered field type instead.
+fn field_type_info(
+    context: &ReadContext,
+    field_info: &FieldInfo,
+) -> Result<Option<Rc<TypeInfo>>, Error> {
+    let type_id = field_info.field_type.type_id;
+    let needs_local_type = matches!(
+        type_id,
+        types::STRUCT | types::NAMED_STRUCT | types::EXT | types::NAMED_EXT
+    );
+    if context.is_compatible() || !needs_local_type {
+        return Ok(None);
+    }
+    context
+        .get_type_resolver()
+        .get_user_type_info_by_id(field_info.field_type.user_type_id)
+        .map(Some)
+        .ok_or_else(|| {
+            Error::type_error(format!(
+                "can't resolve the {} type of field `{}` outside compatible mode",
+                types::format_type_id(type_id),
+                field_info.field_name
+            ))
+        })
+}
+
+/// Returns the declared element type, or reads the element type written inline.
+fn element_type(
+    context: &mut ReadContext,
+    declared: bool,
+    nullable: bool,
+    declared_type: &FieldType,
+) -> Result<(FieldType, Option<Rc<TypeInfo>>), Error> {
+    if declared {
+        return Ok((declared_type.clone(), None));
+    }
+    let type_info = context.read_any_type_info()?;
+    let field_type = FieldType::new_with_user_type_id(
+        type_info.get_type_id() as u32,
+        type_info.get_user_type_id(),
+        nullable,
+        false,
+        Vec::new(),
+    );
+    Ok((field_type, Some(type_info)))
+}
+
+fn dump_collection(context: &mut ReadContext, field_type: &FieldType) -> Result<Node, Error> {
+    let length = context.reader.read_var_u32()? as usize;
+    if length == 0 {
+        return Ok(Node::Array(Vec::new()));
+    }
+    let header = context.reader.read_u8()?;
+    let has_null = (header & HAS_NULL) != 0;
+    let is_same_type = (header & IS_SAME_TYPE) != 0;
+    let skip_ref_flag = is_same_type && !has_null;
+    let is_declared = (header & DECL_ELEMENT_TYPE) != 0;
+    let default_elem_type = field_type
+        .generics
+        .first()
+        .ok_or_else(|| Error::invalid_data("empty generics"))?;
+    let (elem_type, type_info) = element_type(
+        context,
+        !is_same_type || is_declared,
+        has_null,
+        default_elem_type,
+    )?;
+    let mut items = Vec::new();
+    context.inc_depth()?;
+    for _ in 0..length {
+        items.push(dump_value(context, &elem_type, !skip_ref_flag, &type_info)?);
+    }
+    context.dec_depth();
+    Ok(Node::Array(items))
+}
+
+fn map_entry(key: Node, value: Node) -> Node {
+    Node::Object(vec![("key".into(), key), ("value".into(), value)])
+}
+
+fn dump_map(context: &mut ReadContext, field_type: &FieldType) -> Result<Node, Error> {
+    let length = context.reader.read_var_u32()?;
+    let mut map_entries = Vec::new();
+    if length == 0 {
+        return Ok(Node::Array(map_entries));
+    }
+    ensure!(
+        field_type.generics.len() >= 2,
+        Error::invalid_data("map must have at least 2 generics")
+    );
+    let default_key_type = &field_type.generics[0];
+    let default_value_type = &field_type.generics[1];
+    let mut len_counter = 0;
+    context.inc_depth()?;
+    while len_counter < length {
+        let header = context.reader.read_u8()?;
+        let key_declared = (header & DECL_KEY_TYPE) != 0;
+        let value_declared = (header & DECL_VALUE_TYPE) != 0;
+        if header & KEY_NULL != 0 && header & VALUE_NULL != 0 {
+            map_entries.push(map_entry(Node::Null, Node::Null));
+            len_counter += 1;
+            continue;
+        }
+        if header & KEY_NULL != 0 {
+            let (value_type, value_type_info) =
+                element_type(context, value_declared, true, default_value_type)?;
+            let value = dump_value(context, &value_type, false, &value_type_info)?;
+            map_entries.push(map_entry(Node::Null, value));
+            len_counter += 1;
+            continue;
+        }
+        if header & VALUE_NULL != 0 {
+            let (key_type, key_type_info) =
+                element_type(context, key_declared, true, default_key_type)?;
+            let key = dump_value(context, &key_type, false, &key_type_info)?;
+            map_entries.push(map_entry(key, Node::Null));
+            len_counter += 1;
+            continue;
+        }
+        let chunk_size = context.reader.read_u8()?;
+        let (key_type, key_type_info) =
+            element_type(context, key_declared, true, default_key_type)?;
+        let (value_type, value_type_info) =
+            element_type(context, value_declared, true, default_value_type)?;
+        for _ in 0..chunk_size {
+            let key = dump_value(context, &key_type, false, &key_type_info)?;
+            let value = dump_value(context, &value_type, false, &value_type_info)?;
+            map_entries.push(map_entry(key, value));
+        }
+        len_counter += chunk_size as u32;
+    }
+    context.dec_depth();
+    Ok(Node::Array(map_entries))
+}
+
+fn read_scalar(context: &mut ReadContext, type_id: u32) -> Result<Node, Error> {
+    macro_rules! read {
+        ($ty:ty) => {
+            <$ty as Serializer>::fory_read_data(context)?
+        };
+    }
+    let node = match type_id {
+        types::BOOL => Node::Bool(read!(bool)),
+        types::INT8 => Node::number(read!(i8)),
+        types::INT16 => Node::number(read!(i16)),
+        types::INT32 => Node::number(context.reader.read_i32()?),
+        types::VARINT32 => Node::number(read!(i32)),
+        types::INT64 => Node::number(context.reader.read_i64()?),
+        types::VARINT64 => Node::number(read!(i64)),
+        types::TAGGED_INT64 => Node::number(context.reader.read_tagged_i64()?),
+        types::UINT8 => Node::number(read!(u8)),
+        types::UINT16 => Node::number(read!(u16)),
+        types::UINT32 => Node::number(context.reader.read_u32()?),
+        types::VAR_UINT32 => Node::number(read!(u32)),
+        types::UINT64 => Node::number(context.reader.read_u64()?),
+        types::VAR_UINT64 => Node::number(read!(u64)),
+        types::TAGGED_UINT64 => Node::number(context.reader.read_tagged_u64()?),
+        types::U128 => Node::number(read!(u128)),
+        types::INT128 => Node::number(read!(i128)),
+        types::USIZE => Node::number(read!(usize)),
+        types::ISIZE => Node::number(read!(isize)),
+        types::FLOAT16 => {
+            let value = read!(float16).to_f32();
+            Node::float(value, value.is_finite())
+        }
+        types::BFLOAT16 => {
+            let value = read!(bfloat16).to_f32();
+            Node::float(value, value.is_finite())
+        }
+        types::FLOAT32 => {
+            let value = read!(f32);
+            Node::float(value, value.is_finite())
+        }
+        types::FLOAT64 => {
+            let value = read!(f64);
+            Node::float(value, value.is_finite())
+        }
+        types::STRING => Node::Str(read!(String)),
+        types::CHAR => Node::str(read!(char)),
+        types::DURATION => Node::str(format!("{:?}", read!(Duration))),
+        types::TIMESTAMP => Node::str(format!("{:?}", read!(Timestamp))),
+        types::DATE => Node::str(format!("{:?}", read!(Date))),
+        types::DECIMAL => Node::str(format!("{:?}", read!(crate::Decimal))),
+        types::UUID => {
+            let bytes = context.reader.read_bytes(16)?;
+            let mut uuid = String::with_capacity(36);
+            for (i, byte) in bytes.iter().enumerate() {
+                if matches!(i, 4 | 6 | 8 | 10) {
+                    uuid.push('-');
+                }
+                let _ = write!(uuid, "{:02x}", byte);
+            }
+            Node::Str(uuid)
+        }
+        types::IPV4_ADDR => Node::str(read!(std::net::Ipv4Addr)),
+        types::IPV6_ADDR => Node::str(read!(std::net::Ipv6Addr)),
+        types::IP_ADDR => Node::str(read!(std::net::IpAddr)),
+        types::SOCKET_ADDR => Node::str(read!(std::net::SocketAddr)),
+        types::OS_STRING => Node::str(format!("{:?}", read!(std::ffi::OsString))),
+        types::BINARY | types::UINT8_ARRAY => Node::numbers(read!(Vec<u8>)),
+        types::BOOL_ARRAY => Node::Array(read!(Vec<bool>).into_iter().map(Node::Bool).collect()),
+        types::INT8_ARRAY => Node::numbers(read!(Vec<i8>)),
+        types::INT16_ARRAY => Node::numbers(read!(Vec<i16>)),
+        types::INT32_ARRAY => Node::numbers(read!(Vec<i32>)),
+        types::INT64_ARRAY => Node::numbers(read!(Vec<i64>)),
+        types::UINT16_ARRAY => Node::numbers(read!(Vec<u16>)),
+        types::UINT32_ARRAY => Node::numbers(read!(Vec<u32>)),
+        types::UINT64_ARRAY => Node::numbers(read!(Vec<u64>)),
+        types::U128_ARRAY => Node::numbers(read!(Vec<u128>)),
+        types::INT128_ARRAY => Node::numbers(read!(Vec<i128>)),
+        types::USIZE_ARRAY => Node::numbers(read!(Vec<usize>)),
+        types::ISIZE_ARRAY => Node::numbers(read!(Vec<isize>)),
+        types::FLOAT16_ARRAY => float_array(read!(Vec<float16>).into_iter().map(float16::to_f32)),
+        types::BFLOAT16_ARRAY => {
+            float_array(read!(Vec<bfloat16>).into_iter().map(bfloat16::to_f32))
+        }
+        types::FLOAT32_ARRAY => float_array(read!(Vec<f32>)),
+        types::FLOAT64_ARRAY => Node::Array(
+            read!(Vec<f64>)
+                .into_iter()
+                .map(|value| Node::float(value, value.is_finite()))
+                .collect(),
+        ),
+        _ => {
+            return Err(Error::type_error(format!(
+                "Unimplemented type id: {}",
+                type_id
+            )))
+        }
+    };
+    Ok(node)
+}
+
+fn float_array(values: impl IntoIterator<Item = f32>) -> Node {
+    Node::Array(
+        values
+            .into_iter()
+            .map(|value| Node::float(value, value.is_finite()))
+            .collect(),
+    )
+}
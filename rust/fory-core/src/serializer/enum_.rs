@@ -121,6 +121,21 @@ pub fn read_type_info<T: Serializer>(context: &mut ReadContext) -> Result<(), Er
     Ok(())
 }
 
+/// Writes the variant name of an enum declared with `#[fory(repr = "name")]`.
+///
+/// The name uses the same layout as a `String` value, matching Java's
+/// `serializeEnumByName` encoding in native mode.
+#[inline(always)]
+pub fn write_variant_name(name: &str, context: &mut WriteContext) -> Result<(), Error> {
+    crate::serializer::string::write_str_data(name, context)
+}
+
+/// Reads a variant name written by [`write_variant_name`].
+#[inline(always)]
+pub fn read_variant_name(context: &mut ReadContext) -> Result<String, Error> {
+    String::fory_read_data(context)
+}
+
 pub trait NamedEnumVariantMetaTrait: 'static {
     fn fory_get_sorted_field_names() -> &'static [&'static str] {
         &[]
@@ -0,0 +1,122 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Serialization of types from other crates through registered closures.
+//!
+//! The orphan rule prevents implementing [`Serializer`] for a type defined in another
+//! crate. [`Fory::register_external`](crate::Fory::register_external) instead stores a
+//! pair of closures in the [`TypeResolver`], and values are written through the
+//! [`External`] wrapper, whose serializer looks the closures up at runtime. Derived
+//! structs do the wrapping for fields marked `#[fory(external)]`.
+
+use crate::context::{ReadContext, WriteContext};
+use crate::error::Error;
+use crate::resolver::TypeResolver;
+use crate::serializer::{ForyDefault, Serializer};
+use crate::type_id::TypeId;
+use std::ops::{Deref, DerefMut};
+
+/// Writes the data of an external value, without ref flag or type info.
+pub type ExternalWriteFn<T> = dyn Fn(&T, &mut WriteContext) -> Result<(), Error> + Send + Sync;
+
+/// Reads the data written by the matching [`ExternalWriteFn`].
+pub type ExternalReadFn<T> = dyn Fn(&mut ReadContext) -> Result<T, Error> + Send + Sync;
+
+/// Closures registered for an external type.
+pub(crate) struct ExternalSerializer<T> {
+    pub(crate) write: Box<ExternalWriteFn<T>>,
+    pub(crate) read: Box<ExternalReadFn<T>>,
+}
+
+/// Serializes a `T` with the closures registered through
+/// [`Fory::register_external`](crate::Fory::register_external).
+///
+/// Use it wherever a [`Serializer`] is needed for a type that can't implement one, such
+/// as a root value or a collection element. Struct fields can keep the plain type and
+/// be marked `#[fory(external)]` instead.
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct External<T>(pub T);
+
+impl<T> External<T> {
+    /// Views a `&T` as an `&External<T>` without copying it.
+    #[inline(always)]
+    pub fn from_ref(value: &T) -> &External<T> {
+        // SAFETY: `External<T>` is `repr(transparent)` over `T`.
+        unsafe { &*(value as *const T as *const External<T>) }
+    }
+
+    /// Returns the wrapped value.
+    #[inline(always)]
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> From<T> for External<T> {
+    #[inline(always)]
+    fn from(value: T) -> Self {
+        External(value)
+    }
+}
+
+impl<T> Deref for External<T> {
+    type Target = T;
+
+    #[inline(always)]
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for External<T> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+impl<T: 'static> Serializer for External<T> {
+    #[inline(always)]
+    fn fory_write_data(&self, context: &mut WriteContext) -> Result<(), Error> {
+        let serializer = context.get_type_resolver().get_external_serializer::<T>()?;
+        (serializer.write)(&self.0, context)
+    }
+
+    #[inline(always)]
+    fn fory_read_data(context: &mut ReadContext) -> Result<Self, Error> {
+        let serializer = context.get_type_resolver().get_external_serializer::<T>()?;
+        (serializer.read)(context).map(External)
+    }
+
+    #[inline(always)]
+    fn fory_type_id_dyn(&self, type_resolver: &TypeResolver) -> Result<TypeId, Error> {
+        Self::fory_get_type_id(type_resolver)
+    }
+
+    #[inline(always)]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl<T: Default> ForyDefault for External<T> {
+    #[inline(always)]
+    fn fory_default() -> Self {
+        External(T::default())
+    }
+}
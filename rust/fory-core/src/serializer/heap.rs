@@ -20,8 +20,8 @@ use crate::context::WriteContext;
 use crate::error::Error;
 use crate::resolver::TypeResolver;
 use crate::serializer::collection::{
-    read_collection_data, read_collection_type_info, write_collection_data,
-    write_collection_type_info,
+    estimate_collection_size, read_collection_data, read_collection_type_info,
+    write_collection_data, write_collection_type_info,
 };
 
 use crate::serializer::{ForyDefault, Serializer};
@@ -43,7 +43,7 @@ impl<T: Serializer + ForyDefault + Ord> Serializer for BinaryHeap<T> {
     }
 
     fn fory_write_type_info(context: &mut WriteContext) -> Result<(), Error> {
-        write_collection_type_info(context, TypeId::SET as u32)
+        write_collection_type_info(context, TypeId::LIST as u32)
     }
 
     fn fory_read_data(context: &mut ReadContext) -> Result<Self, Error> {
@@ -51,23 +51,27 @@ impl<T: Serializer + ForyDefault + Ord> Serializer for BinaryHeap<T> {
     }
 
     fn fory_read_type_info(context: &mut ReadContext) -> Result<(), Error> {
-        read_collection_type_info(context, TypeId::SET as u32)
+        read_collection_type_info(context, TypeId::LIST as u32)
     }
 
     fn fory_reserved_space() -> usize {
         mem::size_of::<i32>()
     }
 
+    fn fory_estimate_size(&self) -> usize {
+        estimate_collection_size::<T>(self.len())
+    }
+
     fn fory_get_type_id(_: &TypeResolver) -> Result<TypeId, Error> {
-        Ok(TypeId::SET)
+        Ok(TypeId::LIST)
     }
 
     fn fory_type_id_dyn(&self, _: &TypeResolver) -> Result<TypeId, Error> {
-        Ok(TypeId::SET)
+        Ok(TypeId::LIST)
     }
 
     fn fory_static_type_id() -> TypeId {
-        TypeId::SET
+        TypeId::LIST
     }
 
     fn as_any(&self) -> &dyn std::any::Any {
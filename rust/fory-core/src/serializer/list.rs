@@ -18,7 +18,7 @@
 use crate::context::ReadContext;
 use crate::context::WriteContext;
 use crate::error::Error;
-use crate::resolver::TypeResolver;
+use crate::resolver::{RefMode, TypeResolver};
 use crate::serializer::primitive_list;
 use crate::serializer::{ForyDefault, Serializer};
 use crate::type_id::TypeId;
@@ -26,9 +26,10 @@ use std::collections::{LinkedList, VecDeque};
 use std::mem;
 
 use super::collection::{
-    read_collection_data, read_collection_type_info, read_vec_data, write_collection_data,
-    write_collection_type_info,
+    estimate_collection_size, read_collection_data, read_collection_type_info, read_vec_data,
+    read_vec_data_into, write_collection_data, write_collection_type_info,
 };
+use super::util::read_into_basic;
 
 #[inline(always)]
 pub(super) fn get_primitive_type_id<T: Serializer>() -> TypeId {
@@ -92,14 +93,46 @@ pub(super) fn is_primitive_type<T: Serializer>() -> bool {
     )
 }
 
+/// Writes a slice with the data format of `Vec<T>`. `Vec<T>`, `Box<[T]>` and
+/// [`Fory::serialize_slice`](crate::Fory::serialize_slice) all write through this.
+#[inline(always)]
+pub(crate) fn write_slice<T: Serializer>(
+    items: &[T],
+    context: &mut WriteContext,
+    has_generics: bool,
+) -> Result<(), Error> {
+    if is_primitive_type::<T>() {
+        primitive_list::fory_write_data(items, context)
+    } else {
+        write_collection_data(items, context, has_generics)
+    }
+}
+
+#[inline(always)]
+pub(crate) fn write_slice_type_info<T: Serializer>(
+    context: &mut WriteContext,
+) -> Result<(), Error> {
+    let id = get_primitive_type_id::<T>();
+    if id != TypeId::UNKNOWN {
+        primitive_list::fory_write_type_info(context, id)
+    } else {
+        write_collection_type_info(context, TypeId::LIST as u32)
+    }
+}
+
+#[inline(always)]
+pub(crate) fn estimate_slice_size<T: Serializer>(items: &[T]) -> usize {
+    if is_primitive_type::<T>() {
+        primitive_list::fory_estimate_size(items)
+    } else {
+        estimate_collection_size::<T>(items.len())
+    }
+}
+
 impl<T: Serializer + ForyDefault> Serializer for Vec<T> {
     #[inline(always)]
     fn fory_write_data(&self, context: &mut WriteContext) -> Result<(), Error> {
-        if is_primitive_type::<T>() {
-            primitive_list::fory_write_data(self, context)
-        } else {
-            write_collection_data(self, context, false)
-        }
+        write_slice(self, context, false)
     }
 
     #[inline(always)]
@@ -108,21 +141,12 @@ impl<T: Serializer + ForyDefault> Serializer for Vec<T> {
         context: &mut WriteContext,
         has_generics: bool,
     ) -> Result<(), Error> {
-        if is_primitive_type::<T>() {
-            primitive_list::fory_write_data(self, context)
-        } else {
-            write_collection_data(self, context, has_generics)
-        }
+        write_slice(self, context, has_generics)
     }
 
     #[inline(always)]
     fn fory_write_type_info(context: &mut WriteContext) -> Result<(), Error> {
-        let id = get_primitive_type_id::<T>();
-        if id != TypeId::UNKNOWN {
-            primitive_list::fory_write_type_info(context, id)
-        } else {
-            write_collection_type_info(context, TypeId::LIST as u32)
-        }
+        write_slice_type_info::<T>(context)
     }
 
     #[inline(always)]
@@ -134,6 +158,25 @@ impl<T: Serializer + ForyDefault> Serializer for Vec<T> {
         }
     }
 
+    #[inline(always)]
+    fn fory_read_into(
+        &mut self,
+        context: &mut ReadContext,
+        ref_mode: RefMode,
+        read_type_info: bool,
+    ) -> Result<(), Error> {
+        read_into_basic(self, context, ref_mode, read_type_info)
+    }
+
+    #[inline(always)]
+    fn fory_read_data_into(&mut self, context: &mut ReadContext) -> Result<(), Error> {
+        if is_primitive_type::<T>() {
+            primitive_list::fory_read_data_into(self, context)
+        } else {
+            read_vec_data_into(self, context)
+        }
+    }
+
     #[inline(always)]
     fn fory_read_type_info(context: &mut ReadContext) -> Result<(), Error> {
         let id = get_primitive_type_id::<T>();
@@ -154,6 +197,11 @@ impl<T: Serializer + ForyDefault> Serializer for Vec<T> {
         }
     }
 
+    #[inline(always)]
+    fn fory_estimate_size(&self) -> usize {
+        estimate_slice_size(self)
+    }
+
     #[inline(always)]
     fn fory_get_type_id(_: &TypeResolver) -> Result<TypeId, Error> {
         let id = get_primitive_type_id::<T>();
@@ -200,6 +248,76 @@ impl<T> ForyDefault for Vec<T> {
     }
 }
 
+/// `Box<[T]>` shares the wire format of `Vec<T>`. Reads decode a `Vec<T>` and shrink it
+/// into the boxed slice.
+impl<T: Serializer + ForyDefault> Serializer for Box<[T]> {
+    #[inline(always)]
+    fn fory_write_data(&self, context: &mut WriteContext) -> Result<(), Error> {
+        write_slice(self, context, false)
+    }
+
+    #[inline(always)]
+    fn fory_write_data_generic(
+        &self,
+        context: &mut WriteContext,
+        has_generics: bool,
+    ) -> Result<(), Error> {
+        write_slice(self, context, has_generics)
+    }
+
+    #[inline(always)]
+    fn fory_write_type_info(context: &mut WriteContext) -> Result<(), Error> {
+        write_slice_type_info::<T>(context)
+    }
+
+    #[inline(always)]
+    fn fory_read_data(context: &mut ReadContext) -> Result<Self, Error> {
+        Ok(Vec::<T>::fory_read_data(context)?.into_boxed_slice())
+    }
+
+    #[inline(always)]
+    fn fory_read_type_info(context: &mut ReadContext) -> Result<(), Error> {
+        Vec::<T>::fory_read_type_info(context)
+    }
+
+    #[inline(always)]
+    fn fory_reserved_space() -> usize {
+        Vec::<T>::fory_reserved_space()
+    }
+
+    #[inline(always)]
+    fn fory_estimate_size(&self) -> usize {
+        estimate_slice_size(self)
+    }
+
+    #[inline(always)]
+    fn fory_get_type_id(type_resolver: &TypeResolver) -> Result<TypeId, Error> {
+        Vec::<T>::fory_get_type_id(type_resolver)
+    }
+
+    #[inline(always)]
+    fn fory_type_id_dyn(&self, _: &TypeResolver) -> Result<TypeId, Error> {
+        Ok(Vec::<T>::fory_static_type_id())
+    }
+
+    #[inline(always)]
+    fn fory_static_type_id() -> TypeId {
+        Vec::<T>::fory_static_type_id()
+    }
+
+    #[inline(always)]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl<T> ForyDefault for Box<[T]> {
+    #[inline(always)]
+    fn fory_default() -> Self {
+        Box::default()
+    }
+}
+
 impl<T: Serializer + ForyDefault> Serializer for VecDeque<T> {
     #[inline(always)]
     fn fory_write_data(&self, context: &mut WriteContext) -> Result<(), Error> {
@@ -235,6 +353,11 @@ impl<T: Serializer + ForyDefault> Serializer for VecDeque<T> {
         mem::size_of::<u32>()
     }
 
+    #[inline(always)]
+    fn fory_estimate_size(&self) -> usize {
+        estimate_collection_size::<T>(self.len())
+    }
+
     #[inline(always)]
     fn fory_get_type_id(_: &TypeResolver) -> Result<TypeId, Error> {
         Ok(TypeId::LIST)
@@ -298,6 +421,11 @@ impl<T: Serializer + ForyDefault> Serializer for LinkedList<T> {
         mem::size_of::<u32>()
     }
 
+    #[inline(always)]
+    fn fory_estimate_size(&self) -> usize {
+        estimate_collection_size::<T>(self.len())
+    }
+
     #[inline(always)]
     fn fory_get_type_id(_: &TypeResolver) -> Result<TypeId, Error> {
         Ok(TypeId::LIST)
@@ -20,10 +20,11 @@ use crate::ensure;
 use crate::error::Error;
 use crate::resolver::RefMode;
 use crate::resolver::{TypeInfo, TypeResolver};
-use crate::serializer::util::read_basic_type_info;
+use crate::serializer::util::{read_basic_type_info, read_into_basic};
 use crate::serializer::{ForyDefault, Serializer};
 use crate::type_id::{need_to_write_type_for_field, TypeId, SIZE_OF_REF_AND_TYPE};
 use std::collections::{BTreeMap, HashMap};
+use std::hash::BuildHasher;
 use std::rc::Rc;
 
 const MAX_CHUNK_SIZE: u8 = 255;
@@ -40,7 +41,7 @@ fn map_size_limit_exceeded(len: u32, max: u32) -> Error {
     Error::size_limit_exceeded(format!("Map size {} exceeds limit {}", len, max))
 }
 
-fn check_map_len(context: &ReadContext, len: u32) -> Result<(), Error> {
+pub(crate) fn check_map_len(context: &ReadContext, len: u32) -> Result<(), Error> {
     let len = len as usize;
     let remaining = context.reader.slice_after_cursor().len();
     if len > remaining {
@@ -50,6 +51,27 @@ fn check_map_len(context: &ReadContext, len: u32) -> Result<(), Error> {
     Ok(())
 }
 
+/// Returns the entries of `map` ordered by the bytes `write_key` produces for their keys.
+pub(crate) fn sorted_entries<'a, K, V, S>(
+    map: &'a HashMap<K, V, S>,
+    context: &mut WriteContext,
+    write_key: impl Fn(&K, &mut WriteContext) -> Result<(), Error>,
+) -> Result<Vec<(&'a K, &'a V)>, Error> {
+    let entries: Vec<(&K, &V)> = map.iter().collect();
+    let order = context.order_by_encoding(entries.iter().map(|(key, _)| *key), write_key)?;
+    Ok(order.into_iter().map(|i| entries[i]).collect())
+}
+
+/// Size hint for a map of `len` entries: the length header plus the reserved space of each
+/// key and value, with one byte per entry covering the chunk headers. Entries aren't
+/// visited, so the hint is O(1).
+#[inline(always)]
+pub fn estimate_map_size<K: Serializer, V: Serializer>(len: usize) -> usize {
+    size_of::<u64>()
+        + SIZE_OF_REF_AND_TYPE
+        + len * (K::fory_reserved_space() + V::fory_reserved_space() + 1)
+}
+
 fn write_chunk_size(context: &mut WriteContext, header_offset: usize, size: u8) {
     context.writer.set_bytes(header_offset + 1, &[size]);
 }
@@ -359,11 +381,11 @@ where
     Ok(())
 }
 
-/// Macro to generate read_*_data_dyn_ref functions for HashMap and BTreeMap.
+/// Macro to generate read_*_data_dyn_ref functions for HashMap, BTreeMap and IndexMap.
 /// This avoids code duplication while maintaining zero runtime cost.
 macro_rules! impl_read_map_dyn_ref {
-    ($fn_name:ident, $map_type:ty, $($extra_trait_bounds:tt)*) => {
-        fn $fn_name<K, V>(
+    ($fn_name:ident $(<$param:ident: $bound:path>)?, $map_type:ty, $($extra_trait_bounds:tt)*) => {
+        fn $fn_name<K, V $(, $param: $bound)?>(
             context: &mut ReadContext,
             mut map: $map_type,
             length: u32,
@@ -539,8 +561,8 @@ macro_rules! impl_read_map_dyn_ref {
 
 // Generate read_hashmap_data_dyn_ref for HashMap
 impl_read_map_dyn_ref!(
-    read_hashmap_data_dyn_ref,
-    HashMap<K, V>,
+    read_hashmap_data_dyn_ref<S: BuildHasher>,
+    HashMap<K, V, S>,
     Eq + std::hash::Hash
 );
 
@@ -551,11 +573,14 @@ impl_read_map_dyn_ref!(
     Ord
 );
 
-impl<K: Serializer + ForyDefault + Eq + std::hash::Hash, V: Serializer + ForyDefault> Serializer
-    for HashMap<K, V>
+impl<K, V, S> Serializer for HashMap<K, V, S>
+where
+    K: Serializer + ForyDefault + Eq + std::hash::Hash,
+    V: Serializer + ForyDefault,
+    S: BuildHasher + Default + 'static,
 {
     fn fory_write_data(&self, context: &mut WriteContext) -> Result<(), Error> {
-        write_map_data(self.iter(), self.len(), context, false)
+        self.fory_write_data_generic(context, false)
     }
 
     fn fory_write_data_generic(
@@ -563,13 +588,34 @@ impl<K: Serializer + ForyDefault + Eq + std::hash::Hash, V: Serializer + ForyDef
         context: &mut WriteContext,
         has_generics: bool,
     ) -> Result<(), Error> {
+        if context.is_deterministic() && self.len() > 1 {
+            let entries =
+                sorted_entries(self, context, |key, context| key.fory_write_data(context))?;
+            return write_map_data(entries.into_iter(), self.len(), context, has_generics);
+        }
         write_map_data(self.iter(), self.len(), context, has_generics)
     }
 
     fn fory_read_data(context: &mut ReadContext) -> Result<Self, Error> {
+        let mut map = HashMap::default();
+        map.fory_read_data_into(context)?;
+        Ok(map)
+    }
+
+    fn fory_read_into(
+        &mut self,
+        context: &mut ReadContext,
+        ref_mode: RefMode,
+        read_type_info: bool,
+    ) -> Result<(), Error> {
+        read_into_basic(self, context, ref_mode, read_type_info)
+    }
+
+    fn fory_read_data_into(&mut self, context: &mut ReadContext) -> Result<(), Error> {
+        self.clear();
         let len = context.reader.read_var_u32()?;
         if len == 0 {
-            return Ok(HashMap::new());
+            return Ok(());
         }
         let max = context.max_collection_size();
         if len > max {
@@ -581,10 +627,13 @@ impl<K: Serializer + ForyDefault + Eq + std::hash::Hash, V: Serializer + ForyDef
             || V::fory_is_polymorphic()
             || V::fory_is_shared_ref()
         {
-            let map: HashMap<K, V> = HashMap::with_capacity(len as usize);
-            return read_hashmap_data_dyn_ref(context, map, len);
+            self.reserve(len as usize);
+            let map = std::mem::take(self);
+            *self = read_hashmap_data_dyn_ref(context, map, len)?;
+            return Ok(());
         }
-        let mut map = HashMap::<K, V>::with_capacity(len as usize);
+        self.reserve(len as usize);
+        let map = self;
         let mut len_counter = 0;
         loop {
             if len_counter == len {
@@ -665,13 +714,17 @@ impl<K: Serializer + ForyDefault + Eq + std::hash::Hash, V: Serializer + ForyDef
             // advance the counter after processing the chunk
             len_counter += chunk_size as u32;
         }
-        Ok(map)
+        Ok(())
     }
 
     fn fory_reserved_space() -> usize {
         size_of::<i32>()
     }
 
+    fn fory_estimate_size(&self) -> usize {
+        estimate_map_size::<K, V>(self.len())
+    }
+
     fn fory_get_type_id(_: &TypeResolver) -> Result<TypeId, Error> {
         Ok(TypeId::MAP)
     }
@@ -701,9 +754,9 @@ impl<K: Serializer + ForyDefault + Eq + std::hash::Hash, V: Serializer + ForyDef
     }
 }
 
-impl<K, V> ForyDefault for HashMap<K, V> {
+impl<K, V, S: Default> ForyDefault for HashMap<K, V, S> {
     fn fory_default() -> Self {
-        HashMap::new()
+        HashMap::default()
     }
 }
 
@@ -827,6 +880,10 @@ impl<K: Serializer + ForyDefault + Ord + std::hash::Hash, V: Serializer + ForyDe
         size_of::<i32>()
     }
 
+    fn fory_estimate_size(&self) -> usize {
+        estimate_map_size::<K, V>(self.len())
+    }
+
     fn fory_get_type_id(_: &TypeResolver) -> Result<TypeId, Error> {
         Ok(TypeId::MAP)
     }
@@ -861,3 +918,86 @@ impl<K, V> ForyDefault for BTreeMap<K, V> {
         BTreeMap::new()
     }
 }
+
+#[cfg(feature = "indexmap")]
+impl_read_map_dyn_ref!(
+    read_indexmap_data_dyn_ref,
+    indexmap::IndexMap<K, V>,
+    Eq + std::hash::Hash
+);
+
+/// Shares the map wire format. Entries are written in insertion order, and reading inserts
+/// them in stream order, so the order survives a round trip between `IndexMap`s.
+#[cfg(feature = "indexmap")]
+impl<K: Serializer + ForyDefault + Eq + std::hash::Hash, V: Serializer + ForyDefault> Serializer
+    for indexmap::IndexMap<K, V>
+{
+    fn fory_write_data(&self, context: &mut WriteContext) -> Result<(), Error> {
+        write_map_data(self.iter(), self.len(), context, false)
+    }
+
+    fn fory_write_data_generic(
+        &self,
+        context: &mut WriteContext,
+        has_generics: bool,
+    ) -> Result<(), Error> {
+        write_map_data(self.iter(), self.len(), context, has_generics)
+    }
+
+    fn fory_read_data(context: &mut ReadContext) -> Result<Self, Error> {
+        let len = context.reader.read_var_u32()?;
+        if len == 0 {
+            return Ok(indexmap::IndexMap::new());
+        }
+        let max = context.max_collection_size();
+        if len > max {
+            return Err(map_size_limit_exceeded(len, max));
+        }
+        check_map_len(context, len)?;
+        let map = indexmap::IndexMap::with_capacity(len as usize);
+        read_indexmap_data_dyn_ref(context, map, len)
+    }
+
+    fn fory_reserved_space() -> usize {
+        size_of::<i32>()
+    }
+
+    fn fory_estimate_size(&self) -> usize {
+        estimate_map_size::<K, V>(self.len())
+    }
+
+    fn fory_get_type_id(_: &TypeResolver) -> Result<TypeId, Error> {
+        Ok(TypeId::MAP)
+    }
+
+    fn fory_type_id_dyn(&self, _: &TypeResolver) -> Result<TypeId, Error> {
+        Ok(TypeId::MAP)
+    }
+
+    fn fory_static_type_id() -> TypeId
+    where
+        Self: Sized,
+    {
+        TypeId::MAP
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn fory_write_type_info(context: &mut WriteContext) -> Result<(), Error> {
+        context.writer.write_u8(TypeId::MAP as u8);
+        Ok(())
+    }
+
+    fn fory_read_type_info(context: &mut ReadContext) -> Result<(), Error> {
+        read_basic_type_info::<Self>(context)
+    }
+}
+
+#[cfg(feature = "indexmap")]
+impl<K, V> ForyDefault for indexmap::IndexMap<K, V> {
+    fn fory_default() -> Self {
+        indexmap::IndexMap::new()
+    }
+}
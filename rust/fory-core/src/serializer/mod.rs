@@ -18,38 +18,64 @@
 pub mod any;
 mod arc;
 mod array;
+mod atomic;
 mod bool;
+pub mod borrow;
 mod box_;
+#[cfg(feature = "bytes")]
+mod bytes;
+mod char;
 #[doc(hidden)]
 pub mod codec;
 pub mod collection;
+mod cow;
 mod datetime;
+pub(crate) mod diff;
+pub(crate) mod dump;
 pub mod enum_;
+pub mod external;
 mod heap;
 mod list;
 pub mod map;
 mod marker;
 mod mutex;
+mod net;
+mod nonzero;
 mod number;
 mod option;
+mod os_string;
+mod path;
 mod primitive_list;
+mod range;
 mod rc;
 mod refcell;
+mod result;
 mod scalar_conversion;
 mod set;
 pub mod skip;
+#[cfg(feature = "smallvec")]
+mod smallvec;
 mod string;
 pub mod struct_;
+mod time;
 pub mod trait_object;
 mod tuple;
 #[doc(hidden)]
 pub mod unknown_case;
 mod unsigned_number;
 pub mod util;
+#[cfg(feature = "uuid")]
+mod uuid;
 pub mod weak;
+mod wrapping;
 
 mod core;
 mod decimal;
 pub use any::{read_box_any, write_box_any};
+pub use borrow::BorrowDeserialize;
 pub use core::{read_data, write_data, ForyDefault, Serializer, StructSerializer};
+pub use diff::FieldDiff;
+pub use external::External;
+pub(crate) use list::{estimate_slice_size, write_slice, write_slice_type_info};
+pub use struct_::PresentFields;
 pub use util::send_sync::box_send_sync;
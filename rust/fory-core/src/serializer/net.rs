@@ -0,0 +1,208 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! `std::net` address types have no xlang type, so each uses its own Rust-specific
+//! type id and is only supported in native mode.
+//!
+//! - `Ipv4Addr` is written as its 4 octets and `Ipv6Addr` as its 16 octets.
+//! - `IpAddr` is a discriminator byte (`4` or `6`) followed by the octets.
+//! - `SocketAddr` is an `IpAddr` followed by the port as a little-endian `u16`.
+//!   V6 socket addresses also carry the flow info and scope id as var_uint32.
+
+use crate::context::ReadContext;
+use crate::context::WriteContext;
+use crate::error::Error;
+use crate::resolver::TypeResolver;
+use crate::serializer::util::read_basic_type_info;
+use crate::serializer::{ForyDefault, Serializer};
+use crate::type_id::TypeId;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+
+const IPV4_FLAG: u8 = 4;
+const IPV6_FLAG: u8 = 6;
+
+#[inline(always)]
+fn check_native(context: &WriteContext, name: &str) -> Result<(), Error> {
+    if context.is_xlang() {
+        return Err(Error::not_allowed(format!(
+            "{name} is not supported in cross-language mode"
+        )));
+    }
+    Ok(())
+}
+
+#[inline(always)]
+fn read_octets<const N: usize>(context: &mut ReadContext) -> Result<[u8; N], Error> {
+    let mut octets = [0u8; N];
+    octets.copy_from_slice(context.reader.read_bytes(N)?);
+    Ok(octets)
+}
+
+#[inline(always)]
+fn write_ip_addr(ip: &IpAddr, context: &mut WriteContext) {
+    match ip {
+        IpAddr::V4(v4) => {
+            context.writer.write_u8(IPV4_FLAG);
+            context.writer.write_bytes(&v4.octets());
+        }
+        IpAddr::V6(v6) => {
+            context.writer.write_u8(IPV6_FLAG);
+            context.writer.write_bytes(&v6.octets());
+        }
+    }
+}
+
+#[inline(always)]
+fn read_ip_addr(context: &mut ReadContext) -> Result<IpAddr, Error> {
+    match context.reader.read_u8()? {
+        IPV4_FLAG => Ok(IpAddr::V4(Ipv4Addr::from(read_octets::<4>(context)?))),
+        IPV6_FLAG => Ok(IpAddr::V6(Ipv6Addr::from(read_octets::<16>(context)?))),
+        flag => Err(Error::invalid_data(format!(
+            "invalid IP address version flag: {flag}"
+        ))),
+    }
+}
+
+#[inline(always)]
+fn write_socket_addr(addr: &SocketAddr, context: &mut WriteContext) {
+    write_ip_addr(&addr.ip(), context);
+    context.writer.write_u16(addr.port());
+    if let SocketAddr::V6(v6) = addr {
+        context.writer.write_var_u32(v6.flowinfo());
+        context.writer.write_var_u32(v6.scope_id());
+    }
+}
+
+#[inline(always)]
+fn read_socket_addr(context: &mut ReadContext) -> Result<SocketAddr, Error> {
+    let ip = read_ip_addr(context)?;
+    let port = context.reader.read_u16()?;
+    Ok(match ip {
+        IpAddr::V4(v4) => SocketAddr::V4(SocketAddrV4::new(v4, port)),
+        IpAddr::V6(v6) => {
+            let flowinfo = context.reader.read_var_u32()?;
+            let scope_id = context.reader.read_var_u32()?;
+            SocketAddr::V6(SocketAddrV6::new(v6, port, flowinfo, scope_id))
+        }
+    })
+}
+
+macro_rules! impl_net_serializer {
+    ($ty:ty, $type_id:expr, $reserved:expr, $default:expr, $writer:expr, $reader:expr) => {
+        impl Serializer for $ty {
+            #[inline(always)]
+            fn fory_write_data(&self, context: &mut WriteContext) -> Result<(), Error> {
+                check_native(context, stringify!($ty))?;
+                $writer(self, context);
+                Ok(())
+            }
+
+            #[inline(always)]
+            fn fory_read_data(context: &mut ReadContext) -> Result<Self, Error> {
+                $reader(context)
+            }
+
+            #[inline]
+            fn fory_read_data_as_send_sync_any(
+                context: &mut ReadContext,
+            ) -> Result<Box<dyn std::any::Any + Send + Sync>, Error>
+            where
+                Self: Sized + ForyDefault,
+            {
+                Ok(crate::serializer::box_send_sync(Self::fory_read_data(
+                    context,
+                )?))
+            }
+
+            #[inline(always)]
+            fn fory_reserved_space() -> usize {
+                $reserved
+            }
+
+            #[inline(always)]
+            fn fory_get_type_id(_: &TypeResolver) -> Result<TypeId, Error> {
+                Ok($type_id)
+            }
+
+            #[inline(always)]
+            fn fory_type_id_dyn(&self, _: &TypeResolver) -> Result<TypeId, Error> {
+                Ok($type_id)
+            }
+
+            #[inline(always)]
+            fn fory_static_type_id() -> TypeId {
+                $type_id
+            }
+
+            #[inline(always)]
+            fn as_any(&self) -> &dyn std::any::Any {
+                self
+            }
+
+            #[inline(always)]
+            fn fory_write_type_info(context: &mut WriteContext) -> Result<(), Error> {
+                context.writer.write_var_u32($type_id as u32);
+                Ok(())
+            }
+
+            #[inline(always)]
+            fn fory_read_type_info(context: &mut ReadContext) -> Result<(), Error> {
+                read_basic_type_info::<Self>(context)
+            }
+        }
+
+        impl ForyDefault for $ty {
+            #[inline(always)]
+            fn fory_default() -> Self {
+                $default
+            }
+        }
+    };
+}
+
+impl_net_serializer!(
+    Ipv4Addr,
+    TypeId::IPV4_ADDR,
+    4,
+    Ipv4Addr::UNSPECIFIED,
+    |ip: &Ipv4Addr, context: &mut WriteContext| context.writer.write_bytes(&ip.octets()),
+    |context: &mut ReadContext| Ok(Ipv4Addr::from(read_octets::<4>(context)?))
+);
+impl_net_serializer!(
+    Ipv6Addr,
+    TypeId::IPV6_ADDR,
+    16,
+    Ipv6Addr::UNSPECIFIED,
+    |ip: &Ipv6Addr, context: &mut WriteContext| context.writer.write_bytes(&ip.octets()),
+    |context: &mut ReadContext| Ok(Ipv6Addr::from(read_octets::<16>(context)?))
+);
+impl_net_serializer!(
+    IpAddr,
+    TypeId::IP_ADDR,
+    17,
+    IpAddr::V4(Ipv4Addr::UNSPECIFIED),
+    write_ip_addr,
+    read_ip_addr
+);
+impl_net_serializer!(
+    SocketAddr,
+    TypeId::SOCKET_ADDR,
+    29,
+    SocketAddr::V4(SocketAddrV4::new(Ipv4Addr::UNSPECIFIED, 0)),
+    write_socket_addr,
+    read_socket_addr
+);
@@ -0,0 +1,124 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! `NonZero*` integers share the type id and wire format of their inner integer, so they
+//! interoperate with plain integer fields. A zero on the wire is rejected on read.
+//!
+//! The default value, used for absent compatible-mode fields, is one.
+
+use crate::context::ReadContext;
+use crate::context::WriteContext;
+use crate::error::Error;
+use crate::resolver::TypeResolver;
+use crate::serializer::{ForyDefault, Serializer};
+use crate::type_id::TypeId;
+use std::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
+};
+
+macro_rules! impl_non_zero_serializer {
+    ($ty:ty, $inner:ty) => {
+        impl Serializer for $ty {
+            #[inline(always)]
+            fn fory_write_data(&self, context: &mut WriteContext) -> Result<(), Error> {
+                self.get().fory_write_data(context)
+            }
+
+            #[inline(always)]
+            fn fory_read_data(context: &mut ReadContext) -> Result<Self, Error> {
+                let value = <$inner as Serializer>::fory_read_data(context)?;
+                <$ty>::new(value)
+                    .ok_or_else(|| Error::invalid_data(concat!("zero value for ", stringify!($ty))))
+            }
+
+            #[inline]
+            fn fory_read_data_as_send_sync_any(
+                context: &mut ReadContext,
+            ) -> Result<Box<dyn std::any::Any + Send + Sync>, Error>
+            where
+                Self: Sized + ForyDefault,
+            {
+                Ok(crate::serializer::box_send_sync(Self::fory_read_data(
+                    context,
+                )?))
+            }
+
+            #[inline(always)]
+            fn fory_reserved_space() -> usize {
+                <$inner as Serializer>::fory_reserved_space()
+            }
+
+            #[inline(always)]
+            fn fory_get_type_id(type_resolver: &TypeResolver) -> Result<TypeId, Error> {
+                <$inner as Serializer>::fory_get_type_id(type_resolver)
+            }
+
+            #[inline(always)]
+            fn fory_type_id_dyn(&self, type_resolver: &TypeResolver) -> Result<TypeId, Error> {
+                <$inner as Serializer>::fory_get_type_id(type_resolver)
+            }
+
+            #[inline(always)]
+            fn fory_static_type_id() -> TypeId {
+                <$inner as Serializer>::fory_static_type_id()
+            }
+
+            /// Keeps `Vec<NonZero*>` off the primitive array path, whose bulk copy would
+            /// skip the non-zero check.
+            #[inline(always)]
+            fn fory_is_wrapper_type() -> bool {
+                true
+            }
+
+            #[inline(always)]
+            fn as_any(&self) -> &dyn std::any::Any {
+                self
+            }
+
+            #[inline(always)]
+            fn fory_write_type_info(context: &mut WriteContext) -> Result<(), Error> {
+                <$inner as Serializer>::fory_write_type_info(context)
+            }
+
+            #[inline(always)]
+            fn fory_read_type_info(context: &mut ReadContext) -> Result<(), Error> {
+                <$inner as Serializer>::fory_read_type_info(context)
+            }
+        }
+
+        impl ForyDefault for $ty {
+            #[inline(always)]
+            fn fory_default() -> Self {
+                <$ty>::new(1).expect("one is non-zero")
+            }
+        }
+    };
+}
+
+impl_non_zero_serializer!(NonZeroI8, i8);
+impl_non_zero_serializer!(NonZeroI16, i16);
+impl_non_zero_serializer!(NonZeroI32, i32);
+impl_non_zero_serializer!(NonZeroI64, i64);
+impl_non_zero_serializer!(NonZeroI128, i128);
+impl_non_zero_serializer!(NonZeroIsize, isize);
+impl_non_zero_serializer!(NonZeroU8, u8);
+impl_non_zero_serializer!(NonZeroU16, u16);
+impl_non_zero_serializer!(NonZeroU32, u32);
+impl_non_zero_serializer!(NonZeroU64, u64);
+impl_non_zero_serializer!(NonZeroU128, u128);
+impl_non_zero_serializer!(NonZeroUsize, usize);
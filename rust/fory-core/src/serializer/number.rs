@@ -28,17 +28,33 @@ use crate::serializer::{ForyDefault, Serializer};
 use crate::type_id::TypeId;
 
 macro_rules! impl_num_serializer {
+    (fixed $ty:ty, $writer:expr, $reader:expr, $field_type:expr) => {
+        impl_num_serializer!(
+            @impl $ty,
+            |context: &mut WriteContext, value| context.write_fixed($writer, value),
+            |context: &mut ReadContext| context.read_fixed($reader),
+            $field_type
+        );
+    };
     ($ty:ty, $writer:expr, $reader:expr, $field_type:expr) => {
+        impl_num_serializer!(
+            @impl $ty,
+            |context: &mut WriteContext, value| $writer(&mut context.writer, value),
+            |context: &mut ReadContext| $reader(&mut context.reader),
+            $field_type
+        );
+    };
+    (@impl $ty:ty, $writer:expr, $reader:expr, $field_type:expr) => {
         impl Serializer for $ty {
             #[inline(always)]
             fn fory_write_data(&self, context: &mut WriteContext) -> Result<(), Error> {
-                $writer(&mut context.writer, *self);
+                ($writer)(context, *self);
                 Ok(())
             }
 
             #[inline(always)]
             fn fory_read_data(context: &mut ReadContext) -> Result<Self, Error> {
-                $reader(&mut context.reader)
+                ($reader)(context)
             }
             #[inline]
             fn fory_read_data_as_send_sync_any(
@@ -98,7 +114,7 @@ macro_rules! impl_num_serializer {
 }
 
 impl_num_serializer!(i8, Writer::write_i8, Reader::read_i8, TypeId::INT8);
-impl_num_serializer!(i16, Writer::write_i16, Reader::read_i16, TypeId::INT16);
+impl_num_serializer!(fixed i16, Writer::write_i16, Reader::read_i16, TypeId::INT16);
 impl_num_serializer!(
     i32,
     Writer::write_var_i32,
@@ -111,19 +127,19 @@ impl_num_serializer!(
     Reader::read_var_i64,
     TypeId::VARINT64
 );
-impl_num_serializer!(f32, Writer::write_f32, Reader::read_f32, TypeId::FLOAT32);
-impl_num_serializer!(f64, Writer::write_f64, Reader::read_f64, TypeId::FLOAT64);
+impl_num_serializer!(fixed f32, Writer::write_f32, Reader::read_f32, TypeId::FLOAT32);
+impl_num_serializer!(fixed f64, Writer::write_f64, Reader::read_f64, TypeId::FLOAT64);
 
 // Custom implementation for float16 (cannot use 0 as float16)
 impl Serializer for float16 {
     #[inline(always)]
     fn fory_write_data(&self, context: &mut WriteContext) -> Result<(), Error> {
-        Writer::write_f16(&mut context.writer, *self);
+        context.write_fixed(Writer::write_f16, *self);
         Ok(())
     }
     #[inline(always)]
     fn fory_read_data(context: &mut ReadContext) -> Result<Self, Error> {
-        Reader::read_f16(&mut context.reader)
+        context.read_fixed(Reader::read_f16)
     }
     #[inline]
     fn fory_read_data_as_send_sync_any(
@@ -177,12 +193,12 @@ impl ForyDefault for float16 {
 impl Serializer for bfloat16 {
     #[inline(always)]
     fn fory_write_data(&self, context: &mut WriteContext) -> Result<(), Error> {
-        Writer::write_bf16(&mut context.writer, *self);
+        context.write_fixed(Writer::write_bf16, *self);
         Ok(())
     }
     #[inline(always)]
     fn fory_read_data(context: &mut ReadContext) -> Result<Self, Error> {
-        Reader::read_bf16(&mut context.reader)
+        context.read_fixed(Reader::read_bf16)
     }
     #[inline]
     fn fory_read_data_as_send_sync_any(
@@ -232,10 +248,75 @@ impl ForyDefault for bfloat16 {
         bfloat16::ZERO
     }
 }
-impl_num_serializer!(i128, Writer::write_i128, Reader::read_i128, TypeId::INT128);
+impl_num_serializer!(fixed i128, Writer::write_i128, Reader::read_i128, TypeId::INT128);
 impl_num_serializer!(
     isize,
     Writer::write_isize,
     Reader::read_isize,
     TypeId::ISIZE
 );
+
+#[cfg(feature = "half")]
+mod half_support {
+    use super::*;
+
+    /// `half::f16` shares the wire format of [`float16`]: its raw IEEE 754 binary16 bits.
+    impl Serializer for half::f16 {
+        #[inline(always)]
+        fn fory_write_data(&self, context: &mut WriteContext) -> Result<(), Error> {
+            context.write_fixed(Writer::write_u16, self.to_bits());
+            Ok(())
+        }
+        #[inline(always)]
+        fn fory_read_data(context: &mut ReadContext) -> Result<Self, Error> {
+            Ok(half::f16::from_bits(context.read_fixed(Reader::read_u16)?))
+        }
+        #[inline]
+        fn fory_read_data_as_send_sync_any(
+            context: &mut ReadContext,
+        ) -> Result<Box<dyn std::any::Any + Send + Sync>, Error>
+        where
+            Self: Sized + ForyDefault,
+        {
+            Ok(crate::serializer::box_send_sync(Self::fory_read_data(
+                context,
+            )?))
+        }
+        #[inline(always)]
+        fn fory_reserved_space() -> usize {
+            std::mem::size_of::<half::f16>()
+        }
+        #[inline(always)]
+        fn fory_get_type_id(_: &TypeResolver) -> Result<TypeId, Error> {
+            Ok(TypeId::FLOAT16)
+        }
+        #[inline(always)]
+        fn fory_type_id_dyn(&self, _: &TypeResolver) -> Result<TypeId, Error> {
+            Ok(TypeId::FLOAT16)
+        }
+        #[inline(always)]
+        fn fory_static_type_id() -> TypeId {
+            TypeId::FLOAT16
+        }
+        #[inline(always)]
+        fn as_any(&self) -> &dyn std::any::Any {
+            self
+        }
+        #[inline(always)]
+        fn fory_write_type_info(context: &mut WriteContext) -> Result<(), Error> {
+            context.writer.write_var_u32(TypeId::FLOAT16 as u32);
+            Ok(())
+        }
+        #[inline(always)]
+        fn fory_read_type_info(context: &mut ReadContext) -> Result<(), Error> {
+            read_basic_type_info::<Self>(context)
+        }
+    }
+
+    impl ForyDefault for half::f16 {
+        #[inline(always)]
+        fn fory_default() -> Self {
+            half::f16::ZERO
+        }
+    }
+}
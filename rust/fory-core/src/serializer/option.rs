@@ -24,6 +24,17 @@ use crate::serializer::{ForyDefault, Serializer};
 use crate::type_id::TypeId;
 use std::rc::Rc;
 
+/// Ref mode for the value inside `Some`. A nested option writes its own null
+/// flag after the outer one, so `None` and `Some(None)` stay distinct.
+#[inline(always)]
+fn inner_ref_mode<T: Serializer>() -> RefMode {
+    if T::fory_is_option() {
+        RefMode::NullOnly
+    } else {
+        RefMode::None
+    }
+}
+
 impl<T: Serializer + ForyDefault> Serializer for Option<T> {
     #[inline(always)]
     fn fory_write(
@@ -37,7 +48,13 @@ impl<T: Serializer + ForyDefault> Serializer for Option<T> {
             RefMode::None => {
                 // Write inner directly, no null check
                 if let Some(v) = self {
-                    T::fory_write(v, context, RefMode::None, write_type_info, has_generics)
+                    T::fory_write(
+                        v,
+                        context,
+                        inner_ref_mode::<T>(),
+                        write_type_info,
+                        has_generics,
+                    )
                 } else {
                     // None with RefMode::None is a protocol error
                     Err(Error::invalid_data("Option::None with RefMode::None"))
@@ -46,7 +63,13 @@ impl<T: Serializer + ForyDefault> Serializer for Option<T> {
             RefMode::NullOnly => {
                 if let Some(v) = self {
                     context.writer.write_i8(RefFlag::NotNullValue as i8);
-                    T::fory_write(v, context, RefMode::None, write_type_info, has_generics)
+                    T::fory_write(
+                        v,
+                        context,
+                        inner_ref_mode::<T>(),
+                        write_type_info,
+                        has_generics,
+                    )
                 } else {
                     context.writer.write_i8(RefFlag::Null as i8);
                     Ok(())
@@ -55,7 +78,11 @@ impl<T: Serializer + ForyDefault> Serializer for Option<T> {
             RefMode::Tracking => {
                 // Only handle null here, pass Tracking to inner for ref handling
                 if let Some(v) = self {
-                    // DON'T write flag here - inner (e.g. Rc) handles RefValue/Ref flags
+                    // DON'T write flag here - inner (e.g. Rc) handles RefValue/Ref flags,
+                    // unless inner is an Option whose own Null flag would be ambiguous.
+                    if T::fory_is_option() {
+                        context.writer.write_i8(RefFlag::NotNullValue as i8);
+                    }
                     T::fory_write(v, context, RefMode::Tracking, write_type_info, has_generics)
                 } else {
                     context.writer.write_i8(RefFlag::Null as i8);
@@ -68,7 +95,11 @@ impl<T: Serializer + ForyDefault> Serializer for Option<T> {
     #[inline(always)]
     fn fory_write_data(&self, context: &mut WriteContext) -> Result<(), Error> {
         if let Some(v) = self {
-            T::fory_write_data(v, context)
+            if T::fory_is_option() {
+                T::fory_write(v, context, RefMode::NullOnly, false, false)
+            } else {
+                T::fory_write_data(v, context)
+            }
         } else {
             unreachable!("write should be call by serialize")
         }
@@ -90,7 +121,11 @@ impl<T: Serializer + ForyDefault> Serializer for Option<T> {
         match ref_mode {
             RefMode::None => {
                 // Read inner directly, no null check
-                Ok(Some(T::fory_read(context, RefMode::None, read_type_info)?))
+                Ok(Some(T::fory_read(
+                    context,
+                    inner_ref_mode::<T>(),
+                    read_type_info,
+                )?))
             }
             RefMode::NullOnly => {
                 let ref_flag = context.reader.read_i8()?;
@@ -98,7 +133,11 @@ impl<T: Serializer + ForyDefault> Serializer for Option<T> {
                     return Ok(None);
                 }
                 // NotNullValue - read inner without ref handling
-                Ok(Some(T::fory_read(context, RefMode::None, read_type_info)?))
+                Ok(Some(T::fory_read(
+                    context,
+                    inner_ref_mode::<T>(),
+                    read_type_info,
+                )?))
             }
             RefMode::Tracking => {
                 let ref_flag = context.reader.read_i8()?;
@@ -106,7 +145,9 @@ impl<T: Serializer + ForyDefault> Serializer for Option<T> {
                     return Ok(None);
                 }
                 // Rewind to let inner type handle the ref flag (RefValue/Ref)
-                context.reader.move_back(1);
+                if !T::fory_is_option() {
+                    context.reader.move_back(1);
+                }
                 Ok(Some(T::fory_read(
                     context,
                     RefMode::Tracking,
@@ -126,10 +167,10 @@ impl<T: Serializer + ForyDefault> Serializer for Option<T> {
     {
         match ref_mode {
             RefMode::None => {
-                if T::fory_is_polymorphic() {
+                if T::fory_is_option() || T::fory_is_polymorphic() {
                     Ok(Some(T::fory_read_with_type_info(
                         context,
-                        RefMode::None,
+                        inner_ref_mode::<T>(),
                         type_info,
                     )?))
                 } else {
@@ -141,10 +182,10 @@ impl<T: Serializer + ForyDefault> Serializer for Option<T> {
                 if ref_flag == RefFlag::Null as i8 {
                     return Ok(None);
                 }
-                if T::fory_is_polymorphic() {
+                if T::fory_is_option() || T::fory_is_polymorphic() {
                     Ok(Some(T::fory_read_with_type_info(
                         context,
-                        RefMode::None,
+                        inner_ref_mode::<T>(),
                         type_info,
                     )?))
                 } else {
@@ -157,7 +198,9 @@ impl<T: Serializer + ForyDefault> Serializer for Option<T> {
                     return Ok(None);
                 }
                 // Rewind to let inner type handle the ref flag
-                context.reader.move_back(1);
+                if !T::fory_is_option() {
+                    context.reader.move_back(1);
+                }
                 Ok(Some(T::fory_read_with_type_info(
                     context,
                     RefMode::Tracking,
@@ -169,7 +212,9 @@ impl<T: Serializer + ForyDefault> Serializer for Option<T> {
 
     #[inline(always)]
     fn fory_read_data(context: &mut ReadContext) -> Result<Self, Error> {
-        if T::fory_is_polymorphic() {
+        if T::fory_is_option() {
+            Ok(Some(T::fory_read(context, RefMode::NullOnly, false)?))
+        } else if T::fory_is_polymorphic() {
             Ok(Some(T::fory_read(context, RefMode::None, true)?))
         } else {
             Ok(Some(T::fory_read_data(context)?))
@@ -186,6 +231,12 @@ impl<T: Serializer + ForyDefault> Serializer for Option<T> {
         std::mem::size_of::<T>()
     }
 
+    #[inline(always)]
+    fn fory_estimate_size(&self) -> usize {
+        self.as_ref()
+            .map_or(1, |value| 1 + value.fory_estimate_size())
+    }
+
     #[inline(always)]
     fn fory_get_type_id(type_resolver: &TypeResolver) -> Result<TypeId, Error> {
         T::fory_get_type_id(type_resolver)
@@ -0,0 +1,175 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+//! `OsString` has no xlang type, so it uses its own Rust-specific type id and is only
+//! supported in native mode. Unlike `PathBuf`, which is written as a UTF-8 string, it keeps
+//! the platform's raw encoding so that values which are not valid UTF-8 survive a round trip.
+//!
+//! The value is a discriminator byte followed by the raw units as a primitive array:
+//!
+//! - `0` (`UNIX_BYTES`): the bytes of the `OsStr`, as `Vec<u8>`.
+//! - `1` (`WIDE_UNITS`): the UTF-16 code units of the `OsStr` on Windows, as `Vec<u16>`.
+//!
+//! A reader on the other platform family accepts the value only if it is valid Unicode and
+//! fails with `Error::InvalidData` otherwise.
+
+use crate::context::ReadContext;
+use crate::context::WriteContext;
+use crate::error::Error;
+use crate::resolver::TypeResolver;
+use crate::serializer::util::read_basic_type_info;
+use crate::serializer::{primitive_list, ForyDefault, Serializer};
+use crate::type_id::TypeId;
+use std::ffi::OsString;
+
+const UNIX_BYTES: u8 = 0;
+const WIDE_UNITS: u8 = 1;
+
+#[cfg(unix)]
+fn write_os_string(value: &OsString, context: &mut WriteContext) -> Result<(), Error> {
+    use std::os::unix::ffi::OsStrExt;
+    context.writer.write_u8(UNIX_BYTES);
+    primitive_list::fory_write_data(value.as_bytes(), context)
+}
+
+#[cfg(windows)]
+fn write_os_string(value: &OsString, context: &mut WriteContext) -> Result<(), Error> {
+    use std::os::windows::ffi::OsStrExt;
+    context.writer.write_u8(WIDE_UNITS);
+    let units: Vec<u16> = value.encode_wide().collect();
+    primitive_list::fory_write_data(&units, context)
+}
+
+/// Platforms without a raw `OsStr` view store UTF-8, which is also a valid Unix byte string.
+#[cfg(not(any(unix, windows)))]
+fn write_os_string(value: &OsString, context: &mut WriteContext) -> Result<(), Error> {
+    let Some(s) = value.to_str() else {
+        return Err(Error::invalid_data("OsString is not valid UTF-8"));
+    };
+    context.writer.write_u8(UNIX_BYTES);
+    primitive_list::fory_write_data(s.as_bytes(), context)
+}
+
+fn os_string_from_bytes(bytes: Vec<u8>) -> Result<OsString, Error> {
+    #[cfg(unix)]
+    {
+        use std::os::unix::ffi::OsStringExt;
+        Ok(OsString::from_vec(bytes))
+    }
+    #[cfg(not(unix))]
+    {
+        String::from_utf8(bytes)
+            .map(OsString::from)
+            .map_err(|_| Error::invalid_data("Unix OsString is not valid UTF-8"))
+    }
+}
+
+fn os_string_from_wide(units: Vec<u16>) -> Result<OsString, Error> {
+    #[cfg(windows)]
+    {
+        use std::os::windows::ffi::OsStringExt;
+        Ok(OsString::from_wide(&units))
+    }
+    #[cfg(not(windows))]
+    {
+        String::from_utf16(&units)
+            .map(OsString::from)
+            .map_err(|_| Error::invalid_data("Windows OsString is not valid UTF-16"))
+    }
+}
+
+impl Serializer for OsString {
+    #[inline(always)]
+    fn fory_write_data(&self, context: &mut WriteContext) -> Result<(), Error> {
+        if context.is_xlang() {
+            return Err(Error::not_allowed(
+                "OsString is not supported in cross-language mode",
+            ));
+        }
+        write_os_string(self, context)
+    }
+
+    #[inline(always)]
+    fn fory_read_data(context: &mut ReadContext) -> Result<Self, Error> {
+        match context.reader.read_u8()? {
+            UNIX_BYTES => os_string_from_bytes(primitive_list::fory_read_data::<u8>(context)?),
+            WIDE_UNITS => os_string_from_wide(primitive_list::fory_read_data::<u16>(context)?),
+            flag => Err(Error::invalid_data(format!(
+                "invalid OsString encoding flag: {flag}"
+            ))),
+        }
+    }
+
+    #[inline]
+    fn fory_read_data_as_send_sync_any(
+        context: &mut ReadContext,
+    ) -> Result<Box<dyn std::any::Any + Send + Sync>, Error>
+    where
+        Self: Sized + ForyDefault,
+    {
+        Ok(crate::serializer::box_send_sync(Self::fory_read_data(
+            context,
+        )?))
+    }
+
+    #[inline(always)]
+    fn fory_reserved_space() -> usize {
+        1 + std::mem::size_of::<u32>()
+    }
+
+    #[inline(always)]
+    fn fory_estimate_size(&self) -> usize {
+        1 + std::mem::size_of::<u64>() + self.len() * 2
+    }
+
+    #[inline(always)]
+    fn fory_get_type_id(_: &TypeResolver) -> Result<TypeId, Error> {
+        Ok(TypeId::OS_STRING)
+    }
+
+    #[inline(always)]
+    fn fory_type_id_dyn(&self, _: &TypeResolver) -> Result<TypeId, Error> {
+        Ok(TypeId::OS_STRING)
+    }
+
+    #[inline(always)]
+    fn fory_static_type_id() -> TypeId {
+        TypeId::OS_STRING
+    }
+
+    #[inline(always)]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline(always)]
+    fn fory_write_type_info(context: &mut WriteContext) -> Result<(), Error> {
+        context.writer.write_var_u32(TypeId::OS_STRING as u32);
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn fory_read_type_info(context: &mut ReadContext) -> Result<(), Error> {
+        read_basic_type_info::<Self>(context)
+    }
+}
+
+impl ForyDefault for OsString {
+    #[inline(always)]
+    fn fory_default() -> Self {
+        OsString::new()
+    }
+}
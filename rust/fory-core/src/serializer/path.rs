@@ -0,0 +1,109 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+//! `PathBuf` and `Cow<'static, Path>` share the wire format of `String`, so paths written
+//! here read back as strings and vice versa. Paths that are not valid UTF-8 can't be
+//! written and fail with [`Error::InvalidPath`]. Deserialized `Cow` values are always
+//! `Cow::Owned`.
+
+use crate::context::ReadContext;
+use crate::context::WriteContext;
+use crate::error::Error;
+use crate::resolver::TypeResolver;
+use crate::serializer::string::write_str_data;
+use crate::serializer::{ForyDefault, Serializer};
+use crate::type_id::TypeId;
+use std::borrow::Cow;
+use std::path::{Path, PathBuf};
+
+#[inline(always)]
+fn path_str(path: &Path) -> Result<&str, Error> {
+    path.to_str()
+        .ok_or_else(|| Error::invalid_path(format!("path is not valid UTF-8: {}", path.display())))
+}
+
+macro_rules! impl_path_serializer {
+    ($ty:ty, $from_string:expr, $default:expr) => {
+        impl Serializer for $ty {
+            #[inline(always)]
+            fn fory_write_data(&self, context: &mut WriteContext) -> Result<(), Error> {
+                write_str_data(path_str(self)?, context)
+            }
+
+            #[inline(always)]
+            fn fory_read_data(context: &mut ReadContext) -> Result<Self, Error> {
+                Ok($from_string(String::fory_read_data(context)?))
+            }
+
+            #[inline(always)]
+            fn fory_reserved_space() -> usize {
+                String::fory_reserved_space()
+            }
+
+            #[inline(always)]
+            fn fory_estimate_size(&self) -> usize {
+                std::mem::size_of::<u64>() + self.as_os_str().len()
+            }
+
+            #[inline(always)]
+            fn fory_get_type_id(_: &TypeResolver) -> Result<TypeId, Error> {
+                Ok(TypeId::STRING)
+            }
+
+            #[inline(always)]
+            fn fory_type_id_dyn(&self, _: &TypeResolver) -> Result<TypeId, Error> {
+                Ok(TypeId::STRING)
+            }
+
+            #[inline(always)]
+            fn fory_static_type_id() -> TypeId
+            where
+                Self: Sized,
+            {
+                TypeId::STRING
+            }
+
+            #[inline(always)]
+            fn as_any(&self) -> &dyn std::any::Any {
+                self
+            }
+
+            #[inline(always)]
+            fn fory_write_type_info(context: &mut WriteContext) -> Result<(), Error> {
+                String::fory_write_type_info(context)
+            }
+
+            #[inline(always)]
+            fn fory_read_type_info(context: &mut ReadContext) -> Result<(), Error> {
+                String::fory_read_type_info(context)
+            }
+        }
+
+        impl ForyDefault for $ty {
+            #[inline(always)]
+            fn fory_default() -> Self {
+                $default
+            }
+        }
+    };
+}
+
+impl_path_serializer!(PathBuf, PathBuf::from, PathBuf::new());
+impl_path_serializer!(
+    Cow<'static, Path>,
+    |s: String| Cow::Owned(PathBuf::from(s)),
+    Cow::Borrowed(Path::new(""))
+);
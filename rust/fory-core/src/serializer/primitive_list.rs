@@ -15,6 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use crate::buffer::reverse_element_bytes;
 use crate::context::ReadContext;
 use crate::context::WriteContext;
 use crate::ensure;
@@ -66,6 +67,10 @@ pub fn fory_write_data<T: Serializer>(this: &[T], context: &mut WriteContext) ->
                 let slice = std::slice::from_raw_parts(ptr, len_bytes);
                 context.writer.write_bytes(slice);
             }
+            if context.is_big_endian() {
+                let start = context.writer.len() - len_bytes;
+                reverse_element_bytes(&mut context.writer.bf[start..], std::mem::size_of::<T>());
+            }
         }
         #[cfg(target_endian = "big")]
         {
@@ -84,6 +89,16 @@ pub fn fory_write_type_info(context: &mut WriteContext, type_id: TypeId) -> Resu
 }
 
 pub fn fory_read_data<T: Serializer>(context: &mut ReadContext) -> Result<Vec<T>, Error> {
+    let mut vec = Vec::new();
+    fory_read_data_into(&mut vec, context)?;
+    Ok(vec)
+}
+
+/// Reads a primitive array into `vec`, reusing its allocation when the capacity suffices.
+pub fn fory_read_data_into<T: Serializer>(
+    vec: &mut Vec<T>,
+    context: &mut ReadContext,
+) -> Result<(), Error> {
     let size_bytes = context.reader.read_var_u32()? as usize;
     if size_bytes % std::mem::size_of::<T>() != 0 {
         return Err(Error::invalid_data("Invalid data length"));
@@ -102,7 +117,8 @@ pub fn fory_read_data<T: Serializer>(context: &mut ReadContext) -> Result<Vec<T>
         ));
     }
     let len = size_bytes / std::mem::size_of::<T>();
-    let mut vec: Vec<T> = Vec::with_capacity(len);
+    vec.clear();
+    vec.reserve_exact(len);
 
     #[cfg(target_endian = "little")]
     {
@@ -112,6 +128,10 @@ pub fn fory_read_data<T: Serializer>(context: &mut ReadContext) -> Result<Vec<T>
             let src = context.reader.read_bytes(size_bytes)?;
             std::ptr::copy_nonoverlapping(src.as_ptr(), dst_ptr, size_bytes);
             vec.set_len(len);
+            if context.is_big_endian() {
+                let bytes = std::slice::from_raw_parts_mut(dst_ptr, size_bytes);
+                reverse_element_bytes(bytes, std::mem::size_of::<T>());
+            }
         }
     }
     #[cfg(target_endian = "big")]
@@ -121,7 +141,7 @@ pub fn fory_read_data<T: Serializer>(context: &mut ReadContext) -> Result<Vec<T>
             vec.push(T::read(context)?);
         }
     }
-    Ok(vec)
+    Ok(())
 }
 
 pub fn fory_read_type_info(context: &mut ReadContext, type_id: TypeId) -> Result<(), Error> {
@@ -144,3 +164,8 @@ pub fn fory_read_type_info(context: &mut ReadContext, type_id: TypeId) -> Result
 pub fn fory_reserved_space<T>() -> usize {
     std::mem::size_of::<T>()
 }
+
+/// Size hint for `values`: the byte length header plus the raw element bytes.
+pub fn fory_estimate_size<T>(values: &[T]) -> usize {
+    std::mem::size_of::<u64>() + std::mem::size_of_val(values)
+}
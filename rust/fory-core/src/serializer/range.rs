@@ -0,0 +1,141 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! `Range<T>` and `RangeInclusive<T>` have no xlang type, so each uses its own
+//! Rust-specific type id and is only supported in native mode.
+//!
+//! The payload is the start bound followed by the end bound, each with its ref flag and
+//! type info, so readers can skip it without knowing `T`. An exhausted `RangeInclusive`
+//! is written as its start and end only and reads back as a fresh range.
+
+use crate::context::ReadContext;
+use crate::context::WriteContext;
+use crate::error::Error;
+use crate::resolver::{RefMode, TypeResolver};
+use crate::serializer::util::read_basic_type_info;
+use crate::serializer::{ForyDefault, Serializer};
+use crate::type_id::{TypeId, SIZE_OF_REF_AND_TYPE};
+use std::ops::{Range, RangeInclusive};
+
+#[inline(always)]
+fn bound_ref_mode<T: Serializer>() -> RefMode {
+    if T::fory_is_shared_ref() {
+        RefMode::Tracking
+    } else {
+        RefMode::NullOnly
+    }
+}
+
+#[inline(always)]
+fn write_bounds<T: Serializer>(
+    start: &T,
+    end: &T,
+    context: &mut WriteContext,
+    name: &str,
+) -> Result<(), Error> {
+    if context.is_xlang() {
+        return Err(Error::not_allowed(format!(
+            "{name} is not supported in cross-language mode"
+        )));
+    }
+    start.fory_write(context, bound_ref_mode::<T>(), true, false)?;
+    end.fory_write(context, bound_ref_mode::<T>(), true, false)
+}
+
+#[inline(always)]
+fn read_bounds<T: Serializer + ForyDefault>(context: &mut ReadContext) -> Result<(T, T), Error> {
+    let start = T::fory_read(context, bound_ref_mode::<T>(), true)?;
+    let end = T::fory_read(context, bound_ref_mode::<T>(), true)?;
+    Ok((start, end))
+}
+
+macro_rules! impl_range_serializer {
+    ($ty:ident, $type_id:expr, $name:literal, |$range:ident| $bounds:expr, $build:expr) => {
+        impl<T: Serializer + ForyDefault> Serializer for $ty<T> {
+            #[inline(always)]
+            fn fory_write_data(&self, context: &mut WriteContext) -> Result<(), Error> {
+                let $range = self;
+                let (start, end) = $bounds;
+                write_bounds(start, end, context, $name)
+            }
+
+            #[inline(always)]
+            fn fory_read_data(context: &mut ReadContext) -> Result<Self, Error> {
+                let (start, end) = read_bounds::<T>(context)?;
+                Ok($build(start, end))
+            }
+
+            #[inline(always)]
+            fn fory_reserved_space() -> usize {
+                2 * (SIZE_OF_REF_AND_TYPE + T::fory_reserved_space())
+            }
+
+            #[inline(always)]
+            fn fory_get_type_id(_: &TypeResolver) -> Result<TypeId, Error> {
+                Ok($type_id)
+            }
+
+            #[inline(always)]
+            fn fory_type_id_dyn(&self, _: &TypeResolver) -> Result<TypeId, Error> {
+                Ok($type_id)
+            }
+
+            #[inline(always)]
+            fn fory_static_type_id() -> TypeId {
+                $type_id
+            }
+
+            #[inline(always)]
+            fn as_any(&self) -> &dyn std::any::Any {
+                self
+            }
+
+            #[inline(always)]
+            fn fory_write_type_info(context: &mut WriteContext) -> Result<(), Error> {
+                context.writer.write_var_u32($type_id as u32);
+                Ok(())
+            }
+
+            #[inline(always)]
+            fn fory_read_type_info(context: &mut ReadContext) -> Result<(), Error> {
+                read_basic_type_info::<Self>(context)
+            }
+        }
+
+        impl<T: ForyDefault> ForyDefault for $ty<T> {
+            #[inline(always)]
+            fn fory_default() -> Self {
+                $build(T::fory_default(), T::fory_default())
+            }
+        }
+    };
+}
+
+impl_range_serializer!(
+    Range,
+    TypeId::RANGE,
+    "Range",
+    |range| (&range.start, &range.end),
+    |start, end| Range { start, end }
+);
+impl_range_serializer!(
+    RangeInclusive,
+    TypeId::RANGE_INCLUSIVE,
+    "RangeInclusive",
+    |range| (range.start(), range.end()),
+    RangeInclusive::new
+);
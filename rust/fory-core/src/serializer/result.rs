@@ -0,0 +1,115 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! `Result<T, E>` has no xlang type, so it uses its own Rust-specific type id and is
+//! only supported in native mode.
+//!
+//! The payload is a one-byte tag (`0` = `Ok`, `1` = `Err`) followed by the arm value
+//! with its ref flag and type info, so readers can skip it without knowing `T` or `E`.
+
+use crate::context::ReadContext;
+use crate::context::WriteContext;
+use crate::error::Error;
+use crate::resolver::{RefMode, TypeResolver};
+use crate::serializer::util::read_basic_type_info;
+use crate::serializer::{ForyDefault, Serializer};
+use crate::type_id::{TypeId, SIZE_OF_REF_AND_TYPE};
+
+pub(crate) const OK_TAG: u8 = 0;
+pub(crate) const ERR_TAG: u8 = 1;
+
+#[inline(always)]
+fn arm_ref_mode<T: Serializer>() -> RefMode {
+    if T::fory_is_shared_ref() {
+        RefMode::Tracking
+    } else {
+        RefMode::NullOnly
+    }
+}
+
+impl<T: Serializer + ForyDefault, E: Serializer + ForyDefault> Serializer for Result<T, E> {
+    #[inline(always)]
+    fn fory_write_data(&self, context: &mut WriteContext) -> Result<(), Error> {
+        if context.is_xlang() {
+            return Err(Error::not_allowed(
+                "Result is not supported in cross-language mode",
+            ));
+        }
+        match self {
+            Ok(value) => {
+                context.writer.write_u8(OK_TAG);
+                value.fory_write(context, arm_ref_mode::<T>(), true, false)
+            }
+            Err(err) => {
+                context.writer.write_u8(ERR_TAG);
+                err.fory_write(context, arm_ref_mode::<E>(), true, false)
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn fory_read_data(context: &mut ReadContext) -> Result<Self, Error> {
+        match context.reader.read_u8()? {
+            OK_TAG => Ok(Ok(T::fory_read(context, arm_ref_mode::<T>(), true)?)),
+            ERR_TAG => Ok(Err(E::fory_read(context, arm_ref_mode::<E>(), true)?)),
+            tag => Err(Error::invalid_data(format!("invalid Result tag: {tag}"))),
+        }
+    }
+
+    #[inline(always)]
+    fn fory_reserved_space() -> usize {
+        1 + SIZE_OF_REF_AND_TYPE + T::fory_reserved_space().max(E::fory_reserved_space())
+    }
+
+    #[inline(always)]
+    fn fory_get_type_id(_: &TypeResolver) -> Result<TypeId, Error> {
+        Ok(TypeId::RESULT)
+    }
+
+    #[inline(always)]
+    fn fory_type_id_dyn(&self, _: &TypeResolver) -> Result<TypeId, Error> {
+        Ok(TypeId::RESULT)
+    }
+
+    #[inline(always)]
+    fn fory_static_type_id() -> TypeId {
+        TypeId::RESULT
+    }
+
+    #[inline(always)]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline(always)]
+    fn fory_write_type_info(context: &mut WriteContext) -> Result<(), Error> {
+        context.writer.write_var_u32(TypeId::RESULT as u32);
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn fory_read_type_info(context: &mut ReadContext) -> Result<(), Error> {
+        read_basic_type_info::<Self>(context)
+    }
+}
+
+impl<T: ForyDefault, E> ForyDefault for Result<T, E> {
+    #[inline(always)]
+    fn fory_default() -> Self {
+        Ok(T::fory_default())
+    }
+}
@@ -16,6 +16,7 @@
 // under the License.
 
 use super::codec::{field_ref_mode, Codec};
+use crate::buffer::Reader;
 use crate::context::ReadContext;
 use crate::error::Error;
 use crate::meta::{FieldInfo, FieldType};
@@ -241,17 +242,21 @@ fn read_i64_payload(
             )),
         },
         type_id::INT8 => Ok(i64::from(context.reader.read_i8()?)),
-        type_id::INT16 => Ok(i64::from(context.reader.read_i16()?)),
-        type_id::INT32 => Ok(i64::from(context.reader.read_i32()?)),
+        type_id::INT16 => Ok(i64::from(context.read_fixed(Reader::read_i16)?)),
+        type_id::INT32 => Ok(i64::from(context.read_fixed(Reader::read_i32)?)),
         type_id::VARINT32 => Ok(i64::from(context.reader.read_var_i32()?)),
-        type_id::INT64 => context.reader.read_i64(),
+        type_id::INT64 => context.read_fixed(Reader::read_i64),
         type_id::VARINT64 => context.reader.read_var_i64(),
         type_id::TAGGED_INT64 => context.reader.read_tagged_i64(),
         type_id::UINT8 => Ok(i64::from(context.reader.read_u8()?)),
-        type_id::UINT16 => Ok(i64::from(context.reader.read_u16()?)),
-        type_id::UINT32 => Ok(i64::from(context.reader.read_u32()?)),
+        type_id::UINT16 => Ok(i64::from(context.read_fixed(Reader::read_u16)?)),
+        type_id::UINT32 => Ok(i64::from(context.read_fixed(Reader::read_u32)?)),
         type_id::VAR_UINT32 => Ok(i64::from(context.reader.read_var_u32()?)),
-        type_id::UINT64 => u64_to_i64(context.reader.read_u64()?, remote_type, local_type),
+        type_id::UINT64 => u64_to_i64(
+            context.read_fixed(Reader::read_u64)?,
+            remote_type,
+            local_type,
+        ),
         type_id::VAR_UINT64 => u64_to_i64(context.reader.read_var_u64()?, remote_type, local_type),
         type_id::TAGGED_UINT64 => {
             u64_to_i64(context.reader.read_tagged_u64()?, remote_type, local_type)
@@ -429,12 +434,12 @@ fn read_u64_payload(
             local_type,
         ),
         type_id::INT16 => signed_to_u64(
-            i64::from(context.reader.read_i16()?),
+            i64::from(context.read_fixed(Reader::read_i16)?),
             remote_type,
             local_type,
         ),
         type_id::INT32 => signed_to_u64(
-            i64::from(context.reader.read_i32()?),
+            i64::from(context.read_fixed(Reader::read_i32)?),
             remote_type,
             local_type,
         ),
@@ -443,16 +448,20 @@ fn read_u64_payload(
             remote_type,
             local_type,
         ),
-        type_id::INT64 => signed_to_u64(context.reader.read_i64()?, remote_type, local_type),
+        type_id::INT64 => signed_to_u64(
+            context.read_fixed(Reader::read_i64)?,
+            remote_type,
+            local_type,
+        ),
         type_id::VARINT64 => signed_to_u64(context.reader.read_var_i64()?, remote_type, local_type),
         type_id::TAGGED_INT64 => {
             signed_to_u64(context.reader.read_tagged_i64()?, remote_type, local_type)
         }
         type_id::UINT8 => Ok(u64::from(context.reader.read_u8()?)),
-        type_id::UINT16 => Ok(u64::from(context.reader.read_u16()?)),
-        type_id::UINT32 => Ok(u64::from(context.reader.read_u32()?)),
+        type_id::UINT16 => Ok(u64::from(context.read_fixed(Reader::read_u16)?)),
+        type_id::UINT32 => Ok(u64::from(context.read_fixed(Reader::read_u32)?)),
         type_id::VAR_UINT32 => Ok(u64::from(context.reader.read_var_u32()?)),
-        type_id::UINT64 => context.reader.read_u64(),
+        type_id::UINT64 => context.read_fixed(Reader::read_u64),
         type_id::VAR_UINT64 => context.reader.read_var_u64(),
         type_id::TAGGED_UINT64 => context.reader.read_tagged_u64(),
         _ => read_u64_cold(context, local_type, remote_type),
@@ -522,15 +531,23 @@ fn read_f32_payload(
             unsigned_integer_to_f32(value, remote_type, local_type)
         }
         type_id::FLOAT16 => {
-            let value = context.reader.read_f16()?;
+            let value = context.read_fixed(Reader::read_f16)?;
             checked_float16(value, remote_type, local_type).map(float16::to_f32)
         }
         type_id::BFLOAT16 => {
-            let value = context.reader.read_bf16()?;
+            let value = context.read_fixed(Reader::read_bf16)?;
             checked_bfloat16(value, remote_type, local_type).map(bfloat16::to_f32)
         }
-        type_id::FLOAT32 => checked_f32(context.reader.read_f32()?, remote_type, local_type),
-        type_id::FLOAT64 => f64_to_f32_exact(context.reader.read_f64()?, remote_type, local_type),
+        type_id::FLOAT32 => checked_f32(
+            context.read_fixed(Reader::read_f32)?,
+            remote_type,
+            local_type,
+        ),
+        type_id::FLOAT64 => f64_to_f32_exact(
+            context.read_fixed(Reader::read_f64)?,
+            remote_type,
+            local_type,
+        ),
         _ => read_f32_cold(context, local_type, remote_type),
     }
 }
@@ -572,17 +589,24 @@ fn read_f64_payload(
             unsigned_integer_to_f64(value, remote_type, local_type)
         }
         type_id::FLOAT16 => {
-            let value = context.reader.read_f16()?;
+            let value = context.read_fixed(Reader::read_f16)?;
             checked_float16(value, remote_type, local_type).map(|value| f64::from(value.to_f32()))
         }
         type_id::BFLOAT16 => {
-            let value = context.reader.read_bf16()?;
+            let value = context.read_fixed(Reader::read_bf16)?;
             checked_bfloat16(value, remote_type, local_type).map(|value| f64::from(value.to_f32()))
         }
-        type_id::FLOAT32 => {
-            checked_f32(context.reader.read_f32()?, remote_type, local_type).map(f64::from)
-        }
-        type_id::FLOAT64 => checked_f64(context.reader.read_f64()?, remote_type, local_type),
+        type_id::FLOAT32 => checked_f32(
+            context.read_fixed(Reader::read_f32)?,
+            remote_type,
+            local_type,
+        )
+        .map(f64::from),
+        type_id::FLOAT64 => checked_f64(
+            context.read_fixed(Reader::read_f64)?,
+            remote_type,
+            local_type,
+        ),
         _ => read_f64_cold(context, local_type, remote_type),
     }
 }
@@ -594,7 +618,11 @@ fn read_float16_payload(
     remote_type: u32,
 ) -> Result<float16, Error> {
     match remote_type {
-        type_id::FLOAT16 => checked_float16(context.reader.read_f16()?, remote_type, local_type),
+        type_id::FLOAT16 => checked_float16(
+            context.read_fixed(Reader::read_f16)?,
+            remote_type,
+            local_type,
+        ),
         _ => match remote_type {
             type_id::STRING => {
                 let value = String::fory_read_data(context)?;
@@ -619,7 +647,11 @@ fn read_bfloat16_payload(
     remote_type: u32,
 ) -> Result<bfloat16, Error> {
     match remote_type {
-        type_id::BFLOAT16 => checked_bfloat16(context.reader.read_bf16()?, remote_type, local_type),
+        type_id::BFLOAT16 => checked_bfloat16(
+            context.read_fixed(Reader::read_bf16)?,
+            remote_type,
+            local_type,
+        ),
         _ => match remote_type {
             type_id::STRING => {
                 let value = String::fory_read_data(context)?;
@@ -797,10 +829,10 @@ fn read_f64_cold(
 #[inline(always)]
 fn read_float_value(context: &mut ReadContext, remote_type: u32) -> Result<FloatValue, Error> {
     match remote_type {
-        type_id::FLOAT16 => Ok(FloatValue::F16(context.reader.read_f16()?)),
-        type_id::BFLOAT16 => Ok(FloatValue::BF16(context.reader.read_bf16()?)),
-        type_id::FLOAT32 => Ok(FloatValue::F32(context.reader.read_f32()?)),
-        type_id::FLOAT64 => Ok(FloatValue::F64(context.reader.read_f64()?)),
+        type_id::FLOAT16 => Ok(FloatValue::F16(context.read_fixed(Reader::read_f16)?)),
+        type_id::BFLOAT16 => Ok(FloatValue::BF16(context.read_fixed(Reader::read_bf16)?)),
+        type_id::FLOAT32 => Ok(FloatValue::F32(context.read_fixed(Reader::read_f32)?)),
+        type_id::FLOAT64 => Ok(FloatValue::F64(context.read_fixed(Reader::read_f64)?)),
         _ => Err(Error::invalid_data("invalid compatible scalar remote type")),
     }
 }
@@ -1093,23 +1125,31 @@ fn read_scalar_value(context: &mut ReadContext, type_id: u32) -> Result<ScalarVa
             }
         }
         type_id::INT8 => ScalarValue::Int(BigInt::from(context.reader.read_i8()?)),
-        type_id::INT16 => ScalarValue::Int(BigInt::from(context.reader.read_i16()?)),
-        type_id::INT32 => ScalarValue::Int(BigInt::from(context.reader.read_i32()?)),
+        type_id::INT16 => ScalarValue::Int(BigInt::from(context.read_fixed(Reader::read_i16)?)),
+        type_id::INT32 => ScalarValue::Int(BigInt::from(context.read_fixed(Reader::read_i32)?)),
         type_id::VARINT32 => ScalarValue::Int(BigInt::from(context.reader.read_var_i32()?)),
-        type_id::INT64 => ScalarValue::Int(BigInt::from(context.reader.read_i64()?)),
+        type_id::INT64 => ScalarValue::Int(BigInt::from(context.read_fixed(Reader::read_i64)?)),
         type_id::VARINT64 => ScalarValue::Int(BigInt::from(context.reader.read_var_i64()?)),
         type_id::TAGGED_INT64 => ScalarValue::Int(BigInt::from(context.reader.read_tagged_i64()?)),
         type_id::UINT8 => ScalarValue::Int(BigInt::from(context.reader.read_u8()?)),
-        type_id::UINT16 => ScalarValue::Int(BigInt::from(context.reader.read_u16()?)),
-        type_id::UINT32 => ScalarValue::Int(BigInt::from(context.reader.read_u32()?)),
+        type_id::UINT16 => ScalarValue::Int(BigInt::from(context.read_fixed(Reader::read_u16)?)),
+        type_id::UINT32 => ScalarValue::Int(BigInt::from(context.read_fixed(Reader::read_u32)?)),
         type_id::VAR_UINT32 => ScalarValue::Int(BigInt::from(context.reader.read_var_u32()?)),
-        type_id::UINT64 => ScalarValue::Int(BigInt::from(context.reader.read_u64()?)),
+        type_id::UINT64 => ScalarValue::Int(BigInt::from(context.read_fixed(Reader::read_u64)?)),
         type_id::VAR_UINT64 => ScalarValue::Int(BigInt::from(context.reader.read_var_u64()?)),
         type_id::TAGGED_UINT64 => ScalarValue::Int(BigInt::from(context.reader.read_tagged_u64()?)),
-        type_id::FLOAT16 => ScalarValue::Float(FloatValue::F16(context.reader.read_f16()?)),
-        type_id::BFLOAT16 => ScalarValue::Float(FloatValue::BF16(context.reader.read_bf16()?)),
-        type_id::FLOAT32 => ScalarValue::Float(FloatValue::F32(context.reader.read_f32()?)),
-        type_id::FLOAT64 => ScalarValue::Float(FloatValue::F64(context.reader.read_f64()?)),
+        type_id::FLOAT16 => {
+            ScalarValue::Float(FloatValue::F16(context.read_fixed(Reader::read_f16)?))
+        }
+        type_id::BFLOAT16 => {
+            ScalarValue::Float(FloatValue::BF16(context.read_fixed(Reader::read_bf16)?))
+        }
+        type_id::FLOAT32 => {
+            ScalarValue::Float(FloatValue::F32(context.read_fixed(Reader::read_f32)?))
+        }
+        type_id::FLOAT64 => {
+            ScalarValue::Float(FloatValue::F64(context.read_fixed(Reader::read_f64)?))
+        }
         type_id::STRING => ScalarValue::String(String::fory_read_data(context)?),
         type_id::DECIMAL => ScalarValue::Decimal(Decimal::fory_read_data(context)?),
         _ => {
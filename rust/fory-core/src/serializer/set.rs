@@ -20,16 +20,21 @@ use crate::context::WriteContext;
 use crate::error::Error;
 use crate::resolver::TypeResolver;
 use crate::serializer::collection::{
-    read_collection_data, read_collection_type_info, write_collection_data,
-    write_collection_type_info,
+    estimate_collection_size, read_collection_data, read_collection_type_info,
+    write_collection_data, write_collection_type_info,
 };
 
 use crate::serializer::{ForyDefault, Serializer};
 use crate::type_id::TypeId;
 use std::collections::{BTreeSet, HashSet};
+use std::hash::BuildHasher;
 use std::mem;
 
-impl<T: Serializer + ForyDefault + Eq + std::hash::Hash> Serializer for HashSet<T> {
+impl<T, S> Serializer for HashSet<T, S>
+where
+    T: Serializer + ForyDefault + Eq + std::hash::Hash,
+    S: BuildHasher + Default + 'static,
+{
     fn fory_write_data(&self, context: &mut WriteContext) -> Result<(), Error> {
         write_collection_data(self, context, false)
     }
@@ -58,6 +63,10 @@ impl<T: Serializer + ForyDefault + Eq + std::hash::Hash> Serializer for HashSet<
         mem::size_of::<i32>()
     }
 
+    fn fory_estimate_size(&self) -> usize {
+        estimate_collection_size::<T>(self.len())
+    }
+
     fn fory_get_type_id(_: &TypeResolver) -> Result<TypeId, Error> {
         Ok(TypeId::SET)
     }
@@ -78,9 +87,9 @@ impl<T: Serializer + ForyDefault + Eq + std::hash::Hash> Serializer for HashSet<
     }
 }
 
-impl<T> ForyDefault for HashSet<T> {
+impl<T, S: Default> ForyDefault for HashSet<T, S> {
     fn fory_default() -> Self {
-        HashSet::new()
+        HashSet::default()
     }
 }
 
@@ -113,6 +122,10 @@ impl<T: Serializer + ForyDefault + Ord> Serializer for BTreeSet<T> {
         mem::size_of::<i32>()
     }
 
+    fn fory_estimate_size(&self) -> usize {
+        estimate_collection_size::<T>(self.len())
+    }
+
     fn fory_get_type_id(_: &TypeResolver) -> Result<TypeId, Error> {
         Ok(TypeId::SET)
     }
@@ -20,8 +20,8 @@ use crate::ensure;
 use crate::error::Error;
 use crate::meta::FieldType;
 use crate::serializer::collection::{DECL_ELEMENT_TYPE, HAS_NULL, IS_SAME_TYPE};
-use crate::serializer::util;
 use crate::serializer::Serializer;
+use crate::serializer::{struct_, util};
 use crate::type_id as types;
 use crate::types::{Date, Duration, Timestamp};
 use crate::util::ENABLE_FORY_DEBUG_OUTPUT;
@@ -38,7 +38,7 @@ pub fn skip_field_value(
 }
 
 #[inline(always)]
-fn unknown_field_type() -> FieldType {
+pub(crate) fn unknown_field_type() -> FieldType {
     FieldType::new(types::UNKNOWN, true, Vec::new())
 }
 
@@ -57,6 +57,19 @@ pub fn skip_any_value(context: &mut ReadContext, read_ref_flag: bool) -> Result<
         // RefValue (0) or NotNullValue (-1) means we need to read the actual object
     }
 
+    let (field_type, type_info_opt) = read_any_type(context)?;
+    // Don't read ref flag again in skip_value since we already handled it.
+    // Pass type_info so skip_struct doesn't try to read type_id/meta_index again.
+    skip_value(context, &field_type, false, false, &type_info_opt)
+}
+
+/// Reads the type info that precedes a value of unknown static type.
+///
+/// Returns the field type to read the value as, plus the registered type info for struct,
+/// enum, ext and union values.
+pub(crate) fn read_any_type(
+    context: &mut ReadContext,
+) -> Result<(FieldType, Option<Rc<crate::TypeInfo>>), Error> {
     // Read type_id first
     let type_id = context.reader.read_u8()? as u32;
     let internal_id = type_id;
@@ -67,14 +80,9 @@ pub fn skip_any_value(context: &mut ReadContext, read_ref_flag: bool) -> Result<
         None
     };
 
-    // NONE type has no data - return early
-    if internal_id == types::NONE {
-        return Ok(());
-    }
-
     // For struct-like types, also read meta_index to get type_info
     // This is critical for polymorphic collections where elements are struct types.
-    let (field_type, type_info_opt) = match internal_id {
+    let any_type = match internal_id {
         types::LIST | types::SET => (
             FieldType::new(type_id, true, vec![unknown_field_type()]),
             None,
@@ -104,7 +112,7 @@ pub fn skip_any_value(context: &mut ReadContext, read_ref_flag: bool) -> Result<
                 let type_info = context
                     .get_type_resolver()
                     .get_type_info_by_meta_string_name(rc_namespace, rc_type_name)
-                    .ok_or_else(|| crate::Error::type_error("Name harness not found"))?;
+                    .ok_or_else(|| crate::Error::unknown_type_id("Name harness not found"))?;
                 (FieldType::new(type_id, true, Vec::new()), Some(type_info))
             }
         }
@@ -128,9 +136,7 @@ pub fn skip_any_value(context: &mut ReadContext, read_ref_flag: bool) -> Result<
             )
         }
     };
-    // Don't read ref flag again in skip_value since we already handled it.
-    // Pass type_info so skip_struct doesn't try to read type_id/meta_index again.
-    skip_value(context, &field_type, false, false, &type_info_opt)
+    Ok(any_type)
 }
 
 fn skip_collection(context: &mut ReadContext, field_type: &FieldType) -> Result<(), Error> {
@@ -299,6 +305,18 @@ fn skip_struct(
     context: &mut ReadContext,
     type_id_num: u32,
     type_info: &Option<Rc<crate::TypeInfo>>,
+) -> Result<(), Error> {
+    let sparse = context.is_sparse_options();
+    skip_struct_fields(context, type_id_num, type_info, sparse)
+}
+
+/// Skips struct-like data. `sparse` is false for enum variants, which never carry a
+/// presence bitmap.
+fn skip_struct_fields(
+    context: &mut ReadContext,
+    type_id_num: u32,
+    type_info: &Option<Rc<crate::TypeInfo>>,
+    sparse: bool,
 ) -> Result<(), Error> {
     let type_info_rc: Option<Rc<crate::TypeInfo>>;
     let type_info_value = if type_info.is_none() {
@@ -324,8 +342,16 @@ fn skip_struct(
         );
     }
     let field_infos = type_meta.get_field_infos().to_vec();
+    let presence = if sparse {
+        struct_::read_field_presence(context, &field_infos)?
+    } else {
+        struct_::FieldPresence::all()
+    };
     context.inc_depth()?;
-    for field_info in field_infos.iter() {
+    for (index, field_info) in field_infos.iter().enumerate() {
+        if !presence.is_present(index) {
+            continue;
+        }
         if ENABLE_FORY_DEBUG_OUTPUT {
             eprintln!(
                 "[skip_struct] field: {:?}, type_id: {}, internal_id: {}",
@@ -733,6 +759,63 @@ fn skip_value(
             <Vec<isize> as Serializer>::fory_read_data(context)?;
         }
 
+        // ============ CHAR (TypeId = 72) ============
+        types::CHAR => {
+            <char as Serializer>::fory_read_data(context)?;
+        }
+
+        // ============ UUID (TypeId = 73) ============
+        types::UUID => {
+            context.reader.skip(16)?;
+        }
+
+        // ============ IPV4_ADDR (TypeId = 74) ============
+        types::IPV4_ADDR => {
+            <std::net::Ipv4Addr as Serializer>::fory_read_data(context)?;
+        }
+
+        // ============ IPV6_ADDR (TypeId = 75) ============
+        types::IPV6_ADDR => {
+            <std::net::Ipv6Addr as Serializer>::fory_read_data(context)?;
+        }
+
+        // ============ IP_ADDR (TypeId = 76) ============
+        types::IP_ADDR => {
+            <std::net::IpAddr as Serializer>::fory_read_data(context)?;
+        }
+
+        // ============ SOCKET_ADDR (TypeId = 77) ============
+        types::SOCKET_ADDR => {
+            <std::net::SocketAddr as Serializer>::fory_read_data(context)?;
+        }
+
+        // ============ RESULT (TypeId = 78) ============
+        types::RESULT => {
+            // The arm carries its own ref flag and type info.
+            context.reader.read_u8()?;
+            skip_any_value(context, true)?;
+        }
+
+        // ============ RANGE / RANGE_INCLUSIVE (TypeId = 79, 80) ============
+        types::RANGE | types::RANGE_INCLUSIVE => {
+            // Both bounds carry their own ref flag and type info.
+            skip_any_value(context, true)?;
+            skip_any_value(context, true)?;
+        }
+
+        // ============ ENCRYPTED (TypeId = 81) ============
+        types::ENCRYPTED => {
+            let len = context.reader.read_var_u32()?;
+            context.reader.skip(len as usize)?;
+        }
+
+        // ============ OS_STRING (TypeId = 82) ============
+        types::OS_STRING => {
+            context.reader.read_u8()?;
+            let len = context.reader.read_var_u32()?;
+            context.reader.skip(len as usize)?;
+        }
+
         _ => {
             return Err(Error::type_error(format!(
                 "Unimplemented type id: {}",
@@ -773,13 +856,13 @@ pub fn skip_enum_variant(
             // For named variants, we need the type_info which should have been read already
             if type_info.is_some() {
                 let type_id = type_info.as_ref().unwrap().get_type_id() as u32;
-                skip_struct(context, type_id, type_info)
+                skip_struct_fields(context, type_id, type_info, false)
             } else {
                 // If no type_info provided, read it inline using streaming protocol
                 let type_info_rc = context.read_type_meta()?;
                 let type_id = type_info_rc.get_type_id() as u32;
                 let type_info_opt = Some(type_info_rc);
-                skip_struct(context, type_id, &type_info_opt)
+                skip_struct_fields(context, type_id, &type_info_opt, false)
             }
         }
         _ => {
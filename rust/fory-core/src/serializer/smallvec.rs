@@ -0,0 +1,105 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! `smallvec::SmallVec` uses the `LIST` wire format, the same as `VecDeque` and `Vec`
+//! struct fields, so a `SmallVec<[T; N]>` field reads payloads written from `Vec<T>`
+//! fields and vice versa. Reads collect into the `SmallVec`, which stays inline when the
+//! payload has at most `N` elements.
+
+use crate::context::{ReadContext, WriteContext};
+use crate::error::Error;
+use crate::resolver::TypeResolver;
+use crate::serializer::collection::{
+    estimate_collection_size, read_collection_data, read_collection_type_info,
+    write_collection_data, write_collection_type_info,
+};
+use crate::serializer::{ForyDefault, Serializer};
+use crate::type_id::TypeId;
+use ::smallvec::{Array, SmallVec};
+use std::mem;
+
+impl<A> Serializer for SmallVec<A>
+where
+    A: Array + 'static,
+    A::Item: Serializer + ForyDefault,
+{
+    #[inline(always)]
+    fn fory_write_data(&self, context: &mut WriteContext) -> Result<(), Error> {
+        write_collection_data(self, context, false)
+    }
+
+    #[inline(always)]
+    fn fory_write_data_generic(
+        &self,
+        context: &mut WriteContext,
+        has_generics: bool,
+    ) -> Result<(), Error> {
+        write_collection_data(self, context, has_generics)
+    }
+
+    #[inline(always)]
+    fn fory_write_type_info(context: &mut WriteContext) -> Result<(), Error> {
+        write_collection_type_info(context, TypeId::LIST as u32)
+    }
+
+    #[inline(always)]
+    fn fory_read_data(context: &mut ReadContext) -> Result<Self, Error> {
+        read_collection_data(context)
+    }
+
+    #[inline(always)]
+    fn fory_read_type_info(context: &mut ReadContext) -> Result<(), Error> {
+        read_collection_type_info(context, TypeId::LIST as u32)
+    }
+
+    #[inline(always)]
+    fn fory_reserved_space() -> usize {
+        mem::size_of::<u32>()
+    }
+
+    #[inline(always)]
+    fn fory_estimate_size(&self) -> usize {
+        estimate_collection_size::<A::Item>(self.len())
+    }
+
+    #[inline(always)]
+    fn fory_get_type_id(_: &TypeResolver) -> Result<TypeId, Error> {
+        Ok(TypeId::LIST)
+    }
+
+    #[inline(always)]
+    fn fory_type_id_dyn(&self, _: &TypeResolver) -> Result<TypeId, Error> {
+        Ok(TypeId::LIST)
+    }
+
+    #[inline(always)]
+    fn fory_static_type_id() -> TypeId {
+        TypeId::LIST
+    }
+
+    #[inline(always)]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl<A: Array> ForyDefault for SmallVec<A> {
+    #[inline(always)]
+    fn fory_default() -> Self {
+        SmallVec::new()
+    }
+}
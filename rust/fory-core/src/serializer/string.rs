@@ -29,15 +29,37 @@ enum StrEncoding {
     Latin1 = 0,
     Utf16 = 1,
     Utf8 = 2,
+    /// Not an encoding: the header holds the index of an earlier string in the payload
+    /// instead of a length. Only written when `string_ref` is enabled.
+    Ref = 3,
+}
+
+/// Size hint for `s`: the length header plus the UTF-8 bytes.
+#[inline(always)]
+pub(super) fn estimate_str_size(s: &str) -> usize {
+    mem::size_of::<u64>() + s.len()
+}
+
+/// Writes `s` with the same layout as a `String` value.
+#[inline(always)]
+pub(super) fn write_str_data(s: &str, context: &mut WriteContext) -> Result<(), Error> {
+    if context.is_string_ref() && !s.is_empty() {
+        if let Some(index) = context.intern_string(s) {
+            let bitor = (index as u64) << 2 | StrEncoding::Ref as u64;
+            context.writer.write_var_u36_small(bitor);
+            return Ok(());
+        }
+    }
+    let bitor = (s.len() as i32 as u64) << 2 | StrEncoding::Utf8 as u64;
+    context.writer.write_var_u36_small(bitor);
+    context.writer.write_utf8_string(s);
+    Ok(())
 }
 
 impl Serializer for String {
     #[inline(always)]
     fn fory_write_data(&self, context: &mut WriteContext) -> Result<(), Error> {
-        let bitor = (self.len() as i32 as u64) << 2 | StrEncoding::Utf8 as u64;
-        context.writer.write_var_u36_small(bitor);
-        context.writer.write_utf8_string(self);
-        Ok(())
+        write_str_data(self, context)
     }
 
     #[inline(always)]
@@ -57,6 +79,7 @@ impl Serializer for String {
                     context.reader.read_utf8_string_unchecked(len)
                 }
             }
+            3 => return context.get_string_ref(len as usize).cloned(),
             _ => {
                 return Err(Error::encoding_error(format!(
                     "wrong encoding value: {}",
@@ -64,6 +87,9 @@ impl Serializer for String {
                 )))
             }
         }?;
+        if context.is_string_ref() && !s.is_empty() {
+            context.record_string(&s);
+        }
         Ok(s)
     }
     #[inline]
@@ -83,6 +109,11 @@ impl Serializer for String {
         mem::size_of::<i32>()
     }
 
+    #[inline(always)]
+    fn fory_estimate_size(&self) -> usize {
+        estimate_str_size(self)
+    }
+
     #[inline(always)]
     fn fory_get_type_id(_: &TypeResolver) -> Result<TypeId, Error> {
         Ok(TypeId::STRING)
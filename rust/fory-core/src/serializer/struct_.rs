@@ -18,11 +18,13 @@
 use crate::context::{ReadContext, WriteContext};
 use crate::ensure;
 use crate::error::Error;
-use crate::resolver::{RefFlag, RefMode};
-use crate::serializer::{Serializer, StructSerializer};
-use crate::type_id::TypeId;
+use crate::meta::FieldInfo;
+use crate::resolver::{RefFlag, RefMode, TypeInfo};
+use crate::serializer::{ForyDefault, Serializer, StructSerializer};
+use crate::type_id::{self, TypeId};
 use crate::util::ENABLE_FORY_DEBUG_OUTPUT;
 use std::any::Any;
+use std::rc::Rc;
 
 #[inline(always)]
 pub fn actual_type_id(_type_id: u32, register_by_name: bool, compatible: bool) -> u32 {
@@ -52,9 +54,27 @@ pub fn write_type_info_fast<T: StructSerializer>(context: &mut WriteContext) ->
     context.write_struct_type_info::<T>()
 }
 
+/// Reads the type info in front of a struct value, rejecting payloads whose wire type id is
+/// not one of the struct kinds instead of decoding them as an empty struct.
+#[inline(always)]
+pub fn read_struct_type_info(context: &mut ReadContext) -> Result<Rc<TypeInfo>, Error> {
+    let remote_type_id = context.reader.peek_u8()? as u32;
+    ensure!(
+        matches!(
+            remote_type_id,
+            type_id::STRUCT
+                | type_id::COMPATIBLE_STRUCT
+                | type_id::NAMED_STRUCT
+                | type_id::NAMED_COMPATIBLE_STRUCT
+        ),
+        Error::type_mismatch(TypeId::STRUCT as u32, remote_type_id)
+    );
+    context.read_any_type_info()
+}
+
 #[inline(always)]
 pub fn read_type_info<T: Serializer>(context: &mut ReadContext) -> Result<(), Error> {
-    context.read_any_type_info()?;
+    read_struct_type_info(context)?;
     Ok(())
 }
 
@@ -79,7 +99,12 @@ pub fn read_type_info_fast<T: StructSerializer>(context: &mut ReadContext) -> Re
     let local_user_type_id = context
         .get_type_resolver()
         .get_user_type_id_by_index(&std::any::TypeId::of::<T>(), T::fory_type_index())?;
-    if remote_user_type_id != local_user_type_id {
+    if remote_user_type_id != local_user_type_id
+        && context
+            .get_type_resolver()
+            .canonical_user_type_id(remote_user_type_id)
+            != local_user_type_id
+    {
         return Err(Error::type_error(format!(
             "User type id mismatch: local {} vs remote {}",
             local_user_type_id, remote_user_type_id
@@ -113,6 +138,165 @@ pub fn write<T: Serializer>(
     this.fory_write_data(context)
 }
 
+/// Number of leading struct fields that `sparse_options` can leave out of the payload.
+/// Later fields are always written, so the presence bitmap fits in a `u64`.
+pub const MAX_SPARSE_FIELDS: usize = 64;
+
+/// Writes the presence bitmap that precedes the fields of a struct when `sparse_options` is
+/// on.
+///
+/// Bit `i` of `absent` is set when the field at index `i` in TypeMeta order is `None`, and
+/// the caller leaves that field out of the payload.
+#[inline(always)]
+pub fn write_field_presence(context: &mut WriteContext, absent: u64) {
+    context.writer.write_var_u64(absent);
+}
+
+/// Which fields of a struct value are in the payload.
+pub struct FieldPresence {
+    absent: u64,
+}
+
+impl FieldPresence {
+    /// Presence of a payload without a bitmap, where every field is written.
+    #[inline(always)]
+    pub fn all() -> Self {
+        FieldPresence { absent: 0 }
+    }
+
+    #[inline(always)]
+    pub fn is_present(&self, index: usize) -> bool {
+        index >= MAX_SPARSE_FIELDS || self.absent & (1 << index) == 0
+    }
+}
+
+/// Which fields of a `#[fory(track_presence)]` struct the writer's schema contained.
+///
+/// The struct declares one field of this type, which the derive leaves out of the payload
+/// and fills when a compatible read meets a remote schema that differs from the local one.
+/// A field the writer did not know about reads as its default, and this is how to tell it
+/// apart from a default the writer sent. Values built locally, or read from a payload with
+/// the local schema, report every field as present.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PresentFields {
+    absent: Vec<&'static str>,
+}
+
+impl PresentFields {
+    /// Marks the entries of `names`, one per local field in TypeMeta order, that no field
+    /// of the remote schema `fields` was matched to as absent.
+    pub fn from_remote(names: &[&'static str], fields: &[FieldInfo]) -> Self {
+        let mut matched = vec![false; names.len()];
+        for field in fields {
+            if field.field_id >= 0 {
+                if let Some(matched) = matched.get_mut(field.field_id as usize / 2) {
+                    *matched = true;
+                }
+            }
+        }
+        let absent = names
+            .iter()
+            .zip(matched)
+            .filter(|(_, matched)| !matched)
+            .map(|(name, _)| *name)
+            .collect();
+        PresentFields { absent }
+    }
+
+    /// Returns whether the payload carried `field`, named as in the Rust struct.
+    pub fn contains(&self, field: &str) -> bool {
+        !self.absent.contains(&field)
+    }
+}
+
+impl ForyDefault for PresentFields {
+    fn fory_default() -> Self {
+        PresentFields::default()
+    }
+}
+
+/// Returns the dispatch id of each remote field in a projected struct read.
+///
+/// A remote field keeps its matched id when the local field it matches is named in
+/// `projection`; every other field gets `-1`, so it is skipped over and its local field
+/// keeps its default. Fields of a payload written with the local schema carry no matched
+/// ids, so `same_schema` dispatches them by position. Returns `None` when the read is not
+/// projected.
+pub fn project_fields(
+    projection: Option<Vec<String>>,
+    local_fields: &[FieldInfo],
+    fields: &[FieldInfo],
+    same_schema: bool,
+) -> Result<Option<Vec<i16>>, Error> {
+    let Some(projection) = projection else {
+        return Ok(None);
+    };
+    for name in &projection {
+        ensure!(
+            local_fields.iter().any(|field| &field.field_name == name),
+            Error::type_error(format!("projected field '{name}' is not a struct field"))
+        );
+    }
+    let is_projected = |index: usize| {
+        local_fields
+            .get(index)
+            .is_some_and(|local| projection.contains(&local.field_name))
+    };
+    let ids = fields
+        .iter()
+        .enumerate()
+        .map(|(index, field)| {
+            let (local_index, id) = if same_schema {
+                (index, (index * 2) as i16)
+            } else {
+                (field.field_id.max(0) as usize / 2, field.field_id)
+            };
+            if id >= 0 && is_projected(local_index) {
+                id
+            } else {
+                -1
+            }
+        })
+        .collect();
+    Ok(Some(ids))
+}
+
+/// Reads the presence bitmap written by [`write_field_presence`] for `fields` when the
+/// payload is sparse. Only nullable fields can be absent.
+pub fn read_field_presence(
+    context: &mut ReadContext,
+    fields: &[FieldInfo],
+) -> Result<FieldPresence, Error> {
+    if !context.is_sparse_options() {
+        return Ok(FieldPresence::all());
+    }
+    let absent = context.reader.read_var_u64()?;
+    let mut unknown = absent;
+    for (index, field) in fields.iter().take(MAX_SPARSE_FIELDS).enumerate() {
+        if field.field_type.nullable {
+            unknown &= !(1 << index);
+        }
+    }
+    ensure!(
+        unknown == 0,
+        Error::invalid_data(format!(
+            "presence bitmap {absent:#x} marks fields that can't be absent"
+        ))
+    );
+    Ok(FieldPresence { absent })
+}
+
+/// Reads the presence bitmap of a payload written with the local schema, whose fields the
+/// caller only checks for presence when they can be absent.
+#[inline(always)]
+pub fn read_local_field_presence(context: &mut ReadContext) -> Result<FieldPresence, Error> {
+    if !context.is_sparse_options() {
+        return Ok(FieldPresence::all());
+    }
+    let absent = context.reader.read_var_u64()?;
+    Ok(FieldPresence { absent })
+}
+
 pub type BeforeWriteFieldFunc =
     fn(struct_name: &str, field_name: &str, field_value: &dyn Any, context: &mut WriteContext);
 pub type AfterWriteFieldFunc =
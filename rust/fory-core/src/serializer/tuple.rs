@@ -21,6 +21,7 @@ use crate::resolver::RefMode;
 use crate::resolver::TypeResolver;
 use crate::serializer::collection::{read_collection_type_info, write_collection_type_info};
 use crate::serializer::skip::skip_any_value;
+use crate::serializer::util::read_basic_type_info;
 use crate::serializer::{ForyDefault, Serializer};
 use crate::type_id::TypeId;
 use std::mem;
@@ -77,6 +78,18 @@ impl Serializer for () {
     fn as_any(&self) -> &dyn std::any::Any {
         self
     }
+
+    // NONE has no registered type info, so write and check the bare id.
+    #[inline(always)]
+    fn fory_write_type_info(context: &mut WriteContext) -> Result<(), Error> {
+        context.writer.write_u8(TypeId::NONE as u8);
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn fory_read_type_info(context: &mut ReadContext) -> Result<(), Error> {
+        read_basic_type_info::<Self>(context)
+    }
 }
 
 impl ForyDefault for () {
@@ -145,7 +158,9 @@ impl<T0: Serializer + ForyDefault> Serializer for (T0,) {
         write_collection_type_info(context, TypeId::LIST as u32)
     }
 
-    #[inline(always)]
+    // See the note in `impl_tuple_serializer`.
+    #[cfg_attr(debug_assertions, inline)]
+    #[cfg_attr(not(debug_assertions), inline(always))]
     fn fory_read_data(context: &mut ReadContext) -> Result<Self, Error> {
         if !context.is_compatible() && !context.is_xlang() {
             // Non-compatible mode: read elements directly
@@ -178,7 +193,7 @@ impl<T0: Serializer + ForyDefault> Serializer for (T0,) {
 
     #[inline(always)]
     fn fory_reserved_space() -> usize {
-        mem::size_of::<u32>()
+        mem::size_of::<u32>() + T0::fory_reserved_space()
     }
 
     #[inline(always)]
@@ -392,7 +407,10 @@ macro_rules! impl_tuple_serializer {
                 write_collection_type_info(context, TypeId::LIST as u32)
             }
 
-            #[inline(always)]
+            // Unoptimized builds don't share stack slots, so forcing every nested tuple read into
+            // the enclosing struct reader gives derived compatible readers frames of over 1 MiB.
+            #[cfg_attr(debug_assertions, inline)]
+            #[cfg_attr(not(debug_assertions), inline(always))]
             fn fory_read_data(context: &mut ReadContext) -> Result<Self, Error> {
                 if !context.is_compatible() && !context.is_xlang() {
                     // Non-compatible mode: read elements directly
@@ -446,7 +464,8 @@ macro_rules! impl_tuple_serializer {
 
             #[inline(always)]
             fn fory_reserved_space() -> usize {
-                mem::size_of::<u32>() // Size for length
+                // Size for length plus the elements
+                mem::size_of::<u32>() + $T0::fory_reserved_space() $(+ $T::fory_reserved_space())*
             }
 
             #[inline(always)]
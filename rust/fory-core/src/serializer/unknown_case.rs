@@ -15,6 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use crate::buffer::Writer;
 use crate::context::{ReadContext, WriteContext};
 use crate::error::Error;
 use crate::resolver::{RefFlag, RefMode};
@@ -58,18 +59,18 @@ fn write_typed_payload(context: &mut WriteContext, unknown: &UnknownCase) -> Res
         type_id::INT8 => context
             .writer
             .write_i8(*unknown.downcast_ref::<i8>().unwrap()),
-        type_id::INT16 => context
-            .writer
-            .write_i16(*unknown.downcast_ref::<i16>().unwrap()),
-        type_id::INT32 => context
-            .writer
-            .write_i32(*unknown.downcast_ref::<i32>().unwrap()),
+        type_id::INT16 => {
+            context.write_fixed(Writer::write_i16, *unknown.downcast_ref::<i16>().unwrap())
+        }
+        type_id::INT32 => {
+            context.write_fixed(Writer::write_i32, *unknown.downcast_ref::<i32>().unwrap())
+        }
         type_id::VARINT32 => context
             .writer
             .write_var_i32(*unknown.downcast_ref::<i32>().unwrap()),
-        type_id::INT64 => context
-            .writer
-            .write_i64(*unknown.downcast_ref::<i64>().unwrap()),
+        type_id::INT64 => {
+            context.write_fixed(Writer::write_i64, *unknown.downcast_ref::<i64>().unwrap())
+        }
         type_id::VARINT64 => context
             .writer
             .write_var_i64(*unknown.downcast_ref::<i64>().unwrap()),
@@ -79,18 +80,18 @@ fn write_typed_payload(context: &mut WriteContext, unknown: &UnknownCase) -> Res
         type_id::UINT8 => context
             .writer
             .write_u8(*unknown.downcast_ref::<u8>().unwrap()),
-        type_id::UINT16 => context
-            .writer
-            .write_u16(*unknown.downcast_ref::<u16>().unwrap()),
-        type_id::UINT32 => context
-            .writer
-            .write_u32(*unknown.downcast_ref::<u32>().unwrap()),
+        type_id::UINT16 => {
+            context.write_fixed(Writer::write_u16, *unknown.downcast_ref::<u16>().unwrap())
+        }
+        type_id::UINT32 => {
+            context.write_fixed(Writer::write_u32, *unknown.downcast_ref::<u32>().unwrap())
+        }
         type_id::VAR_UINT32 => context
             .writer
             .write_var_u32(*unknown.downcast_ref::<u32>().unwrap()),
-        type_id::UINT64 => context
-            .writer
-            .write_u64(*unknown.downcast_ref::<u64>().unwrap()),
+        type_id::UINT64 => {
+            context.write_fixed(Writer::write_u64, *unknown.downcast_ref::<u64>().unwrap())
+        }
         type_id::VAR_UINT64 => context
             .writer
             .write_var_u64(*unknown.downcast_ref::<u64>().unwrap()),
@@ -26,17 +26,33 @@ use crate::type_id::TypeId;
 
 // Macro for xlang-compatible unsigned types (u8, u16, u32, u64)
 macro_rules! impl_xlang_unsigned_num_serializer {
+    (fixed $ty:ty, $writer:expr, $reader:expr, $field_type:expr) => {
+        impl_xlang_unsigned_num_serializer!(
+            @impl $ty,
+            |context: &mut WriteContext, value| context.write_fixed($writer, value),
+            |context: &mut ReadContext| context.read_fixed($reader),
+            $field_type
+        );
+    };
     ($ty:ty, $writer:expr, $reader:expr, $field_type:expr) => {
+        impl_xlang_unsigned_num_serializer!(
+            @impl $ty,
+            |context: &mut WriteContext, value| $writer(&mut context.writer, value),
+            |context: &mut ReadContext| $reader(&mut context.reader),
+            $field_type
+        );
+    };
+    (@impl $ty:ty, $writer:expr, $reader:expr, $field_type:expr) => {
         impl Serializer for $ty {
             #[inline(always)]
             fn fory_write_data(&self, context: &mut WriteContext) -> Result<(), Error> {
-                $writer(&mut context.writer, *self);
+                ($writer)(context, *self);
                 Ok(())
             }
 
             #[inline(always)]
             fn fory_read_data(context: &mut ReadContext) -> Result<Self, Error> {
-                $reader(&mut context.reader)
+                ($reader)(context)
             }
             #[inline]
             fn fory_read_data_as_send_sync_any(
@@ -97,7 +113,23 @@ macro_rules! impl_xlang_unsigned_num_serializer {
 
 // Macro for Rust-specific unsigned types (u128, usize) - not supported in xlang mode
 macro_rules! impl_rust_unsigned_num_serializer {
+    (fixed $ty:ty, $writer:expr, $reader:expr, $field_type:expr) => {
+        impl_rust_unsigned_num_serializer!(
+            @impl $ty,
+            |context: &mut WriteContext, value| context.write_fixed($writer, value),
+            |context: &mut ReadContext| context.read_fixed($reader),
+            $field_type
+        );
+    };
     ($ty:ty, $writer:expr, $reader:expr, $field_type:expr) => {
+        impl_rust_unsigned_num_serializer!(
+            @impl $ty,
+            |context: &mut WriteContext, value| $writer(&mut context.writer, value),
+            |context: &mut ReadContext| $reader(&mut context.reader),
+            $field_type
+        );
+    };
+    (@impl $ty:ty, $writer:expr, $reader:expr, $field_type:expr) => {
         impl Serializer for $ty {
             #[inline(always)]
             fn fory_write_data(&self, context: &mut WriteContext) -> Result<(), Error> {
@@ -107,13 +139,13 @@ macro_rules! impl_rust_unsigned_num_serializer {
                         " is not supported in cross-language mode"
                     )));
                 }
-                $writer(&mut context.writer, *self);
+                ($writer)(context, *self);
                 Ok(())
             }
 
             #[inline(always)]
             fn fory_read_data(context: &mut ReadContext) -> Result<Self, Error> {
-                $reader(&mut context.reader)
+                ($reader)(context)
             }
             #[inline]
             fn fory_read_data_as_send_sync_any(
@@ -174,7 +206,7 @@ macro_rules! impl_rust_unsigned_num_serializer {
 
 // xlang-compatible unsigned types
 impl_xlang_unsigned_num_serializer!(u8, Writer::write_u8, Reader::read_u8, TypeId::UINT8);
-impl_xlang_unsigned_num_serializer!(u16, Writer::write_u16, Reader::read_u16, TypeId::UINT16);
+impl_xlang_unsigned_num_serializer!(fixed u16, Writer::write_u16, Reader::read_u16, TypeId::UINT16);
 impl_xlang_unsigned_num_serializer!(
     u32,
     Writer::write_var_u32,
@@ -189,7 +221,7 @@ impl_xlang_unsigned_num_serializer!(
 );
 
 // Rust-specific unsigned types (not supported in xlang mode)
-impl_rust_unsigned_num_serializer!(u128, Writer::write_u128, Reader::read_u128, TypeId::U128);
+impl_rust_unsigned_num_serializer!(fixed u128, Writer::write_u128, Reader::read_u128, TypeId::U128);
 impl_rust_unsigned_num_serializer!(
     usize,
     Writer::write_usize,
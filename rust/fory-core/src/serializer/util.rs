@@ -18,7 +18,8 @@
 use crate::context::{ReadContext, WriteContext};
 use crate::ensure;
 use crate::error::Error;
-use crate::serializer::Serializer;
+use crate::resolver::{RefFlag, RefMode};
+use crate::serializer::{ForyDefault, Serializer};
 use crate::type_id::TypeId;
 use crate::type_id::{is_user_type, ENUM, NAMED_ENUM, NAMED_UNION, TYPED_UNION, UNION, UNKNOWN};
 
@@ -34,6 +35,30 @@ pub(crate) fn read_basic_type_info<T: Serializer>(context: &mut ReadContext) ->
     Ok(())
 }
 
+/// In-place counterpart of the default [`Serializer::fory_read`] fast path.
+///
+/// Types that keep the default `fory_read` but override `fory_read_data_into` use this
+/// to implement `fory_read_into` without duplicating the ref flag handling.
+#[inline(always)]
+pub(crate) fn read_into_basic<T: Serializer + ForyDefault>(
+    value: &mut T,
+    context: &mut ReadContext,
+    ref_mode: RefMode,
+    read_type_info: bool,
+) -> Result<(), Error> {
+    if ref_mode != RefMode::None {
+        let ref_flag = context.reader.read_i8()?;
+        if ref_flag == RefFlag::Null as i8 {
+            *value = T::fory_default();
+            return Ok(());
+        }
+    }
+    if read_type_info {
+        T::fory_read_type_info(context)?;
+    }
+    value.fory_read_data_into(context)
+}
+
 /// Returns whether a schema-known struct field value carries inline type information.
 ///
 /// Compatible/xlang struct field metadata describes the schema kind, but dynamic fields and
@@ -0,0 +1,86 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! `Wrapping<T>` is written as its inner value, so wrapping integers interoperate with plain
+//! integer fields and `Vec<Wrapping<u32>>` uses the same primitive array format as `Vec<u32>`.
+
+use crate::context::ReadContext;
+use crate::context::WriteContext;
+use crate::error::Error;
+use crate::resolver::TypeResolver;
+use crate::serializer::{ForyDefault, Serializer};
+use crate::type_id::TypeId;
+use std::num::Wrapping;
+
+impl<T: Serializer + ForyDefault> Serializer for Wrapping<T> {
+    #[inline(always)]
+    fn fory_write_data(&self, context: &mut WriteContext) -> Result<(), Error> {
+        self.0.fory_write_data(context)
+    }
+
+    #[inline(always)]
+    fn fory_read_data(context: &mut ReadContext) -> Result<Self, Error> {
+        T::fory_read_data(context).map(Wrapping)
+    }
+
+    #[inline(always)]
+    fn fory_reserved_space() -> usize {
+        T::fory_reserved_space()
+    }
+
+    #[inline(always)]
+    fn fory_estimate_size(&self) -> usize {
+        self.0.fory_estimate_size()
+    }
+
+    #[inline(always)]
+    fn fory_get_type_id(type_resolver: &TypeResolver) -> Result<TypeId, Error> {
+        T::fory_get_type_id(type_resolver)
+    }
+
+    #[inline(always)]
+    fn fory_type_id_dyn(&self, type_resolver: &TypeResolver) -> Result<TypeId, Error> {
+        T::fory_get_type_id(type_resolver)
+    }
+
+    #[inline(always)]
+    fn fory_static_type_id() -> TypeId {
+        T::fory_static_type_id()
+    }
+
+    #[inline(always)]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline(always)]
+    fn fory_write_type_info(context: &mut WriteContext) -> Result<(), Error> {
+        T::fory_write_type_info(context)
+    }
+
+    #[inline(always)]
+    fn fory_read_type_info(context: &mut ReadContext) -> Result<(), Error> {
+        T::fory_read_type_info(context)
+    }
+}
+
+impl<T: ForyDefault> ForyDefault for Wrapping<T> {
+    #[inline(always)]
+    fn fory_default() -> Self {
+        Wrapping(T::fory_default())
+    }
+}
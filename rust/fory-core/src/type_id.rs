@@ -99,8 +99,24 @@ pub enum TypeId {
     INT128_ARRAY = 69,
     USIZE_ARRAY = 70,
     ISIZE_ARRAY = 71,
+    CHAR = 72,
+    UUID = 73,
+    IPV4_ADDR = 74,
+    IPV6_ADDR = 75,
+    IP_ADDR = 76,
+    SOCKET_ADDR = 77,
+    // Rust native Result: a one-byte tag (0 = Ok, 1 = Err) followed by the arm value.
+    RESULT = 78,
+    // Rust native Range and RangeInclusive: the start bound followed by the end bound.
+    RANGE = 79,
+    RANGE_INCLUSIVE = 80,
+    // Rust native #[fory(encrypt)] field: a var_uint32 length followed by the AES-GCM
+    // ciphertext of the field as it would otherwise be written.
+    ENCRYPTED = 81,
+    // Rust native OsString: a platform encoding byte followed by the raw bytes or UTF-16 units.
+    OS_STRING = 82,
     // Bound value for range checks (types with id >= BOUND are not internal types).
-    BOUND = 72,
+    BOUND = 83,
 }
 
 pub const BOOL: u32 = TypeId::BOOL as u32;
@@ -168,6 +184,17 @@ pub const U128_ARRAY: u32 = TypeId::U128_ARRAY as u32;
 pub const INT128_ARRAY: u32 = TypeId::INT128_ARRAY as u32;
 pub const USIZE_ARRAY: u32 = TypeId::USIZE_ARRAY as u32;
 pub const ISIZE_ARRAY: u32 = TypeId::ISIZE_ARRAY as u32;
+pub const CHAR: u32 = TypeId::CHAR as u32;
+pub const UUID: u32 = TypeId::UUID as u32;
+pub const IPV4_ADDR: u32 = TypeId::IPV4_ADDR as u32;
+pub const IPV6_ADDR: u32 = TypeId::IPV6_ADDR as u32;
+pub const IP_ADDR: u32 = TypeId::IP_ADDR as u32;
+pub const SOCKET_ADDR: u32 = TypeId::SOCKET_ADDR as u32;
+pub const RESULT: u32 = TypeId::RESULT as u32;
+pub const RANGE: u32 = TypeId::RANGE as u32;
+pub const RANGE_INCLUSIVE: u32 = TypeId::RANGE_INCLUSIVE as u32;
+pub const ENCRYPTED: u32 = TypeId::ENCRYPTED as u32;
+pub const OS_STRING: u32 = TypeId::OS_STRING as u32;
 pub const UNKNOWN: u32 = TypeId::UNKNOWN as u32;
 pub const BOUND: u32 = TypeId::BOUND as u32;
 
@@ -184,7 +211,7 @@ pub const fn is_enum_type_id(type_id: TypeId) -> bool {
     matches!(type_id, TypeId::ENUM | TypeId::NAMED_ENUM | TypeId::UNION)
 }
 
-pub static BASIC_TYPES: [TypeId; 35] = [
+pub static BASIC_TYPES: [TypeId; 36] = [
     TypeId::BOOL,
     TypeId::INT8,
     TypeId::INT16,
@@ -221,9 +248,10 @@ pub static BASIC_TYPES: [TypeId; 35] = [
     TypeId::USIZE,
     TypeId::ISIZE,
     TypeId::USIZE_ARRAY,
+    TypeId::CHAR,
 ];
 
-pub static PRIMITIVE_TYPES: [u32; 24] = [
+pub static PRIMITIVE_TYPES: [u32; 25] = [
     TypeId::BOOL as u32,
     TypeId::INT8 as u32,
     TypeId::INT16 as u32,
@@ -249,6 +277,7 @@ pub static PRIMITIVE_TYPES: [u32; 24] = [
     TypeId::INT128 as u32,
     TypeId::USIZE as u32,
     TypeId::ISIZE as u32,
+    TypeId::CHAR as u32,
 ];
 
 pub static PRIMITIVE_ARRAY_TYPES: [u32; 19] = [
@@ -273,7 +302,7 @@ pub static PRIMITIVE_ARRAY_TYPES: [u32; 19] = [
     TypeId::USIZE_ARRAY as u32,
     TypeId::ISIZE_ARRAY as u32,
 ];
-pub static BASIC_TYPE_NAMES: [&str; 21] = [
+pub static BASIC_TYPE_NAMES: [&str; 22] = [
     "bool",
     "i8",
     "i16",
@@ -295,6 +324,7 @@ pub static BASIC_TYPE_NAMES: [&str; 21] = [
     "u128",
     "usize",
     "isize",
+    "char",
 ];
 
 pub static CONTAINER_TYPES: [TypeId; 3] = [TypeId::LIST, TypeId::SET, TypeId::MAP];
@@ -346,6 +376,7 @@ pub const fn is_primitive_type_id(type_id: TypeId) -> bool {
             | TypeId::INT128
             | TypeId::USIZE
             | TypeId::ISIZE
+            | TypeId::CHAR
     )
 }
 
@@ -418,6 +449,19 @@ pub const fn needs_user_type_id(type_id: u32) -> bool {
 pub mod config_flags {
     pub const IS_CROSS_LANGUAGE_FLAG: u8 = 1 << 0;
     pub const IS_OUT_OF_BAND_FLAG: u8 = 1 << 1;
+    pub const IS_COMPRESSED_FLAG: u8 = 1 << 2;
+    pub const IS_BIG_ENDIAN_FLAG: u8 = 1 << 3;
+    pub const IS_CHECKSUMMED_FLAG: u8 = 1 << 4;
+    pub const IS_VERSIONED_FLAG: u8 = 1 << 5;
+    /// A byte of [`native_options`](super::native_options) follows the bitmap.
+    pub const HAS_NATIVE_OPTIONS_FLAG: u8 = 1 << 6;
+}
+
+/// Bits of the native options byte, which follows the root header bitmap when
+/// [`config_flags::HAS_NATIVE_OPTIONS_FLAG`] is set.
+pub mod native_options {
+    pub const SPARSE_OPTIONS_FLAG: u8 = 1 << 0;
+    pub const STRING_REF_FLAG: u8 = 1 << 1;
 }
 
 // every object start with i8 i16 reference flag and type flag
@@ -494,6 +538,17 @@ pub fn format_type_id(type_id: u32) -> String {
         69 => "INT128_ARRAY",
         70 => "USIZE_ARRAY",
         71 => "ISIZE_ARRAY",
+        72 => "CHAR",
+        73 => "UUID",
+        74 => "IPV4_ADDR",
+        75 => "IPV6_ADDR",
+        76 => "IP_ADDR",
+        77 => "SOCKET_ADDR",
+        78 => "RESULT",
+        79 => "RANGE",
+        80 => "RANGE_INCLUSIVE",
+        81 => "ENCRYPTED",
+        82 => "OS_STRING",
         _ => "UNKNOWN_TYPE",
     };
 
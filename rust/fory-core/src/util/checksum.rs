@@ -0,0 +1,132 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Whole-payload integrity checksums.
+//!
+//! A checksummed message is laid out as:
+//!
+//! ```text
+//! | bitmap | IS_CHECKSUMMED_FLAG | len: var_u32 | payload | crc32: u32 |
+//! ```
+//!
+//! where the payload is everything the plain message holds after its bitmap
+//! byte, possibly compressed, and the little-endian CRC32 footer covers every
+//! byte before it.
+
+use crate::buffer::{Reader, Writer};
+use crate::config::Checksum;
+use crate::error::Error;
+use crate::type_id::config_flags::{IS_CHECKSUMMED_FLAG, IS_CROSS_LANGUAGE_FLAG};
+
+/// Appends a checksum footer to the message that starts at `buf[start]`.
+pub(crate) fn checksum_message(
+    checksum: Checksum,
+    buf: &mut Vec<u8>,
+    start: usize,
+) -> Result<(), Error> {
+    match checksum {
+        Checksum::None => Ok(()),
+        Checksum::Crc32 => {
+            if buf[start] & IS_CROSS_LANGUAGE_FLAG != 0 {
+                return Err(Error::not_allowed(
+                    "payload checksums are not supported in cross-language mode",
+                ));
+            }
+            let payload_len = buf.len() - start - 1;
+            let len = u32::try_from(payload_len).map_err(|_| {
+                Error::encode_error(format!(
+                    "payload of {} bytes exceeds the u32 checksum length prefix",
+                    payload_len
+                ))
+            })?;
+            let mut prefix = Vec::with_capacity(5);
+            Writer::from_buffer(&mut prefix).write_var_u32(len);
+            buf[start] |= IS_CHECKSUMMED_FLAG;
+            buf.splice(start + 1..start + 1, prefix);
+            let crc = crc32(&buf[start..]);
+            Writer::from_buffer(buf).write_u32(crc);
+            Ok(())
+        }
+    }
+}
+
+/// Reads a checksummed message from `reader`, verifies its footer and returns
+/// the equivalent plain message, header bitmap included.
+pub(crate) fn verify_message(reader: &mut Reader) -> Result<Vec<u8>, Error> {
+    let start = reader.get_cursor();
+    let bitmap = reader.read_u8()? & !IS_CHECKSUMMED_FLAG;
+    let len = reader.read_var_u32()? as usize;
+    reader.read_bytes(len)?;
+    let end = reader.get_cursor();
+    let expected = reader.read_u32()?;
+    let actual = crc32(&reader.bf[start..end]);
+    if expected != actual {
+        return Err(Error::checksum_mismatch(expected, actual));
+    }
+    let mut message = Vec::with_capacity(len + 1);
+    message.push(bitmap);
+    message.extend_from_slice(&reader.bf[end - len..end]);
+    Ok(message)
+}
+
+const CRC32_TABLE: [u32; 256] = crc32_table();
+
+const fn crc32_table() -> [u32; 256] {
+    // Reflected IEEE 802.3 polynomial, as used by zlib and PNG.
+    const POLY: u32 = 0xEDB8_8320;
+    let mut table = [0u32; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 != 0 {
+                (crc >> 1) ^ POLY
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+}
+
+/// Computes the CRC-32 (IEEE) of `data`.
+pub(crate) fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &byte in data {
+        crc = CRC32_TABLE[((crc ^ byte as u32) & 0xFF) as usize] ^ (crc >> 8);
+    }
+    !crc
+}
+
+#[cfg(test)]
+mod tests {
+    use super::crc32;
+
+    #[test]
+    fn crc32_check_values() {
+        assert_eq!(crc32(b""), 0);
+        assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+        assert_eq!(
+            crc32(b"The quick brown fox jumps over the lazy dog"),
+            0x414F_A339
+        );
+    }
+}
@@ -0,0 +1,132 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Field-level encryption for `#[fory(encrypt)]` fields.
+//!
+//! An encrypted field is written as:
+//!
+//! ```text
+//! | len: var_u32 | nonce: 12 bytes | ciphertext | tag: 16 bytes |
+//! ```
+//!
+//! where `len` counts everything after it, and the ciphertext is the AES-256-GCM
+//! encryption of the bytes the field would otherwise be written as. The associated
+//! data is the field's [`field_aad`], so a blob only authenticates in the field it
+//! was written for.
+
+use crate::error::Error;
+use std::fmt;
+
+#[cfg(feature = "encryption")]
+const NONCE_SIZE: usize = 12;
+
+/// AES-256-GCM key used for `#[fory(encrypt)]` fields.
+///
+/// Set with [`ForyBuilder::cipher`](crate::ForyBuilder::cipher), which requires the
+/// `encryption` feature. Each field is encrypted under a fresh random nonce.
+#[derive(Clone)]
+#[non_exhaustive]
+#[cfg_attr(not(feature = "encryption"), allow(dead_code))]
+pub struct FieldCipher {
+    #[cfg(feature = "encryption")]
+    aead: aes_gcm::Aes256Gcm,
+}
+
+impl fmt::Debug for FieldCipher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("FieldCipher(<redacted>)")
+    }
+}
+
+impl FieldCipher {
+    /// Creates a cipher from a 256-bit key.
+    #[cfg(feature = "encryption")]
+    pub fn new(key: [u8; 32]) -> Self {
+        use aes_gcm::KeyInit;
+        FieldCipher {
+            aead: aes_gcm::Aes256Gcm::new(&key.into()),
+        }
+    }
+
+    /// Encrypts `plaintext` bound to `aad`, returning the nonce followed by the
+    /// ciphertext and tag.
+    #[cfg(feature = "encryption")]
+    pub(crate) fn encrypt(&self, plaintext: &[u8], aad: &[u8]) -> Result<Vec<u8>, Error> {
+        use aes_gcm::aead::{Aead, AeadCore, OsRng, Payload};
+        let nonce = aes_gcm::Aes256Gcm::generate_nonce(&mut OsRng);
+        let ciphertext = self
+            .aead
+            .encrypt(
+                &nonce,
+                Payload {
+                    msg: plaintext,
+                    aad,
+                },
+            )
+            .map_err(|_| Error::encode_error("AES-GCM encryption failed"))?;
+        let mut out = Vec::with_capacity(NONCE_SIZE + ciphertext.len());
+        out.extend_from_slice(&nonce);
+        out.extend_from_slice(&ciphertext);
+        Ok(out)
+    }
+
+    /// Decrypts the output of [`encrypt`](Self::encrypt) under the same `aad`.
+    #[cfg(feature = "encryption")]
+    pub(crate) fn decrypt(&self, data: &[u8], aad: &[u8]) -> Result<Vec<u8>, Error> {
+        use aes_gcm::aead::{Aead, Payload};
+        if data.len() < NONCE_SIZE {
+            return Err(Error::decrypt_failed(format!(
+                "encrypted field of {} bytes is shorter than its nonce",
+                data.len()
+            )));
+        }
+        let (nonce, ciphertext) = data.split_at(NONCE_SIZE);
+        self.aead
+            .decrypt(
+                aes_gcm::Nonce::from_slice(nonce),
+                Payload {
+                    msg: ciphertext,
+                    aad,
+                },
+            )
+            .map_err(|_| Error::decrypt_failed("encrypted field failed authentication"))
+    }
+
+    #[cfg(not(feature = "encryption"))]
+    pub(crate) fn encrypt(&self, _plaintext: &[u8], _aad: &[u8]) -> Result<Vec<u8>, Error> {
+        Err(Error::unsupported(
+            "field encryption requires the `encryption` feature",
+        ))
+    }
+
+    #[cfg(not(feature = "encryption"))]
+    pub(crate) fn decrypt(&self, _data: &[u8], _aad: &[u8]) -> Result<Vec<u8>, Error> {
+        Err(Error::decrypt_failed(
+            "field decryption requires the `encryption` feature",
+        ))
+    }
+}
+
+/// Associated data binding an encrypted field to its struct and field: the hash of the
+/// struct's registered id or name, followed by the field id (or the hash of the field
+/// name when it has no id), both little-endian.
+pub(crate) fn field_aad(type_hash: u64, field_id: u64) -> [u8; 16] {
+    let mut aad = [0u8; 16];
+    aad[..8].copy_from_slice(&type_hash.to_le_bytes());
+    aad[8..].copy_from_slice(&field_id.to_le_bytes());
+    aad
+}
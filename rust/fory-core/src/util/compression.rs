@@ -0,0 +1,186 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Whole-payload compression.
+//!
+//! A compressed message is laid out as:
+//!
+//! ```text
+//! | bitmap | IS_COMPRESSED_FLAG | algorithm: u8 | len: var_u32 | compressed payload |
+//! ```
+//!
+//! where the compressed payload is everything the uncompressed message holds
+//! after its bitmap byte.
+
+use crate::buffer::{Reader, Writer};
+use crate::config::Compression;
+use crate::error::Error;
+use crate::type_id::config_flags::{IS_COMPRESSED_FLAG, IS_CROSS_LANGUAGE_FLAG};
+
+const ALGORITHM_LZ4: u8 = 1;
+const ALGORITHM_SNAPPY: u8 = 2;
+
+/// Compresses the message that starts at `buf[start]` in place.
+#[cfg_attr(
+    not(any(feature = "lz4", feature = "snappy")),
+    allow(unused_variables, clippy::ptr_arg)
+)]
+pub(crate) fn compress_message(
+    compression: Compression,
+    buf: &mut Vec<u8>,
+    start: usize,
+) -> Result<(), Error> {
+    match compression {
+        Compression::None => Ok(()),
+        #[cfg(feature = "lz4")]
+        Compression::Lz4 => {
+            ensure_native_mode(buf[start])?;
+            let compressed = lz4_compress(&buf[start + 1..])?;
+            write_compressed(buf, start, ALGORITHM_LZ4, &compressed)
+        }
+        #[cfg(feature = "snappy")]
+        Compression::Snappy => {
+            ensure_native_mode(buf[start])?;
+            let compressed = snappy_compress(&buf[start + 1..])?;
+            write_compressed(buf, start, ALGORITHM_SNAPPY, &compressed)
+        }
+    }
+}
+
+#[cfg_attr(not(any(feature = "lz4", feature = "snappy")), allow(dead_code))]
+fn ensure_native_mode(bitmap: u8) -> Result<(), Error> {
+    if bitmap & IS_CROSS_LANGUAGE_FLAG != 0 {
+        return Err(Error::not_allowed(
+            "payload compression is not supported in cross-language mode",
+        ));
+    }
+    Ok(())
+}
+
+#[cfg_attr(not(any(feature = "lz4", feature = "snappy")), allow(dead_code))]
+fn write_compressed(
+    buf: &mut Vec<u8>,
+    start: usize,
+    algorithm: u8,
+    compressed: &[u8],
+) -> Result<(), Error> {
+    let len = u32::try_from(compressed.len()).map_err(|_| {
+        Error::encode_error(format!(
+            "compressed payload of {} bytes exceeds the u32 length prefix",
+            compressed.len()
+        ))
+    })?;
+    let bitmap = buf[start] | IS_COMPRESSED_FLAG;
+    buf.truncate(start);
+    let mut writer = Writer::from_buffer(buf);
+    writer.write_u8(bitmap);
+    writer.write_u8(algorithm);
+    writer.write_var_u32(len);
+    writer.write_bytes(compressed);
+    Ok(())
+}
+
+/// Reads a compressed message from `reader` and returns the equivalent
+/// uncompressed message, header bitmap included.
+pub(crate) fn decompress_message(reader: &mut Reader, max_size: usize) -> Result<Vec<u8>, Error> {
+    let bitmap = reader.read_u8()? & !IS_COMPRESSED_FLAG;
+    let algorithm = reader.read_u8()?;
+    let len = reader.read_var_u32()? as usize;
+    let compressed = reader.read_bytes(len)?;
+    let mut message = vec![bitmap];
+    match algorithm {
+        ALGORITHM_LZ4 => lz4_decompress(compressed, max_size, &mut message)?,
+        ALGORITHM_SNAPPY => snappy_decompress(compressed, max_size, &mut message)?,
+        _ => {
+            return Err(Error::invalid_data(format!(
+                "unknown compression algorithm: {}",
+                algorithm
+            )))
+        }
+    }
+    Ok(message)
+}
+
+#[cfg(feature = "lz4")]
+fn lz4_compress(data: &[u8]) -> Result<Vec<u8>, Error> {
+    use std::io::Write;
+    let mut encoder = lz4_flex::frame::FrameEncoder::new(Vec::with_capacity(data.len() / 2));
+    encoder
+        .write_all(data)
+        .map_err(|e| Error::encode_error(format!("LZ4 compression failed: {e}")))?;
+    encoder
+        .finish()
+        .map_err(|e| Error::encode_error(format!("LZ4 compression failed: {e}")))
+}
+
+#[cfg(feature = "lz4")]
+fn lz4_decompress(data: &[u8], max_size: usize, out: &mut Vec<u8>) -> Result<(), Error> {
+    use std::io::Read;
+    let decoder = lz4_flex::frame::FrameDecoder::new(data);
+    // Read one byte past the limit so oversized payloads are detected
+    // without decompressing them entirely.
+    let read = decoder
+        .take(max_size as u64 + 1)
+        .read_to_end(out)
+        .map_err(|e| Error::invalid_data(format!("LZ4 decompression failed: {e}")))?;
+    if read > max_size {
+        return Err(Error::size_limit_exceeded(format!(
+            "Decompressed size exceeds limit {}",
+            max_size
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(not(feature = "lz4"))]
+fn lz4_decompress(_data: &[u8], _max_size: usize, _out: &mut Vec<u8>) -> Result<(), Error> {
+    Err(Error::unsupported(
+        "LZ4 compressed payload requires the `lz4` feature",
+    ))
+}
+
+#[cfg(feature = "snappy")]
+fn snappy_compress(data: &[u8]) -> Result<Vec<u8>, Error> {
+    snap::raw::Encoder::new()
+        .compress_vec(data)
+        .map_err(|e| Error::encode_error(format!("Snappy compression failed: {e}")))
+}
+
+#[cfg(feature = "snappy")]
+fn snappy_decompress(data: &[u8], max_size: usize, out: &mut Vec<u8>) -> Result<(), Error> {
+    let len = snap::raw::decompress_len(data)
+        .map_err(|e| Error::invalid_data(format!("Snappy decompression failed: {e}")))?;
+    if len > max_size {
+        return Err(Error::size_limit_exceeded(format!(
+            "Decompressed size {} exceeds limit {}",
+            len, max_size
+        )));
+    }
+    let start = out.len();
+    out.resize(start + len, 0);
+    snap::raw::Decoder::new()
+        .decompress(data, &mut out[start..])
+        .map_err(|e| Error::invalid_data(format!("Snappy decompression failed: {e}")))?;
+    Ok(())
+}
+
+#[cfg(not(feature = "snappy"))]
+fn snappy_decompress(_data: &[u8], _max_size: usize, _out: &mut Vec<u8>) -> Result<(), Error> {
+    Err(Error::unsupported(
+        "Snappy compressed payload requires the `snappy` feature",
+    ))
+}
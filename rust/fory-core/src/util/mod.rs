@@ -15,6 +15,9 @@
 // specific language governing permissions and limitations
 // under the License.
 
+pub(crate) mod checksum;
+pub(crate) mod cipher;
+pub(crate) mod compression;
 mod string_util;
 mod sync;
 
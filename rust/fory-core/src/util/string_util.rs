@@ -775,7 +775,7 @@ pub mod buffer_rw_string {
         // Rust is the only runtime that checks UTF-8 string payloads by default; borrow first so
         // the check adds no temporary Vec before constructing the final String.
         let value = std::str::from_utf8(slice)
-            .map_err(|_| Error::encoding_error("invalid UTF-8 string"))?
+            .map_err(|_| Error::utf8("invalid UTF-8 string"))?
             .to_owned();
         reader.move_next(len);
         Ok(value)
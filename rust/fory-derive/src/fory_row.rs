@@ -20,6 +20,9 @@ use proc_macro::TokenStream;
 use quote::quote;
 
 pub fn derive_row(ast: &syn::DeriveInput) -> TokenStream {
+    if let Err(err) = crate::object::util::validate_field_names(ast) {
+        return err.into_compile_error().into();
+    }
     let name = &ast.ident;
     let source_fields = match &ast.data {
         syn::Data::Struct(s) => source_fields(&s.fields),
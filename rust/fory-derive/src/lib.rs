@@ -117,6 +117,21 @@
 //!   By default, `ForyStruct` does NOT generate `impl Default` to avoid conflicts with existing
 //!   `Default` implementations. Use this attribute when you want the macro to generate both
 //!   `ForyDefault` and `Default` for you.
+//! - **`#[fory(repr = "name")]`**: Makes a `ForyEnum` write its variant name instead of its
+//!   ordinal in native mode. Reading an unknown name fails with `Error::UnknownEnum`.
+//! - **`#[fory(stable_ordinals)]`**: Makes a `ForyEnum` write each variant's explicit
+//!   discriminant as its ordinal instead of its position, and rejects variants without one.
+//!   Variants can then be inserted anywhere without changing how existing ones are encoded.
+//! - **`#[fory(track_presence)]`**: Fills the struct's `PresentFields` field on compatible
+//!   reads and generates `was_present(&self, field: &str) -> bool`, which tells a field the
+//!   writer's schema lacked apart from one it sent with a default value.
+//! - **`#[fory(version = N)]`**: Writes schema version `N` into the header of payloads rooted
+//!   at the struct. `Fory::deserialize_versioned` returns it alongside the value.
+//! - **`#[fory(min_version = N)]`**: Rejects root payloads older than version `N` with
+//!   `Error::VersionTooOld`. Requires `version`.
+//! - **`#[fory(encrypt)]`**: Writes an individual field as AES-GCM ciphertext with the key set
+//!   by `ForyBuilder::cipher` (`encryption` feature). A wrong key fails with
+//!   `Error::DecryptFailed`.
 //! - **`#[fory(default)]`**: Marks the default `ForyUnion` variant. `ForyUnion` requires exactly
 //!   one default variant so schema evolution and null fallback have an explicit owner.
 //!
@@ -138,6 +153,7 @@
 //! - `fory::Date`
 //! - `fory::Timestamp`
 //! - `fory::Duration`
+//! - `std::time::Duration` and `std::time::SystemTime`
 //! - `chrono::NaiveDate`, `chrono::NaiveDateTime`, and `chrono::Duration` when the `chrono` feature is enabled
 //!
 //! **Custom Types:**
@@ -189,7 +205,10 @@
 
 use fory_row::derive_row;
 use proc_macro::TokenStream;
-use syn::{parse_macro_input, spanned::Spanned, Attribute, Data, DeriveInput, Fields, LitBool};
+use syn::{
+    parse_macro_input, spanned::Spanned, Attribute, Data, DeriveInput, Fields, LitBool, LitInt,
+    LitStr,
+};
 
 mod fory_row;
 mod object;
@@ -283,6 +302,11 @@ pub(crate) struct ForyAttrs {
     pub debug_enabled: bool,
     pub generate_default: bool,
     pub evolving: Option<bool>,
+    pub repr_name: bool,
+    pub track_presence: bool,
+    pub stable_ordinals: bool,
+    pub version: Option<u32>,
+    pub min_version: Option<u32>,
 }
 
 /// Parse fory attributes and return ForyAttrs
@@ -290,6 +314,11 @@ fn parse_fory_attrs(attrs: &[Attribute]) -> syn::Result<ForyAttrs> {
     let mut debug_flag: Option<bool> = None;
     let mut generate_default_flag: Option<bool> = None;
     let mut evolving_flag: Option<bool> = None;
+    let mut repr_name_flag: Option<bool> = None;
+    let mut track_presence_flag: Option<bool> = None;
+    let mut stable_ordinals_flag: Option<bool> = None;
+    let mut version: Option<u32> = None;
+    let mut min_version: Option<u32> = None;
 
     for attr in attrs {
         if attr.path().is_ident("fory") {
@@ -345,6 +374,77 @@ fn parse_fory_attrs(attrs: &[Attribute]) -> syn::Result<ForyAttrs> {
                         Some(_) => evolving_flag,
                         None => Some(value),
                     };
+                } else if meta.path.is_ident("repr") {
+                    let lit: LitStr = meta.value()?.parse()?;
+                    let value = match lit.value().as_str() {
+                        "name" => true,
+                        "ordinal" => false,
+                        _ => {
+                            return Err(syn::Error::new(
+                                lit.span(),
+                                "unsupported `repr` value; expected \"name\" or \"ordinal\"",
+                            ));
+                        }
+                    };
+                    repr_name_flag = match repr_name_flag {
+                        Some(existing) if existing != value => {
+                            return Err(syn::Error::new(
+                                meta.path.span(),
+                                "conflicting `repr` attribute values",
+                            ));
+                        }
+                        Some(_) => repr_name_flag,
+                        None => Some(value),
+                    };
+                } else if meta.path.is_ident("track_presence") {
+                    let value = if meta.input.is_empty() {
+                        true
+                    } else {
+                        let lit: LitBool = meta.value()?.parse()?;
+                        lit.value
+                    };
+                    track_presence_flag = match track_presence_flag {
+                        Some(existing) if existing != value => {
+                            return Err(syn::Error::new(
+                                meta.path.span(),
+                                "conflicting `track_presence` attribute values",
+                            ));
+                        }
+                        Some(_) => track_presence_flag,
+                        None => Some(value),
+                    };
+                } else if meta.path.is_ident("stable_ordinals") {
+                    let value = if meta.input.is_empty() {
+                        true
+                    } else {
+                        let lit: LitBool = meta.value()?.parse()?;
+                        lit.value
+                    };
+                    stable_ordinals_flag = match stable_ordinals_flag {
+                        Some(existing) if existing != value => {
+                            return Err(syn::Error::new(
+                                meta.path.span(),
+                                "conflicting `stable_ordinals` attribute values",
+                            ));
+                        }
+                        Some(_) => stable_ordinals_flag,
+                        None => Some(value),
+                    };
+                } else if meta.path.is_ident("version") || meta.path.is_ident("min_version") {
+                    let lit: LitInt = meta.value()?.parse()?;
+                    let value: u32 = lit.base10_parse()?;
+                    let (slot, name) = if meta.path.is_ident("version") {
+                        (&mut version, "version")
+                    } else {
+                        (&mut min_version, "min_version")
+                    };
+                    if slot.is_some_and(|existing| existing != value) {
+                        return Err(syn::Error::new(
+                            meta.path.span(),
+                            format!("conflicting `{name}` attribute values"),
+                        ));
+                    }
+                    *slot = Some(value);
                 } else {
                     return Err(meta.error("unsupported type-level fory attribute"));
                 }
@@ -357,5 +457,10 @@ fn parse_fory_attrs(attrs: &[Attribute]) -> syn::Result<ForyAttrs> {
         debug_enabled: debug_flag.unwrap_or(false),
         generate_default: generate_default_flag.unwrap_or(false),
         evolving: evolving_flag,
+        repr_name: repr_name_flag.unwrap_or(false),
+        track_presence: track_presence_flag.unwrap_or(false),
+        stable_ordinals: stable_ordinals_flag.unwrap_or(false),
+        version,
+        min_version,
     })
 }
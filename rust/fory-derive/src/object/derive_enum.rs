@@ -150,8 +150,7 @@ fn gen_read_variant_elements(
                 match binding {
                     FieldBinding::Codec(binding) => {
                         let var = binding.private_ident.clone();
-                        let default_expr =
-                            super::field_codec::default_expr_for_type(binding.value_ty);
+                        let default_expr = binding.default_expr();
                         let index = serialized_index;
                         serialized_index += 1;
                         let read_value = binding.read_with_mode_expr(
@@ -168,9 +167,8 @@ fn gen_read_variant_elements(
                         private_idents.push(var);
                     }
                     FieldBinding::Skipped(binding) => {
+                        let default_expr = binding.default_expr();
                         let var = binding.private_ident;
-                        let default_expr =
-                            super::field_codec::default_expr_for_type(&binding.source.field.ty);
                         read_fields.push(quote! {
                             let #var = #default_expr;
                         });
@@ -358,7 +356,48 @@ pub fn gen_write(_data_enum: &DataEnum) -> TokenStream {
     }
 }
 
-fn xlang_variant_branches(data_enum: &DataEnum, default_variant_value: u32) -> Vec<TokenStream> {
+/// Returns the ordinal each variant is written with: its position by default, or its explicit
+/// discriminant under `#[fory(stable_ordinals)]`, where every variant must declare one so that
+/// inserting a variant cannot shift the ordinals of existing ones.
+pub(crate) fn variant_ordinals(
+    data_enum: &DataEnum,
+    stable_ordinals: bool,
+) -> syn::Result<Vec<u32>> {
+    if !stable_ordinals {
+        return Ok((0..data_enum.variants.len() as u32).collect());
+    }
+    data_enum
+        .variants
+        .iter()
+        .map(|variant| {
+            let Some((_, expr)) = &variant.discriminant else {
+                return Err(syn::Error::new_spanned(
+                    variant,
+                    format!(
+                        "variant `{}` needs an explicit discriminant under `#[fory(stable_ordinals)]`",
+                        variant.ident
+                    ),
+                ));
+            };
+            match expr {
+                syn::Expr::Lit(syn::ExprLit {
+                    lit: syn::Lit::Int(lit),
+                    ..
+                }) => lit.base10_parse::<u32>(),
+                _ => Err(syn::Error::new_spanned(
+                    expr,
+                    "`#[fory(stable_ordinals)]` discriminants must be non-negative integer literals",
+                )),
+            }
+        })
+        .collect()
+}
+
+fn xlang_variant_branches(
+    data_enum: &DataEnum,
+    ordinals: &[u32],
+    default_variant_value: u32,
+) -> Vec<TokenStream> {
     let is_union_compatible = is_union_compatible_enum(data_enum);
 
     data_enum
@@ -379,7 +418,7 @@ fn xlang_variant_branches(data_enum: &DataEnum, default_variant_value: u32) -> V
             let mut tag_value = if is_union_compatible {
                 xlang_union_case_id(data_enum, idx, v)
             } else {
-                idx as u32
+                ordinals[idx]
             };
             if is_skip_enum_variant(v) {
                 tag_value = default_variant_value;
@@ -450,14 +489,18 @@ fn xlang_variant_branches(data_enum: &DataEnum, default_variant_value: u32) -> V
         .collect()
 }
 
-fn rust_variant_branches(data_enum: &DataEnum, default_variant_value: u32) -> Vec<TokenStream> {
+fn rust_variant_branches(
+    data_enum: &DataEnum,
+    ordinals: &[u32],
+    default_variant_value: u32,
+) -> Vec<TokenStream> {
     data_enum
         .variants
         .iter()
         .enumerate()
         .map(|(idx, v)| {
             let ident = &v.ident;
-            let mut tag_value = idx as u32;
+            let mut tag_value = ordinals[idx];
             if is_skip_enum_variant(v) {
                 tag_value = default_variant_value;
             }
@@ -513,6 +556,7 @@ fn rust_variant_branches(data_enum: &DataEnum, default_variant_value: u32) -> Ve
 
 fn rust_compatible_variant_write_branches(
     data_enum: &DataEnum,
+    ordinals: &[u32],
     default_variant_value: u32,
 ) -> Vec<TokenStream> {
     use crate::object::util::get_struct_name;
@@ -524,7 +568,7 @@ fn rust_compatible_variant_write_branches(
         .enumerate()
         .map(|(idx, v)| {
             let ident = &v.ident;
-            let mut tag_value = idx as u32;
+            let mut tag_value = ordinals[idx];
             if is_skip_enum_variant(v) {
                 tag_value = default_variant_value;
             }
@@ -588,19 +632,19 @@ fn rust_compatible_variant_write_branches(
         .collect()
 }
 
-pub fn gen_write_data(data_enum: &DataEnum) -> TokenStream {
+pub fn gen_write_data(data_enum: &DataEnum, ordinals: &[u32]) -> TokenStream {
     let default_variant_value = data_enum
         .variants
         .iter()
         .position(is_default_value_variant)
-        .unwrap_or(0) as u32;
+        .map_or(0, |idx| ordinals[idx]);
 
     let xlang_variant_branches: Vec<TokenStream> =
-        xlang_variant_branches(data_enum, default_variant_value);
+        xlang_variant_branches(data_enum, ordinals, default_variant_value);
     let rust_variant_branches: Vec<TokenStream> =
-        rust_variant_branches(data_enum, default_variant_value);
+        rust_variant_branches(data_enum, ordinals, default_variant_value);
     let rust_compatible_variant_branches: Vec<TokenStream> =
-        rust_compatible_variant_write_branches(data_enum, default_variant_value);
+        rust_compatible_variant_write_branches(data_enum, ordinals, default_variant_value);
 
     quote! {
         if context.is_xlang() {
@@ -624,6 +668,42 @@ pub fn gen_write_data(data_enum: &DataEnum) -> TokenStream {
     }
 }
 
+/// Writes variant names for `#[fory(repr = "name")]` enums in native mode. Xlang payloads
+/// keep the ordinal encoding because the xlang spec defines enums by ordinal.
+pub fn gen_write_data_by_name(data_enum: &DataEnum, ordinals: &[u32]) -> TokenStream {
+    let default_variant = data_enum
+        .variants
+        .iter()
+        .find(|v| is_default_value_variant(v))
+        .or_else(|| data_enum.variants.first());
+    let name_branches: Vec<TokenStream> = data_enum
+        .variants
+        .iter()
+        .map(|v| {
+            let ident = &v.ident;
+            let name = if is_skip_enum_variant(v) {
+                default_variant.map_or(ident, |d| &d.ident).to_string()
+            } else {
+                ident.to_string()
+            };
+            quote! {
+                Self::#ident => ::fory_core::serializer::enum_::write_variant_name(#name, context),
+            }
+        })
+        .collect();
+    let ordinal_write = gen_write_data(data_enum, ordinals);
+
+    quote! {
+        if context.is_xlang() {
+            #ordinal_write
+        } else {
+            match self {
+                #(#name_branches)*
+            }
+        }
+    }
+}
+
 pub fn gen_write_type_info(data_enum: &DataEnum) -> TokenStream {
     let is_union_compatible = is_union_compatible_enum(data_enum);
     let has_data_variants = data_enum
@@ -709,6 +789,7 @@ pub fn gen_static_type_id(data_enum: &DataEnum) -> TokenStream {
 
 fn xlang_variant_read_branches(
     data_enum: &DataEnum,
+    ordinals: &[u32],
     default_variant_value: u32,
 ) -> Vec<TokenStream> {
     let is_union_compatible = is_union_compatible_enum(data_enum);
@@ -726,7 +807,7 @@ fn xlang_variant_read_branches(
             let mut tag_value = if is_union_compatible {
                 xlang_union_case_id(data_enum, idx, v)
             } else {
-                idx as u32
+                ordinals[idx]
             };
             if is_skip_enum_variant(v) {
                 tag_value = default_variant_value;
@@ -804,6 +885,7 @@ fn xlang_variant_read_branches(
 
 fn rust_variant_read_branches(
     data_enum: &DataEnum,
+    ordinals: &[u32],
     default_variant_value: u32,
 ) -> Vec<TokenStream> {
     data_enum
@@ -812,7 +894,7 @@ fn rust_variant_read_branches(
         .enumerate()
         .map(|(idx, v)| {
             let ident = &v.ident;
-            let mut tag_value = idx as u32;
+            let mut tag_value = ordinals[idx];
             if is_skip_enum_variant(v) {
                 tag_value = default_variant_value;
             }
@@ -862,6 +944,7 @@ fn rust_variant_read_branches(
 
 fn rust_compatible_variant_read_branches(
     data_enum: &DataEnum,
+    ordinals: &[u32],
     default_variant_value: u32,
 ) -> Vec<TokenStream> {
     data_enum
@@ -870,7 +953,7 @@ fn rust_compatible_variant_read_branches(
         .enumerate()
         .map(|(idx, v)| {
             let ident = &v.ident;
-            let mut tag_value = idx as u32;
+            let mut tag_value = ordinals[idx];
             if is_skip_enum_variant(v) {
                 tag_value = default_variant_value;
             }
@@ -943,6 +1026,7 @@ fn rust_compatible_variant_read_branches(
                         crate::object::read::gen_read_compatible_with_construction(
                             &source_fields,
                             Some(ident),
+                            None,
                         );
 
                     // Generate default value for this variant
@@ -979,32 +1063,28 @@ fn rust_compatible_variant_read_branches(
         .collect()
 }
 
-pub fn gen_read_data(data_enum: &DataEnum) -> TokenStream {
+pub fn gen_read_data(data_enum: &DataEnum, ordinals: &[u32]) -> TokenStream {
     let is_union_compatible = is_union_compatible_enum(data_enum);
     let has_data_variants = data_enum
         .variants
         .iter()
         .any(|v| !matches!(v.fields, Fields::Unit));
-    let default_variant_value = data_enum
+    let default_variant_index = data_enum
         .variants
         .iter()
         .position(is_default_value_variant)
-        .unwrap_or(0) as u32;
+        .unwrap_or(0);
+    let default_variant_value = ordinals[default_variant_index];
 
     let xlang_variant_branches: Vec<TokenStream> =
-        xlang_variant_read_branches(data_enum, default_variant_value);
+        xlang_variant_read_branches(data_enum, ordinals, default_variant_value);
     let rust_variant_branches: Vec<TokenStream> =
-        rust_variant_read_branches(data_enum, default_variant_value);
+        rust_variant_read_branches(data_enum, ordinals, default_variant_value);
     let rust_compatible_variant_branches: Vec<TokenStream> =
-        rust_compatible_variant_read_branches(data_enum, default_variant_value);
+        rust_compatible_variant_read_branches(data_enum, ordinals, default_variant_value);
 
     // Get the default variant for compatible mode fallback
-    let default_variant = data_enum
-        .variants
-        .iter()
-        .nth(default_variant_value as usize)
-        .or_else(|| data_enum.variants.first())
-        .unwrap();
+    let default_variant = &data_enum.variants[default_variant_index];
 
     let default_variant_ident = &default_variant.ident;
     let default_variant_construction = match &default_variant.fields {
@@ -1061,7 +1141,7 @@ pub fn gen_read_data(data_enum: &DataEnum) -> TokenStream {
             }
         }
     };
-    quote! {
+    let read_value = quote! {
         if context.is_xlang() {
             let ordinal = context.reader.read_var_u32()?;
             match ordinal {
@@ -1094,6 +1174,51 @@ pub fn gen_read_data(data_enum: &DataEnum) -> TokenStream {
                 }
             }
         }
+    };
+    if has_data_variants {
+        // Only payload variants can nest, so unit enums skip the depth bookkeeping.
+        quote! {
+            context.inc_nesting_depth()?;
+            let value: ::std::result::Result<Self, ::fory_core::error::Error> = { #read_value };
+            let value = value?;
+            context.dec_nesting_depth();
+            Ok(value)
+        }
+    } else {
+        read_value
+    }
+}
+
+/// Reads variant names written by [`gen_write_data_by_name`]. Unknown names are an error even
+/// in compatible mode, matching the ordinal path outside compatible mode.
+pub fn gen_read_data_by_name(data_enum: &DataEnum, ordinals: &[u32]) -> TokenStream {
+    let name_branches: Vec<TokenStream> = data_enum
+        .variants
+        .iter()
+        .filter(|v| !is_skip_enum_variant(v))
+        .map(|v| {
+            let ident = &v.ident;
+            let name = ident.to_string();
+            quote! {
+                #name => Ok(Self::#ident),
+            }
+        })
+        .collect();
+    let ordinal_read = gen_read_data(data_enum, ordinals);
+
+    quote! {
+        if context.is_xlang() {
+            #ordinal_read
+        } else {
+            let name = ::fory_core::serializer::enum_::read_variant_name(context)?;
+            match name.as_str() {
+                #(#name_branches)*
+                _ => Err(::fory_core::error::Error::unknown_enum(format!(
+                    "unknown enum variant name: {}",
+                    name
+                ))),
+            }
+        }
     }
 }
 
@@ -21,19 +21,26 @@ use super::field_meta::{
 };
 use super::read::create_private_field_name;
 use super::util::{
-    get_type_id_by_type_ast, trait_object_is_any_send_sync, trait_object_is_any_without_auto_traits,
+    get_meta_field_name, get_type_id_by_type_ast, trait_object_is_any_send_sync,
+    trait_object_is_any_without_auto_traits,
 };
 use crate::util::{is_arc_dyn_trait, is_box_dyn_trait, is_rc_dyn_trait, SourceField};
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote, ToTokens};
 use syn::{GenericArgument, PathArguments, Type};
 
+/// Mirrors `fory_core::serializer::struct_::MAX_SPARSE_FIELDS`.
+const MAX_SPARSE_FIELDS: usize = 64;
+
 pub(crate) struct ResolvedField<'a> {
     pub source: &'a SourceField<'a>,
     pub private_ident: syn::Ident,
     pub dispatch: FieldDispatch,
     pub value_ty: &'a Type,
     pub field_id: i16,
+    pub default: Option<TokenStream>,
+    /// Whether the field is `#[fory(encrypt)]`, so it is written as one opaque value.
+    pub encrypted: bool,
 }
 
 pub(crate) enum FieldDispatch {
@@ -60,6 +67,40 @@ impl<'a> ResolvedField<'a> {
         }
     }
 
+    /// Whether the field is left out of the payload when it is `None` and
+    /// `sparse_options` is enabled.
+    pub fn is_sparse(&self) -> bool {
+        !self.encrypted && is_option_type(self.value_ty)
+    }
+
+    /// [`is_sparse`](Self::is_sparse) for the field at `index` in TypeMeta order. Only the
+    /// first `MAX_SPARSE_FIELDS` fields have a bit in the presence bitmap.
+    pub fn is_sparse_at(&self, index: usize) -> bool {
+        index < MAX_SPARSE_FIELDS && self.is_sparse()
+    }
+
+    /// Reader converting a remote scalar to the local field type, if the field has one.
+    fn compatible_scalar_reader(&self) -> Option<TokenStream> {
+        if self.encrypted {
+            return None;
+        }
+        compatible_scalar_reader_for(self.value_ty)
+    }
+
+    /// Expression telling whether the field of `self` is written under `sparse_options`.
+    pub fn present_expr(&self) -> TokenStream {
+        if self.is_sparse() {
+            let access = super::util::get_field_accessor(
+                self.source.field,
+                self.source.original_index,
+                true,
+            );
+            quote! { #access.is_some() }
+        } else {
+            quote! { true }
+        }
+    }
+
     pub fn reserved_space(&self) -> TokenStream {
         match &self.dispatch {
             FieldDispatch::Codec { .. } => {
@@ -76,6 +117,24 @@ impl<'a> ResolvedField<'a> {
         }
     }
 
+    pub fn estimate_size(&self) -> TokenStream {
+        let access =
+            super::util::get_field_accessor(self.source.field, self.source.original_index, true);
+        match &self.dispatch {
+            FieldDispatch::Codec { .. } => {
+                let call = self.codec_call();
+                quote! { #call::estimate_size(&#access) }
+            }
+            FieldDispatch::Serializer { .. } => {
+                let ty = self.value_ty;
+                quote! {
+                    <#ty as ::fory_core::Serializer>::fory_estimate_size(&#access)
+                        + ::fory_core::type_id::SIZE_OF_REF_AND_TYPE
+                }
+            }
+        }
+    }
+
     pub fn write_field(&self) -> TokenStream {
         let access =
             super::util::get_field_accessor(self.source.field, self.source.original_index, true);
@@ -193,6 +252,45 @@ impl<'a> ResolvedField<'a> {
         }
     }
 
+    /// Reads the field in place into `self`, reusing the existing value's allocations.
+    pub fn read_field_into(&self) -> TokenStream {
+        let access =
+            super::util::get_field_accessor(self.source.field, self.source.original_index, true);
+        match &self.dispatch {
+            FieldDispatch::Codec { .. } => {
+                let call = self.codec_call();
+                quote! {
+                    #call::read_field_into(&mut #access, context)?;
+                }
+            }
+            FieldDispatch::Serializer { .. } => {
+                let ty = self.value_ty;
+                if serializer_field_can_use_data_path(self.source.field) {
+                    quote! {
+                        <#ty as ::fory_core::Serializer>::fory_read_data_into(&mut #access, context)?;
+                    }
+                } else {
+                    let ref_mode = serializer_ref_mode_for_field(self.source.field);
+                    quote! {
+                        let read_type_info = if context.is_compatible() {
+                            ::fory_core::serializer::util::field_need_read_type_info(
+                                <#ty as ::fory_core::Serializer>::fory_static_type_id() as u32
+                            )
+                        } else {
+                            <#ty as ::fory_core::Serializer>::fory_is_polymorphic()
+                        };
+                        <#ty as ::fory_core::Serializer>::fory_read_into(
+                            &mut #access,
+                            context,
+                            #ref_mode,
+                            read_type_info
+                        )?;
+                    }
+                }
+            }
+        }
+    }
+
     pub fn read_with_mode_expr(
         &self,
         ref_mode: TokenStream,
@@ -214,10 +312,17 @@ impl<'a> ResolvedField<'a> {
         }
     }
 
+    /// Value used when the field is absent from the incoming data.
+    pub fn default_expr(&self) -> TokenStream {
+        self.default
+            .clone()
+            .unwrap_or_else(|| default_expr_for_type(self.value_ty))
+    }
+
     pub fn declare_compatible_var(&self) -> TokenStream {
         let var = &self.private_ident;
         let ty = self.value_ty;
-        let default_expr = default_expr_for_type(self.value_ty);
+        let default_expr = self.default_expr();
         quote! {
             let mut #var: #ty = #default_expr;
         }
@@ -278,7 +383,7 @@ impl<'a> ResolvedField<'a> {
         let var = &self.private_ident;
         match &self.dispatch {
             FieldDispatch::Codec { .. } => {
-                if let Some(read_scalar) = compatible_scalar_reader_for(self.value_ty) {
+                if let Some(read_scalar) = self.compatible_scalar_reader() {
                     let call = self.codec_call();
                     let local_type = if extract_option_inner_type(self.value_ty).is_some() {
                         quote! { local_field_type.type_id }
@@ -342,7 +447,7 @@ impl<'a> ResolvedField<'a> {
     pub fn compatible_needs_local_field_type(&self) -> bool {
         match &self.dispatch {
             FieldDispatch::Codec { .. } => {
-                compatible_scalar_reader_for(self.value_ty).is_none()
+                self.compatible_scalar_reader().is_none()
                     || extract_option_inner_type(self.value_ty).is_some()
             }
             FieldDispatch::Serializer { .. } => false,
@@ -351,7 +456,7 @@ impl<'a> ResolvedField<'a> {
 
     pub fn field_info(&self) -> TokenStream {
         let field_id = self.field_id;
-        let name = &self.source.field_name;
+        let name = get_meta_field_name(self.source.field, self.source.original_index);
         match &self.dispatch {
             FieldDispatch::Codec { .. } => {
                 let call = self.codec_call();
@@ -379,14 +484,23 @@ impl<'a> ResolvedField<'a> {
 pub(crate) struct SkippedField<'a> {
     pub source: &'a SourceField<'a>,
     pub private_ident: syn::Ident,
+    pub default: Option<TokenStream>,
 }
 
 impl<'a> SkippedField<'a> {
+    /// Value a skipped field is initialized with.
+    pub fn default_expr(&self) -> TokenStream {
+        self.default
+            .clone()
+            .unwrap_or_else(|| default_expr_for_type(&self.source.field.ty))
+    }
+
     pub fn read_default(&self) -> TokenStream {
         let var = &self.private_ident;
-        let default_expr = default_expr_for_type(&self.source.field.ty);
+        let ty = &self.source.field.ty;
+        let default_expr = self.default_expr();
         quote! {
-            let #var = #default_expr;
+            let #var: #ty = #default_expr;
         }
     }
 
@@ -394,6 +508,16 @@ impl<'a> SkippedField<'a> {
         let var = &self.private_ident;
         quote! { #var }
     }
+
+    /// Resets the field of `self` to the value a fresh read would produce.
+    pub fn reset_field(&self) -> TokenStream {
+        let access =
+            super::util::get_field_accessor(self.source.field, self.source.original_index, true);
+        let default_expr = self.default_expr();
+        quote! {
+            #access = #default_expr;
+        }
+    }
 }
 
 pub(crate) enum FieldBinding<'a> {
@@ -413,6 +537,7 @@ pub(crate) fn build_bindings<'a>(
                 return Ok(FieldBinding::Skipped(SkippedField {
                     source,
                     private_ident,
+                    default: explicit_default(&meta),
                 }));
             }
             let type_class = classify_field_type(&source.field.ty);
@@ -424,18 +549,51 @@ pub(crate) fn build_bindings<'a>(
             } else {
                 -1
             };
-            let dispatch = field_dispatch_for(&source.field.ty, &meta, nullable, track_ref)?;
+            let dispatch = if meta.encrypt {
+                encrypted_dispatch_for(source, &meta, nullable, track_ref)?
+            } else {
+                field_dispatch_for(&source.field.ty, &meta, nullable, track_ref)?
+            };
             Ok(FieldBinding::Codec(ResolvedField {
                 source,
                 private_ident,
                 dispatch,
                 value_ty: &source.field.ty,
                 field_id,
+                default: explicit_default(&meta),
+                encrypted: meta.encrypt,
             }))
         })
         .collect()
 }
 
+/// Wraps the field's codec in `EncryptedCodec`, binding the ciphertext to the struct and
+/// to the field id, or to the hash of the field name when the field has no id.
+fn encrypted_dispatch_for(
+    source: &SourceField<'_>,
+    meta: &ForyFieldMeta,
+    nullable: bool,
+    track_ref: bool,
+) -> syn::Result<FieldDispatch> {
+    let ty = &source.field.ty;
+    let inner_meta = ForyFieldMeta {
+        encrypt: false,
+        ..meta.clone()
+    };
+    let inner_codec = codec_type_for(ty, &inner_meta, nullable, track_ref)?;
+    let field_id = if meta.uses_tag_id() {
+        meta.effective_id() as u64
+    } else {
+        let name = get_meta_field_name(source.field, source.original_index);
+        fory_core::util::murmurhash3_x64_128(name.as_bytes(), 47).0
+    };
+    Ok(FieldDispatch::Codec {
+        codec_ty: quote! {
+            ::fory_core::serializer::codec::EncryptedCodec<#ty, #inner_codec, Self, #field_id>
+        },
+    })
+}
+
 fn field_dispatch_for(
     ty: &Type,
     meta: &ForyFieldMeta,
@@ -450,6 +608,7 @@ fn field_dispatch_for(
         && meta.list.is_none()
         && !meta.array
         && meta.map.is_none()
+        && !meta.external
         && is_container_type(ty)
         && !is_vec_type(ty)
         && !contains_custom_trait_object(ty)
@@ -483,7 +642,31 @@ pub(crate) fn codec_type_for(
         });
     }
 
+    if meta.external {
+        if meta.encoding.is_some()
+            || meta.list.is_some()
+            || meta.map.is_some()
+            || meta.array
+            || meta.bytes
+        {
+            return Err(syn::Error::new_spanned(
+                ty,
+                "external cannot be combined with encoding, list, map, array or bytes config",
+            ));
+        }
+        return Ok(quote! {
+            ::fory_core::serializer::codec::ExternalCodec<#ty, #nullable, #track_ref>
+        });
+    }
+
     if let Some((name, Some(args))) = type_name_and_args(ty) {
+        if name == "Wrapping" {
+            let inner = single_type_arg(args, ty, "Wrapping")?;
+            let inner_codec = codec_type_for(inner, meta, nullable, track_ref)?;
+            return Ok(quote! {
+                ::fory_core::serializer::codec::WrappingCodec<#inner, #inner_codec>
+            });
+        }
         if name == "Vec" {
             if meta.encoding.is_some() {
                 return Err(syn::Error::new_spanned(
@@ -551,6 +734,20 @@ pub(crate) fn codec_type_for(
                 ::fory_core::serializer::codec::VecCodec<#elem_ty, #elem_codec, #nullable, #track_ref>
             });
         }
+        if let Some(elem_ty) = boxed_slice_elem_type(&name, args) {
+            validate_serializer_backed_collection_meta(ty, meta, "Box<[T]>")?;
+            let elem_meta = ForyFieldMeta::default();
+            let elem_class = classify_field_type(elem_ty);
+            let elem_codec = codec_type_for(
+                elem_ty,
+                &elem_meta,
+                elem_meta.effective_nullable(elem_class) || is_option_type(elem_ty),
+                elem_meta.effective_ref(elem_class),
+            )?;
+            return Ok(quote! {
+                ::fory_core::serializer::codec::BoxedSliceCodec<#elem_ty, #elem_codec, #nullable, #track_ref>
+            });
+        }
         if name == "HashMap" {
             if meta.encoding.is_some() {
                 return Err(syn::Error::new_spanned(
@@ -694,7 +891,7 @@ pub(crate) fn codec_type_for(
         }
         if is_serializer_backed_collection(&name) {
             validate_serializer_backed_collection_meta(ty, meta, &name)?;
-            let elem_ty = single_type_arg(args, ty, &name)?;
+            let elem_ty = collection_elem_type(args, ty, &name)?;
             let elem_meta = ForyFieldMeta::default();
             let elem_class = classify_field_type(elem_ty);
             let elem_codec = codec_type_for(
@@ -1071,6 +1268,40 @@ fn single_type_arg<'a>(
         .ok_or_else(|| syn::Error::new_spanned(ty, format!("{owner} requires one type argument")))
 }
 
+/// Element type of a list-like collection. `SmallVec` is parameterized by its inline
+/// array `[T; N]`, so its element is the array element rather than the type argument.
+fn collection_elem_type<'a>(
+    args: &'a syn::punctuated::Punctuated<GenericArgument, syn::token::Comma>,
+    ty: &Type,
+    owner: &str,
+) -> syn::Result<&'a Type> {
+    let arg = single_type_arg(args, ty, owner)?;
+    if owner != "SmallVec" {
+        return Ok(arg);
+    }
+    match arg {
+        Type::Array(array) => Ok(array.elem.as_ref()),
+        _ => Err(syn::Error::new_spanned(
+            ty,
+            "SmallVec requires an inline array type argument such as [T; N]",
+        )),
+    }
+}
+
+/// Element type of a `Box<[T]>`, which the derive treats like `Vec<T>`.
+fn boxed_slice_elem_type<'a>(
+    name: &str,
+    args: &'a syn::punctuated::Punctuated<GenericArgument, syn::token::Comma>,
+) -> Option<&'a Type> {
+    if name != "Box" {
+        return None;
+    }
+    match args.first() {
+        Some(GenericArgument::Type(Type::Slice(slice))) => Some(slice.elem.as_ref()),
+        _ => None,
+    }
+}
+
 fn two_type_args<'a>(
     args: &'a syn::punctuated::Punctuated<GenericArgument, syn::token::Comma>,
     ty: &Type,
@@ -1104,8 +1335,10 @@ fn is_container_type(ty: &Type) -> bool {
             | "HashSet"
             | "BTreeSet"
             | "BinaryHeap"
+            | "SmallVec"
             | "HashMap"
             | "BTreeMap"
+            | "IndexMap"
     )
 }
 
@@ -1206,8 +1439,8 @@ fn field_type_expr_for(ty: &Type, nullable: bool, track_ref: bool) -> syn::Resul
                     vec![elem_type],
                 ));
             }
-            "VecDeque" | "LinkedList" => {
-                let elem_ty = single_type_arg(args, ty, &name)?;
+            "VecDeque" | "LinkedList" | "BinaryHeap" | "SmallVec" => {
+                let elem_ty = collection_elem_type(args, ty, &name)?;
                 let elem_type = nested_field_type_expr(elem_ty)?;
                 return Ok(field_type_literal(
                     ::fory_core::type_id::TypeId::LIST as u32,
@@ -1216,7 +1449,7 @@ fn field_type_expr_for(ty: &Type, nullable: bool, track_ref: bool) -> syn::Resul
                     vec![elem_type],
                 ));
             }
-            "HashSet" | "BTreeSet" | "BinaryHeap" => {
+            "HashSet" | "BTreeSet" => {
                 let elem_ty = single_type_arg(args, ty, &name)?;
                 let elem_type = nested_field_type_expr(elem_ty)?;
                 return Ok(field_type_literal(
@@ -1226,7 +1459,7 @@ fn field_type_expr_for(ty: &Type, nullable: bool, track_ref: bool) -> syn::Resul
                     vec![elem_type],
                 ));
             }
-            "HashMap" | "BTreeMap" => {
+            "HashMap" | "BTreeMap" | "IndexMap" => {
                 let (key_ty, value_ty) = two_type_args(args, ty, &name)?;
                 let key_type = nested_field_type_expr(key_ty)?;
                 let value_type = nested_field_type_expr(value_ty)?;
@@ -1303,7 +1536,10 @@ fn serializer_field_markers(field: &syn::Field) -> (bool, bool) {
 }
 
 fn is_serializer_backed_collection(name: &str) -> bool {
-    matches!(name, "VecDeque" | "LinkedList" | "BTreeSet" | "BinaryHeap")
+    matches!(
+        name,
+        "VecDeque" | "LinkedList" | "BTreeSet" | "BinaryHeap" | "SmallVec"
+    )
 }
 
 fn primitive_array_type_id_for_vec_element(ty: &Type) -> syn::Result<TokenStream> {
@@ -1337,7 +1573,7 @@ fn primitive_array_type_id_for_vec_element(ty: &Type) -> syn::Result<TokenStream
 
 fn serializer_backed_collection_type_id(name: &str) -> TokenStream {
     match name {
-        "BTreeSet" | "BinaryHeap" => quote! { { ::fory_core::type_id::TypeId::SET as u8 } },
+        "BTreeSet" => quote! { { ::fory_core::type_id::TypeId::SET as u8 } },
         _ => quote! { { ::fory_core::type_id::TypeId::LIST as u8 } },
     }
 }
@@ -1381,7 +1617,7 @@ fn validate_serializer_backed_collection_meta(
 }
 
 fn is_serializer_backed_map(name: &str) -> bool {
-    matches!(name, "BTreeMap")
+    matches!(name, "BTreeMap" | "IndexMap")
 }
 
 fn validate_serializer_backed_map_meta(
@@ -1453,6 +1689,23 @@ fn is_primitive_array_type(ty: &Type) -> bool {
     ::fory_core::type_id::PRIMITIVE_ARRAY_TYPES.contains(&get_type_id_by_type_ast(ty))
 }
 
+/// Default given by the field attributes. External types can't implement `ForyDefault`,
+/// so `#[fory(external)]` fields fall back to `Default`.
+fn explicit_default(meta: &ForyFieldMeta) -> Option<TokenStream> {
+    meta.default.clone().or_else(|| {
+        meta.external
+            .then(|| quote! { ::std::default::Default::default() })
+    })
+}
+
+/// Value a field of a fresh `ForyDefault` instance holds.
+pub(crate) fn default_expr_for_field(field: &syn::Field) -> TokenStream {
+    match parse_field_meta(field) {
+        Ok(meta) if meta.external => quote! { ::std::default::Default::default() },
+        _ => default_expr_for_type(&field.ty),
+    }
+}
+
 pub(crate) fn default_expr_for_type(ty: &Type) -> TokenStream {
     if let Some((_, trait_name)) = is_rc_dyn_trait(ty) {
         let wrapper_ty = format_ident!("{}Rc", trait_name);
@@ -21,12 +21,17 @@
 //! - `id = N`: Non-negative field tag ID for compact encoding
 //! - `nullable`: Whether the field can be null (default: false, except Option/RcWeak/ArcWeak)
 //! - `ref`: Whether to enable reference tracking (default: false, except Rc/Arc/RcWeak/ArcWeak)
-//! - `skip`: Skip this field during serialization
+//! - `skip`: Skip this field during serialization (implied for `PhantomData` and
+//!   `PresentFields` fields)
+//! - `rename = "name"`: Field name used in type metadata instead of the Rust identifier
+//! - `default = expr`: Value used when the field is absent from the incoming schema or skipped
 //! - `encoding`: Integer wire encoding, one of `varint`, `fixed`, or `tagged`
 //! - `list(element(...))`: Nested list element configuration
 //! - `array`: Dense numeric/vector array schema for `Vec<T>`
 //! - `bytes`: Binary blob schema for `Vec<u8>`
 //! - `map(key(...), value(...))`: Nested map key/value configuration
+//! - `external`: Serialize through closures registered with `Fory::register_external`
+//! - `encrypt`: Write the field as AES-GCM ciphertext with the key set by `ForyBuilder::cipher`
 
 use quote::ToTokens;
 use std::collections::HashMap;
@@ -44,6 +49,10 @@ pub struct ForyFieldMeta {
     pub r#ref: Option<bool>,
     /// Whether to skip this field entirely
     pub skip: bool,
+    /// Field name used in type metadata instead of the Rust identifier.
+    pub rename: Option<String>,
+    /// Expression used instead of `ForyDefault` when the field is not read.
+    pub default: Option<proc_macro2::TokenStream>,
     /// Integer wire encoding selected by semantic field config.
     pub encoding: Option<IntEncoding>,
     /// Nested list element configuration.
@@ -54,6 +63,10 @@ pub struct ForyFieldMeta {
     pub bytes: bool,
     /// Nested map key/value configuration.
     pub map: Option<ForyMapMeta>,
+    /// Serialize through closures registered with `Fory::register_external`.
+    pub external: bool,
+    /// Write the field as AES-GCM ciphertext with the key set by `ForyBuilder::cipher`.
+    pub encrypt: bool,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -171,6 +184,19 @@ pub fn parse_field_meta(field: &Field) -> syn::Result<ForyFieldMeta> {
         attr.parse_nested_meta(|nested| parse_meta_item(&mut meta, nested, true))?;
     }
 
+    if meta.rename.is_some() && field.ident.is_none() {
+        return Err(syn::Error::new(
+            field.span(),
+            "rename is only valid on named struct fields",
+        ));
+    }
+
+    // `PhantomData` carries no data, so it never takes a field slot on the wire.
+    // `PresentFields` describes the payload a value was read from rather than the value.
+    if is_phantom_data_type(&field.ty) || is_present_fields_type(&field.ty) {
+        meta.skip = true;
+    }
+
     Ok(meta)
 }
 
@@ -221,6 +247,38 @@ fn parse_meta_item(
             return Err(syn::Error::new(nested.path.span(), "duplicate skip config"));
         }
         meta.skip = true;
+    } else if nested.path.is_ident("rename") {
+        if !allow_field_keys {
+            return Err(syn::Error::new(
+                nested.path.span(),
+                "rename is only valid on a struct field, not inside nested list/map config",
+            ));
+        }
+        let lit: syn::LitStr = nested.value()?.parse()?;
+        if lit.value().is_empty() {
+            return Err(syn::Error::new(lit.span(), "rename must not be empty"));
+        }
+        if meta.rename.is_some() {
+            return Err(syn::Error::new(
+                nested.path.span(),
+                "duplicate rename config",
+            ));
+        }
+        meta.rename = Some(lit.value());
+    } else if nested.path.is_ident("default") {
+        if !allow_field_keys {
+            return Err(syn::Error::new(
+                nested.path.span(),
+                "default is only valid on a struct field, not inside nested list/map config",
+            ));
+        }
+        if meta.default.is_some() {
+            return Err(syn::Error::new(
+                nested.path.span(),
+                "duplicate default config",
+            ));
+        }
+        meta.default = Some(parse_default_value(&nested)?);
     } else if nested.path.is_ident("encoding") {
         let encoding = parse_encoding_value(&nested)?;
         if meta.encoding.is_some() {
@@ -263,6 +321,46 @@ fn parse_meta_item(
             ));
         }
         meta.bytes = true;
+    } else if nested.path.is_ident("external") {
+        if !allow_field_keys {
+            return Err(syn::Error::new(
+                nested.path.span(),
+                "external is only valid on a struct field, not inside nested list/map config",
+            ));
+        }
+        if meta.external {
+            return Err(syn::Error::new(
+                nested.path.span(),
+                "duplicate external config",
+            ));
+        }
+        if !nested.input.is_empty() && !nested.input.peek(syn::Token![,]) {
+            return Err(syn::Error::new(
+                nested.path.span(),
+                "external does not accept parameters; use #[fory(external)]",
+            ));
+        }
+        meta.external = true;
+    } else if nested.path.is_ident("encrypt") {
+        if !allow_field_keys {
+            return Err(syn::Error::new(
+                nested.path.span(),
+                "encrypt is only valid on a struct field, not inside nested list/map config",
+            ));
+        }
+        if meta.encrypt {
+            return Err(syn::Error::new(
+                nested.path.span(),
+                "duplicate encrypt config",
+            ));
+        }
+        if !nested.input.is_empty() && !nested.input.peek(syn::Token![,]) {
+            return Err(syn::Error::new(
+                nested.path.span(),
+                "encrypt does not accept parameters; use #[fory(encrypt)]",
+            ));
+        }
+        meta.encrypt = true;
     } else if nested.path.is_ident("map") {
         if meta.map.is_some() {
             return Err(syn::Error::new(nested.path.span(), "duplicate map config"));
@@ -372,6 +470,19 @@ fn parse_encoding_name(value: &str, span: proc_macro2::Span) -> syn::Result<IntE
     }
 }
 
+/// Parse `default = expr` or `default = "expr"` into the expression tokens
+fn parse_default_value(meta: &syn::meta::ParseNestedMeta) -> syn::Result<proc_macro2::TokenStream> {
+    let value = meta.value()?;
+    if value.peek(syn::LitStr) {
+        let lit: syn::LitStr = value.parse()?;
+        let expr: syn::Expr = lit.parse()?;
+        Ok(expr.to_token_stream())
+    } else {
+        let expr: syn::Expr = value.parse()?;
+        Ok(expr.to_token_stream())
+    }
+}
+
 /// Parse a boolean value or treat standalone flag as true
 fn parse_bool_or_flag(meta: &syn::meta::ParseNestedMeta) -> syn::Result<bool> {
     if meta.input.is_empty() || meta.input.peek(syn::Token![,]) {
@@ -447,13 +558,43 @@ pub fn is_option_type(ty: &Type) -> bool {
     extract_outer_type_name(ty) == "Option"
 }
 
+/// Returns true if the outer type is `PhantomData`
+pub fn is_phantom_data_type(ty: &Type) -> bool {
+    extract_outer_type_name(ty) == "PhantomData"
+}
+
+/// Returns true if the outer type is `PresentFields`
+pub fn is_present_fields_type(ty: &Type) -> bool {
+    extract_outer_type_name(ty) == "PresentFields"
+}
+
+/// Returns true for `Arc<str>` and `Arc<[u8]>`
+fn is_arc_str_or_bytes(ty: &Type) -> bool {
+    let Type::Path(type_path) = ty else {
+        return false;
+    };
+    let Some(seg) = type_path.path.segments.last() else {
+        return false;
+    };
+    let PathArguments::AngleBracketed(args) = &seg.arguments else {
+        return false;
+    };
+    match args.args.first() {
+        Some(GenericArgument::Type(Type::Path(inner))) => inner.path.is_ident("str"),
+        Some(GenericArgument::Type(Type::Slice(slice))) => {
+            matches!(&*slice.elem, Type::Path(elem) if elem.path.is_ident("u8"))
+        }
+        _ => false,
+    }
+}
+
 /// Classify a field type to determine default nullable/ref behavior
 pub fn classify_field_type(ty: &Type) -> FieldTypeClass {
     let type_name = extract_outer_type_name(ty);
     match type_name.as_str() {
         // Primitives
         "i8" | "i16" | "i32" | "i64" | "i128" | "isize" | "u8" | "u16" | "u32" | "u64" | "u128"
-        | "usize" | "f32" | "f64" | "bool" => FieldTypeClass::Primitive,
+        | "usize" | "f32" | "f64" | "bool" | "char" => FieldTypeClass::Primitive,
 
         // Option<T>
         "Option" => {
@@ -475,6 +616,8 @@ pub fn classify_field_type(ty: &Type) -> FieldTypeClass {
 
         // Shared ownership types (std library)
         "Rc" => FieldTypeClass::Rc,
+        // `Arc<str>` and `Arc<[u8]>` are plain string and binary values.
+        "Arc" if is_arc_str_or_bytes(ty) => FieldTypeClass::Other,
         "Arc" => FieldTypeClass::Arc,
 
         // Fory's weak reference types (nullable AND ref tracking by default)
@@ -568,6 +711,60 @@ mod tests {
         assert_eq!(meta.r#ref, Some(false));
     }
 
+    #[test]
+    fn test_parse_rename() {
+        let field: Field = parse_quote! {
+            #[fory(rename = "userName", nullable)]
+            user_name: Option<String>
+        };
+        let meta = parse_field_meta(&field).unwrap();
+        assert_eq!(meta.rename.as_deref(), Some("userName"));
+        assert_eq!(meta.nullable, Some(true));
+
+        let field: Field = parse_quote! {
+            #[fory(rename = "")]
+            user_name: String
+        };
+        let err = parse_field_meta(&field).unwrap_err();
+        assert!(err.to_string().contains("rename must not be empty"));
+
+        let field: Field = parse_quote! {
+            #[fory(list(element(rename = "item")))]
+            values: Vec<i32>
+        };
+        let err = parse_field_meta(&field).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("rename is only valid on a struct field"));
+    }
+
+    #[test]
+    fn test_parse_default() {
+        let field: Field = parse_quote! {
+            #[fory(default = -1)]
+            version: i32
+        };
+        let meta = parse_field_meta(&field).unwrap();
+        assert_eq!(meta.default.unwrap().to_string(), "- 1");
+
+        let field: Field = parse_quote! {
+            #[fory(default = "String::from(\"unknown\")")]
+            name: String
+        };
+        let meta = parse_field_meta(&field).unwrap();
+        assert_eq!(
+            meta.default.unwrap().to_string(),
+            "String :: from (\"unknown\")"
+        );
+
+        let field: Field = parse_quote! {
+            #[fory(default = 1, default = 2)]
+            version: i32
+        };
+        let err = parse_field_meta(&field).unwrap_err();
+        assert!(err.to_string().contains("duplicate default config"));
+    }
+
     #[test]
     fn test_parse_array_attribute() {
         let field: Field = parse_quote! {
@@ -610,6 +807,15 @@ mod tests {
         assert!(meta.skip);
     }
 
+    #[test]
+    fn test_phantom_data_is_skipped() {
+        let field: Field = parse_quote! {
+            _marker: std::marker::PhantomData<T>
+        };
+        let meta = parse_field_meta(&field).unwrap();
+        assert!(meta.skip);
+    }
+
     #[test]
     fn test_validate_duplicate_ids() {
         let field1: Field = parse_quote! {
@@ -656,6 +862,15 @@ mod tests {
         let field: Field = parse_quote! { x: Arc<Vec<u8>> };
         assert_eq!(classify_field_type(&field.ty), FieldTypeClass::Arc);
 
+        let field: Field = parse_quote! { x: Arc<str> };
+        assert_eq!(classify_field_type(&field.ty), FieldTypeClass::Other);
+
+        let field: Field = parse_quote! { x: std::sync::Arc<[u8]> };
+        assert_eq!(classify_field_type(&field.ty), FieldTypeClass::Other);
+
+        let field: Field = parse_quote! { x: Option<Arc<str>> };
+        assert_eq!(classify_field_type(&field.ty), FieldTypeClass::Option);
+
         let field: Field = parse_quote! { x: RcWeak<String> };
         assert_eq!(classify_field_type(&field.ty), FieldTypeClass::RcWeak);
 
@@ -725,11 +940,15 @@ mod tests {
             nullable: Some(true),
             r#ref: None,
             skip: false,
+            rename: None,
+            default: None,
             encoding: None,
             list: None,
             array: false,
             bytes: false,
             map: None,
+            external: false,
+            encrypt: false,
         };
         assert!(meta.effective_nullable(FieldTypeClass::Primitive)); // Would be false by default
 
@@ -739,11 +958,15 @@ mod tests {
             nullable: None,
             r#ref: Some(false),
             skip: false,
+            rename: None,
+            default: None,
             encoding: None,
             list: None,
             array: false,
             bytes: false,
             map: None,
+            external: false,
+            encrypt: false,
         };
         assert!(!meta.effective_ref(FieldTypeClass::Rc)); // Would be true by default
     }
@@ -791,6 +1014,61 @@ mod tests {
         assert_eq!(meta.encoding, Some(IntEncoding::Fixed));
     }
 
+    #[test]
+    fn test_parse_external_attribute() {
+        let field: Field = parse_quote! {
+            #[fory(external)]
+            value: geo::Point
+        };
+        assert!(parse_field_meta(&field).unwrap().external);
+
+        let field: Field = parse_quote! {
+            #[fory(external = true)]
+            value: geo::Point
+        };
+        let err = parse_field_meta(&field).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("external does not accept parameters"));
+    }
+
+    #[test]
+    fn test_parse_external_rejects_nested_config() {
+        let field: Field = parse_quote! {
+            #[fory(list(element(external)))]
+            points: Vec<geo::Point>
+        };
+        let err = parse_field_meta(&field).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("external is only valid on a struct field"));
+
+        let field: Field = parse_quote! {
+            #[fory(map(key(external)))]
+            points: HashMap<geo::Point, i32>
+        };
+        let err = parse_field_meta(&field).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("external is only valid on a struct field"));
+    }
+
+    #[test]
+    fn test_parse_encrypt_attribute() {
+        let field: Field = parse_quote! {
+            #[fory(encrypt)]
+            ssn: String
+        };
+        assert!(parse_field_meta(&field).unwrap().encrypt);
+
+        let field: Field = parse_quote! {
+            #[fory(encrypt, encrypt)]
+            ssn: String
+        };
+        let err = parse_field_meta(&field).unwrap_err();
+        assert!(err.to_string().contains("duplicate encrypt config"));
+    }
+
     #[test]
     fn test_removed_type_id_attribute() {
         let field: Field = parse_quote! {
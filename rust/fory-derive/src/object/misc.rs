@@ -72,6 +72,32 @@ pub fn gen_actual_type_id_no_evolving() -> TokenStream {
     }
 }
 
+/// Generates the body of `fory_meta_field_name`, which maps the Rust name of each named
+/// field to its type metadata name where the two differ.
+pub fn gen_meta_field_name(source_fields: &[SourceField<'_>]) -> TokenStream {
+    let arms: Vec<_> = source_fields
+        .iter()
+        .filter_map(|sf| {
+            let ident = sf.field.ident.as_ref()?.to_string();
+            let meta_name = super::util::get_meta_field_name(sf.field, sf.original_index);
+            (ident != meta_name)
+                .then(|| quote! { #ident => ::std::option::Option::Some(#meta_name), })
+        })
+        .collect();
+    if arms.is_empty() {
+        return quote! {
+            let _ = field;
+            ::std::option::Option::None
+        };
+    }
+    quote! {
+        match field {
+            #(#arms)*
+            _ => ::std::option::Option::None,
+        }
+    }
+}
+
 pub fn gen_get_sorted_field_names(fields: &[&Field]) -> TokenStream {
     let static_field_names = get_sort_fields_ts(fields);
     quote! {
@@ -89,11 +89,28 @@ fn get_source_fields_loop_ts(source_fields: &[SourceField<'_>]) -> TokenStream {
         Ok(bindings) => bindings,
         Err(err) => return err.to_compile_error(),
     };
+    let mut field_index = 0usize;
     let read_fields_ts: Vec<_> = bindings
         .iter()
         .map(|binding| match binding {
             FieldBinding::Codec(binding) => {
+                let index = field_index;
+                field_index += 1;
                 let base = binding.read_field();
+                let base = if binding.is_sparse_at(index) {
+                    let var = &binding.private_ident;
+                    let ty = binding.value_ty;
+                    quote! {
+                        let #var: #ty = if presence.is_present(#index) {
+                            #base
+                            #var
+                        } else {
+                            ::std::option::Option::None
+                        };
+                    }
+                } else {
+                    base
+                };
                 if is_debug_enabled() {
                     let struct_name = get_struct_name().expect("struct context not set");
                     let struct_name_lit =
@@ -129,6 +146,29 @@ fn get_source_fields_loop_ts(source_fields: &[SourceField<'_>]) -> TokenStream {
     }
 }
 
+/// Reads the presence bitmap of a same-schema payload. It is consumed even when no local
+/// field can be absent, since sparse payloads carry one for every struct.
+fn gen_read_local_presence(source_fields: &[SourceField<'_>]) -> TokenStream {
+    let has_sparse_fields = build_bindings(source_fields).is_ok_and(|bindings| {
+        bindings
+            .iter()
+            .filter_map(|binding| match binding {
+                FieldBinding::Codec(binding) => Some(binding),
+                FieldBinding::Skipped(_) => None,
+            })
+            .enumerate()
+            .any(|(index, binding)| binding.is_sparse_at(index))
+    });
+    let read = quote! {
+        ::fory_core::serializer::struct_::read_local_field_presence(context)?
+    };
+    if has_sparse_fields {
+        quote! { let presence = #read; }
+    } else {
+        quote! { #read; }
+    }
+}
+
 pub fn gen_read_data(source_fields: &[SourceField<'_>]) -> TokenStream {
     let fields: Vec<&Field> = source_fields.iter().map(|sf| sf.field).collect();
     // Generate runtime version hash computation that detects enum fields
@@ -163,6 +203,7 @@ pub fn gen_read_data(source_fields: &[SourceField<'_>]) -> TokenStream {
 
     let field_inits: Vec<_> = indexed.into_iter().map(|(_, ts)| ts).collect();
     let self_construction = crate::util::ok_self_construction(is_tuple, &field_inits);
+    let read_presence = gen_read_local_presence(source_fields);
 
     quote! {
         // Read and check version hash when class version checking is enabled
@@ -172,7 +213,10 @@ pub fn gen_read_data(source_fields: &[SourceField<'_>]) -> TokenStream {
             let local_version: i32 = #version_hash_ts;
             ::fory_core::meta::TypeMeta::check_struct_version(read_version, local_version, type_name)?;
         }
+        #read_presence
+        context.inc_nesting_depth()?;
         #read_fields
+        context.dec_nesting_depth();
         #self_construction
     }
 }
@@ -197,7 +241,7 @@ pub fn gen_read(_struct_ident: &Ident) -> TokenStream {
             }
             if context.is_compatible() {
                 let type_info = if read_type_info {
-                    context.read_any_type_info()?
+                    ::fory_core::serializer::struct_::read_struct_type_info(context)?
                 } else {
                     let rs_type_id = ::std::any::TypeId::of::<Self>();
                     context.get_type_info(&rs_type_id)?
@@ -217,6 +261,136 @@ pub fn gen_read(_struct_ident: &Ident) -> TokenStream {
     }
 }
 
+/// Generates `fory_read_into` and `fory_read_data_into`, which read a struct in place so
+/// that fields owning buffers keep their allocations across reads.
+pub fn gen_read_into(source_fields: &[SourceField<'_>]) -> TokenStream {
+    let fields: Vec<&Field> = source_fields.iter().map(|sf| sf.field).collect();
+    let version_hash_ts = gen_struct_version_hash_ts(&fields);
+    let bindings = match build_bindings(source_fields) {
+        Ok(bindings) => bindings,
+        Err(err) => return err.to_compile_error(),
+    };
+    let mut field_index = 0usize;
+    let read_fields_ts: Vec<_> = bindings
+        .iter()
+        .map(|binding| match binding {
+            FieldBinding::Codec(binding) => {
+                let index = field_index;
+                field_index += 1;
+                let base = binding.read_field_into();
+                let base = if binding.is_sparse_at(index) {
+                    let access = super::util::get_field_accessor(
+                        binding.source.field,
+                        binding.source.original_index,
+                        true,
+                    );
+                    quote! {
+                        if presence.is_present(#index) {
+                            #base
+                        } else {
+                            #access = ::std::option::Option::None;
+                        }
+                    }
+                } else {
+                    base
+                };
+                if is_debug_enabled() {
+                    let struct_name = get_struct_name().expect("struct context not set");
+                    let struct_name_lit =
+                        syn::LitStr::new(&struct_name, proc_macro2::Span::call_site());
+                    let field_name_lit = syn::LitStr::new(
+                        &binding.source.field_name,
+                        proc_macro2::Span::call_site(),
+                    );
+                    let access = super::util::get_field_accessor(
+                        binding.source.field,
+                        binding.source.original_index,
+                        true,
+                    );
+                    quote! {
+                        ::fory_core::serializer::struct_::struct_before_read_field(
+                            #struct_name_lit,
+                            #field_name_lit,
+                            context,
+                        );
+                        #base
+                        ::fory_core::serializer::struct_::struct_after_read_field(
+                            #struct_name_lit,
+                            #field_name_lit,
+                            (&#access) as &dyn ::std::any::Any,
+                            context,
+                        );
+                    }
+                } else {
+                    base
+                }
+            }
+            FieldBinding::Skipped(binding) => binding.reset_field(),
+        })
+        .collect();
+    let read_presence = gen_read_local_presence(source_fields);
+
+    quote! {
+        #[inline]
+        fn fory_read_data_into(&mut self, context: &mut ::fory_core::ReadContext) -> ::std::result::Result<(), ::fory_core::error::Error> {
+            if context.is_check_struct_version() {
+                let read_version = context.reader.read_i32()?;
+                let type_name = ::std::any::type_name::<Self>();
+                let local_version: i32 = #version_hash_ts;
+                ::fory_core::meta::TypeMeta::check_struct_version(read_version, local_version, type_name)?;
+            }
+            #read_presence
+            context.inc_nesting_depth()?;
+            #(#read_fields_ts)*
+            context.dec_nesting_depth();
+            ::std::result::Result::Ok(())
+        }
+
+        #[inline]
+        fn fory_read_into(&mut self, context: &mut ::fory_core::ReadContext, ref_mode: ::fory_core::RefMode, read_type_info: bool) -> ::std::result::Result<(), ::fory_core::error::Error> {
+            let ref_flag = if ref_mode != ::fory_core::RefMode::None {
+                context.reader.read_i8()?
+            } else {
+                ::fory_core::RefFlag::NotNullValue as i8
+            };
+            if ref_flag == (::fory_core::RefFlag::NotNullValue as i8) || ref_flag == (::fory_core::RefFlag::RefValue as i8) {
+                if ref_flag == (::fory_core::RefFlag::RefValue as i8) && ref_mode == ::fory_core::RefMode::Tracking {
+                    context.ref_reader.reserve_ref_id();
+                }
+                if context.is_compatible() {
+                    let type_info = if read_type_info {
+                        ::fory_core::serializer::struct_::read_struct_type_info(context)?
+                    } else {
+                        let rs_type_id = ::std::any::TypeId::of::<Self>();
+                        context.get_type_info(&rs_type_id)?
+                    };
+                    let local_type_hash = context.get_type_resolver().get_type_meta_by_index_ref(
+                        &::std::any::TypeId::of::<Self>(),
+                        <Self as ::fory_core::StructSerializer>::fory_type_index(),
+                    )?.get_hash();
+                    if type_info.get_type_meta_ref().get_hash() == local_type_hash {
+                        // Same schema: the payload is laid out exactly like the local fields.
+                        self.fory_read_data_into(context)
+                    } else {
+                        *self = <Self as ::fory_core::StructSerializer>::fory_read_compatible(context, type_info)?;
+                        ::std::result::Result::Ok(())
+                    }
+                } else {
+                    if read_type_info {
+                        <Self as ::fory_core::Serializer>::fory_read_type_info(context)?;
+                    }
+                    self.fory_read_data_into(context)
+                }
+            } else if ref_flag == (::fory_core::RefFlag::Null as i8) {
+                *self = <Self as ::fory_core::ForyDefault>::fory_default();
+                ::std::result::Result::Ok(())
+            } else {
+                Err(::fory_core::error::Error::invalid_ref(format!("Unknown ref flag, value:{ref_flag}")))
+            }
+        }
+    }
+}
+
 pub fn gen_read_with_type_info() -> TokenStream {
     // fn fory_read_with_type_info(
     //     context: &mut ReadContext,
@@ -244,13 +418,18 @@ pub fn gen_read_with_type_info() -> TokenStream {
     }
 }
 
-pub fn gen_read_compatible(source_fields: &[SourceField<'_>]) -> TokenStream {
-    gen_read_compatible_with_construction(source_fields, None)
+/// `presence_field` is the `PresentFields` field of a `#[fory(track_presence)]` struct.
+pub fn gen_read_compatible(
+    source_fields: &[SourceField<'_>],
+    presence_field: Option<&SourceField<'_>>,
+) -> TokenStream {
+    gen_read_compatible_with_construction(source_fields, None, presence_field)
 }
 
 pub(crate) fn gen_read_compatible_with_construction(
     source_fields: &[SourceField<'_>],
     variant_ident: Option<&Ident>,
+    presence_field: Option<&SourceField<'_>>,
 ) -> TokenStream {
     let bindings = match build_bindings(source_fields) {
         Ok(bindings) => bindings,
@@ -329,6 +508,25 @@ pub(crate) fn gen_read_compatible_with_construction(
             [direct_arm, compatible_arm]
         })
         .collect();
+    let absent_arms: Vec<TokenStream> = bindings
+        .iter()
+        .filter_map(|binding| match binding {
+            FieldBinding::Codec(binding) => Some(binding),
+            FieldBinding::Skipped(_) => None,
+        })
+        .enumerate()
+        .filter(|(_, binding)| binding.is_sparse())
+        .map(|(sorted_idx, binding)| {
+            let direct_field_id = (sorted_idx * 2) as i16;
+            let compatible_field_id = (sorted_idx * 2 + 1) as i16;
+            let var = &binding.private_ident;
+            quote! {
+                #direct_field_id | #compatible_field_id => {
+                    #var = ::std::option::Option::None;
+                }
+            }
+        })
+        .collect();
     let skip_arm = if is_debug_enabled() {
         let struct_name = get_struct_name().expect("struct context not set");
         let struct_name_lit = syn::LitStr::new(&struct_name, proc_macro2::Span::call_site());
@@ -445,6 +643,7 @@ pub(crate) fn gen_read_compatible_with_construction(
         }
     } else {
         quote! {
+            let projection = context.take_projection();
             let meta = context.get_type_resolver().get_type_meta_by_index_ref(
                 &::std::any::TypeId::of::<Self>(),
                 <Self as ::fory_core::StructSerializer>::fory_type_index(),
@@ -452,23 +651,93 @@ pub(crate) fn gen_read_compatible_with_construction(
             let local_type_hash = meta.get_hash();
             let remote_meta = type_info.get_type_meta_ref();
             let remote_type_hash = remote_meta.get_hash();
-            if remote_type_hash == local_type_hash {
+            if remote_type_hash == local_type_hash && projection.is_none() {
                 return <Self as ::fory_core::Serializer>::fory_read_data(context);
             }
             #fields_binding
+            let projected = ::fory_core::serializer::struct_::project_fields(
+                projection,
+                meta.get_field_infos(),
+                fields,
+                remote_type_hash == local_type_hash,
+            )?;
+            let presence = ::fory_core::serializer::struct_::read_field_presence(context, fields)?;
+            context.inc_nesting_depth()?;
+        }
+    };
+    // Enum variants never carry a presence bitmap.
+    let absent_check = if variant_ident.is_some() {
+        quote! {}
+    } else if absent_arms.is_empty() {
+        quote! {
+            if !presence.is_present(_field_index) {
+                continue;
+            }
         }
+    } else {
+        quote! {
+            if !presence.is_present(_field_index) {
+                match _field.field_id {
+                    #(#absent_arms)*
+                    _ => {}
+                }
+                continue;
+            }
+        }
+    };
+    // Projections only apply to root structs, never to enum variants.
+    let field_id = if variant_ident.is_some() {
+        quote! { let _field_id = _field.field_id; }
+    } else {
+        quote! {
+            let _field_id = match &projected {
+                Some(ids) => ids[_field_index],
+                None => _field.field_id,
+            };
+        }
+    };
+    let record_presence = match presence_field {
+        Some(presence_field) => {
+            let names: Vec<String> = bindings
+                .iter()
+                .filter_map(|binding| match binding {
+                    FieldBinding::Codec(binding) => Some(match &binding.source.field.ident {
+                        Some(ident) => ident.to_string(),
+                        None => binding.source.original_index.to_string(),
+                    }),
+                    FieldBinding::Skipped(_) => None,
+                })
+                .collect();
+            let var =
+                create_private_field_name(presence_field.field, presence_field.original_index);
+            let ty = &presence_field.field.ty;
+            quote! {
+                let #var: #ty = ::fory_core::PresentFields::from_remote(&[#(#names),*], fields);
+            }
+        }
+        None => quote! {},
+    };
+    // Variant fields are counted by the enclosing enum read.
+    let leave_nesting = if variant_ident.is_some() {
+        quote! {}
+    } else {
+        quote! { context.dec_nesting_depth(); }
     };
 
     quote! {
         #schema_setup
         #(#declare_ts)*
-        for _field in fields.iter() {
-            match _field.field_id {
+        for (_field_index, _field) in fields.iter().enumerate() {
+            #absent_check
+            #field_id
+            match _field_id {
                 #(#match_arms)*
                 #skip_arm
                 #invalid_arm
             }
         }
+        #leave_nesting
+        #record_presence
         #construction
     }
 }
@@ -15,6 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use crate::object::field_meta::is_present_fields_type;
 use crate::object::util::is_default_value_variant;
 use crate::object::{derive_enum, misc, read, write};
 use crate::util::{extract_fields, source_fields};
@@ -39,6 +40,48 @@ fn has_existing_default(ast: &syn::DeriveInput, trait_name: &str) -> bool {
 }
 
 pub fn derive_serializer(ast: &syn::DeriveInput, attrs: ForyAttrs) -> TokenStream {
+    if let Err(err) = crate::object::util::validate_field_types(ast) {
+        return err.into_compile_error().into();
+    }
+    if let Err(err) = crate::object::util::validate_field_names(ast) {
+        return err.into_compile_error().into();
+    }
+    if attrs.repr_name {
+        let is_unit_enum = matches!(&ast.data, syn::Data::Enum(e)
+            if e.variants.iter().all(|v| matches!(v.fields, syn::Fields::Unit)));
+        if !is_unit_enum {
+            return syn::Error::new(
+                ast.ident.span(),
+                "`#[fory(repr = \"name\")]` is only supported on fieldless enums",
+            )
+            .into_compile_error()
+            .into();
+        }
+    }
+    let ordinals = match &ast.data {
+        syn::Data::Enum(e) => match derive_enum::variant_ordinals(e, attrs.stable_ordinals) {
+            Ok(ordinals) => ordinals,
+            Err(err) => return err.into_compile_error().into(),
+        },
+        _ if attrs.stable_ordinals => {
+            return syn::Error::new(
+                ast.ident.span(),
+                "`#[fory(stable_ordinals)]` is only supported on enums",
+            )
+            .into_compile_error()
+            .into();
+        }
+        _ => Vec::new(),
+    };
+    let version_ts = match gen_version(ast, &attrs) {
+        Ok(version_ts) => version_ts,
+        Err(err) => return err.into_compile_error().into(),
+    };
+    let presence_impl = match gen_presence_impl(ast, attrs.track_presence) {
+        Ok(presence_impl) => presence_impl,
+        Err(err) => return err.into_compile_error().into(),
+    };
+    let ast = &crate::object::util::with_type_param_bounds(ast);
     let name = &ast.ident;
     let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
 
@@ -59,28 +102,40 @@ pub fn derive_serializer(ast: &syn::DeriveInput, attrs: ForyAttrs) -> TokenStrea
     let (
         actual_type_id_ts,
         get_sorted_field_names_ts,
+        meta_field_name_ts,
         fields_info_ts,
         variants_fields_info_ts,
         read_compatible_ts,
         read_compatible_as_send_sync_any_ts,
         enum_variant_meta_types,
+        struct_hash_ts,
     ) = match &ast.data {
         syn::Data::Struct(s) => {
             let source_fields = source_fields(&s.fields);
+            let presence_field = if attrs.track_presence {
+                source_fields
+                    .iter()
+                    .find(|sf| is_present_fields_type(&sf.field.ty))
+            } else {
+                None
+            };
             let fields = extract_fields(&source_fields);
             let actual_type_id_ts = if attrs.evolving == Some(false) {
                 misc::gen_actual_type_id_no_evolving()
             } else {
                 misc::gen_actual_type_id()
             };
+            let struct_hash = crate::object::util::compute_struct_version_hash(&fields);
             (
                 actual_type_id_ts,
                 misc::gen_get_sorted_field_names(&fields),
+                misc::gen_meta_field_name(&source_fields),
                 misc::gen_field_fields_info(&source_fields),
                 quote! { ::std::result::Result::Ok(::std::vec::Vec::new()) }, // No variants for structs
-                read::gen_read_compatible(&source_fields),
+                read::gen_read_compatible(&source_fields, presence_field),
                 send_sync_tokens.struct_read_compatible.clone(),
                 vec![], // No variant meta types for structs
+                quote! { #struct_hash },
             )
         }
         syn::Data::Enum(s) => {
@@ -90,6 +145,10 @@ pub fn derive_serializer(ast: &syn::DeriveInput, attrs: ForyAttrs) -> TokenStrea
             (
                 derive_enum::gen_actual_type_id(s),
                 quote! { &[] },
+                quote! {
+                    let _ = field;
+                    ::std::option::Option::None
+                },
                 derive_enum::gen_field_fields_info(s),
                 derive_enum::gen_variants_fields_info(name, s),
                 quote! {
@@ -98,6 +157,7 @@ pub fn derive_serializer(ast: &syn::DeriveInput, attrs: ForyAttrs) -> TokenStrea
                 },
                 quote! {},
                 variant_meta_types,
+                quote! { 0 },
             )
         }
         syn::Data::Union(_) => {
@@ -112,8 +172,10 @@ pub fn derive_serializer(ast: &syn::DeriveInput, attrs: ForyAttrs) -> TokenStrea
         read_ts,
         read_with_type_info_ts,
         read_data_ts,
+        read_into_ts,
         read_type_info_ts,
         reserved_space_ts,
+        estimate_size_ts,
         static_type_id_ts,
     ) = match &ast.data {
         syn::Data::Struct(s) => {
@@ -125,20 +187,32 @@ pub fn derive_serializer(ast: &syn::DeriveInput, attrs: ForyAttrs) -> TokenStrea
                 read::gen_read(name),
                 read::gen_read_with_type_info(),
                 read::gen_read_data(&source_fields),
+                read::gen_read_into(&source_fields),
                 read::gen_read_type_info(),
                 write::gen_reserved_space(&source_fields),
+                write::gen_estimate_size(&source_fields),
                 quote! { ::fory_core::TypeId::STRUCT },
             )
         }
         syn::Data::Enum(e) => (
             derive_enum::gen_write(e),
-            derive_enum::gen_write_data(e),
+            if attrs.repr_name {
+                derive_enum::gen_write_data_by_name(e, &ordinals)
+            } else {
+                derive_enum::gen_write_data(e, &ordinals)
+            },
             derive_enum::gen_write_type_info(e),
             derive_enum::gen_read(e),
             derive_enum::gen_read_with_type_info(e),
-            derive_enum::gen_read_data(e),
+            if attrs.repr_name {
+                derive_enum::gen_read_data_by_name(e, &ordinals)
+            } else {
+                derive_enum::gen_read_data(e, &ordinals)
+            },
+            quote! {},
             derive_enum::gen_read_type_info(e),
             derive_enum::gen_reserved_space(),
+            quote! {},
             derive_enum::gen_static_type_id(e),
         ),
         syn::Data::Union(_) => {
@@ -157,6 +231,8 @@ pub fn derive_serializer(ast: &syn::DeriveInput, attrs: ForyAttrs) -> TokenStrea
 
         #default_impl
 
+        #presence_impl
+
         impl #impl_generics ::fory_core::StructSerializer for #name #ty_generics #where_clause {
             #[inline(always)]
             fn fory_type_index() -> u32 {
@@ -172,6 +248,15 @@ pub fn derive_serializer(ast: &syn::DeriveInput, attrs: ForyAttrs) -> TokenStrea
                 #get_sorted_field_names_ts
             }
 
+            fn fory_meta_field_name(field: &str) -> ::std::option::Option<&'static str> {
+                #meta_field_name_ts
+            }
+
+            #[inline(always)]
+            fn fory_struct_hash() -> i32 {
+                #struct_hash_ts
+            }
+
             fn fory_fields_info(type_resolver: &::fory_core::resolver::TypeResolver) -> ::std::result::Result<::std::vec::Vec<::fory_core::meta::FieldInfo>, ::fory_core::error::Error> {
                 #fields_info_ts
             }
@@ -220,6 +305,10 @@ pub fn derive_serializer(ast: &syn::DeriveInput, attrs: ForyAttrs) -> TokenStrea
                 #reserved_space_ts
             }
 
+            #version_ts
+
+            #estimate_size_ts
+
             #[inline(always)]
             fn fory_write(&self, context: &mut ::fory_core::WriteContext, ref_mode: ::fory_core::RefMode, write_type_info: bool, _: bool) -> ::std::result::Result<(), ::fory_core::error::Error> {
                 #write_ts
@@ -250,6 +339,8 @@ pub fn derive_serializer(ast: &syn::DeriveInput, attrs: ForyAttrs) -> TokenStrea
                 #read_data_ts
             }
 
+            #read_into_ts
+
             #serializer_send_sync_ts
 
             #[inline(always)]
@@ -263,6 +354,81 @@ pub fn derive_serializer(ast: &syn::DeriveInput, attrs: ForyAttrs) -> TokenStrea
     code
 }
 
+/// Generates `fory_version` and `fory_min_version` for a `#[fory(version = N)]` struct.
+fn gen_version(ast: &syn::DeriveInput, attrs: &ForyAttrs) -> syn::Result<proc_macro2::TokenStream> {
+    let Some(version) = attrs.version else {
+        return match attrs.min_version {
+            Some(_) => Err(syn::Error::new(
+                ast.ident.span(),
+                "`#[fory(min_version = N)]` requires `#[fory(version = N)]`",
+            )),
+            None => Ok(quote! {}),
+        };
+    };
+    if !matches!(ast.data, syn::Data::Struct(_)) {
+        return Err(syn::Error::new(
+            ast.ident.span(),
+            "`#[fory(version = N)]` is only supported on structs",
+        ));
+    }
+    let min_version = attrs.min_version.unwrap_or(0);
+    if min_version > version {
+        return Err(syn::Error::new(
+            ast.ident.span(),
+            "`min_version` can't be greater than `version`",
+        ));
+    }
+    Ok(quote! {
+        #[inline(always)]
+        fn fory_version() -> ::std::option::Option<u32> {
+            ::std::option::Option::Some(#version)
+        }
+
+        #[inline(always)]
+        fn fory_min_version() -> u32 {
+            #min_version
+        }
+    })
+}
+
+/// Generates `was_present` for a `#[fory(track_presence)]` struct.
+fn gen_presence_impl(
+    ast: &syn::DeriveInput,
+    track_presence: bool,
+) -> syn::Result<proc_macro2::TokenStream> {
+    if !track_presence {
+        return Ok(quote! {});
+    }
+    let missing_field = || {
+        syn::Error::new(
+            ast.ident.span(),
+            "`#[fory(track_presence)]` requires a struct field of type `PresentFields`",
+        )
+    };
+    let syn::Data::Struct(s) = &ast.data else {
+        return Err(missing_field());
+    };
+    let (index, field) = s
+        .fields
+        .iter()
+        .enumerate()
+        .find(|(_, field)| is_present_fields_type(&field.ty))
+        .ok_or_else(missing_field)?;
+    let access = crate::object::util::get_field_accessor(field, index, true);
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// Returns whether the payload this value was read from carried `field`.
+            ///
+            /// Fields the writer's schema lacked read as their default and report `false`.
+            pub fn was_present(&self, field: &str) -> bool {
+                #access.contains(field)
+            }
+        }
+    })
+}
+
 struct SendSyncTokens {
     serializer: proc_macro2::TokenStream,
     struct_read_compatible: proc_macro2::TokenStream,
@@ -356,7 +522,7 @@ fn generate_default_impl(
             let mut indexed: Vec<_> = source_fields
                 .iter()
                 .map(|sf| {
-                    let value = super::field_codec::default_expr_for_type(&sf.field.ty);
+                    let value = super::field_codec::default_expr_for_field(sf.field);
                     (sf.original_index, sf.field_init(value))
                 })
                 .collect();
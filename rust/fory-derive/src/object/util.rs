@@ -22,18 +22,36 @@ use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
 use std::cell::RefCell;
 use std::collections::HashSet;
+use syn::spanned::Spanned;
 use syn::{Field, Fields, GenericArgument, Index, PathArguments, Type};
 
 /// Get field name for a field, handling both named and tuple struct fields.
-/// For named fields, returns the field name.
+/// For named fields, returns the `#[fory(rename = "...")]` value if present,
+/// otherwise the field name.
 /// For tuple struct fields, returns the index as a string (e.g., "0", "1").
-pub(super) fn get_field_name(field: &Field, index: usize) -> String {
+/// Fails when the field's `#[fory(...)]` attributes are malformed.
+pub(crate) fn try_get_field_name(field: &Field, index: usize) -> syn::Result<String> {
+    use super::field_meta::parse_field_meta;
+
     match &field.ident {
-        Some(ident) => ident.to_string(),
-        None => index.to_string(),
+        Some(ident) => Ok(parse_field_meta(field)?
+            .rename
+            .unwrap_or_else(|| ident.to_string())),
+        None => Ok(index.to_string()),
     }
 }
 
+/// [`try_get_field_name`] for fields that [`validate_field_names`] already accepted.
+pub(super) fn get_field_name(field: &Field, index: usize) -> String {
+    try_get_field_name(field, index).expect("field attributes are checked by validate_field_names")
+}
+
+/// Get the name a field is identified by in type metadata, which is the
+/// snake_case form of [`get_field_name`].
+pub(crate) fn get_meta_field_name(field: &Field, index: usize) -> String {
+    to_snake_case(&get_field_name(field, index))
+}
+
 /// Get the field accessor token for a field.
 /// For named fields: `self.field_name`
 /// For tuple struct fields: `self.0`, `self.1`, etc.
@@ -232,9 +250,13 @@ fn extract_option_inner(s: &str) -> Option<&str> {
     extract_generic_inner(s, "Option")
 }
 
-const PRIMITIVE_TYPE_NAMES: [&str; 17] = [
-    "bool", "i8", "i16", "i32", "i64", "i128", "float16", "Float16", "bfloat16", "BFloat16", "f32",
-    "f64", "u8", "u16", "u32", "u64", "u128",
+const PRIMITIVE_TYPE_NAMES: [&str; 18] = [
+    "bool", "i8", "i16", "i32", "i64", "i128", "float16", "Float16", "f16", "bfloat16", "BFloat16",
+    "f32", "f64", "u8", "u16", "u32", "u64", "u128",
+];
+
+const ATOMIC_INNER_TYPE_NAMES: [&str; 11] = [
+    "bool", "i8", "i16", "i32", "i64", "isize", "u8", "u16", "u32", "u64", "usize",
 ];
 
 fn is_primitive_type_name(ty: &str) -> bool {
@@ -250,7 +272,7 @@ fn get_primitive_type_id(ty: &str) -> u32 {
         "i32" => TypeId::VARINT32 as u32,
         // Use VARINT64 for i64 to match Java xlang mode and Rust type resolver registration
         "i64" => TypeId::VARINT64 as u32,
-        "float16" | "Float16" => TypeId::FLOAT16 as u32,
+        "float16" | "Float16" | "f16" => TypeId::FLOAT16 as u32,
         "bfloat16" | "BFloat16" => TypeId::BFLOAT16 as u32,
         "f32" => TypeId::FLOAT32 as u32,
         "f64" => TypeId::FLOAT64 as u32,
@@ -291,19 +313,64 @@ pub(crate) fn get_type_id_by_name(ty: &str) -> u32 {
     if PRIMITIVE_TYPE_NAMES.contains(&unqualified_ty) {
         return get_primitive_type_id(unqualified_ty);
     }
+    // `NonZero*` integers share the type id of their inner integer.
+    if let Some(inner) = unqualified_ty.strip_prefix("NonZero") {
+        let inner = inner.to_ascii_lowercase();
+        if inner.starts_with(['i', 'u']) && PRIMITIVE_TYPE_NAMES.contains(&inner.as_str()) {
+            return get_primitive_type_id(&inner);
+        }
+    }
+    // `Atomic*` values share the type id of their inner integer or `bool`.
+    if let Some(inner) = unqualified_ty.strip_prefix("Atomic") {
+        let inner = inner.to_ascii_lowercase();
+        if ATOMIC_INNER_TYPE_NAMES.contains(&inner.as_str()) {
+            return get_type_id_by_name(&inner);
+        }
+    }
+    // `Wrapping<T>` shares the type id of `T`.
+    if let Some(inner) = extract_generic_inner(ty, "Wrapping") {
+        return get_type_id_by_name(inner);
+    }
+    // `Box<[T]>` shares the type id of `Vec<T>`.
+    if extract_generic_inner(ty, "Box")
+        .is_some_and(|inner| inner.starts_with('[') && !inner.contains(';'))
+    {
+        return TypeId::LIST as u32;
+    }
 
     // Check internal types
     match unqualified_ty {
-        "String" => return TypeId::STRING as u32,
+        "String" | "PathBuf" => return TypeId::STRING as u32,
         "Date" => return TypeId::DATE as u32,
         "Timestamp" => return TypeId::TIMESTAMP as u32,
         "Duration" => return TypeId::DURATION as u32,
         "NaiveDate" => return TypeId::DATE as u32,
         "NaiveDateTime" => return TypeId::TIMESTAMP as u32,
+        "SystemTime" => return TypeId::TIMESTAMP as u32,
         "Decimal" => return TypeId::DECIMAL as u32,
+        "Uuid" => return TypeId::UUID as u32,
+        "Ipv4Addr" => return TypeId::IPV4_ADDR as u32,
+        "Ipv6Addr" => return TypeId::IPV6_ADDR as u32,
+        "IpAddr" => return TypeId::IP_ADDR as u32,
+        "SocketAddr" => return TypeId::SOCKET_ADDR as u32,
+        "OsString" => return TypeId::OS_STRING as u32,
         "bytes" => return TypeId::BINARY as u32,
+        "Bytes" | "BytesMut" => return TypeId::BINARY as u32,
         _ => {}
     }
+    // `Cow<'_, str>`, `Cow<'_, Path>`, `Cow<'_, [u8]>`, `Arc<str>` and `Arc<[u8]>` share the
+    // wire format of `String` and `Vec<u8>`.
+    if let Some(args) = unqualified_ty
+        .strip_prefix("Cow<")
+        .or_else(|| unqualified_ty.strip_prefix("Arc<"))
+        .and_then(|args| args.strip_suffix('>'))
+    {
+        match args.rsplit(',').next() {
+            Some("str" | "Path") => return TypeId::STRING as u32,
+            Some("[u8]") => return TypeId::BINARY as u32,
+            _ => {}
+        }
+    }
 
     // Check primitive arrays (fixed-size arrays [T; N])
     // These will be serialized similarly to Vec but with fixed size
@@ -317,7 +384,7 @@ pub(crate) fn get_type_id_by_name(ty: &str) -> u32 {
                 "i32" => return TypeId::INT32_ARRAY as u32,
                 "i64" => return TypeId::INT64_ARRAY as u32,
                 "i128" => return TypeId::INT128_ARRAY as u32,
-                "float16" | "Float16" => return TypeId::FLOAT16_ARRAY as u32,
+                "float16" | "Float16" | "f16" => return TypeId::FLOAT16_ARRAY as u32,
                 "bfloat16" | "BFloat16" => return TypeId::BFLOAT16_ARRAY as u32,
                 "f32" => return TypeId::FLOAT32_ARRAY as u32,
                 "f64" => return TypeId::FLOAT64_ARRAY as u32,
@@ -337,23 +404,37 @@ pub(crate) fn get_type_id_by_name(ty: &str) -> u32 {
     if extract_generic_inner(ty, "Vec").is_some()
         || extract_generic_inner(ty, "VecDeque").is_some()
         || extract_generic_inner(ty, "LinkedList").is_some()
+        || extract_generic_inner(ty, "BinaryHeap").is_some()
+        || extract_generic_inner(ty, "SmallVec").is_some()
     {
         return TypeId::LIST as u32;
     }
 
     if extract_generic_inner(ty, "HashSet").is_some()
         || extract_generic_inner(ty, "BTreeSet").is_some()
-        || extract_generic_inner(ty, "BinaryHeap").is_some()
     {
         return TypeId::SET as u32;
     }
 
     if extract_generic_inner(ty, "HashMap").is_some()
         || extract_generic_inner(ty, "BTreeMap").is_some()
+        || extract_generic_inner(ty, "IndexMap").is_some()
     {
         return TypeId::MAP as u32;
     }
 
+    if extract_generic_inner(ty, "Result").is_some() {
+        return TypeId::RESULT as u32;
+    }
+
+    if extract_generic_inner(ty, "Range").is_some() {
+        return TypeId::RANGE as u32;
+    }
+
+    if extract_generic_inner(ty, "RangeInclusive").is_some() {
+        return TypeId::RANGE_INCLUSIVE as u32;
+    }
+
     // Check tuple types (represented as "Tuple" by extract_type_name or starts with '(')
     if ty == "Tuple" || ty.starts_with('(') {
         return TypeId::LIST as u32;
@@ -453,6 +534,12 @@ fn group_fields_by_type(fields: &[&Field]) -> FieldGroups {
             FieldSortKey::name(ident.clone())
         };
 
+        // Encrypted fields are opaque blobs whatever their declared type.
+        if meta.encrypt {
+            non_primitive_fields.push((ident, sort_key, TypeId::ENCRYPTED as u32));
+            continue;
+        }
+
         let ty: String = field
             .ty
             .to_token_stream()
@@ -616,7 +703,7 @@ fn array_type_id_for_vec_name(ty: &str) -> Option<u32> {
         "u16" => Some(TypeId::UINT16_ARRAY as u32),
         "u32" => Some(TypeId::UINT32_ARRAY as u32),
         "u64" => Some(TypeId::UINT64_ARRAY as u32),
-        "float16" | "Float16" => Some(TypeId::FLOAT16_ARRAY as u32),
+        "float16" | "Float16" | "f16" => Some(TypeId::FLOAT16_ARRAY as u32),
         "bfloat16" | "BFloat16" => Some(TypeId::BFLOAT16_ARRAY as u32),
         "f32" => Some(TypeId::FLOAT32_ARRAY as u32),
         "f64" => Some(TypeId::FLOAT64_ARRAY as u32),
@@ -679,6 +766,10 @@ fn build_type_fingerprint(
 ) -> String {
     use super::field_meta::{classify_field_type, extract_option_inner_type, is_option_type};
 
+    if meta.encrypt {
+        return format!("{},0,0", TypeId::ENCRYPTED as u32);
+    }
+
     let type_class = classify_field_type(ty);
     let nullable = meta.effective_nullable(type_class) || is_option_type(ty);
     let track_ref = include_ref && meta.effective_ref(type_class);
@@ -723,8 +814,16 @@ fn build_type_fingerprint(
     };
 
     match name.as_str() {
-        "Vec" | "VecDeque" | "LinkedList" if type_id == TypeId::LIST as u32 => {
-            if let Some(elem_ty) = args.and_then(single_type_arg) {
+        "Vec" | "VecDeque" | "LinkedList" | "BinaryHeap" | "SmallVec" | "Box"
+            if type_id == TypeId::LIST as u32 =>
+        {
+            let elem_ty = args.and_then(single_type_arg).map(|arg| match arg {
+                // SmallVec<[T; N]> lists T, not its inline array.
+                Type::Array(array) if name == "SmallVec" => array.elem.as_ref(),
+                Type::Slice(slice) if name == "Box" => slice.elem.as_ref(),
+                _ => arg,
+            });
+            if let Some(elem_ty) = elem_ty {
                 fingerprint.push('[');
                 fingerprint.push_str(&build_type_fingerprint(
                     elem_ty,
@@ -735,7 +834,7 @@ fn build_type_fingerprint(
                 fingerprint.push(']');
             }
         }
-        "HashSet" | "BTreeSet" | "BinaryHeap" if type_id == TypeId::SET as u32 => {
+        "HashSet" | "BTreeSet" if type_id == TypeId::SET as u32 => {
             if let Some(elem_ty) = args.and_then(single_type_arg) {
                 fingerprint.push('[');
                 fingerprint.push_str(&build_type_fingerprint(
@@ -747,7 +846,7 @@ fn build_type_fingerprint(
                 fingerprint.push(']');
             }
         }
-        "HashMap" | "BTreeMap" if type_id == TypeId::MAP as u32 => {
+        "HashMap" | "BTreeMap" | "IndexMap" if type_id == TypeId::MAP as u32 => {
             if let Some((key_ty, value_ty)) = args.and_then(two_type_args) {
                 fingerprint.push('[');
                 fingerprint.push_str(&build_type_fingerprint(
@@ -766,6 +865,26 @@ fn build_type_fingerprint(
                 fingerprint.push(']');
             }
         }
+        "Result" if type_id == TypeId::RESULT as u32 => {
+            if let Some((ok_ty, err_ty)) = args.and_then(two_type_args) {
+                let arm_meta = super::field_meta::ForyFieldMeta::default();
+                fingerprint.push('[');
+                fingerprint.push_str(&build_type_fingerprint(ok_ty, &arm_meta, false, false));
+                fingerprint.push('|');
+                fingerprint.push_str(&build_type_fingerprint(err_ty, &arm_meta, false, false));
+                fingerprint.push(']');
+            }
+        }
+        "Range" | "RangeInclusive"
+            if type_id == TypeId::RANGE as u32 || type_id == TypeId::RANGE_INCLUSIVE as u32 =>
+        {
+            if let Some(bound_ty) = args.and_then(single_type_arg) {
+                let bound_meta = super::field_meta::ForyFieldMeta::default();
+                fingerprint.push('[');
+                fingerprint.push_str(&build_type_fingerprint(bound_ty, &bound_meta, false, false));
+                fingerprint.push(']');
+            }
+        }
         _ => {}
     }
 
@@ -825,11 +944,17 @@ fn compute_struct_fingerprint(fields: &[&Field]) -> String {
     fingerprint
 }
 
+/// Computes the struct version hash from the field fingerprint at compile time.
+pub(crate) fn compute_struct_version_hash(fields: &[&Field]) -> i32 {
+    let fingerprint = compute_struct_fingerprint(fields);
+    let (hash, _) = fory_core::util::murmurhash3_x64_128(fingerprint.as_bytes(), 47);
+    (hash & 0xFFFF_FFFF) as i32
+}
+
 /// Generates TokenStream for struct version hash (computed at compile time).
 pub(crate) fn gen_struct_version_hash_ts(fields: &[&Field]) -> TokenStream {
     let fingerprint = compute_struct_fingerprint(fields);
-    let (hash, _) = fory_core::util::murmurhash3_x64_128(fingerprint.as_bytes(), 47);
-    let version_hash = (hash & 0xFFFF_FFFF) as i32;
+    let version_hash = compute_struct_version_hash(fields);
 
     quote! {
         {
@@ -851,6 +976,10 @@ pub(crate) fn is_skip_field(field: &syn::Field) -> bool {
     super::field_meta::is_skip_field(field)
 }
 
+fn is_external_field(field: &syn::Field) -> bool {
+    super::field_meta::parse_field_meta(field).is_ok_and(|meta| meta.external)
+}
+
 pub(crate) fn is_skip_enum_variant(variant: &syn::Variant) -> bool {
     variant.attrs.iter().any(|attr| {
         attr.path().is_ident("fory") && {
@@ -930,6 +1059,216 @@ pub(crate) fn is_unknown_case_type(ty: &Type) -> bool {
     )
 }
 
+/// Returns a copy of `ast` whose generics carry the bounds the generated impls need:
+/// `'static` on every type parameter, plus `Serializer + ForyDefault` on parameters that a
+/// serialized field uses directly. Parameters that only appear in skipped fields or behind
+/// associated-type paths such as `C::Term` are left to the user's own `where` clause.
+///
+/// `#[fory(external)]` fields are filled with `Default::default()` when a compatible-mode
+/// payload omits them, so their types also get a `Default` bound spanned at the field. A
+/// missing impl is then reported once on the field rather than inside the generated code.
+pub(crate) fn with_type_param_bounds(ast: &syn::DeriveInput) -> syn::DeriveInput {
+    let mut ast = ast.clone();
+    let external_tys: Vec<Type> = data_fields(&ast.data)
+        .into_iter()
+        .filter(|field| !is_skip_field(field) && is_external_field(field))
+        .map(|field| field.ty.clone())
+        .collect();
+    if !external_tys.is_empty() {
+        let where_clause = ast.generics.make_where_clause();
+        for ty in &external_tys {
+            where_clause.predicates.push(syn::parse_quote_spanned!(
+                ty.span() => #ty: ::std::default::Default
+            ));
+        }
+    }
+    let params: Vec<syn::Ident> = ast
+        .generics
+        .type_params()
+        .map(|param| param.ident.clone())
+        .collect();
+    if params.is_empty() {
+        return ast;
+    }
+    let mut used = HashSet::new();
+    for field in data_fields(&ast.data)
+        .into_iter()
+        .filter(|field| !is_skip_field(field))
+    {
+        collect_type_params(&field.ty, &params, &mut used);
+    }
+    let where_clause = ast.generics.make_where_clause();
+    for param in &params {
+        where_clause
+            .predicates
+            .push(syn::parse_quote!(#param: 'static));
+        if used.contains(param) {
+            where_clause.predicates.push(syn::parse_quote!(
+                #param: ::fory_core::Serializer + ::fory_core::ForyDefault
+            ));
+        }
+    }
+    ast
+}
+
+/// Rejects field types that no serializer can exist for, such as references and function
+/// pointers, so the user gets one error on the field instead of a cascade of unsatisfied trait
+/// bounds in the generated code.
+pub(crate) fn validate_field_types(ast: &syn::DeriveInput) -> syn::Result<()> {
+    let mut result: syn::Result<()> = Ok(());
+    for (index, field) in data_fields(&ast.data).into_iter().enumerate() {
+        if is_skip_field(field) {
+            continue;
+        }
+        let Some((ty, reason)) = find_unsupported_type(&field.ty, false) else {
+            continue;
+        };
+        let name = match &field.ident {
+            Some(ident) => ident.to_string(),
+            None => index.to_string(),
+        };
+        let err = syn::Error::new_spanned(
+            &field.ty,
+            format!(
+                "field `{}` has unsupported type `{}`: {}",
+                name,
+                ty.to_token_stream(),
+                reason
+            ),
+        );
+        match &mut result {
+            Ok(()) => result = Err(err),
+            Err(errors) => errors.combine(err),
+        }
+    }
+    result
+}
+
+/// Checks that the `#[fory(...)]` attributes of every field parse and that no two fields
+/// of a struct or enum variant share a name in type metadata, which renames can cause.
+/// The other name helpers assume both.
+pub(crate) fn validate_field_names(ast: &syn::DeriveInput) -> syn::Result<()> {
+    let field_groups: Vec<&Fields> = match &ast.data {
+        syn::Data::Struct(data) => vec![&data.fields],
+        syn::Data::Enum(data) => data.variants.iter().map(|v| &v.fields).collect(),
+        syn::Data::Union(_) => Vec::new(),
+    };
+    let mut result: syn::Result<()> = Ok(());
+    for fields in field_groups {
+        let mut seen: HashSet<String> = HashSet::new();
+        for (index, field) in fields.iter().enumerate() {
+            let err = match try_get_field_name(field, index) {
+                Ok(name) => {
+                    let meta_name = to_snake_case(&name);
+                    if seen.insert(meta_name.clone()) {
+                        continue;
+                    }
+                    let span = field
+                        .ident
+                        .as_ref()
+                        .map_or_else(|| field.span(), |ident| ident.span());
+                    syn::Error::new(
+                        span,
+                        format!(
+                            "field name `{}` is already used by another field in type metadata",
+                            meta_name
+                        ),
+                    )
+                }
+                Err(err) => err,
+            };
+            match &mut result {
+                Ok(()) => result = Err(err),
+                Err(errors) => errors.combine(err),
+            }
+        }
+    }
+    result
+}
+
+fn find_unsupported_type(ty: &Type, nested: bool) -> Option<(&Type, &'static str)> {
+    match ty {
+        Type::Reference(_) => Some((
+            ty,
+            "borrowed fields cannot be deserialized; use an owned type such as `String` or `Vec<T>`",
+        )),
+        Type::Ptr(_) => Some((ty, "raw pointers cannot be serialized")),
+        Type::BareFn(_) => Some((ty, "function pointers cannot be serialized")),
+        // Slices are fine behind a pointer type such as `Cow<'static, [u8]>`.
+        Type::Slice(_) if !nested => Some((
+            ty,
+            "unsized slices cannot be deserialized; use `Vec<T>` or `[T; N]`",
+        )),
+        Type::Path(type_path) => type_path.path.segments.iter().find_map(|segment| {
+            let PathArguments::AngleBracketed(args) = &segment.arguments else {
+                return None;
+            };
+            args.args.iter().find_map(|arg| match arg {
+                GenericArgument::Type(inner) => find_unsupported_type(inner, true),
+                _ => None,
+            })
+        }),
+        Type::Array(array) => find_unsupported_type(&array.elem, nested),
+        Type::Tuple(tuple) => tuple
+            .elems
+            .iter()
+            .find_map(|elem| find_unsupported_type(elem, nested)),
+        Type::Paren(paren) => find_unsupported_type(&paren.elem, nested),
+        Type::Group(group) => find_unsupported_type(&group.elem, nested),
+        _ => None,
+    }
+}
+
+fn data_fields(data: &syn::Data) -> Vec<&Field> {
+    match data {
+        syn::Data::Struct(data) => data.fields.iter().collect(),
+        syn::Data::Enum(data) => data
+            .variants
+            .iter()
+            .flat_map(|variant| variant.fields.iter())
+            .collect(),
+        syn::Data::Union(_) => Vec::new(),
+    }
+}
+
+fn collect_type_params(ty: &Type, params: &[syn::Ident], used: &mut HashSet<syn::Ident>) {
+    match ty {
+        Type::Path(type_path) => {
+            if type_path.qself.is_some() {
+                return;
+            }
+            let segments = &type_path.path.segments;
+            if let Some(first) = segments.first() {
+                if params.contains(&first.ident) {
+                    if segments.len() == 1 {
+                        used.insert(first.ident.clone());
+                    }
+                    return;
+                }
+            }
+            for segment in segments {
+                let PathArguments::AngleBracketed(args) = &segment.arguments else {
+                    continue;
+                };
+                for arg in &args.args {
+                    if let GenericArgument::Type(inner) = arg {
+                        collect_type_params(inner, params, used);
+                    }
+                }
+            }
+        }
+        Type::Array(array) => collect_type_params(&array.elem, params, used),
+        Type::Tuple(tuple) => {
+            for elem in &tuple.elems {
+                collect_type_params(elem, params, used);
+            }
+        }
+        Type::Paren(paren) => collect_type_params(&paren.elem, params, used),
+        Type::Group(group) => collect_type_params(&group.elem, params, used),
+        _ => {}
+    }
+}
+
 pub(crate) fn type_param_send_sync_bounds(generics: &syn::Generics) -> HashSet<String> {
     let mut params = HashSet::new();
     for param in generics.type_params() {
@@ -980,13 +1319,20 @@ pub(crate) fn type_is_send_sync(ty: &Type, send_sync_params: &HashSet<String>) -
                 return true;
             }
             match name.as_str() {
-                "bool" | "i8" | "i16" | "i32" | "i64" | "i128" | "isize" | "u8" | "u16" | "u32"
-                | "u64" | "u128" | "usize" | "f32" | "f64" | "String" | "Date" | "Timestamp"
-                | "Duration" | "Decimal" | "float16" | "bfloat16" | "Float16" | "BFloat16"
-                | "UnknownCase" => true,
+                "bool" | "char" | "i8" | "i16" | "i32" | "i64" | "i128" | "isize" | "u8"
+                | "u16" | "u32" | "u64" | "u128" | "usize" | "f32" | "f64" | "String"
+                | "PathBuf" | "Date" | "Timestamp" | "Duration" | "SystemTime" | "Decimal"
+                | "Uuid" | "Ipv4Addr" | "Ipv6Addr" | "IpAddr" | "SocketAddr" | "OsString"
+                | "float16" | "f16" | "bfloat16" | "Float16" | "BFloat16" | "UnknownCase"
+                | "NonZeroI8" | "NonZeroI16" | "NonZeroI32" | "NonZeroI64" | "NonZeroI128"
+                | "NonZeroIsize" | "NonZeroU8" | "NonZeroU16" | "NonZeroU32" | "NonZeroU64"
+                | "NonZeroU128" | "NonZeroUsize" | "AtomicBool" | "AtomicI8" | "AtomicI16"
+                | "AtomicI32" | "AtomicI64" | "AtomicIsize" | "AtomicU8" | "AtomicU16"
+                | "AtomicU32" | "AtomicU64" | "AtomicUsize" => true,
                 "Rc" | "RcWeak" | "RefCell" | "Cell" => false,
                 "Option" | "Vec" | "VecDeque" | "LinkedList" | "HashSet" | "BTreeSet"
-                | "BinaryHeap" | "Box" | "Arc" | "ArcWeak" | "Mutex" => {
+                | "BinaryHeap" | "SmallVec" | "Box" | "Arc" | "ArcWeak" | "Mutex" | "Wrapping"
+                | "Range" | "RangeInclusive" => {
                     let Some(inner) = first_type_arg(&segment.arguments) else {
                         return false;
                     };
@@ -999,7 +1345,7 @@ pub(crate) fn type_is_send_sync(ty: &Type, send_sync_params: &HashSet<String>) -
                         _ => type_is_send_sync(inner, send_sync_params),
                     }
                 }
-                "HashMap" | "BTreeMap" => {
+                "HashMap" | "BTreeMap" | "IndexMap" => {
                     let Some((key, value)) = two_path_type_args(&segment.arguments) else {
                         return false;
                     };
@@ -1123,6 +1469,190 @@ mod tests {
     use super::*;
     use syn::parse_quote;
 
+    #[test]
+    fn validate_field_types_rejects_unserializable_fields() {
+        let ast: syn::DeriveInput = parse_quote! {
+            struct Bad {
+                ok: Vec<Option<String>>,
+                bytes: Cow<'static, [u8]>,
+                any: Box<dyn Any>,
+                name: &'static str,
+                callbacks: Vec<fn(i32) -> i32>,
+                #[fory(skip)]
+                skipped: &'static str,
+            }
+        };
+        let err = validate_field_types(&ast).unwrap_err();
+        let messages: Vec<String> = err.into_iter().map(|err| err.to_string()).collect();
+        assert_eq!(messages.len(), 2);
+        assert!(messages[0].starts_with("field `name` has unsupported type `& 'static str`"));
+        assert!(messages[1].starts_with("field `callbacks` has unsupported type `fn (i32) -> i32`"));
+
+        let ok: syn::DeriveInput = parse_quote! {
+            struct Good(i32, [u8; 4], (String, Arc<[u8]>));
+        };
+        assert!(validate_field_types(&ok).is_ok());
+    }
+
+    #[test]
+    fn external_fields_get_default_bound() {
+        let ast: syn::DeriveInput = parse_quote! {
+            struct Place<T> {
+                name: T,
+                #[fory(external)]
+                location: Point,
+                #[fory(external, skip)]
+                cache: Cache,
+            }
+        };
+        let ast = with_type_param_bounds(&ast);
+        let predicates: Vec<String> = ast
+            .generics
+            .where_clause
+            .unwrap()
+            .predicates
+            .iter()
+            .map(|predicate| predicate.to_token_stream().to_string())
+            .collect();
+        assert_eq!(
+            predicates,
+            [
+                "Point : :: std :: default :: Default",
+                "T : 'static",
+                "T : :: fory_core :: Serializer + :: fory_core :: ForyDefault",
+            ]
+        );
+    }
+
+    #[test]
+    fn non_zero_integers_use_inner_type_id() {
+        assert_eq!(
+            get_type_id_by_name("NonZeroU32"),
+            get_type_id_by_name("u32")
+        );
+        assert_eq!(
+            get_type_id_by_name("std::num::NonZeroI64"),
+            get_type_id_by_name("i64")
+        );
+        assert_eq!(
+            get_type_id_by_name("Option<NonZeroUsize>"),
+            get_type_id_by_name("usize")
+        );
+        assert_eq!(get_type_id_by_name("NonZeroF32"), TypeId::UNKNOWN as u32);
+    }
+
+    #[test]
+    fn wrapping_uses_inner_type_id() {
+        assert_eq!(
+            get_type_id_by_name("Wrapping<u32>"),
+            get_type_id_by_name("u32")
+        );
+        assert_eq!(
+            get_type_id_by_name("std::num::Wrapping<i64>"),
+            get_type_id_by_name("i64")
+        );
+        assert_eq!(
+            get_type_id_by_name("Option<Wrapping<u8>>"),
+            get_type_id_by_name("u8")
+        );
+    }
+
+    #[test]
+    fn paths_use_string_type_id() {
+        assert_eq!(get_type_id_by_name("PathBuf"), TypeId::STRING as u32);
+        assert_eq!(
+            get_type_id_by_name("Cow<'static,Path>"),
+            TypeId::STRING as u32
+        );
+    }
+
+    #[test]
+    fn arc_str_and_bytes_use_string_and_binary_type_ids() {
+        assert_eq!(get_type_id_by_name("Arc<str>"), TypeId::STRING as u32);
+        assert_eq!(
+            get_type_id_by_name("std::sync::Arc<[u8]>"),
+            TypeId::BINARY as u32
+        );
+        assert_eq!(get_type_id_by_name("Arc<String>"), TypeId::UNKNOWN as u32);
+    }
+
+    #[test]
+    fn bytes_use_binary_type_id() {
+        assert_eq!(get_type_id_by_name("Bytes"), TypeId::BINARY as u32);
+        assert_eq!(
+            get_type_id_by_name("bytes::BytesMut"),
+            TypeId::BINARY as u32
+        );
+    }
+
+    #[test]
+    fn index_map_uses_map_type_id() {
+        assert_eq!(
+            get_type_id_by_name("IndexMap<String,i32>"),
+            TypeId::MAP as u32
+        );
+        assert_eq!(
+            get_type_id_by_name("indexmap::IndexMap<String,i32>"),
+            TypeId::MAP as u32
+        );
+    }
+
+    #[test]
+    fn boxed_slice_uses_list_type_id() {
+        assert_eq!(get_type_id_by_name("Box<[i32]>"), TypeId::LIST as u32);
+        assert_eq!(
+            get_type_id_by_name("Option<Box<[String]>>"),
+            TypeId::LIST as u32
+        );
+    }
+
+    #[test]
+    fn small_vec_uses_list_type_id() {
+        assert_eq!(
+            get_type_id_by_name("SmallVec<[i32;4]>"),
+            TypeId::LIST as u32
+        );
+        assert_eq!(
+            get_type_id_by_name("smallvec::SmallVec<[String;2]>"),
+            TypeId::LIST as u32
+        );
+    }
+
+    #[test]
+    fn result_uses_result_type_id() {
+        assert_eq!(
+            get_type_id_by_name("Result<Vec<i32>,String>"),
+            TypeId::RESULT as u32
+        );
+        assert_eq!(
+            get_type_id_by_name("std::result::Result<i32,String>"),
+            TypeId::RESULT as u32
+        );
+    }
+
+    #[test]
+    fn range_uses_range_type_ids() {
+        assert_eq!(get_type_id_by_name("Range<usize>"), TypeId::RANGE as u32);
+        assert_eq!(
+            get_type_id_by_name("std::ops::RangeInclusive<i32>"),
+            TypeId::RANGE_INCLUSIVE as u32
+        );
+    }
+
+    #[test]
+    fn atomics_use_inner_type_id() {
+        assert_eq!(get_type_id_by_name("AtomicI64"), get_type_id_by_name("i64"));
+        assert_eq!(
+            get_type_id_by_name("std::sync::atomic::AtomicBool"),
+            get_type_id_by_name("bool")
+        );
+        assert_eq!(
+            get_type_id_by_name("Option<AtomicU32>"),
+            get_type_id_by_name("u32")
+        );
+        assert_eq!(get_type_id_by_name("AtomicPtr"), TypeId::UNKNOWN as u32);
+    }
+
     #[test]
     fn group_fields_normalizes_names_and_preserves_ordering() {
         let fields: Vec<syn::Field> = vec![
@@ -1181,6 +1711,20 @@ mod tests {
         );
     }
 
+    #[test]
+    fn group_fields_moves_encrypted_primitives_to_non_primitive_group() {
+        let fields: Vec<syn::Field> = vec![
+            parse_quote!(pub age: i32),
+            parse_quote!(#[fory(encrypt)] pub salary: i64),
+            parse_quote!(#[fory(encrypt)] pub bonus: Option<i32>),
+            parse_quote!(pub name: String),
+        ];
+        let field_refs: Vec<&syn::Field> = fields.iter().collect();
+
+        let sorted_names = get_sorted_field_names(&field_refs);
+        assert_eq!(sorted_names, vec!["age", "bonus", "name", "salary"]);
+    }
+
     #[test]
     fn group_fields_sorts_uint8_array_with_dense_arrays() {
         let fields: Vec<syn::Field> = vec![
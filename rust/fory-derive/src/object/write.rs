@@ -42,6 +42,29 @@ pub fn gen_reserved_space(source_fields: &[SourceField<'_>]) -> TokenStream {
     quote! { #(#reserved_size_expr)+* }
 }
 
+pub fn gen_estimate_size(source_fields: &[SourceField<'_>]) -> TokenStream {
+    let bindings = match build_bindings(source_fields) {
+        Ok(bindings) => bindings,
+        Err(err) => return err.to_compile_error(),
+    };
+    let estimate_size_expr: Vec<_> = bindings
+        .iter()
+        .filter_map(|binding| match binding {
+            FieldBinding::Codec(binding) => Some(binding.estimate_size()),
+            FieldBinding::Skipped(_) => None,
+        })
+        .collect();
+    if estimate_size_expr.is_empty() {
+        return quote! {};
+    }
+    quote! {
+        #[inline]
+        fn fory_estimate_size(&self) -> usize {
+            #(#estimate_size_expr)+*
+        }
+    }
+}
+
 pub fn gen_write_type_info() -> TokenStream {
     quote! {
         ::fory_core::serializer::struct_::write_type_info_fast::<Self>(context)
@@ -54,11 +77,24 @@ pub fn gen_write_data(source_fields: &[SourceField<'_>]) -> TokenStream {
         Ok(bindings) => bindings,
         Err(err) => return err.to_compile_error(),
     };
+    let mut field_index = 0usize;
     let write_fields_ts: Vec<_> = bindings
         .iter()
         .filter_map(|binding| match binding {
             FieldBinding::Codec(binding) => {
+                let index = field_index;
+                field_index += 1;
                 let base = binding.write_field();
+                let base = if binding.is_sparse_at(index) {
+                    let present = binding.present_expr();
+                    quote! {
+                        if !sparse || #present {
+                            #base
+                        }
+                    }
+                } else {
+                    base
+                };
                 if is_debug_enabled() {
                     let value_ts = get_field_accessor(
                         binding.source.field,
@@ -95,12 +131,36 @@ pub fn gen_write_data(source_fields: &[SourceField<'_>]) -> TokenStream {
         })
         .collect();
 
+    let absent_bits: Vec<_> = bindings
+        .iter()
+        .filter_map(|binding| match binding {
+            FieldBinding::Codec(binding) => Some(binding),
+            FieldBinding::Skipped(_) => None,
+        })
+        .enumerate()
+        .filter(|(index, binding)| binding.is_sparse_at(*index))
+        .map(|(index, binding)| {
+            let present = binding.present_expr();
+            quote! {
+                if !#present {
+                    absent |= 1u64 << #index;
+                }
+            }
+        })
+        .collect();
+
     let version_hash_ts = gen_struct_version_hash_ts(&fields);
     quote! {
         if context.is_check_struct_version() {
             let version_hash: i32 = #version_hash_ts;
             context.writer.write_i32(version_hash);
         }
+        let sparse = context.is_sparse_options();
+        if sparse {
+            let mut absent = 0u64;
+            #(#absent_bits)*
+            ::fory_core::serializer::struct_::write_field_presence(context, absent);
+        }
         #(#write_fields_ts)*
         Ok(())
     }
@@ -23,7 +23,8 @@ use syn::{Field, Fields, GenericArgument, PathArguments, Type, TypePath, TypeTra
 ///
 /// For tuple structs, `original_index` is the field's position in the original
 /// struct definition (0, 1, 2, ...), and `field_name` is the index as a string.
-/// For named structs, `field_name` is the field identifier.
+/// For named structs, `field_name` is the field identifier, even when the field
+/// is renamed with `#[fory(rename = "...")]`.
 #[derive(Clone)]
 pub struct SourceField<'a> {
     pub original_index: usize,
@@ -80,7 +81,7 @@ pub fn source_fields(fields: &Fields) -> Vec<SourceField<'_>> {
 
 /// Returns source fields with their original indices and field names preserved.
 pub fn get_source_fields<'a>(fields: &[&'a Field]) -> Vec<SourceField<'a>> {
-    use crate::object::util::get_sorted_field_names;
+    use crate::object::util::{get_meta_field_name, get_sorted_field_names};
 
     let is_tuple = !fields.is_empty() && fields[0].ident.is_none();
     let sorted_names = get_sorted_field_names(fields);
@@ -100,18 +101,14 @@ pub fn get_source_fields<'a>(fields: &[&'a Field]) -> Vec<SourceField<'a>> {
                 }
             }
         } else {
-            // For named structs, match by field identifier
+            // For named structs, match by metadata name, which may differ from
+            // the field identifier when the field is renamed.
             for (idx, field) in fields.iter().enumerate() {
-                if field
-                    .ident
-                    .as_ref()
-                    .map(|ident| ident == name)
-                    .unwrap_or(false)
-                {
+                if get_meta_field_name(field, idx) == *name {
                     result.push(SourceField {
                         original_index: idx,
                         field,
-                        field_name: name.clone(),
+                        field_name: field.ident.as_ref().unwrap().to_string(),
                         is_tuple_struct: false,
                     });
                     break;
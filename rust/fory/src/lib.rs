@@ -891,6 +891,10 @@
 //! # }
 //! ```
 //!
+//! For types from other crates, [`Fory::register_external`] registers a pair of
+//! closures instead of a `Serializer` impl, and struct fields of that type are marked
+//! `#[fory(external)]`. See the [`External`] wrapper.
+//!
 //! ### 7. Row-Based Serialization
 //!
 //! **What it does:** Provides a high-performance **row format** for zero-copy
@@ -981,15 +985,20 @@
 //! - `bool` - Boolean values
 //! - `i8`, `i16`, `i32`, `i64` - Signed integers
 //! - `f32`, `f64` - Floating point numbers
+//! - `Float16` - Half-precision floats; `half::f16` uses the same wire format when the `half` feature is enabled
 //! - `String` - UTF-8 encoded strings
 //!
 //! ### Collections
 //!
 //! - `Vec<T>` - Dynamic arrays
+//! - `Box<[T]>` - Boxed slices, with the same wire format as `Vec<T>`
 //! - `HashMap<K, V>` - Hash-based maps
 //! - `BTreeMap<K, V>` - Ordered maps
+//! - `indexmap::IndexMap<K, V>` - Insertion-ordered maps, when the `indexmap` feature is enabled
+//! - `smallvec::SmallVec<[T; N]>` - Inline-first lists, when the `smallvec` feature is enabled
 //! - `HashSet<T>` - Hash-based sets
 //! - `Option<T>` - Optional values
+//! - `bytes::Bytes`, `bytes::BytesMut` - Same wire format as `Vec<u8>`, when the `bytes` feature is enabled
 //!
 //! ### Smart Pointers
 //!
@@ -1006,8 +1015,30 @@
 //! - `Date` - Date without timezone, with epoch-day accessors and checked day arithmetic
 //! - `Timestamp` - Point in time, with epoch unit conversions and checked duration arithmetic
 //! - `Duration` - Signed duration, with normalized parts, total unit conversions, and checked arithmetic
+//! - `std::time::SystemTime` and `std::time::Duration` - Same wire format as `Timestamp` and `Duration`
 //! - `chrono::NaiveDate`, `chrono::NaiveDateTime`, and `chrono::Duration` when the `chrono` feature is enabled
 //!
+//! ### Decimal
+//!
+//! - `Decimal` - Arbitrary-precision decimal
+//! - `rust_decimal::Decimal` - Same wire format as `Decimal`, when the `decimal` feature is enabled
+//!
+//! ### Network Addresses
+//!
+//! - `Ipv4Addr`, `Ipv6Addr`, `IpAddr`, and `SocketAddr` - native mode only
+//!
+//! ### Result
+//!
+//! - `Result<T, E>` - tag byte followed by the `Ok` or `Err` value, native mode only
+//!
+//! ### Ranges
+//!
+//! - `Range<T>`, `RangeInclusive<T>` - start bound followed by end bound, native mode only
+//!
+//! ### UUID
+//!
+//! - `uuid::Uuid` - 16 bytes, native mode only, when the `uuid` feature is enabled
+//!
 //! ### Custom Types
 //!
 //! - Structs with `#[derive(ForyStruct)]` - Object graph serialization
@@ -1214,8 +1245,9 @@
 
 pub use fory_core::{
     error::Error, fory::Fory, fory::ForyBuilder, register_trait_type, row::from_row, row::to_row,
-    ArcWeak, BFloat16, Date, Decimal, Duration, Float16, ForyDefault, RcWeak, ReadContext, Reader,
-    RefFlag, RefMode, Serializer, Timestamp, TypeId, TypeResolver, UnknownCase, WriteContext,
-    Writer,
+    ArcWeak, BFloat16, BorrowDeserialize, Checksum, CompatibilityReport, Compression, Date,
+    Decimal, Duration, Endianness, External, FieldCipher, FieldDiff, Float16, ForyDefault,
+    PresentFields, RcWeak, ReadContext, Reader, RefFlag, RefMode, RetypedField, SchemaMode,
+    Serializer, Timestamp, TypeId, TypeResolver, UnknownCase, WriteContext, Writer,
 };
 pub use fory_derive::{ForyEnum, ForyRow, ForyStruct, ForyUnion};
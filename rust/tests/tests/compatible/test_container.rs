@@ -15,7 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
 
 use fory_core::fory::Fory;
 use fory_derive::ForyStruct;
@@ -521,3 +521,77 @@ fn complex() {
         );
     }
 }
+
+#[test]
+fn hash_to_btree_container() {
+    #[derive(ForyStruct, Debug)]
+    struct HashContainer {
+        set: HashSet<String>,
+        map: HashMap<String, String>,
+        nullable_set: HashSet<Option<String>>,
+    }
+
+    #[derive(ForyStruct, Debug, PartialEq)]
+    struct BTreeContainer {
+        set: BTreeSet<String>,
+        map: BTreeMap<String, String>,
+        nullable_set: BTreeSet<Option<String>>,
+    }
+
+    let mut fory1 = Fory::builder().xlang(false).compatible(true).build();
+    fory1.register::<HashContainer>(102).unwrap();
+    let mut fory2 = Fory::builder().xlang(false).compatible(true).build();
+    fory2.register::<BTreeContainer>(102).unwrap();
+    let container = HashContainer {
+        set: basic_set(),
+        map: basic_map(),
+        nullable_set: nullable_basic_set(false),
+    };
+    let bytes = fory1.serialize(&container).unwrap();
+    let obj: BTreeContainer = fory2.deserialize(&bytes).unwrap();
+    assert_eq!(
+        obj,
+        BTreeContainer {
+            set: basic_set().into_iter().collect(),
+            map: basic_map().into_iter().collect(),
+            nullable_set: nullable_basic_set(false).into_iter().collect(),
+        }
+    );
+    let bytes = fory2.serialize(&obj).unwrap();
+    let back: HashContainer = fory1.deserialize(&bytes).unwrap();
+    assert_eq!(back.set, container.set);
+    assert_eq!(back.map, container.map);
+    assert_eq!(back.nullable_set, container.nullable_set);
+}
+
+#[test]
+fn list_to_deque_auto_conv() {
+    let fory = Fory::builder().xlang(false).compatible(true).build();
+    let list: Vec<Option<i8>> = vec![Some(1), None, Some(-3), Some(i8::MAX)];
+    let bytes = fory.serialize(&list).unwrap();
+    assert_eq!(
+        VecDeque::from([1i8, 0, -3, i8::MAX]),
+        fory.deserialize::<VecDeque<i8>>(bytes.as_slice()).unwrap()
+    );
+
+    #[derive(ForyStruct, Debug)]
+    struct ListContainer {
+        values: Vec<Option<i8>>,
+    }
+
+    #[derive(ForyStruct, Debug, PartialEq)]
+    struct DequeContainer {
+        values: VecDeque<i8>,
+    }
+
+    let mut fory1 = Fory::builder().xlang(false).compatible(true).build();
+    fory1.register::<ListContainer>(104).unwrap();
+    let mut fory2 = Fory::builder().xlang(false).compatible(true).build();
+    fory2.register::<DequeContainer>(104).unwrap();
+    let bytes = fory1.serialize(&ListContainer { values: list }).unwrap();
+    let obj: DequeContainer = fory2.deserialize(&bytes).unwrap();
+    assert_eq!(obj.values, VecDeque::from([1i8, 0, -3, i8::MAX]));
+    let bytes = fory2.serialize(&obj).unwrap();
+    let back: ListContainer = fory1.deserialize(&bytes).unwrap();
+    assert_eq!(back.values, vec![Some(1), Some(0), Some(-3), Some(i8::MAX)]);
+}
@@ -280,8 +280,7 @@ fn option() {
         f1: Option<String>,
         f2: Option<String>,
         f3: Vec<Option<String>>,
-        // adjacent Options are not supported
-        // f4: Option<Option<String>>,
+        f4: Option<Option<String>>,
         f5: Vec<Option<Vec<Option<String>>>>,
         last: i64,
     }
@@ -291,6 +290,7 @@ fn option() {
         f1: Some(String::from("f1")),
         f2: None,
         f3: vec![Option::<String>::None, Some(String::from("f3"))],
+        f4: Some(None),
         f5: vec![Some(vec![Some(String::from("f1"))])],
         last: 666,
     };
@@ -845,3 +845,221 @@ fn test_struct_with_generic() {
     }
     assert!(inner_test(&mut fory3).is_ok());
 }
+
+#[test]
+fn reversed_field_order() {
+    #[derive(ForyStruct, Debug)]
+    struct Writer {
+        a: i32,
+        b: String,
+        c: Vec<i64>,
+        d: HashMap<String, i8>,
+        e: i32,
+        only_writer: String,
+        f: Option<String>,
+    }
+
+    #[derive(ForyStruct, Debug, PartialEq)]
+    struct Reader {
+        f: Option<String>,
+        only_reader: Vec<i8>,
+        e: i32,
+        d: HashMap<String, i8>,
+        c: Vec<i64>,
+        b: String,
+        a: i32,
+    }
+
+    let mut writer = Fory::builder().xlang(false).compatible(true).build();
+    writer.register::<Writer>(999).unwrap();
+    let mut reader = Fory::builder().xlang(false).compatible(true).build();
+    reader.register::<Reader>(999).unwrap();
+
+    let value = Writer {
+        a: 1,
+        b: "b".to_string(),
+        c: vec![3, 4],
+        d: HashMap::from([("d".to_string(), 5)]),
+        e: 6,
+        only_writer: "skipped".to_string(),
+        f: Some("f".to_string()),
+    };
+    let bytes = writer.serialize(&value).unwrap();
+    let decoded: Reader = reader.deserialize(&bytes).unwrap();
+    assert_eq!(
+        decoded,
+        Reader {
+            f: Some("f".to_string()),
+            only_reader: Vec::new(),
+            e: 6,
+            d: HashMap::from([("d".to_string(), 5)]),
+            c: vec![3, 4],
+            b: "b".to_string(),
+            a: 1,
+        }
+    );
+}
+
+#[test]
+fn reversed_field_order_with_ids() {
+    #[derive(ForyStruct, Debug)]
+    struct Writer {
+        #[fory(id = 1)]
+        first: i32,
+        #[fory(id = 2)]
+        second: i32,
+        #[fory(id = 3)]
+        third: String,
+    }
+
+    // Same ids in reverse order, under different names: ids take precedence.
+    #[derive(ForyStruct, Debug, PartialEq)]
+    struct Reader {
+        #[fory(id = 3)]
+        label: String,
+        #[fory(id = 2)]
+        y: i32,
+        #[fory(id = 1)]
+        x: i32,
+    }
+
+    let mut writer = Fory::builder().xlang(false).compatible(true).build();
+    writer.register::<Writer>(999).unwrap();
+    let mut reader = Fory::builder().xlang(false).compatible(true).build();
+    reader.register::<Reader>(999).unwrap();
+
+    let bytes = writer
+        .serialize(&Writer {
+            first: 10,
+            second: 20,
+            third: "thirty".to_string(),
+        })
+        .unwrap();
+    let decoded: Reader = reader.deserialize(&bytes).unwrap();
+    assert_eq!(
+        decoded,
+        Reader {
+            label: "thirty".to_string(),
+            y: 20,
+            x: 10,
+        }
+    );
+}
+
+#[test]
+fn shared_name_across_instances() {
+    #[derive(ForyStruct, Debug, PartialEq)]
+    struct Address {
+        city: String,
+    }
+
+    #[derive(ForyStruct, Debug, PartialEq)]
+    struct UserV1 {
+        name: String,
+        address: Address,
+    }
+
+    #[derive(ForyStruct, Debug, PartialEq)]
+    struct UserV2 {
+        name: String,
+        address: Address,
+        age: i32,
+    }
+
+    for xlang in [false, true] {
+        let mut writer = Fory::builder().xlang(xlang).compatible(true).build();
+        writer
+            .register_by_name::<Address>("com.example.Address")
+            .unwrap();
+        writer
+            .register_by_name::<UserV1>("com.example.User")
+            .unwrap();
+        let mut reader = Fory::builder().xlang(xlang).compatible(true).build();
+        reader
+            .register_by_name::<Address>("com.example.Address")
+            .unwrap();
+        reader
+            .register_by_name::<UserV2>("com.example.User")
+            .unwrap();
+
+        let user = UserV1 {
+            name: "ada".to_string(),
+            address: Address {
+                city: "London".to_string(),
+            },
+        };
+        let bytes = writer.serialize(&user).unwrap();
+        let expected = UserV2 {
+            name: "ada".to_string(),
+            address: Address {
+                city: "London".to_string(),
+            },
+            age: 0,
+        };
+        assert_eq!(reader.deserialize::<UserV2>(&bytes).unwrap(), expected);
+
+        // A dynamic read has no static type to go on, so it resolves the name in the meta.
+        let any: Box<dyn std::any::Any> = reader.deserialize(&bytes).unwrap();
+        assert_eq!(*any.downcast::<UserV2>().unwrap(), expected);
+    }
+}
+
+#[test]
+fn track_presence() {
+    #[derive(ForyStruct, Debug)]
+    struct Item1 {
+        f1: i32,
+    }
+
+    #[derive(ForyStruct, Debug)]
+    struct Item1WithEmpty {
+        f1: i32,
+        f4: String,
+        f5: i64,
+    }
+
+    #[derive(ForyStruct, Debug)]
+    #[fory(track_presence)]
+    struct Item2 {
+        f1: i32,
+        f4: String,
+        presence: fory_core::PresentFields,
+    }
+
+    let mut omits = Fory::builder().xlang(false).compatible(true).build();
+    omits.register::<Item1>(1000).unwrap();
+    let mut sends_empty = Fory::builder().xlang(false).compatible(true).build();
+    sends_empty.register::<Item1WithEmpty>(1000).unwrap();
+    let mut reader = Fory::builder().xlang(false).compatible(true).build();
+    reader.register::<Item2>(1000).unwrap();
+
+    let bytes = omits.serialize(&Item1 { f1: 7 }).unwrap();
+    let item: Item2 = reader.deserialize(&bytes).unwrap();
+    assert_eq!((item.f1, item.f4.as_str()), (7, ""));
+    assert!(item.was_present("f1"));
+    assert!(!item.was_present("f4"));
+
+    let bytes = sends_empty
+        .serialize(&Item1WithEmpty {
+            f1: 7,
+            f4: String::new(),
+            f5: 9,
+        })
+        .unwrap();
+    let item: Item2 = reader.deserialize(&bytes).unwrap();
+    assert_eq!((item.f1, item.f4.as_str()), (7, ""));
+    assert!(item.was_present("f1"));
+    assert!(item.was_present("f4"));
+
+    // Values built locally and same-schema payloads report every field as present.
+    let local = Item2 {
+        f1: 1,
+        f4: "x".to_string(),
+        presence: Default::default(),
+    };
+    assert!(local.was_present("f4"));
+    let item: Item2 = reader
+        .deserialize(&reader.serialize(&local).unwrap())
+        .unwrap();
+    assert!(item.was_present("f1") && item.was_present("f4"));
+}
@@ -194,8 +194,7 @@ fn option() {
         f1: Option<String>,
         f2: Option<String>,
         f3: Vec<Option<String>>,
-        // adjacent Options are not supported
-        // f4: Option<Option<String>>,
+        f4: Option<Option<String>>,
         f5: Vec<Option<Vec<Option<String>>>>,
         last: i64,
     }
@@ -205,6 +204,7 @@ fn option() {
         f1: Some(String::from("f1")),
         f2: None,
         f3: vec![Option::<String>::None, Some(String::from("f3"))],
+        f4: Some(None),
         f5: vec![Some(vec![Some(String::from("f1"))])],
         last: 666,
     };
@@ -0,0 +1,114 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use fory_core::fory::Fory;
+use fory_derive::ForyStruct;
+use std::sync::Arc;
+
+#[test]
+fn test_arc_str_wire_compatible() {
+    let fory = Fory::builder().xlang(false).build();
+
+    let text: Arc<str> = Arc::from("hello");
+    let bin = fory.serialize(&text).unwrap();
+    assert_eq!(bin, fory.serialize(&"hello".to_string()).unwrap());
+    let obj: Arc<str> = fory.deserialize(&bin).unwrap();
+    assert_eq!(obj, text);
+    let obj: String = fory.deserialize(&bin).unwrap();
+    assert_eq!(obj, "hello");
+
+    let bytes: Arc<[u8]> = Arc::from(vec![1u8, 2, 3]);
+    let bin = fory.serialize(&bytes).unwrap();
+    assert_eq!(bin, fory.serialize(&vec![1u8, 2, 3]).unwrap());
+    let obj: Arc<[u8]> = fory.deserialize(&bin).unwrap();
+    assert_eq!(obj, bytes);
+    let obj: Vec<u8> = fory.deserialize(&bin).unwrap();
+    assert_eq!(obj, vec![1, 2, 3]);
+
+    let names: Vec<Arc<str>> = vec![Arc::from("a"), Arc::from(""), Arc::from("ccc")];
+    let bin = fory.serialize(&names).unwrap();
+    let obj: Vec<Arc<str>> = fory.deserialize(&bin).unwrap();
+    assert_eq!(obj, names);
+    let obj: Vec<String> = fory.deserialize(&bin).unwrap();
+    assert_eq!(obj, vec!["a", "", "ccc"]);
+}
+
+#[test]
+fn test_arc_str_fields() {
+    #[derive(ForyStruct, Debug, PartialEq)]
+    struct Document {
+        title: Arc<str>,
+        body: Arc<[u8]>,
+        tags: Vec<Arc<str>>,
+        subtitle: Option<Arc<str>>,
+    }
+
+    for compatible in [false, true] {
+        let mut fory = Fory::builder().xlang(false).compatible(compatible).build();
+        fory.register::<Document>(100).unwrap();
+        let shared: Arc<str> = Arc::from("shared");
+        let document = Document {
+            title: shared.clone(),
+            body: Arc::from(vec![7u8, 8, 9]),
+            tags: vec![shared.clone(), shared, Arc::from("other")],
+            subtitle: Some(Arc::from("sub")),
+        };
+        let bin = fory.serialize(&document).unwrap();
+        let obj: Document = fory.deserialize(&bin).unwrap();
+        assert_eq!(document, obj);
+    }
+}
+
+#[test]
+fn test_string_field_to_arc_str_compatible() {
+    #[derive(ForyStruct, Debug)]
+    struct Owned {
+        name: String,
+        #[fory(bytes)]
+        data: Vec<u8>,
+        aliases: Vec<String>,
+    }
+
+    #[derive(ForyStruct, Debug)]
+    struct Shared {
+        name: Arc<str>,
+        data: Arc<[u8]>,
+        aliases: Vec<Arc<str>>,
+    }
+
+    let mut fory1 = Fory::builder().xlang(false).compatible(true).build();
+    fory1.register::<Owned>(101).unwrap();
+    let mut fory2 = Fory::builder().xlang(false).compatible(true).build();
+    fory2.register::<Shared>(101).unwrap();
+
+    let owned = Owned {
+        name: "fory".to_string(),
+        data: vec![1, 2],
+        aliases: vec!["f".to_string(), "fy".to_string()],
+    };
+    let obj: Shared = fory2
+        .deserialize(&fory1.serialize(&owned).unwrap())
+        .unwrap();
+    assert_eq!(&*obj.name, "fory");
+    assert_eq!(&*obj.data, &[1, 2]);
+    assert_eq!(obj.aliases, vec![Arc::from("f"), Arc::from("fy")]);
+
+    let back: Owned = fory1.deserialize(&fory2.serialize(&obj).unwrap()).unwrap();
+    assert_eq!(back.name, "fory");
+    assert_eq!(back.data, vec![1, 2]);
+    assert_eq!(back.aliases, vec!["f", "fy"]);
+}
@@ -0,0 +1,107 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use fory_core::error::Error;
+use fory_core::fory::Fory;
+use fory_derive::ForyStruct;
+use tokio::io::AsyncWriteExt;
+
+#[derive(ForyStruct, Debug, PartialEq)]
+struct Order {
+    id: u64,
+    customer: String,
+    lines: Vec<u32>,
+}
+
+fn order(id: u64) -> Order {
+    Order {
+        id,
+        customer: format!("customer-{id}"),
+        lines: vec![1, 2, 3],
+    }
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_async_roundtrip_over_duplex() {
+    let mut fory = Fory::default();
+    fory.register::<Order>(100).unwrap();
+    let (mut client, mut server) = tokio::io::duplex(64);
+
+    let value = order(7);
+    let expected = fory.serialize(&value).unwrap();
+    // The pipe is smaller than the frame, so the writer has to run concurrently.
+    let (written, decoded) = tokio::join!(
+        fory.serialize_async(&mut client, &value),
+        fory.deserialize_async::<Order, _>(&mut server)
+    );
+    assert!(written.unwrap() > expected.len());
+    assert_eq!(decoded.unwrap(), value);
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_async_back_to_back_frames() {
+    let mut fory = Fory::default();
+    fory.register::<Order>(100).unwrap();
+    let (mut client, mut server) = tokio::io::duplex(4096);
+
+    for id in 0..3 {
+        fory.serialize_async(&mut client, &order(id)).await.unwrap();
+    }
+    for id in 0..3 {
+        let decoded: Order = fory.deserialize_async(&mut server).await.unwrap();
+        assert_eq!(decoded, order(id));
+    }
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_async_truncated_frame() {
+    if fory_core::error::should_panic_on_error() {
+        return;
+    }
+    let fory = Fory::default();
+    let (mut client, mut server) = tokio::io::duplex(4096);
+    // Prefix announces 10 payload bytes, but the stream closes after 3.
+    client.write_all(&[10, 1, 2, 3]).await.unwrap();
+    drop(client);
+
+    let err = fory
+        .deserialize_async::<String, _>(&mut server)
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("3 of 10"), "{err}");
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_async_oversized_frame() {
+    if fory_core::error::should_panic_on_error() {
+        return;
+    }
+    let fory = Fory::builder().max_binary_size(1024).build();
+    let (mut client, mut server) = tokio::io::duplex(4096);
+    // Prefix announces `u32::MAX` payload bytes; the reader must refuse before buffering.
+    client
+        .write_all(&[0xff, 0xff, 0xff, 0xff, 0x0f, 1, 2, 3])
+        .await
+        .unwrap();
+    drop(client);
+
+    let err = fory
+        .deserialize_async::<String, _>(&mut server)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, Error::SizeLimitExceeded(_)), "{err}");
+}
@@ -0,0 +1,136 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use fory_core::Fory;
+use fory_derive::ForyStruct;
+use std::sync::atomic::{
+    AtomicBool, AtomicI16, AtomicI32, AtomicI64, AtomicI8, AtomicIsize, AtomicU16, AtomicU32,
+    AtomicU64, AtomicU8, AtomicUsize, Ordering,
+};
+
+#[derive(ForyStruct, Debug)]
+struct Counters {
+    enabled: AtomicBool,
+    hits: AtomicU64,
+    in_flight: AtomicI32,
+    errors: Option<AtomicU32>,
+    shards: Vec<AtomicU32>,
+}
+
+#[derive(ForyStruct, Debug, PartialEq)]
+struct PlainCounters {
+    hits: i64,
+}
+
+#[derive(ForyStruct, Debug)]
+struct LiveCounters {
+    hits: AtomicI64,
+}
+
+macro_rules! assert_roundtrip {
+    ($fory:expr, $ty:ty, $value:expr) => {{
+        let bytes = $fory.serialize(&<$ty>::new($value)).unwrap();
+        let decoded: $ty = $fory.deserialize(&bytes).unwrap();
+        assert_eq!(decoded.load(Ordering::SeqCst), $value);
+    }};
+}
+
+#[test]
+fn test_atomic_roundtrip() {
+    let fory = Fory::builder().xlang(false).build();
+    assert_roundtrip!(fory, AtomicBool, true);
+    assert_roundtrip!(fory, AtomicI8, i8::MIN);
+    assert_roundtrip!(fory, AtomicI16, -16);
+    assert_roundtrip!(fory, AtomicI32, -32);
+    assert_roundtrip!(fory, AtomicI64, i64::MAX);
+    assert_roundtrip!(fory, AtomicIsize, -1);
+    assert_roundtrip!(fory, AtomicU8, u8::MAX);
+    assert_roundtrip!(fory, AtomicU16, 16);
+    assert_roundtrip!(fory, AtomicU32, 32);
+    assert_roundtrip!(fory, AtomicU64, u64::MAX);
+    assert_roundtrip!(fory, AtomicUsize, 1);
+
+    // An atomic is read back as its plain value, and the other way round.
+    let bytes = fory.serialize(&AtomicU32::new(7)).unwrap();
+    assert_eq!(fory.deserialize::<u32>(&bytes).unwrap(), 7);
+    let bytes = fory.serialize(&-7i8).unwrap();
+    assert_eq!(
+        fory.deserialize::<AtomicI8>(&bytes).unwrap().into_inner(),
+        -7
+    );
+
+    let bytes = fory
+        .serialize(&vec![AtomicI64::new(1), AtomicI64::new(2)])
+        .unwrap();
+    let decoded: Vec<AtomicI64> = fory.deserialize(&bytes).unwrap();
+    let values: Vec<i64> = decoded.iter().map(|v| v.load(Ordering::SeqCst)).collect();
+    assert_eq!(values, vec![1, 2]);
+}
+
+#[test]
+fn test_atomic_struct_fields() {
+    for compatible in [false, true] {
+        for xlang in [false, true] {
+            let mut fory = Fory::builder().compatible(compatible).xlang(xlang).build();
+            fory.register::<Counters>(100).unwrap();
+            let counters = Counters {
+                enabled: AtomicBool::new(true),
+                hits: AtomicU64::new(0),
+                in_flight: AtomicI32::new(-2),
+                errors: Some(AtomicU32::new(5)),
+                shards: vec![AtomicU32::new(1), AtomicU32::new(9)],
+            };
+            counters.hits.fetch_add(41, Ordering::SeqCst);
+            let bytes = fory.serialize(&counters).unwrap();
+            let decoded: Counters = fory.deserialize(&bytes).unwrap();
+            assert!(decoded.enabled.load(Ordering::SeqCst));
+            assert_eq!(decoded.hits.load(Ordering::SeqCst), 41);
+            assert_eq!(decoded.in_flight.load(Ordering::SeqCst), -2);
+            assert_eq!(
+                decoded.errors.map(|errors| errors.load(Ordering::SeqCst)),
+                Some(5)
+            );
+            let shards: Vec<u32> = decoded
+                .shards
+                .iter()
+                .map(|shard| shard.load(Ordering::SeqCst))
+                .collect();
+            assert_eq!(shards, vec![1, 9]);
+        }
+    }
+}
+
+#[test]
+fn test_atomic_reads_plain_field_in_compatible_mode() {
+    for xlang in [false, true] {
+        let mut writer = Fory::builder().compatible(true).xlang(xlang).build();
+        writer.register::<PlainCounters>(100).unwrap();
+        let mut reader = Fory::builder().compatible(true).xlang(xlang).build();
+        reader.register::<LiveCounters>(100).unwrap();
+
+        let bytes = writer.serialize(&PlainCounters { hits: 12 }).unwrap();
+        let live: LiveCounters = reader.deserialize(&bytes).unwrap();
+        assert_eq!(live.hits.load(Ordering::SeqCst), 12);
+
+        live.hits.fetch_add(1, Ordering::SeqCst);
+        let bytes = reader.serialize(&live).unwrap();
+        assert_eq!(
+            writer.deserialize::<PlainCounters>(&bytes).unwrap(),
+            PlainCounters { hits: 13 }
+        );
+    }
+}
@@ -0,0 +1,128 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use fory_core::buffer::Reader;
+use fory_core::error::Error;
+use fory_core::fory::Fory;
+use fory_core::{BorrowDeserialize, TypeId};
+use fory_derive::ForyStruct;
+
+fn assert_borrows_from(input: &[u8], value: &[u8]) {
+    let range = input.as_ptr_range();
+    assert!(range.start <= value.as_ptr() && value.as_ptr() < range.end);
+}
+
+#[test]
+fn test_borrow_str_and_bytes() {
+    for xlang in [false, true] {
+        let fory = Fory::builder().xlang(xlang).build();
+
+        let bytes = fory.serialize(&"hello, 世界".to_string()).unwrap();
+        let s: &str = fory.deserialize_borrowed(&bytes).unwrap();
+        assert_eq!(s, "hello, 世界");
+        assert_borrows_from(&bytes, s.as_bytes());
+
+        let bytes = fory.serialize(&vec![1u8, 2, 3, 255]).unwrap();
+        let b: &[u8] = fory.deserialize_borrowed(&bytes).unwrap();
+        assert_eq!(b, &[1, 2, 3, 255]);
+        assert_borrows_from(&bytes, b);
+    }
+}
+
+#[test]
+fn test_borrow_type_mismatch() {
+    let fory = Fory::builder().xlang(false).build();
+    let bytes = fory.serialize(&vec![1u8, 2, 3]).unwrap();
+    let err = fory.deserialize_borrowed::<&str>(&bytes).unwrap_err();
+    assert!(matches!(err, Error::TypeMismatch(_)));
+}
+
+#[test]
+fn test_borrow_invalid_utf8() {
+    let fory = Fory::builder().xlang(false).build();
+    let mut bytes = fory.serialize(&"ab".to_string()).unwrap();
+    *bytes.last_mut().unwrap() = 0xFF;
+    assert!(fory.deserialize_borrowed::<&str>(&bytes).is_err());
+}
+
+#[derive(ForyStruct, Debug, PartialEq)]
+struct Message {
+    id: i32,
+    sender: String,
+    subject: String,
+}
+
+/// Borrowed view of `Message`: its string fields point into the serialized bytes.
+#[derive(Debug, PartialEq)]
+struct MessageView<'a> {
+    id: i32,
+    sender: &'a str,
+    subject: &'a str,
+}
+
+impl<'de> BorrowDeserialize<'de> for MessageView<'de> {
+    fn fory_borrowed_type_id() -> TypeId {
+        TypeId::STRUCT
+    }
+
+    fn fory_read_borrowed_data(reader: &mut Reader<'de>) -> Result<Self, Error> {
+        // The registered type id follows the STRUCT type id.
+        let user_type_id = reader.read_var_u32()?;
+        if user_type_id != 100 {
+            return Err(Error::type_mismatch(100, user_type_id));
+        }
+        // Fields come in Fory field order: primitives first, then the others by name.
+        Ok(MessageView {
+            id: reader.read_var_i32()?,
+            sender: <&str>::fory_read_borrowed_data(reader)?,
+            subject: <&str>::fory_read_borrowed_data(reader)?,
+        })
+    }
+}
+
+#[test]
+fn test_borrow_struct() {
+    let mut fory = Fory::builder().xlang(false).compatible(false).build();
+    fory.register::<Message>(100).unwrap();
+    let message = Message {
+        id: 7,
+        sender: "ada@example.com".to_string(),
+        subject: "hello, 世界".to_string(),
+    };
+    let bytes = fory.serialize(&message).unwrap();
+
+    let view: MessageView = fory.deserialize_borrowed(&bytes).unwrap();
+    assert_eq!(
+        view,
+        MessageView {
+            id: 7,
+            sender: "ada@example.com",
+            subject: "hello, 世界",
+        }
+    );
+    assert_borrows_from(&bytes, view.sender.as_bytes());
+    assert_borrows_from(&bytes, view.subject.as_bytes());
+
+    // The borrowed fields round-trip back into the owned struct.
+    let owned = Message {
+        id: view.id,
+        sender: view.sender.to_string(),
+        subject: view.subject.to_string(),
+    };
+    assert_eq!(owned, message);
+    assert_eq!(fory.serialize(&owned).unwrap(), bytes);
+}
@@ -144,3 +144,100 @@ fn test_nested_box() {
         .expect("Should deserialize Box<Box<i32>>");
     assert_eq!(**value, **deserialized);
 }
+
+#[test]
+fn test_box_wire_identical() {
+    #[derive(ForyStruct, Debug, PartialEq)]
+    struct Item {
+        id: i32,
+        name: String,
+    }
+
+    let mut fory = Fory::builder().xlang(false).compatible(false).build();
+    fory.register::<Item>(999).unwrap();
+
+    let item = Item {
+        id: 7,
+        name: "boxed".to_string(),
+    };
+    let boxed_bin = fory
+        .serialize(&Box::new(Item {
+            id: 7,
+            name: "boxed".to_string(),
+        }))
+        .unwrap();
+    assert_eq!(boxed_bin, fory.serialize(&item).unwrap());
+    let deserialized: Item = fory
+        .deserialize(&boxed_bin)
+        .expect("Should deserialize Box<Item> as Item");
+    assert_eq!(item, deserialized);
+}
+
+#[test]
+fn test_box_fields() {
+    #[derive(ForyStruct, Debug, PartialEq)]
+    struct Inner {
+        value: i64,
+    }
+
+    #[allow(clippy::box_collection, clippy::vec_box)]
+    #[derive(ForyStruct, Debug, PartialEq)]
+    struct Outer {
+        inner: Box<Inner>,
+        name: Option<Box<String>>,
+        none_name: Option<Box<String>>,
+        values: Vec<Box<i32>>,
+    }
+
+    for compatible in [false, true] {
+        let mut fory = Fory::builder().xlang(false).compatible(compatible).build();
+        fory.register::<Inner>(1000).unwrap();
+        fory.register::<Outer>(1001).unwrap();
+
+        let value = Outer {
+            inner: Box::new(Inner { value: -5 }),
+            name: Some(Box::new("name".to_string())),
+            none_name: None,
+            values: vec![Box::new(1), Box::new(2), Box::new(3)],
+        };
+        let bin = fory.serialize(&value).unwrap();
+        let deserialized: Outer = fory.deserialize(&bin).expect("Should deserialize Outer");
+        assert_eq!(value, deserialized);
+    }
+}
+
+#[derive(ForyStruct, Debug, PartialEq)]
+struct ListNode {
+    val: i32,
+    next: Option<Box<ListNode>>,
+}
+
+fn linked_list(values: &[i32]) -> Option<Box<ListNode>> {
+    values
+        .iter()
+        .rev()
+        .fold(None, |next, &val| Some(Box::new(ListNode { val, next })))
+}
+
+#[test]
+fn test_option_box_linked_list() {
+    for compatible in [false, true] {
+        for xlang in [false, true] {
+            let mut fory = Fory::builder().xlang(xlang).compatible(compatible).build();
+            fory.register::<ListNode>(1002).unwrap();
+
+            let head = *linked_list(&[1, 2, 3, 4, 5]).unwrap();
+            let bin = fory.serialize(&head).unwrap();
+            let deserialized: ListNode = fory.deserialize(&bin).unwrap();
+            assert_eq!(head, deserialized);
+
+            let mut values = vec![];
+            let mut node = Some(&deserialized);
+            while let Some(current) = node {
+                values.push(current.val);
+                node = current.next.as_deref();
+            }
+            assert_eq!(values, vec![1, 2, 3, 4, 5]);
+        }
+    }
+}
@@ -0,0 +1,103 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use fory_core::Fory;
+use fory_derive::ForyStruct;
+
+#[derive(ForyStruct, Debug, PartialEq)]
+struct Frozen {
+    ids: Box<[i32]>,
+    raw: Box<[u8]>,
+    names: Box<[String]>,
+    maybe: Option<Box<[i64]>>,
+}
+
+#[derive(ForyStruct, Debug, PartialEq)]
+struct PeerTable {
+    name: String,
+    ids: Vec<i32>,
+}
+
+#[derive(ForyStruct, Debug, PartialEq)]
+struct LocalTable {
+    name: String,
+    ids: Box<[i32]>,
+}
+
+#[test]
+fn test_boxed_slice_round_trip() {
+    for xlang in [false, true] {
+        for compatible in [false, true] {
+            let mut fory = Fory::builder().xlang(xlang).compatible(compatible).build();
+            fory.register::<Frozen>(100).unwrap();
+
+            let ids: Box<[i32]> = vec![1, -2, 3].into_boxed_slice();
+            let bytes = fory.serialize(&ids).unwrap();
+            assert_eq!(bytes, fory.serialize(&ids.to_vec()).unwrap());
+            let decoded: Box<[i32]> = fory.deserialize(&bytes).unwrap();
+            assert_eq!(decoded, ids);
+            let names: Box<[String]> = vec!["a".to_string(), "b".to_string()].into();
+            let bytes = fory.serialize(&names).unwrap();
+            assert_eq!(
+                fory.deserialize::<Vec<String>>(&bytes).unwrap(),
+                names.to_vec()
+            );
+
+            let frozen = Frozen {
+                ids,
+                raw: Box::new([0u8, 255, 7]),
+                names,
+                maybe: Some(Box::new([i64::MAX])),
+            };
+            let bytes = fory.serialize(&frozen).unwrap();
+            assert_eq!(fory.deserialize::<Frozen>(&bytes).unwrap(), frozen);
+
+            let empty = Frozen {
+                ids: Box::default(),
+                raw: Box::default(),
+                names: Box::default(),
+                maybe: None,
+            };
+            let bytes = fory.serialize(&empty).unwrap();
+            assert_eq!(fory.deserialize::<Frozen>(&bytes).unwrap(), empty);
+        }
+    }
+}
+
+#[test]
+fn test_boxed_slice_reads_vec_field() {
+    for xlang in [false, true] {
+        let mut writer = Fory::builder().xlang(xlang).compatible(true).build();
+        writer.register::<PeerTable>(101).unwrap();
+        let mut reader = Fory::builder().xlang(xlang).compatible(true).build();
+        reader.register::<LocalTable>(101).unwrap();
+
+        let peer = PeerTable {
+            name: "peer".to_string(),
+            ids: vec![4, 5, 6],
+        };
+        let bytes = writer.serialize(&peer).unwrap();
+        let decoded: LocalTable = reader.deserialize(&bytes).unwrap();
+        assert_eq!(decoded.name, "peer");
+        assert_eq!(&*decoded.ids, &[4, 5, 6]);
+
+        let back: PeerTable = writer
+            .deserialize(&reader.serialize(&decoded).unwrap())
+            .unwrap();
+        assert_eq!(back, peer);
+    }
+}
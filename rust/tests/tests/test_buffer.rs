@@ -16,7 +16,7 @@
 // under the License.
 
 use fory_core::buffer::{Reader, Writer};
-use fory_core::Fory;
+use fory_core::{Error, Fory};
 
 #[test]
 fn test_var_i32() {
@@ -61,6 +61,112 @@ fn test_var_i32() {
     }
 }
 
+#[test]
+fn test_var_i64() {
+    let test_data: Vec<(u64, usize)> = vec![
+        // 1 byte(0..2^7)
+        (0, 1),
+        (127, 1),
+        // 2 byte(2^7..2^14)
+        (128, 2),
+        (16_383, 2),
+        // 3 byte(2^14..2^21)
+        (16_384, 3),
+        (2_097_151, 3),
+        // 4 byte(2^21..2^28)
+        (2_097_152, 4),
+        (268_435_455, 4),
+        // 5 byte(2^28..2^35)
+        (268_435_456, 5),
+        ((1 << 35) - 1, 5),
+        // 6 byte(2^35..2^42)
+        (1 << 35, 6),
+        ((1 << 42) - 1, 6),
+        // 7 byte(2^42..2^49)
+        (1 << 42, 7),
+        ((1 << 49) - 1, 7),
+        // 8 byte(2^49..2^56)
+        (1 << 49, 8),
+        ((1 << 56) - 1, 8),
+        // 9 byte(2^56..=u64::MAX), the last byte holds 8 bits
+        (1 << 56, 9),
+        (i64::MAX as u64, 9),
+        (u64::MAX, 9),
+    ];
+    for &(data, len) in &test_data {
+        let mut buffer = vec![];
+        let mut writer = Writer::from_buffer(&mut buffer);
+        writer.write_var_u64(data);
+        assert_eq!(writer.len(), len, "{data}");
+        let binding = writer.dump();
+        let mut reader = Reader::new(binding.as_slice());
+        assert_eq!(reader.read_var_u64().unwrap(), data);
+        assert_eq!(reader.get_cursor(), len);
+    }
+
+    // Zig-zag maps small magnitudes of either sign to short encodings.
+    let signed_data: Vec<(i64, usize)> = vec![
+        (0, 1),
+        (-1, 1),
+        (63, 1),
+        (-64, 1),
+        (64, 2),
+        (-65, 2),
+        (1 << 27, 5),
+        (-(1 << 34), 5),
+        (1 << 34, 6),
+        ((1 << 55) - 1, 8),
+        (-(1 << 55), 8),
+        (1 << 55, 9),
+        (i64::MAX, 9),
+        (i64::MIN, 9),
+    ];
+    for &(data, len) in &signed_data {
+        let mut buffer = vec![];
+        let mut writer = Writer::from_buffer(&mut buffer);
+        writer.write_var_i64(data);
+        assert_eq!(writer.len(), len, "{data}");
+        let binding = writer.dump();
+        let mut reader = Reader::new(binding.as_slice());
+        assert_eq!(reader.read_var_i64().unwrap(), data);
+        assert_eq!(reader.get_cursor(), len);
+    }
+}
+
+#[test]
+fn test_var_u32_rejects_overlong_input() {
+    if fory_core::error::should_panic_on_error() {
+        return;
+    }
+    for data in [i32::MIN, i32::MAX] {
+        let mut buffer = vec![];
+        Writer::from_buffer(&mut buffer).write_var_i32(data);
+        assert_eq!(buffer.len(), 5);
+        assert_eq!(Reader::new(&buffer).read_var_i32().unwrap(), data);
+    }
+
+    let malformed: [&[u8]; 3] = [
+        // Continuation bit still set on the fifth byte.
+        &[0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x01],
+        &[0x80, 0x80, 0x80, 0x80, 0x80],
+        // Fifth byte sets bits beyond 32.
+        &[0x80, 0x80, 0x80, 0x80, 0x10],
+    ];
+    for bytes in malformed {
+        let mut reader = Reader::new(bytes);
+        let err = reader.read_var_u32().unwrap_err();
+        assert!(matches!(err, Error::MalformedVarint(_)), "{err}");
+        assert_eq!(reader.get_cursor(), 0);
+        let err = Reader::new(bytes).read_var_i32().unwrap_err();
+        assert!(matches!(err, Error::MalformedVarint(_)), "{err}");
+    }
+
+    // The ninth byte of a var_uint64 is a full byte, so reading stops there.
+    let mut reader = Reader::new(&[0xFF; 10]);
+    assert_eq!(reader.read_var_u64().unwrap(), u64::MAX);
+    assert_eq!(reader.get_cursor(), 9);
+}
+
 #[test]
 fn test_var_u36_small() {
     let test_data: Vec<u64> = vec![
@@ -98,6 +204,51 @@ fn test_var_u36_small() {
     }
 }
 
+#[test]
+fn test_writer_len_and_capacity() {
+    let mut buffer = vec![];
+    let mut writer = Writer::from_buffer(&mut buffer);
+    assert_eq!(writer.len(), 0);
+    let mut capacity = writer.capacity();
+    for i in 0..1000u32 {
+        writer.write_u8(i as u8);
+        writer.write_u32(i);
+        writer.write_u64(i as u64);
+        assert_eq!(writer.len(), (i as usize + 1) * 13);
+        assert!(writer.capacity() >= capacity);
+        assert!(writer.capacity() >= writer.len());
+        capacity = writer.capacity();
+    }
+
+    writer.reserve(4096);
+    assert!(writer.capacity() - writer.len() >= 4096);
+    let capacity = writer.capacity();
+    writer.write_bytes(&[0; 4096]);
+    assert_eq!(writer.capacity(), capacity);
+    assert_eq!(writer.len(), 13_000 + 4096);
+}
+
+#[test]
+fn test_i128_u128() {
+    for data in [i128::MIN, -1, 0, 1, i128::MAX] {
+        let mut buffer = vec![];
+        let mut writer = Writer::from_buffer(&mut buffer);
+        writer.write_i128(data);
+        assert_eq!(buffer, data.to_le_bytes());
+        let mut reader = Reader::new(buffer.as_slice());
+        assert_eq!(reader.read_i128().unwrap(), data);
+        assert_eq!(reader.get_cursor(), 16);
+    }
+    for data in [0, 1, u128::MAX] {
+        let mut buffer = vec![];
+        let mut writer = Writer::from_buffer(&mut buffer);
+        writer.write_u128(data);
+        assert_eq!(buffer, data.to_le_bytes());
+        let mut reader = Reader::new(buffer.as_slice());
+        assert_eq!(reader.read_u128().unwrap(), data);
+    }
+}
+
 #[test]
 fn test_fixed_width_read_bounds_checks() {
     let mut empty = Reader::new(&[]);
@@ -118,6 +269,94 @@ fn test_fixed_width_read_bounds_checks() {
     assert!(bad_cursor.read_var_u36_small().is_err());
 }
 
+#[test]
+fn test_byte_blocks() {
+    let large: Vec<u8> = (0..1_000_000u32).map(|i| i as u8).collect();
+    let mut buffer = vec![];
+    let mut writer = Writer::from_buffer(&mut buffer);
+    assert_eq!(writer.write_bytes(&[]), 0);
+    assert_eq!(writer.write_bytes(&large), large.len());
+    assert_eq!(writer.write_bytes(&[7]), 1);
+
+    let mut reader = Reader::new(buffer.as_slice());
+    assert_eq!(reader.read_bytes(0).unwrap(), &[] as &[u8]);
+    assert_eq!(reader.read_bytes(large.len()).unwrap(), large.as_slice());
+    assert_eq!(reader.read_bytes(1).unwrap(), &[7]);
+    assert_eq!(reader.read_bytes(0).unwrap(), &[] as &[u8]);
+
+    let fory = Fory::builder().xlang(false).build();
+    for value in [vec![], large] {
+        let bytes = fory.serialize(&value).unwrap();
+        assert_eq!(fory.deserialize::<Vec<u8>>(&bytes).unwrap(), value);
+    }
+}
+
+#[test]
+fn test_read_bytes_underflow() {
+    let mut reader = Reader::new(&[1, 2, 3]);
+    assert_eq!(reader.read_bytes(2).unwrap(), &[1, 2]);
+    assert!(reader.read_bytes(2).is_err());
+    // A failed read leaves the cursor where it was.
+    assert_eq!(reader.get_cursor(), 2);
+    assert!(reader.read_bytes(usize::MAX).is_err());
+    assert_eq!(reader.read_bytes(1).unwrap(), &[3]);
+
+    let fory = Fory::builder().xlang(false).build();
+    let bytes = fory.serialize(&vec![9u8; 64]).unwrap();
+    assert!(fory
+        .deserialize::<Vec<u8>>(&bytes[..bytes.len() - 1])
+        .is_err());
+}
+
+#[test]
+fn test_peek_and_skip() {
+    let mut buffer = vec![];
+    let mut writer = Writer::from_buffer(&mut buffer);
+    writer.write_u8(7);
+    writer.write_var_u32(300);
+    writer.write_var_u32(u32::MAX);
+    writer.write_u8(9);
+
+    let mut reader = Reader::new(&buffer);
+    assert_eq!(reader.peek_u8().unwrap(), 7);
+    assert_eq!(reader.peek_u8().unwrap(), 7);
+    assert_eq!(reader.get_cursor(), 0);
+    reader.skip(1).unwrap();
+    assert_eq!(reader.get_cursor(), 1);
+
+    assert_eq!(reader.peek_var_u32().unwrap(), 300);
+    assert_eq!(reader.get_cursor(), 1);
+    assert_eq!(reader.read_var_u32().unwrap(), 300);
+    assert_eq!(reader.peek_var_u32().unwrap(), u32::MAX);
+    reader.skip(5).unwrap();
+    assert_eq!(reader.get_cursor(), buffer.len() - 1);
+
+    if !fory_core::error::should_panic_on_error() {
+        // Skipping past the end fails without moving the cursor.
+        assert!(matches!(
+            reader.skip(2),
+            Err(Error::BufferOutOfBound(_, 2, _))
+        ));
+        assert!(reader.skip(usize::MAX).is_err());
+        assert_eq!(reader.get_cursor(), buffer.len() - 1);
+        reader.skip(1).unwrap();
+        assert!(reader.peek_u8().is_err());
+        assert!(reader.peek_var_u32().is_err());
+        reader.skip(0).unwrap();
+
+        // A truncated or overlong varint fails to peek and leaves the cursor in place.
+        let mut reader = Reader::new(&[0x80, 0x80]);
+        assert!(reader.peek_var_u32().is_err());
+        assert_eq!(reader.get_cursor(), 0);
+        let mut reader = Reader::new(&[0xff, 0xff, 0xff, 0xff, 0x1f]);
+        assert!(matches!(
+            reader.peek_var_u32(),
+            Err(Error::MalformedVarint(_))
+        ));
+        assert_eq!(reader.get_cursor(), 0);
+    }
+}
+
 #[test]
 fn test_utf8_string_read_rejects_invalid_payload() {
     let mut reader = Reader::new(&[0xff]);
@@ -156,3 +395,30 @@ fn test_fory_can_disable_checked_string_read_for_trusted_data() {
     let value = fory.deserialize::<String>(&bytes).unwrap();
     assert_eq!(value, "valid");
 }
+
+#[test]
+fn test_trusted_utf8_reads_match_checked_reads() {
+    let checked = Fory::builder().xlang(false).compatible(false).build();
+    let trusting = Fory::builder()
+        .xlang(false)
+        .compatible(false)
+        .trust_utf8(true)
+        .build();
+    assert!(checked.is_check_string_read());
+    assert!(!trusting.is_check_string_read());
+
+    let values = vec![
+        String::new(),
+        "plain ascii".to_string(),
+        "café crème".to_string(),
+        "数据序列化".to_string(),
+        "mixed 🦀 and ✓".to_string(),
+        "x".repeat(1000),
+    ];
+    let bytes = checked.serialize(&values).unwrap();
+    assert_eq!(trusting.serialize(&values).unwrap(), bytes);
+    let from_checked: Vec<String> = checked.deserialize(&bytes).unwrap();
+    let from_trusting: Vec<String> = trusting.deserialize(&bytes).unwrap();
+    assert_eq!(from_checked, values);
+    assert_eq!(from_trusting, from_checked);
+}
@@ -0,0 +1,105 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use bytes::{Bytes, BytesMut};
+use fory_core::Fory;
+use fory_derive::ForyStruct;
+
+#[derive(ForyStruct, Debug, PartialEq)]
+struct Packet {
+    id: u32,
+    payload: Bytes,
+    scratch: BytesMut,
+    trailer: Option<Bytes>,
+    chunks: Vec<Bytes>,
+}
+
+#[derive(ForyStruct, Debug, PartialEq)]
+struct PeerPacket {
+    id: u32,
+    #[fory(bytes)]
+    payload: Vec<u8>,
+    #[fory(bytes)]
+    scratch: Vec<u8>,
+}
+
+#[derive(ForyStruct, Debug, PartialEq)]
+struct LocalPacket {
+    id: u32,
+    payload: Bytes,
+    scratch: BytesMut,
+}
+
+#[test]
+fn test_bytes_roundtrip() {
+    let fory = Fory::builder().xlang(false).build();
+    let raw = vec![0u8, 1, 2, 254, 255];
+
+    let bytes = fory.serialize(&Bytes::from(raw.clone())).unwrap();
+    assert_eq!(bytes, fory.serialize(&raw).unwrap());
+    assert_eq!(fory.deserialize::<Bytes>(&bytes).unwrap(), raw);
+    assert_eq!(fory.deserialize::<BytesMut>(&bytes).unwrap(), raw[..]);
+    assert_eq!(fory.deserialize::<Vec<u8>>(&bytes).unwrap(), raw);
+
+    let mut buf = BytesMut::new();
+    buf.extend_from_slice(b"mutable");
+    let bytes = fory.serialize(&buf).unwrap();
+    assert_eq!(bytes, fory.serialize(&b"mutable".to_vec()).unwrap());
+    assert_eq!(fory.deserialize::<BytesMut>(&bytes).unwrap(), buf);
+
+    let empty = fory.serialize(&Bytes::new()).unwrap();
+    assert!(fory.deserialize::<Bytes>(&empty).unwrap().is_empty());
+}
+
+#[test]
+fn test_bytes_struct_fields() {
+    for compatible in [false, true] {
+        let mut fory = Fory::builder().compatible(compatible).xlang(false).build();
+        fory.register::<Packet>(100).unwrap();
+        let packet = Packet {
+            id: 7,
+            payload: Bytes::from_static(b"hello"),
+            scratch: BytesMut::from(&b"world"[..]),
+            trailer: Some(Bytes::from_static(&[0xff])),
+            chunks: vec![Bytes::from_static(b"a"), Bytes::new()],
+        };
+        let bytes = fory.serialize(&packet).unwrap();
+        assert_eq!(fory.deserialize::<Packet>(&bytes).unwrap(), packet);
+    }
+}
+
+#[test]
+fn test_bytes_read_peer_byte_array_fields() {
+    let mut writer = Fory::builder().compatible(true).xlang(false).build();
+    writer.register::<PeerPacket>(100).unwrap();
+    let mut reader = Fory::builder().compatible(true).xlang(false).build();
+    reader.register::<LocalPacket>(100).unwrap();
+
+    let peer = PeerPacket {
+        id: 3,
+        payload: vec![1, 2, 3],
+        scratch: vec![4, 5],
+    };
+    let bytes = writer.serialize(&peer).unwrap();
+    let local: LocalPacket = reader.deserialize(&bytes).unwrap();
+    assert_eq!(local.id, 3);
+    assert_eq!(local.payload, peer.payload);
+    assert_eq!(local.scratch, peer.scratch[..]);
+
+    let bytes = reader.serialize(&local).unwrap();
+    assert_eq!(writer.deserialize::<PeerPacket>(&bytes).unwrap(), peer);
+}
@@ -0,0 +1,89 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+mod test_helpers;
+
+use fory_core::fory::Fory;
+use fory_derive::ForyStruct;
+use std::collections::HashMap;
+use test_helpers::{test_arc_any, test_box_any, test_rc_any, test_roundtrip};
+
+#[test]
+fn test_char() {
+    let fory = Fory::builder().xlang(false).compatible(false).build();
+    for c in [
+        '\0',
+        'a',
+        '~',
+        'é',
+        '中',
+        '😀',
+        '\u{D7FF}',
+        '\u{E000}',
+        '\u{10FFFF}',
+    ] {
+        test_roundtrip(&fory, c);
+        test_box_any(&fory, c);
+        test_rc_any(&fory, c);
+        test_arc_any(&fory, c);
+    }
+    test_roundtrip(&fory, Some('x'));
+    test_roundtrip(&fory, None::<char>);
+    test_roundtrip(&fory, vec!['a', 'é', '😀']);
+    test_roundtrip(&fory, HashMap::from([('a', 1), ('中', 2), ('😀', 3)]));
+}
+
+#[test]
+fn test_char_struct() {
+    #[derive(ForyStruct, Debug, PartialEq)]
+    struct Glyph {
+        c: char,
+        opt_c: Option<char>,
+        widths: HashMap<char, i32>,
+    }
+
+    for compatible in [false, true] {
+        let mut fory = Fory::builder().xlang(false).compatible(compatible).build();
+        fory.register::<Glyph>(100).unwrap();
+        let glyph = Glyph {
+            c: '\u{10FFFF}',
+            opt_c: Some('é'),
+            widths: HashMap::from([('m', 2), ('i', 1)]),
+        };
+        test_roundtrip(&fory, glyph);
+    }
+}
+
+#[test]
+fn test_char_rejects_invalid_code_point() {
+    let fory = Fory::builder().xlang(false).compatible(false).build();
+    let mut bytes = fory.serialize(&'a').unwrap();
+    // Replace the trailing var_uint32 code point with a surrogate (0xD800).
+    bytes.truncate(bytes.len() - 1);
+    bytes.extend_from_slice(&[0x80, 0xB0, 0x03]);
+    let err = fory.deserialize::<char>(&bytes).unwrap_err();
+    assert!(
+        err.to_string().contains("invalid char code point"),
+        "unexpected error: {err}"
+    );
+}
+
+#[test]
+fn test_char_when_xlang() {
+    let fory = Fory::builder().xlang(true).compatible(false).build();
+    assert!(fory.serialize(&'a').is_err());
+}
@@ -0,0 +1,140 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use fory_core::type_id::config_flags::IS_CHECKSUMMED_FLAG;
+use fory_core::{Checksum, Error, Fory, Reader};
+use fory_derive::ForyStruct;
+
+#[derive(ForyStruct, Debug, PartialEq)]
+struct Record {
+    id: i64,
+    name: String,
+    scores: Vec<i32>,
+}
+
+fn record(id: i64) -> Record {
+    Record {
+        id,
+        name: format!("record-{id}"),
+        scores: (0..16).collect(),
+    }
+}
+
+fn fory(checksum: Checksum) -> Fory {
+    let mut fory = Fory::builder()
+        .xlang(false)
+        .compatible(true)
+        .checksum(checksum)
+        .build();
+    fory.register::<Record>(100).unwrap();
+    fory
+}
+
+#[test]
+fn test_checksum_roundtrip() {
+    let plain = fory(Checksum::None);
+    let checked = fory(Checksum::Crc32);
+    assert_eq!(checked.get_checksum(), Checksum::Crc32);
+
+    let value = record(1);
+    let plain_bytes = plain.serialize(&value).unwrap();
+    let checked_bytes = checked.serialize(&value).unwrap();
+    // One length byte after the header and a four-byte footer.
+    assert_eq!(checked_bytes.len(), plain_bytes.len() + 1 + 4);
+    assert_eq!(
+        checked.deserialize::<Record>(&checked_bytes).unwrap(),
+        value
+    );
+
+    // Readers detect the checksum from the header, whatever their own setting.
+    assert_eq!(plain.deserialize::<Record>(&checked_bytes).unwrap(), value);
+    assert_eq!(checked.deserialize::<Record>(&plain_bytes).unwrap(), value);
+
+    // Checksummed and plain messages can be interleaved in one buffer.
+    let mut buf = Vec::new();
+    checked.serialize_to(&mut buf, &record(2)).unwrap();
+    plain.serialize_to(&mut buf, &record(3)).unwrap();
+    checked.serialize_to(&mut buf, &7i32).unwrap();
+    let mut reader = Reader::new(&buf);
+    assert_eq!(
+        checked.deserialize_from::<Record>(&mut reader).unwrap(),
+        record(2)
+    );
+    assert_eq!(
+        checked.deserialize_from::<Record>(&mut reader).unwrap(),
+        record(3)
+    );
+    assert_eq!(checked.deserialize_from::<i32>(&mut reader).unwrap(), 7);
+    assert_eq!(reader.get_cursor(), buf.len());
+
+    let mut target = record(0);
+    checked
+        .deserialize_into(&checked_bytes, &mut target)
+        .unwrap();
+    assert_eq!(target, value);
+    assert!(checked
+        .debug_dump(&checked_bytes)
+        .unwrap()
+        .contains("record-1"));
+}
+
+#[test]
+fn test_checksum_detects_corruption() {
+    if fory_core::error::should_panic_on_error() {
+        return;
+    }
+    let fory = fory(Checksum::Crc32);
+    let bytes = fory.serialize(&record(1)).unwrap();
+    // Flip one bit in every byte after the header in turn, footer included.
+    for index in 1..bytes.len() {
+        let mut corrupted = bytes.clone();
+        corrupted[index] ^= 0x10;
+        match fory.deserialize::<Record>(&corrupted) {
+            Err(Error::ChecksumMismatch(..)) => {}
+            // A corrupted length prefix can also run the footer past the buffer.
+            Err(Error::BufferOutOfBound(..)) if index == 1 => {}
+            other => panic!("corruption at byte {index} not detected: {other:?}"),
+        }
+    }
+
+    let mut truncated = bytes.clone();
+    truncated.pop();
+    assert!(fory.deserialize::<Record>(&truncated).is_err());
+}
+
+#[test]
+fn test_checksum_rejected_in_xlang() {
+    if fory_core::error::should_panic_on_error() {
+        return;
+    }
+    let fory = Fory::builder()
+        .xlang(true)
+        .checksum(Checksum::Crc32)
+        .build();
+    let err = fory.serialize(&1i32).unwrap_err();
+    assert!(matches!(err, Error::NotAllowed(_)), "{err}");
+
+    // Readers reject the checksum bit in an xlang header before unwrapping.
+    let mut bytes = Fory::builder()
+        .xlang(true)
+        .build()
+        .serialize(&1i32)
+        .unwrap();
+    bytes[0] |= IS_CHECKSUMMED_FLAG;
+    let err = fory.deserialize::<i32>(&bytes).unwrap_err();
+    assert!(matches!(err, Error::InvalidData(_)), "{err}");
+}
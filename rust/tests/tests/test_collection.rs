@@ -118,6 +118,60 @@ fn test_heap_container() {
     assert_eq!(deserialized.binary_heap.peek(), Some(&3));
 }
 
+#[test]
+fn test_binaryheap_pop_order() {
+    let fory = Fory::builder().xlang(false).compatible(false).build();
+    let original = BinaryHeap::from(vec![
+        "pear".to_string(),
+        "apple".to_string(),
+        "quince".to_string(),
+        "fig".to_string(),
+        "apple".to_string(),
+    ]);
+
+    let serialized = fory.serialize(&original).unwrap();
+    let deserialized: BinaryHeap<String> = fory.deserialize(&serialized).unwrap();
+    assert_eq!(deserialized.into_sorted_vec(), original.into_sorted_vec());
+
+    // The heap shares the list wire format, so a Vec reads the same bytes.
+    let heap = BinaryHeap::from(vec!["b".to_string(), "a".to_string()]);
+    let as_vec: Vec<String> = fory.deserialize(&fory.serialize(&heap).unwrap()).unwrap();
+    assert_eq!(as_vec, heap.into_vec());
+}
+
+#[derive(ForyStruct, Debug)]
+struct VecPriorities {
+    priorities: Vec<i32>,
+}
+
+#[derive(ForyStruct, Debug)]
+struct HeapPriorities {
+    priorities: BinaryHeap<i32>,
+}
+
+#[test]
+fn test_binaryheap_reads_vec_field_in_compatible_mode() {
+    let mut writer = Fory::builder().xlang(false).compatible(true).build();
+    writer.register::<VecPriorities>(101).unwrap();
+    let mut reader = Fory::builder().xlang(false).compatible(true).build();
+    reader.register::<HeapPriorities>(101).unwrap();
+
+    let serialized = writer
+        .serialize(&VecPriorities {
+            priorities: vec![5, 1, 9, 3],
+        })
+        .unwrap();
+    let mut heap = reader
+        .deserialize::<HeapPriorities>(&serialized)
+        .unwrap()
+        .priorities;
+    let mut popped = Vec::new();
+    while let Some(value) = heap.pop() {
+        popped.push(value);
+    }
+    assert_eq!(popped, vec![9, 5, 3, 1]);
+}
+
 #[test]
 fn test_hashset_max_collection_size_guardrail() {
     let fory = Fory::builder().xlang(false).compatible(false).build();
@@ -0,0 +1,158 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use fory_core::buffer::Reader;
+use fory_core::error::Error;
+use fory_core::fory::Fory;
+use fory_core::Compression;
+use fory_derive::ForyStruct;
+
+#[derive(ForyStruct, Debug, PartialEq)]
+struct Record {
+    id: i64,
+    name: String,
+    tags: Vec<String>,
+}
+
+#[derive(ForyStruct, Debug, PartialEq)]
+struct Batch {
+    records: Vec<Record>,
+}
+
+fn batch() -> Batch {
+    Batch {
+        records: (0..4000)
+            .map(|i| Record {
+                id: i,
+                name: format!("record-{}", i % 10),
+                tags: vec!["alpha".to_string(), "beta".to_string(), "gamma".to_string()],
+            })
+            .collect(),
+    }
+}
+
+fn fory(compression: Compression) -> Fory {
+    let mut fory = Fory::builder()
+        .xlang(false)
+        .compression(compression)
+        .build();
+    fory.register::<Record>(100).unwrap();
+    fory.register::<Batch>(101).unwrap();
+    fory
+}
+
+#[test]
+fn test_lz4_round_trip() {
+    let plain = fory(Compression::None);
+    let lz4 = fory(Compression::Lz4);
+    let value = batch();
+
+    let plain_bytes = plain.serialize(&value).unwrap();
+    let lz4_bytes = lz4.serialize(&value).unwrap();
+    assert!(plain_bytes.len() > 100 * 1024);
+    assert!(lz4_bytes.len() * 2 < plain_bytes.len());
+
+    assert_eq!(value, lz4.deserialize::<Batch>(&lz4_bytes).unwrap());
+    // Readers decompress regardless of their own compression setting.
+    assert_eq!(value, plain.deserialize::<Batch>(&lz4_bytes).unwrap());
+    assert_eq!(value, lz4.deserialize::<Batch>(&plain_bytes).unwrap());
+}
+
+#[test]
+fn test_lz4_concatenated_messages() {
+    let fory = fory(Compression::Lz4);
+    let value = batch();
+
+    let mut buf = Vec::new();
+    let first_len = fory.serialize_to(&mut buf, &value).unwrap();
+    fory.serialize_to(&mut buf, &"tail".to_string()).unwrap();
+
+    let mut reader = Reader::new(&buf);
+    assert_eq!(value, fory.deserialize_from::<Batch>(&mut reader).unwrap());
+    assert_eq!(reader.get_cursor(), first_len);
+    assert_eq!(
+        "tail",
+        fory.deserialize_from::<String>(&mut reader).unwrap()
+    );
+    assert_eq!(reader.get_cursor(), buf.len());
+
+    let (_, len) = fory.deserialize_with_len::<Batch>(&buf).unwrap();
+    assert_eq!(len, first_len);
+
+    let mut sink = Vec::new();
+    fory.serialize_to_writer(&mut sink, &value).unwrap();
+    assert_eq!(&sink, &buf[..first_len]);
+}
+
+#[test]
+fn test_lz4_rejected_in_xlang() {
+    let fory = Fory::builder()
+        .xlang(true)
+        .compression(Compression::Lz4)
+        .build();
+    let err = fory.serialize(&"value".to_string()).unwrap_err();
+    assert!(matches!(err, Error::NotAllowed(_)));
+}
+
+#[test]
+fn test_lz4_decompressed_size_limit() {
+    let bytes = fory(Compression::Lz4).serialize(&batch()).unwrap();
+    let mut reader = Fory::builder().xlang(false).max_binary_size(1024).build();
+    reader.register::<Record>(100).unwrap();
+    reader.register::<Batch>(101).unwrap();
+    let err = reader.deserialize::<Batch>(&bytes).unwrap_err();
+    assert!(matches!(err, Error::SizeLimitExceeded(_)));
+}
+
+#[test]
+fn test_snappy_cross_configuration() {
+    let snappy = fory(Compression::Snappy);
+    let plain = fory(Compression::None);
+    let value = batch();
+
+    let snappy_bytes = snappy.serialize(&value).unwrap();
+    let plain_bytes = plain.serialize(&value).unwrap();
+    assert!(snappy_bytes.len() * 2 < plain_bytes.len());
+
+    // The header flag decides how each message is decoded, not the reader's config.
+    assert_eq!(value, plain.deserialize::<Batch>(&snappy_bytes).unwrap());
+    assert_eq!(value, snappy.deserialize::<Batch>(&plain_bytes).unwrap());
+    assert_eq!(value, snappy.deserialize::<Batch>(&snappy_bytes).unwrap());
+
+    // Messages with different compression can share one buffer.
+    let mut buf = Vec::new();
+    snappy.serialize_to(&mut buf, &value).unwrap();
+    fory(Compression::Lz4)
+        .serialize_to(&mut buf, &value)
+        .unwrap();
+    plain.serialize_to(&mut buf, &value).unwrap();
+    let mut reader = Reader::new(&buf);
+    for _ in 0..3 {
+        assert_eq!(value, plain.deserialize_from::<Batch>(&mut reader).unwrap());
+    }
+    assert_eq!(reader.get_cursor(), buf.len());
+}
+
+#[test]
+fn test_snappy_decompressed_size_limit() {
+    let bytes = fory(Compression::Snappy).serialize(&batch()).unwrap();
+    let mut reader = Fory::builder().xlang(false).max_binary_size(1024).build();
+    reader.register::<Record>(100).unwrap();
+    reader.register::<Batch>(101).unwrap();
+    let err = reader.deserialize::<Batch>(&bytes).unwrap_err();
+    assert!(matches!(err, Error::SizeLimitExceeded(_)));
+}
@@ -0,0 +1,102 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use fory_core::fory::Fory;
+use fory_derive::ForyStruct;
+use std::borrow::Cow;
+
+#[test]
+fn test_cow_wire_compatible() {
+    let fory = Fory::builder().xlang(false).build();
+
+    let borrowed: Cow<'static, str> = Cow::Borrowed("hello");
+    let bin = fory.serialize(&borrowed).unwrap();
+    assert_eq!(bin, fory.serialize(&"hello".to_string()).unwrap());
+    let obj: Cow<'static, str> = fory.deserialize(&bin).unwrap();
+    assert!(matches!(obj, Cow::Owned(ref s) if s == "hello"));
+
+    let bytes: Cow<'static, [u8]> = Cow::Borrowed(&[1, 2, 3]);
+    let bin = fory.serialize(&bytes).unwrap();
+    assert_eq!(bin, fory.serialize(&vec![1u8, 2, 3]).unwrap());
+    let obj: Cow<'static, [u8]> = fory.deserialize(&bin).unwrap();
+    assert!(matches!(obj, Cow::Owned(ref v) if v == &[1, 2, 3]));
+    let obj: Vec<u8> = fory.deserialize(&bin).unwrap();
+    assert_eq!(obj, vec![1, 2, 3]);
+}
+
+#[test]
+fn test_cow_fields() {
+    #[derive(ForyStruct, Debug, PartialEq)]
+    struct Message {
+        topic: Cow<'static, str>,
+        payload: Cow<'static, [u8]>,
+        reply_to: Option<Cow<'static, str>>,
+    }
+
+    for compatible in [false, true] {
+        let mut fory = Fory::builder().xlang(false).compatible(compatible).build();
+        fory.register::<Message>(100).unwrap();
+        let message = Message {
+            topic: Cow::Borrowed("events"),
+            payload: Cow::Owned(vec![7, 8, 9]),
+            reply_to: Some(Cow::Borrowed("replies")),
+        };
+        let bin = fory.serialize(&message).unwrap();
+        let obj: Message = fory.deserialize(&bin).unwrap();
+        assert_eq!(message, obj);
+    }
+}
+
+#[test]
+fn test_string_field_to_cow_compatible() {
+    #[derive(ForyStruct, Debug)]
+    struct Owned {
+        name: String,
+        #[fory(bytes)]
+        data: Vec<u8>,
+        id: i32,
+    }
+
+    #[derive(ForyStruct, Debug)]
+    struct Borrowing {
+        name: Cow<'static, str>,
+        data: Cow<'static, [u8]>,
+        id: i32,
+    }
+
+    let mut fory1 = Fory::builder().xlang(false).compatible(true).build();
+    fory1.register::<Owned>(101).unwrap();
+    let mut fory2 = Fory::builder().xlang(false).compatible(true).build();
+    fory2.register::<Borrowing>(101).unwrap();
+
+    let owned = Owned {
+        name: "fory".to_string(),
+        data: vec![1, 2],
+        id: 3,
+    };
+    let obj: Borrowing = fory2
+        .deserialize(&fory1.serialize(&owned).unwrap())
+        .unwrap();
+    assert!(matches!(obj.name, Cow::Owned(ref s) if s == "fory"));
+    assert_eq!(obj.data.as_ref(), &[1, 2]);
+    assert_eq!(obj.id, 3);
+
+    let back: Owned = fory1.deserialize(&fory2.serialize(&obj).unwrap()).unwrap();
+    assert_eq!(back.name, "fory");
+    assert_eq!(back.data, vec![1, 2]);
+    assert_eq!(back.id, 3);
+}
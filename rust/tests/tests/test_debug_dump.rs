@@ -0,0 +1,217 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use fory_core::{FieldDiff, Fory};
+use fory_derive::ForyStruct;
+use std::collections::{HashMap, HashSet};
+use std::rc::Rc;
+
+#[derive(ForyStruct, Debug, PartialEq)]
+struct Address {
+    city: String,
+    zip: u32,
+}
+
+#[derive(ForyStruct, Debug, PartialEq)]
+struct Person {
+    name: String,
+    age: i32,
+    nickname: Option<String>,
+    scores: Vec<i64>,
+    tags: Vec<String>,
+    attrs: HashMap<String, i32>,
+    address: Address,
+}
+
+fn person() -> Person {
+    Person {
+        name: "Alice \"A\"".to_string(),
+        age: 30,
+        nickname: None,
+        scores: vec![7, -1],
+        tags: vec!["admin".to_string()],
+        attrs: HashMap::from([("level".to_string(), 3)]),
+        address: Address {
+            city: "Paris".to_string(),
+            zip: 75001,
+        },
+    }
+}
+
+#[test]
+fn test_debug_dump_nested_struct() {
+    for compatible in [false, true] {
+        for xlang in [false, true] {
+            let mut fory = Fory::builder().compatible(compatible).xlang(xlang).build();
+            fory.register::<Address>(100).unwrap();
+            fory.register::<Person>(101).unwrap();
+            let bytes = fory.serialize(&person()).unwrap();
+
+            let dump = fory.debug_dump(&bytes).unwrap();
+            for field in [
+                "name", "age", "nickname", "scores", "tags", "attrs", "city", "zip",
+            ] {
+                assert!(
+                    dump.contains(&format!("\"{field}\": {{")),
+                    "{field}:\n{dump}"
+                );
+            }
+            assert!(dump.contains("\"user_type_id\": 101"), "{dump}");
+            assert!(dump.contains("\"value\": \"Alice \\\"A\\\"\""), "{dump}");
+            assert!(dump.contains("\"value\": 75001"), "{dump}");
+            assert!(dump.contains("\"value\": -1"), "{dump}");
+            assert!(dump.contains("\"value\": \"level\""), "{dump}");
+            assert!(dump.contains("\"ref\": \"null\""), "{dump}");
+            // The dump doesn't consume the payload.
+            assert_eq!(fory.deserialize::<Person>(&bytes).unwrap(), person());
+        }
+    }
+}
+
+#[derive(ForyStruct, Debug, PartialEq)]
+struct Shared {
+    first: Rc<Address>,
+    second: Rc<Address>,
+}
+
+#[test]
+fn test_debug_dump_shared_refs_and_scalars() {
+    let mut fory = Fory::builder()
+        .compatible(true)
+        .xlang(false)
+        .track_ref(true)
+        .build();
+    fory.register_by_name::<Address>("demo.Address").unwrap();
+    fory.register_by_name::<Shared>("demo.Shared").unwrap();
+    let address = Rc::new(person().address);
+    let bytes = fory
+        .serialize(&Shared {
+            first: address.clone(),
+            second: address,
+        })
+        .unwrap();
+    let dump = fory.debug_dump(&bytes).unwrap();
+    assert!(dump.contains("\"type_name\": \"demo.Address\""), "{dump}");
+    assert!(dump.contains("\"ref\": \"ref_value\""), "{dump}");
+    assert!(
+        dump.contains("\"ref\": \"ref\",\n      \"ref_id\": 1"),
+        "{dump}"
+    );
+
+    let fory = Fory::builder().xlang(false).build();
+    let dump = fory
+        .debug_dump(&fory.serialize(&vec![1.5f32]).unwrap())
+        .unwrap();
+    assert_eq!(
+        dump,
+        r#"{
+  "ref": "not_null",
+  "type_id": 55,
+  "type": "FLOAT32_ARRAY",
+  "value": [
+    1.5
+  ]
+}"#
+    );
+}
+
+fn diff(path: &str, left: Option<&str>, right: Option<&str>) -> FieldDiff {
+    FieldDiff {
+        path: path.to_string(),
+        left: left.map(str::to_string),
+        right: right.map(str::to_string),
+    }
+}
+
+#[test]
+fn test_diff_pinpoints_changed_field() {
+    for compatible in [false, true] {
+        for xlang in [false, true] {
+            let mut fory = Fory::builder().compatible(compatible).xlang(xlang).build();
+            fory.register::<Address>(100).unwrap();
+            fory.register::<Person>(101).unwrap();
+            let a = fory.serialize(&person()).unwrap();
+            assert!(fory.diff(&a, &a).unwrap().is_empty());
+
+            let mut changed = person();
+            changed.address.zip = 75002;
+            let b = fory.serialize(&changed).unwrap();
+            assert_eq!(
+                fory.diff(&a, &b).unwrap(),
+                [diff("address.zip", Some("75001"), Some("75002"))]
+            );
+
+            let mut changed = person();
+            changed.nickname = Some("Al".to_string());
+            changed.scores.push(9);
+            changed.attrs.insert("rank".to_string(), 1);
+            changed.attrs.insert("level".to_string(), 4);
+            let b = fory.serialize(&changed).unwrap();
+            // Struct fields are compared in wire order, which need not be declaration order.
+            let mut diffs = fory.diff(&a, &b).unwrap();
+            diffs.sort_by(|a, b| a.path.cmp(&b.path));
+            let paths: Vec<_> = diffs.iter().map(|d| d.path.as_str()).collect();
+            assert_eq!(
+                paths,
+                [
+                    "attrs[\"level\"]",
+                    "attrs[\"rank\"]",
+                    "nickname",
+                    "scores[2]"
+                ],
+                "{diffs:?}"
+            );
+            assert_eq!(diffs[0], diff("attrs[\"level\"]", Some("3"), Some("4")));
+            assert_eq!(diffs[1], diff("attrs[\"rank\"]", None, Some("1")));
+            assert_eq!(diffs[3], diff("scores[2]", None, Some("9")));
+            // A value that turns from null into a string differs in its ref flag, so it is
+            // reported as a whole.
+            assert!(diffs[2].left.as_deref().unwrap().contains("\"null\""));
+            assert!(diffs[2].right.as_deref().unwrap().contains("\"Al\""));
+        }
+    }
+}
+
+#[test]
+fn test_diff_ignores_hash_order() {
+    let fory = Fory::builder().xlang(false).build();
+    let keys: Vec<String> = (0..64).map(|i| format!("key{i}")).collect();
+    let forward: HashMap<String, i32> = keys.iter().cloned().zip(0..).collect();
+    let mut backward = HashMap::new();
+    for (i, key) in keys.iter().enumerate().rev() {
+        backward.insert(key.clone(), i as i32);
+    }
+    let a = fory.serialize(&forward).unwrap();
+    let b = fory.serialize(&backward).unwrap();
+    assert!(fory.diff(&a, &b).unwrap().is_empty());
+
+    backward.insert("key7".to_string(), -7);
+    let b = fory.serialize(&backward).unwrap();
+    assert_eq!(
+        fory.diff(&a, &b).unwrap(),
+        [diff("[\"key7\"]", Some("7"), Some("-7"))]
+    );
+
+    let left: HashSet<i32> = (0..32).collect();
+    let right: HashSet<i32> = (1..33).collect();
+    let a = fory.serialize(&left).unwrap();
+    let b = fory.serialize(&right).unwrap();
+    assert_eq!(
+        fory.diff(&a, &b).unwrap(),
+        [diff("[0]", Some("0"), None), diff("[32]", None, Some("32")),]
+    );
+}
@@ -0,0 +1,145 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use fory_core::Fory;
+use fory_derive::ForyStruct;
+use std::collections::HashMap;
+
+#[derive(ForyStruct, Debug, PartialEq, Default)]
+struct Inner {
+    samples: Vec<f64>,
+}
+
+#[derive(ForyStruct, Debug, PartialEq, Default)]
+struct Frame {
+    id: i32,
+    values: Vec<i32>,
+    names: Vec<String>,
+    attrs: HashMap<String, i64>,
+    inner: Inner,
+    #[fory(skip)]
+    cached: i64,
+}
+
+fn frame(id: i32, len: usize) -> Frame {
+    Frame {
+        id,
+        values: (0..len as i32).collect(),
+        names: (0..len).map(|i| format!("n{i}")).collect(),
+        attrs: (0..len).map(|i| (format!("k{i}"), i as i64)).collect(),
+        inner: Inner {
+            samples: (0..len).map(|i| i as f64).collect(),
+        },
+        cached: 0,
+    }
+}
+
+#[test]
+fn test_deserialize_into_reuses_allocations() {
+    for compatible in [false, true] {
+        for xlang in [false, true] {
+            let mut fory = Fory::builder().compatible(compatible).xlang(xlang).build();
+            fory.register::<Inner>(100).unwrap();
+            fory.register::<Frame>(101).unwrap();
+
+            let mut target = Frame::default();
+            fory.deserialize_into(&fory.serialize(&frame(1, 64)).unwrap(), &mut target)
+                .unwrap();
+            assert_eq!(target, frame(1, 64));
+            let values_ptr = target.values.as_ptr();
+            let values_capacity = target.values.capacity();
+            let names_ptr = target.names.as_ptr();
+            let attrs_capacity = target.attrs.capacity();
+            let samples_ptr = target.inner.samples.as_ptr();
+
+            target.cached = 7;
+            fory.deserialize_into(&fory.serialize(&frame(2, 32)).unwrap(), &mut target)
+                .unwrap();
+            assert_eq!(target, frame(2, 32));
+            assert_eq!(target.values.as_ptr(), values_ptr);
+            assert_eq!(target.values.capacity(), values_capacity);
+            assert_eq!(target.names.as_ptr(), names_ptr);
+            assert_eq!(target.attrs.capacity(), attrs_capacity);
+            assert_eq!(target.inner.samples.as_ptr(), samples_ptr);
+        }
+    }
+}
+
+#[test]
+fn test_deserialize_into_root_collections() {
+    let fory = Fory::builder().xlang(false).build();
+
+    let mut values: Vec<u16> = Vec::new();
+    fory.deserialize_into(&fory.serialize(&vec![1u16; 100]).unwrap(), &mut values)
+        .unwrap();
+    let ptr = values.as_ptr();
+    fory.deserialize_into(&fory.serialize(&vec![2u16, 3]).unwrap(), &mut values)
+        .unwrap();
+    assert_eq!(values, [2, 3]);
+    assert_eq!(values.as_ptr(), ptr);
+
+    let mut map: HashMap<i32, String> = HashMap::new();
+    let big: HashMap<i32, String> = (0..50).map(|i| (i, i.to_string())).collect();
+    fory.deserialize_into(&fory.serialize(&big).unwrap(), &mut map)
+        .unwrap();
+    assert_eq!(map, big);
+    let capacity = map.capacity();
+    let small: HashMap<i32, String> = HashMap::from([(7, "seven".to_string())]);
+    fory.deserialize_into(&fory.serialize(&small).unwrap(), &mut map)
+        .unwrap();
+    assert_eq!(map, small);
+    assert_eq!(map.capacity(), capacity);
+
+    // Types without an in-place reader are replaced by a freshly read value.
+    let mut text = String::from("old");
+    fory.deserialize_into(&fory.serialize(&"new".to_string()).unwrap(), &mut text)
+        .unwrap();
+    assert_eq!(text, "new");
+}
+
+#[derive(ForyStruct, Debug, PartialEq, Default)]
+struct FrameV2 {
+    id: i32,
+    values: Vec<i32>,
+    extra: String,
+}
+
+#[test]
+fn test_deserialize_into_schema_change() {
+    let mut writer = Fory::builder().xlang(false).build();
+    writer.register::<Inner>(100).unwrap();
+    writer.register::<Frame>(101).unwrap();
+    let mut reader = Fory::builder().xlang(false).build();
+    reader.register::<FrameV2>(101).unwrap();
+
+    let mut target = FrameV2 {
+        id: 9,
+        values: vec![9; 4],
+        extra: "stale".to_string(),
+    };
+    reader
+        .deserialize_into(&writer.serialize(&frame(3, 2)).unwrap(), &mut target)
+        .unwrap();
+    assert_eq!(
+        target,
+        FrameV2 {
+            id: 3,
+            values: vec![0, 1],
+            extra: String::new(),
+        }
+    );
+}
@@ -0,0 +1,79 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use fory_core::Fory;
+use fory_derive::ForyStruct;
+use std::collections::HashMap;
+
+/// Builds the map from scratch, so each call gets a fresh hasher and iteration order.
+fn scores() -> HashMap<i32, String> {
+    (0..64).map(|i| (i * 7 - 200, format!("v{i}"))).collect()
+}
+
+#[derive(ForyStruct, Debug, PartialEq)]
+struct Inventory {
+    counts: HashMap<String, i64>,
+    labels: HashMap<i32, Option<String>>,
+}
+
+fn inventory() -> Inventory {
+    Inventory {
+        counts: (0..32).map(|i| (format!("item-{i}"), i * 3)).collect(),
+        labels: (0..32)
+            .map(|i| (i, (i % 3 != 0).then(|| format!("label-{i}"))))
+            .collect(),
+    }
+}
+
+#[test]
+fn test_deterministic_hashmap() {
+    for xlang in [false, true] {
+        let fory = Fory::builder().xlang(xlang).deterministic(true).build();
+        let expected = fory.serialize(&scores()).unwrap();
+        for _ in 0..100 {
+            assert_eq!(fory.serialize(&scores()).unwrap(), expected);
+        }
+        let decoded: HashMap<i32, String> = fory.deserialize(&expected).unwrap();
+        assert_eq!(decoded, scores());
+
+        // Readers without the option decode the same bytes.
+        let reader = Fory::builder().xlang(xlang).build();
+        let decoded: HashMap<i32, String> = reader.deserialize(&expected).unwrap();
+        assert_eq!(decoded, scores());
+    }
+}
+
+#[test]
+fn test_deterministic_struct_fields() {
+    for compatible in [false, true] {
+        let mut fory = Fory::builder()
+            .xlang(false)
+            .compatible(compatible)
+            .string_ref(true)
+            .deterministic(true)
+            .build();
+        fory.register::<Inventory>(100).unwrap();
+        let expected = fory.serialize(&inventory()).unwrap();
+        for _ in 0..100 {
+            assert_eq!(fory.serialize(&inventory()).unwrap(), expected);
+        }
+        assert_eq!(
+            fory.deserialize::<Inventory>(&expected).unwrap(),
+            inventory()
+        );
+    }
+}
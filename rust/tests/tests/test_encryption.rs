@@ -0,0 +1,247 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use fory_core::{Error, Fory};
+use fory_derive::ForyStruct;
+
+const KEY: [u8; 32] = [7; 32];
+
+#[derive(ForyStruct, Debug, PartialEq)]
+struct Patient {
+    id: u64,
+    name: String,
+    #[fory(encrypt)]
+    ssn: String,
+    #[fory(encrypt)]
+    salary: i64,
+    #[fory(encrypt)]
+    notes: Option<String>,
+}
+
+#[derive(ForyStruct, Debug, PartialEq)]
+struct PatientV0 {
+    id: u64,
+    name: String,
+}
+
+#[derive(ForyStruct, Debug, PartialEq)]
+struct PatientInClear {
+    id: u64,
+    name: String,
+    ssn: String,
+    salary: i64,
+    notes: Option<String>,
+}
+
+#[derive(ForyStruct, Debug, PartialEq)]
+struct Pair {
+    #[fory(encrypt)]
+    a: i64,
+    #[fory(encrypt)]
+    b: i64,
+}
+
+fn patient() -> Patient {
+    Patient {
+        id: 42,
+        name: "Alice Example".to_string(),
+        ssn: "123-45-6789".to_string(),
+        salary: 98_000,
+        notes: Some("allergic to penicillin".to_string()),
+    }
+}
+
+fn fory(compatible: bool, key: Option<[u8; 32]>) -> Fory {
+    let mut builder = Fory::builder().xlang(false).compatible(compatible);
+    if let Some(key) = key {
+        builder = builder.cipher(key);
+    }
+    let mut fory = builder.build();
+    fory.register::<Patient>(100).unwrap();
+    fory
+}
+
+fn contains(haystack: &[u8], needle: &[u8]) -> bool {
+    haystack
+        .windows(needle.len())
+        .any(|window| window == needle)
+}
+
+#[test]
+fn test_encrypted_fields_roundtrip() {
+    for compatible in [false, true] {
+        let fory = fory(compatible, Some(KEY));
+        let value = patient();
+        let bytes = fory.serialize(&value).unwrap();
+        assert!(contains(&bytes, b"Alice Example"));
+        assert!(!contains(&bytes, b"123-45-6789"));
+        assert!(!contains(&bytes, b"penicillin"));
+        assert_eq!(fory.deserialize::<Patient>(&bytes).unwrap(), value);
+
+        let value = Patient {
+            notes: None,
+            ..patient()
+        };
+        let bytes = fory.serialize(&value).unwrap();
+        assert_eq!(fory.deserialize::<Patient>(&bytes).unwrap(), value);
+    }
+}
+
+#[test]
+fn test_schema_marks_encrypted_fields() {
+    let fory = fory(true, Some(KEY));
+    let bytes = fory.serialize(&patient()).unwrap();
+    let dump = fory.debug_dump(&bytes).unwrap();
+    assert!(dump.contains("ENCRYPTED"), "{dump}");
+    assert!(!dump.contains("123-45-6789"), "{dump}");
+
+    // Readers without the encrypted fields skip them without a key.
+    let mut reader = Fory::builder().xlang(false).compatible(true).build();
+    reader.register::<PatientV0>(100).unwrap();
+    let old: PatientV0 = reader.deserialize(&bytes).unwrap();
+    assert_eq!(old.name, "Alice Example");
+}
+
+#[test]
+fn test_wrong_key_fails() {
+    if fory_core::error::should_panic_on_error() {
+        return;
+    }
+    for compatible in [false, true] {
+        let bytes = fory(compatible, Some(KEY)).serialize(&patient()).unwrap();
+
+        let err = fory(compatible, Some([8; 32]))
+            .deserialize::<Patient>(&bytes)
+            .unwrap_err();
+        assert!(matches!(err, Error::DecryptFailed(_)), "{err}");
+
+        let err = fory(compatible, None)
+            .deserialize::<Patient>(&bytes)
+            .unwrap_err();
+        assert!(matches!(err, Error::DecryptFailed(_)), "{err}");
+    }
+}
+
+#[test]
+fn test_write_without_cipher_fails() {
+    if fory_core::error::should_panic_on_error() {
+        return;
+    }
+    let err = fory(false, None).serialize(&patient()).unwrap_err();
+    assert!(matches!(err, Error::NotAllowed(_)), "{err}");
+}
+
+#[test]
+fn test_field_written_in_clear_fails() {
+    if fory_core::error::should_panic_on_error() {
+        return;
+    }
+    let mut writer = Fory::builder().xlang(false).compatible(true).build();
+    writer.register::<PatientInClear>(100).unwrap();
+    let bytes = writer
+        .serialize(&PatientInClear {
+            id: 42,
+            name: "Alice Example".to_string(),
+            ssn: "123-45-6789".to_string(),
+            salary: 98_000,
+            notes: None,
+        })
+        .unwrap();
+
+    let err = fory(true, Some(KEY))
+        .deserialize::<Patient>(&bytes)
+        .unwrap_err();
+    assert!(matches!(err, Error::DecryptFailed(_)), "{err}");
+}
+
+#[test]
+fn test_swapped_ciphertexts_fail() {
+    if fory_core::error::should_panic_on_error() {
+        return;
+    }
+    let mut fory = Fory::builder()
+        .xlang(false)
+        .compatible(false)
+        .cipher(KEY)
+        .build();
+    fory.register::<Pair>(101).unwrap();
+    let mut bytes = fory.serialize(&Pair { a: 1, b: 2 }).unwrap();
+    assert_eq!(
+        fory.deserialize::<Pair>(&bytes).unwrap(),
+        Pair { a: 1, b: 2 }
+    );
+
+    // Both fields end the payload as blobs of the same size; swap them.
+    let n = bytes.len();
+    let blob_len = (2..n / 2)
+        .find(|&len| bytes[n - 2 * len] as usize == len - 1 && bytes[n - len] as usize == len - 1)
+        .unwrap();
+    let (head, tail) = bytes.split_at_mut(n - blob_len);
+    head[n - 2 * blob_len..].swap_with_slice(tail);
+
+    let err = fory.deserialize::<Pair>(&bytes).unwrap_err();
+    assert!(matches!(err, Error::DecryptFailed(_)), "{err}");
+}
+
+#[derive(ForyStruct, Debug, PartialEq)]
+struct Address {
+    city: String,
+    zip: u32,
+}
+
+#[derive(ForyStruct, Debug, PartialEq)]
+struct Contact {
+    #[fory(encrypt)]
+    home: Address,
+    work: Address,
+}
+
+#[derive(ForyStruct, Debug, PartialEq)]
+struct ContactV0 {
+    work: Address,
+}
+
+#[test]
+fn test_skipped_encrypted_struct_keeps_meta_in_step() {
+    let contact = Contact {
+        home: Address {
+            city: "Springfield".to_string(),
+            zip: 12345,
+        },
+        work: Address {
+            city: "Shelbyville".to_string(),
+            zip: 67890,
+        },
+    };
+    let mut writer = Fory::builder()
+        .xlang(false)
+        .compatible(true)
+        .cipher(KEY)
+        .build();
+    writer.register::<Address>(102).unwrap();
+    writer.register::<Contact>(103).unwrap();
+    let bytes = writer.serialize(&contact).unwrap();
+    assert_eq!(writer.deserialize::<Contact>(&bytes).unwrap(), contact);
+
+    // The type meta of `Address` inside the ciphertext is not in the outer meta table,
+    // so a reader skipping `home` still finds the meta `work` refers to.
+    let mut reader = Fory::builder().xlang(false).compatible(true).build();
+    reader.register::<Address>(102).unwrap();
+    reader.register::<ContactV0>(103).unwrap();
+    let old: ContactV0 = reader.deserialize(&bytes).unwrap();
+    assert_eq!(old.work, contact.work);
+}
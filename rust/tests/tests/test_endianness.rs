@@ -0,0 +1,163 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use fory_core::{Endianness, Error, Fory};
+use fory_derive::ForyStruct;
+
+#[derive(ForyStruct, Debug, PartialEq)]
+struct Sample {
+    small: i16,
+    unsigned: u16,
+    single: f32,
+    double: f64,
+    #[fory(encoding = fixed)]
+    fixed_signed: i32,
+    #[fory(encoding = fixed)]
+    fixed_unsigned: u64,
+    wide: i128,
+    varint: i64,
+    label: String,
+    maybe: Option<f32>,
+    doubles: Vec<f64>,
+    ints: Vec<i32>,
+    shorts: [u16; 3],
+}
+
+#[derive(ForyStruct, Debug, PartialEq)]
+struct Widened {
+    small: i64,
+    double: f64,
+}
+
+fn sample() -> Sample {
+    Sample {
+        small: -12345,
+        unsigned: 0xABCD,
+        single: 1.5,
+        double: -2.25e300,
+        fixed_signed: 0x0102_0304,
+        fixed_unsigned: 0x0102_0304_0506_0708,
+        wide: -0x0102_0304_0506_0708_090A_0B0C_0D0E_0F10,
+        varint: 1 << 40,
+        label: "big endian".to_string(),
+        maybe: Some(-0.5),
+        doubles: vec![1.0, -3.75, f64::MAX],
+        ints: vec![1, -2, 0x7FFF_FFFF],
+        shorts: [1, 0x0203, 0xFFFE],
+    }
+}
+
+fn builder(compatible: bool) -> fory_core::ForyBuilder {
+    Fory::builder().xlang(false).compatible(compatible)
+}
+
+#[test]
+fn test_big_endian_round_trip() {
+    for compatible in [false, true] {
+        let mut big = builder(compatible).endianness(Endianness::Big).build();
+        big.register::<Sample>(100).unwrap();
+        assert_eq!(big.get_endianness(), Endianness::Big);
+        let mut little = builder(compatible).build();
+        little.register::<Sample>(100).unwrap();
+        assert_eq!(little.get_endianness(), Endianness::Little);
+
+        let value = sample();
+        let big_bytes = big.serialize(&value).unwrap();
+        let little_bytes = little.serialize(&value).unwrap();
+        assert_ne!(big_bytes, little_bytes);
+        assert_eq!(big_bytes.len(), little_bytes.len());
+        assert_eq!(big.deserialize::<Sample>(&big_bytes).unwrap(), value);
+        assert_eq!(big.deserialize::<Sample>(&little_bytes).unwrap(), value);
+    }
+}
+
+#[test]
+fn test_default_fory_detects_big_endian_payload() {
+    for compatible in [false, true] {
+        let mut big = builder(compatible).endianness(Endianness::Big).build();
+        big.register::<Sample>(100).unwrap();
+        let mut reader = builder(compatible).build();
+        reader.register::<Sample>(100).unwrap();
+
+        let value = sample();
+        let bytes = big.serialize(&value).unwrap();
+        assert_eq!(reader.deserialize::<Sample>(&bytes).unwrap(), value);
+    }
+
+    let big = Fory::builder()
+        .xlang(false)
+        .endianness(Endianness::Big)
+        .build();
+    let reader = Fory::builder().xlang(false).build();
+    let bytes = big.serialize(&[0x0102u16, 0x0304]).unwrap();
+    assert!(bytes.ends_with(&[1, 2, 3, 4]));
+    assert_eq!(
+        reader.deserialize::<[u16; 2]>(&bytes).unwrap(),
+        [0x0102, 0x0304]
+    );
+    let bytes = big.serialize(&1.5f64).unwrap();
+    assert!(bytes.ends_with(&1.5f64.to_be_bytes()));
+    assert_eq!(reader.deserialize::<f64>(&bytes).unwrap(), 1.5);
+    let bytes = big.serialize(&vec![1u16, 2, 3]).unwrap();
+    assert!(bytes.ends_with(&[0, 1, 0, 2, 0, 3]));
+    assert_eq!(reader.deserialize::<Vec<u16>>(&bytes).unwrap(), [1, 2, 3]);
+}
+
+#[test]
+fn test_big_endian_scalar_conversion() {
+    let mut big = builder(true).endianness(Endianness::Big).build();
+    big.register::<Sample>(100).unwrap();
+    let mut reader = builder(true).build();
+    reader.register::<Widened>(100).unwrap();
+
+    let bytes = big.serialize(&sample()).unwrap();
+    let widened = reader.deserialize::<Widened>(&bytes).unwrap();
+    assert_eq!(
+        widened,
+        Widened {
+            small: -12345,
+            double: -2.25e300,
+        }
+    );
+}
+
+#[test]
+fn test_big_endian_requires_native_mode() {
+    if fory_core::error::should_panic_on_error() {
+        return;
+    }
+    let xlang = Fory::builder()
+        .xlang(true)
+        .endianness(Endianness::Big)
+        .build();
+    let err = xlang.serialize(&1.5f64).unwrap_err();
+    assert!(matches!(err, Error::NotAllowed(_)), "{err}");
+
+    // A big-endian flag on an xlang header is invalid.
+    let mut bytes = Fory::builder()
+        .xlang(true)
+        .build()
+        .serialize(&1.5f64)
+        .unwrap();
+    bytes[0] |= 1 << 3;
+    let err = Fory::builder()
+        .xlang(true)
+        .build()
+        .deserialize::<f64>(&bytes)
+        .unwrap_err();
+    assert!(matches!(err, Error::InvalidData(_)), "{err}");
+}
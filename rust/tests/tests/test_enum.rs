@@ -19,7 +19,7 @@
 
 use fory_core::Fory;
 use fory_derive::{ForyEnum, ForyStruct, ForyUnion};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 #[test]
 fn basic() {
@@ -153,6 +153,30 @@ fn struct_with_enum_field() {
     assert_eq!(obj, result);
 }
 
+#[test]
+fn enum_ordinal_matches_explicit_discriminants() {
+    #[derive(ForyEnum, Debug, PartialEq, Clone, Copy)]
+    enum Color {
+        Red = 0,
+        Green = 1,
+        Blue = 2,
+    }
+
+    let mut fory = Fory::builder().xlang(true).compatible(false).build();
+    fory.register::<Color>(100).unwrap();
+
+    for color in [Color::Red, Color::Green, Color::Blue] {
+        let bin = fory.serialize(&color).unwrap();
+        // The enum payload is the single var_uint32 ordinal byte at the end.
+        assert_eq!(*bin.last().unwrap(), color as u8);
+        assert_eq!(color, fory.deserialize::<Color>(&bin).unwrap());
+    }
+
+    let mut bin = fory.serialize(&Color::Green).unwrap();
+    *bin.last_mut().unwrap() = 3;
+    assert!(fory.deserialize::<Color>(&bin).is_err());
+}
+
 /// Test Union-compatible enum xlang serialization format.
 /// This verifies that Rust enum writes: index + ref_flag + type_id + data
 /// which should be compatible with Java's Union: index + xwriteRef(value)
@@ -396,3 +420,245 @@ fn struct_with_enum_field_explicit_nullable() {
     let result: StructWithExplicitNullable = fory.deserialize(&bin).unwrap();
     assert_eq!(obj, result);
 }
+
+#[test]
+fn repr_name_enum() {
+    #[derive(ForyEnum, Debug, PartialEq, Clone, Copy)]
+    #[fory(repr = "name")]
+    enum Level {
+        Low,
+        Medium,
+        High,
+    }
+
+    #[derive(ForyStruct, Debug, PartialEq)]
+    struct Alert {
+        message: String,
+        level: Level,
+    }
+
+    for (xlang, compatible) in [(false, false), (false, true), (true, false)] {
+        let mut fory = Fory::builder().xlang(xlang).compatible(compatible).build();
+        fory.register::<Level>(300).unwrap();
+        fory.register::<Alert>(301).unwrap();
+
+        for level in [Level::Low, Level::Medium, Level::High] {
+            let bin = fory.serialize(&level).unwrap();
+            assert_eq!(level, fory.deserialize::<Level>(&bin).unwrap());
+        }
+        let alert = Alert {
+            message: "disk usage".to_string(),
+            level: Level::High,
+        };
+        let bin = fory.serialize(&alert).unwrap();
+        assert_eq!(alert, fory.deserialize::<Alert>(&bin).unwrap());
+        // Native payloads carry the variant name; xlang keeps the ordinal.
+        let has_name = bin.windows(4).any(|w| w == b"High");
+        assert_eq!(has_name, !xlang);
+    }
+}
+
+#[test]
+fn repr_name_enum_unknown_name() {
+    if fory_core::error::should_panic_on_error() {
+        return;
+    }
+
+    #[derive(ForyEnum, Debug, PartialEq)]
+    #[fory(repr = "name")]
+    enum LevelV1 {
+        Low,
+        High,
+    }
+
+    #[derive(ForyEnum, Debug, PartialEq)]
+    #[fory(repr = "name")]
+    enum LevelV2 {
+        Low,
+        High,
+        Critical,
+    }
+
+    let mut writer = Fory::builder().xlang(false).build();
+    writer.register::<LevelV2>(302).unwrap();
+    let mut reader = Fory::builder().xlang(false).build();
+    reader.register::<LevelV1>(302).unwrap();
+
+    let bin = writer.serialize(&LevelV2::High).unwrap();
+    assert_eq!(LevelV1::High, reader.deserialize::<LevelV1>(&bin).unwrap());
+
+    let bin = writer.serialize(&LevelV2::Critical).unwrap();
+    let err = reader.deserialize::<LevelV1>(&bin).unwrap_err();
+    assert!(
+        matches!(err, fory_core::Error::UnknownEnum(_)),
+        "unexpected error: {err}"
+    );
+    assert!(err.to_string().contains("Critical"), "{err}");
+}
+
+#[test]
+fn stable_ordinals_enum() {
+    #[derive(ForyEnum, Debug, PartialEq)]
+    #[fory(stable_ordinals)]
+    enum PriorityV1 {
+        Low = 0,
+        High = 1,
+    }
+
+    // `Medium` is inserted between the existing variants with a fresh discriminant.
+    #[derive(ForyEnum, Debug, PartialEq)]
+    #[fory(stable_ordinals)]
+    enum PriorityV2 {
+        Low = 0,
+        Medium = 2,
+        High = 1,
+    }
+
+    for xlang in [false, true] {
+        for compatible in [false, true] {
+            let mut old = Fory::builder().xlang(xlang).compatible(compatible).build();
+            old.register_enum::<PriorityV1>(303).unwrap();
+            let mut new = Fory::builder().xlang(xlang).compatible(compatible).build();
+            new.register_enum::<PriorityV2>(303).unwrap();
+
+            let bin = old.serialize(&PriorityV1::High).unwrap();
+            assert_eq!(
+                PriorityV2::High,
+                new.deserialize::<PriorityV2>(&bin).unwrap()
+            );
+            let bin = old.serialize(&PriorityV1::Low).unwrap();
+            assert_eq!(
+                PriorityV2::Low,
+                new.deserialize::<PriorityV2>(&bin).unwrap()
+            );
+
+            let bin = new.serialize(&PriorityV2::High).unwrap();
+            assert_eq!(
+                PriorityV1::High,
+                old.deserialize::<PriorityV1>(&bin).unwrap()
+            );
+            let bin = new.serialize(&PriorityV2::Medium).unwrap();
+            assert_eq!(
+                PriorityV2::Medium,
+                new.deserialize::<PriorityV2>(&bin).unwrap()
+            );
+        }
+    }
+}
+
+#[test]
+fn register_enum_rejects_structs() {
+    #[derive(ForyStruct)]
+    struct NotAnEnum {
+        value: i32,
+    }
+
+    let mut fory = Fory::default();
+    assert!(fory.register_enum::<NotAnEnum>(304).is_err());
+    assert!(fory.register::<NotAnEnum>(304).is_ok());
+}
+
+#[derive(ForyEnum, Debug, PartialEq, Eq, Hash, Clone, Copy, PartialOrd, Ord)]
+enum ColorV1 {
+    #[fory(default)]
+    Red,
+    Green,
+    Blue,
+}
+
+#[derive(ForyEnum, Debug, PartialEq, Eq, Hash, Clone, Copy, PartialOrd, Ord)]
+enum ColorV2 {
+    #[fory(default)]
+    Red,
+    Green,
+    Blue,
+    Purple,
+}
+
+#[derive(ForyStruct, Debug, PartialEq)]
+struct PaletteV1 {
+    counts: HashMap<ColorV1, i32>,
+    labels: BTreeMap<ColorV1, String>,
+}
+
+#[derive(ForyStruct, Debug, PartialEq)]
+struct PaletteV2 {
+    counts: HashMap<ColorV2, i32>,
+    labels: BTreeMap<ColorV2, String>,
+}
+
+#[test]
+fn enum_map_keys() {
+    for xlang in [false, true] {
+        for compatible in [false, true] {
+            let mut fory = Fory::builder().xlang(xlang).compatible(compatible).build();
+            fory.register::<ColorV1>(100).unwrap();
+            fory.register::<PaletteV1>(101).unwrap();
+
+            let counts = HashMap::from([(ColorV1::Red, 1), (ColorV1::Blue, 3)]);
+            let bytes = fory.serialize(&counts).unwrap();
+            assert_eq!(
+                fory.deserialize::<HashMap<ColorV1, i32>>(&bytes).unwrap(),
+                counts
+            );
+
+            let palette = PaletteV1 {
+                counts,
+                labels: BTreeMap::from([
+                    (ColorV1::Green, "grass".to_string()),
+                    (ColorV1::Blue, "sky".to_string()),
+                ]),
+            };
+            let bytes = fory.serialize(&palette).unwrap();
+            assert_eq!(fory.deserialize::<PaletteV1>(&bytes).unwrap(), palette);
+        }
+    }
+
+    // `char` keys are native-mode only.
+    let fory = Fory::builder().xlang(false).build();
+    let by_char = HashMap::from([('a', 1), ('é', 2), ('🦀', 3)]);
+    let bytes = fory.serialize(&by_char).unwrap();
+    assert_eq!(
+        fory.deserialize::<HashMap<char, i32>>(&bytes).unwrap(),
+        by_char
+    );
+}
+
+#[test]
+fn enum_map_key_added_variant() {
+    for xlang in [false, true] {
+        let mut v1 = Fory::builder().xlang(xlang).compatible(true).build();
+        v1.register::<ColorV1>(100).unwrap();
+        v1.register::<PaletteV1>(101).unwrap();
+        let mut v2 = Fory::builder().xlang(xlang).compatible(true).build();
+        v2.register::<ColorV2>(100).unwrap();
+        v2.register::<PaletteV2>(101).unwrap();
+
+        // Old readers see the new key as the default variant.
+        let palette = PaletteV2 {
+            counts: HashMap::from([(ColorV2::Green, 2), (ColorV2::Purple, 5)]),
+            labels: BTreeMap::from([(ColorV2::Purple, "plum".to_string())]),
+        };
+        let old: PaletteV1 = v1.deserialize(&v2.serialize(&palette).unwrap()).unwrap();
+        assert_eq!(
+            old.counts,
+            HashMap::from([(ColorV1::Green, 2), (ColorV1::Red, 5)])
+        );
+        assert_eq!(
+            old.labels,
+            BTreeMap::from([(ColorV1::Red, "plum".to_string())])
+        );
+
+        // New readers keep every old key.
+        let palette = PaletteV1 {
+            counts: HashMap::from([(ColorV1::Red, 1), (ColorV1::Blue, 3)]),
+            labels: BTreeMap::new(),
+        };
+        let new: PaletteV2 = v2.deserialize(&v1.serialize(&palette).unwrap()).unwrap();
+        assert_eq!(
+            new.counts,
+            HashMap::from([(ColorV2::Red, 1), (ColorV2::Blue, 3)])
+        );
+        assert!(new.labels.is_empty());
+    }
+}
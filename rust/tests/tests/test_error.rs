@@ -0,0 +1,120 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use fory_core::{Error, Fory};
+use fory_derive::ForyStruct;
+use std::any::Any;
+
+#[derive(ForyStruct, Debug, PartialEq)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+fn skip_on_panic() -> bool {
+    fory_core::error::should_panic_on_error()
+}
+
+#[test]
+fn test_buffer_out_of_bound() {
+    if skip_on_panic() {
+        return;
+    }
+    let fory = Fory::builder().xlang(false).build();
+    let bytes = fory.serialize(&42i64).unwrap();
+    let err = fory
+        .deserialize::<i64>(&bytes[..bytes.len() - 1])
+        .unwrap_err();
+    assert!(matches!(err, Error::BufferOutOfBound(..)), "{err}");
+}
+
+#[test]
+fn test_type_mismatch() {
+    if skip_on_panic() {
+        return;
+    }
+    let fory = Fory::builder().xlang(false).build();
+    let bytes = fory.serialize(&42i32).unwrap();
+    let err = fory.deserialize::<String>(&bytes).unwrap_err();
+    assert!(matches!(err, Error::TypeMismatch(_)), "{err}");
+}
+
+#[test]
+fn test_unknown_type_id() {
+    if skip_on_panic() {
+        return;
+    }
+    for xlang in [false, true] {
+        let mut writer = Fory::builder().xlang(xlang).compatible(false).build();
+        writer.register::<Point>(100).unwrap();
+        let bytes = writer.serialize(&Point { x: 1, y: 2 }).unwrap();
+        let reader = Fory::builder().xlang(xlang).compatible(false).build();
+        let err = reader.deserialize::<Box<dyn Any>>(&bytes).unwrap_err();
+        assert!(matches!(err, Error::UnknownTypeId(_)), "{err}");
+
+        let mut writer = Fory::builder().xlang(xlang).compatible(false).build();
+        writer.register_by_name::<Point>("Point").unwrap();
+        let bytes = writer.serialize(&Point { x: 1, y: 2 }).unwrap();
+        let err = reader.deserialize::<Box<dyn Any>>(&bytes).unwrap_err();
+        assert!(matches!(err, Error::UnknownTypeId(_)), "{err}");
+    }
+}
+
+#[test]
+fn test_utf8() {
+    if skip_on_panic() {
+        return;
+    }
+    let fory = Fory::builder().xlang(false).build();
+    let mut bytes = fory.serialize(&"ab".to_string()).unwrap();
+    *bytes.last_mut().unwrap() = 0xFF;
+    let err = fory.deserialize::<String>(&bytes).unwrap_err();
+    assert!(matches!(err, Error::Utf8(_)), "{err}");
+    let err = fory.deserialize_borrowed::<&str>(&bytes).unwrap_err();
+    assert!(matches!(err, Error::Utf8(_)), "{err}");
+}
+
+#[test]
+fn test_size_limit_exceeded() {
+    if skip_on_panic() {
+        return;
+    }
+    let fory = Fory::builder().xlang(false).build();
+    let strings = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+    let bytes = fory.serialize(&strings).unwrap();
+    let limited = Fory::builder().xlang(false).max_collection_size(2).build();
+    let err = limited.deserialize::<Vec<String>>(&bytes).unwrap_err();
+    assert!(matches!(err, Error::SizeLimitExceeded(_)), "{err}");
+}
+
+#[test]
+fn test_from_utf8_errors() {
+    if skip_on_panic() {
+        return;
+    }
+    let bytes = vec![b'a', 0xFF];
+    let err = std::str::from_utf8(&bytes)
+        .map_err(Error::from)
+        .unwrap_err();
+    assert!(matches!(err, Error::Utf8(_)), "{err}");
+    let err = String::from_utf8(bytes).map_err(Error::from).unwrap_err();
+    assert!(matches!(err, Error::Utf8(_)), "{err}");
+    assert!(err.to_string().contains("invalid UTF-8 string"), "{err}");
+
+    let err: Box<dyn std::error::Error> = Box::new(Error::utf8("invalid UTF-8 string"));
+    assert_eq!(err.to_string(), "invalid UTF-8 string");
+}
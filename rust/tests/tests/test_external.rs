@@ -0,0 +1,142 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use fory_core::{Error, External, Fory};
+use fory_derive::ForyStruct;
+
+/// Stands in for a type from a geometry crate, which has no `Serializer` impl.
+mod geometry {
+    #[derive(Debug, Clone, Copy, PartialEq, Default)]
+    pub struct Point {
+        x: f64,
+        y: f64,
+    }
+
+    impl Point {
+        pub fn new(x: f64, y: f64) -> Self {
+            Point { x, y }
+        }
+
+        pub fn x(&self) -> f64 {
+            self.x
+        }
+
+        pub fn y(&self) -> f64 {
+            self.y
+        }
+    }
+}
+
+use geometry::Point;
+
+#[derive(ForyStruct, Debug, PartialEq)]
+struct Place {
+    name: String,
+    #[fory(external)]
+    location: Point,
+    #[fory(external)]
+    entrance: Option<Point>,
+}
+
+/// Reads `Place` payloads without the external fields.
+#[derive(ForyStruct, Debug, PartialEq)]
+struct PlaceName {
+    name: String,
+}
+
+fn register_point(fory: &mut Fory) {
+    fory.register_external::<Point>(
+        200,
+        |point, context| {
+            context.writer.write_f64(point.x());
+            context.writer.write_f64(point.y());
+            Ok(())
+        },
+        |context| {
+            let x = context.reader.read_f64()?;
+            let y = context.reader.read_f64()?;
+            Ok(Point::new(x, y))
+        },
+    )
+    .unwrap();
+}
+
+fn place() -> Place {
+    Place {
+        name: "station".to_string(),
+        location: Point::new(1.5, -2.25),
+        entrance: Some(Point::new(1.0, -2.0)),
+    }
+}
+
+#[test]
+fn test_external_struct_field() {
+    for compatible in [false, true] {
+        let mut fory = Fory::builder().xlang(false).compatible(compatible).build();
+        register_point(&mut fory);
+        fory.register::<Place>(100).unwrap();
+
+        let value = place();
+        let bytes = fory.serialize(&value).unwrap();
+        assert_eq!(fory.deserialize::<Place>(&bytes).unwrap(), value);
+
+        let no_entrance = Place {
+            entrance: None,
+            ..place()
+        };
+        let bytes = fory.serialize(&no_entrance).unwrap();
+        assert_eq!(fory.deserialize::<Place>(&bytes).unwrap(), no_entrance);
+    }
+}
+
+#[test]
+fn test_external_values_and_evolution() {
+    let mut writer = Fory::builder().xlang(false).compatible(true).build();
+    register_point(&mut writer);
+    writer.register::<Place>(100).unwrap();
+
+    let points = vec![
+        External(Point::new(0.0, 0.0)),
+        External(Point::new(3.0, 4.0)),
+    ];
+    let bytes = writer.serialize(&points).unwrap();
+    assert_eq!(
+        writer.deserialize::<Vec<External<Point>>>(&bytes).unwrap(),
+        points
+    );
+
+    let mut reader = Fory::builder().xlang(false).compatible(true).build();
+    register_point(&mut reader);
+    reader.register::<PlaceName>(100).unwrap();
+    let bytes = writer.serialize(&place()).unwrap();
+    assert_eq!(
+        reader.deserialize::<PlaceName>(&bytes).unwrap(),
+        PlaceName {
+            name: "station".to_string()
+        }
+    );
+}
+
+#[test]
+fn test_external_requires_registration() {
+    if fory_core::error::should_panic_on_error() {
+        return;
+    }
+    let fory = Fory::builder().xlang(false).build();
+    let err = fory.serialize(&External(Point::new(1.0, 2.0))).unwrap_err();
+    assert!(matches!(err, Error::TypeError(_)), "{err}");
+}
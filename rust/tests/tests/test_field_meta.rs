@@ -17,6 +17,7 @@
 
 //! Tests for field-level `#[fory(...)]` attributes
 
+use fory_core::buffer::Writer;
 use fory_core::meta::FieldType;
 use fory_core::resolver::TypeResolver;
 use fory_core::type_id::TypeId;
@@ -185,6 +186,118 @@ fn test_combined_attributes() {
     assert_eq!(deserialized.optional_data, Some("data".to_string()));
 }
 
+/// Two structs whose Rust field names differ but map to the same schema names
+#[derive(ForyStruct, Debug, PartialEq)]
+struct RenamedUser {
+    #[fory(rename = "userName")]
+    name: String,
+    #[fory(rename = "loginCount")]
+    logins: i32,
+    email: Option<String>,
+}
+
+#[derive(ForyStruct, Debug, PartialEq)]
+struct RenamedAccount {
+    #[fory(rename = "userName")]
+    account_name: String,
+    #[fory(rename = "loginCount")]
+    sign_in_count: i32,
+    email: Option<String>,
+}
+
+#[test]
+fn test_rename_compatible() {
+    let mut fory1 = Fory::builder().xlang(false).compatible(true).build();
+    let mut fory2 = Fory::builder().xlang(false).compatible(true).build();
+    fory1.register::<RenamedUser>(10).unwrap();
+    fory2.register::<RenamedAccount>(10).unwrap();
+
+    let user = RenamedUser {
+        name: "Alice".to_string(),
+        logins: 42,
+        email: Some("alice@example.com".to_string()),
+    };
+    let account: RenamedAccount = fory2.deserialize(&fory1.serialize(&user).unwrap()).unwrap();
+    assert_eq!(account.account_name, "Alice");
+    assert_eq!(account.sign_in_count, 42);
+    assert_eq!(account.email, user.email);
+
+    let back: RenamedUser = fory1
+        .deserialize(&fory2.serialize(&account).unwrap())
+        .unwrap();
+    assert_eq!(back, user);
+
+    let type_resolver = TypeResolver::default();
+    let mut names: Vec<String> = RenamedAccount::fory_fields_info(&type_resolver)
+        .unwrap()
+        .into_iter()
+        .map(|info| info.field_name)
+        .collect();
+    names.sort();
+    assert_eq!(names, ["email", "login_count", "user_name"]);
+
+    // The struct version hash is computed from renamed fields as well.
+    let mut fory1 = Fory::builder()
+        .xlang(false)
+        .check_struct_version(true)
+        .build();
+    let mut fory2 = Fory::builder()
+        .xlang(false)
+        .check_struct_version(true)
+        .build();
+    fory1.register::<RenamedUser>(10).unwrap();
+    fory2.register::<RenamedAccount>(10).unwrap();
+    let account: RenamedAccount = fory2.deserialize(&fory1.serialize(&user).unwrap()).unwrap();
+    assert_eq!(account.account_name, "Alice");
+}
+
+#[derive(ForyStruct, Debug, PartialEq)]
+struct ReleaseV1 {
+    name: String,
+}
+
+#[derive(ForyStruct, Debug, PartialEq)]
+struct ReleaseV2 {
+    name: String,
+    #[fory(default = -1)]
+    version: i32,
+    #[fory(default = "String::from(\"stable\")")]
+    channel: String,
+    #[fory(skip, default = 7)]
+    local_only: u8,
+}
+
+#[test]
+fn test_default_for_missing_field() {
+    let mut fory1 = Fory::builder().xlang(false).compatible(true).build();
+    let mut fory2 = Fory::builder().xlang(false).compatible(true).build();
+    fory1.register::<ReleaseV1>(11).unwrap();
+    fory2.register::<ReleaseV2>(11).unwrap();
+
+    let v1 = ReleaseV1 {
+        name: "fory".to_string(),
+    };
+    let v2: ReleaseV2 = fory2.deserialize(&fory1.serialize(&v1).unwrap()).unwrap();
+    assert_eq!(v2.name, "fory");
+    assert_eq!(v2.version, -1);
+    assert_eq!(v2.channel, "stable");
+    assert_eq!(v2.local_only, 7);
+
+    // Fields present in the incoming data are read as usual.
+    let written = ReleaseV2 {
+        name: "fory".to_string(),
+        version: 3,
+        channel: "nightly".to_string(),
+        local_only: 0,
+    };
+    let read: ReleaseV2 = fory2
+        .deserialize(&fory2.serialize(&written).unwrap())
+        .unwrap();
+    assert_eq!(read.version, 3);
+    assert_eq!(read.channel, "nightly");
+    assert_eq!(read.local_only, 7);
+}
+
 /// Test struct with primitive types (should be non-nullable by default)
 #[derive(ForyStruct, Debug, PartialEq)]
 struct StructWithPrimitives {
@@ -461,7 +574,7 @@ fn serializer_backed_set_and_map_fields_keep_declared_generic_type_meta() {
     assert_eq!(field_type.generics[0].type_id, TypeId::STRING as u32);
 
     let field_type = only_field_type::<BinaryHeapDefaultWire>(&type_resolver);
-    assert_eq!(field_type.type_id, TypeId::SET as u32);
+    assert_eq!(field_type.type_id, TypeId::LIST as u32);
     assert_eq!(field_type.generics.len(), 1);
     assert_eq!(field_type.generics[0].type_id, TypeId::STRING as u32);
 
@@ -1084,3 +1197,98 @@ fn test_field_id_payload_compatible_mode() {
         verbose_bytes.len()
     );
 }
+
+mod renamed_field_v1 {
+    use fory_derive::ForyStruct;
+
+    #[derive(ForyStruct, Debug, PartialEq)]
+    pub struct Account {
+        #[fory(id = 0)]
+        pub owner: String,
+        #[fory(id = 1)]
+        pub balance: i64,
+    }
+}
+
+mod renamed_field_v2 {
+    use fory_derive::ForyStruct;
+
+    #[derive(ForyStruct, Debug, PartialEq)]
+    pub struct Account {
+        #[fory(id = 1)]
+        pub funds: i64,
+        #[fory(id = 0)]
+        pub holder: String,
+        #[fory(id = 2)]
+        pub note: String,
+    }
+}
+
+#[test]
+fn test_field_id_survives_rename() {
+    // Field IDs are matched before names, so renaming a field with a stable ID keeps it compatible.
+    for xlang in [false, true] {
+        let mut fory_v1 = Fory::builder().xlang(xlang).compatible(true).build();
+        fory_v1.register::<renamed_field_v1::Account>(502).unwrap();
+        let mut fory_v2 = Fory::builder().xlang(xlang).compatible(true).build();
+        fory_v2.register::<renamed_field_v2::Account>(502).unwrap();
+
+        let v1 = renamed_field_v1::Account {
+            owner: "alice".to_string(),
+            balance: 42,
+        };
+        let bytes = fory_v1.serialize(&v1).unwrap();
+        let v2: renamed_field_v2::Account = fory_v2.deserialize(&bytes).unwrap();
+        assert_eq!(v2.holder, "alice");
+        assert_eq!(v2.funds, 42);
+        assert_eq!(v2.note, "");
+
+        let bytes = fory_v2.serialize(&v2).unwrap();
+        let back: renamed_field_v1::Account = fory_v1.deserialize(&bytes).unwrap();
+        assert_eq!(back, v1);
+    }
+}
+
+#[derive(ForyStruct, Debug, PartialEq)]
+struct FixedI32Wire {
+    #[fory(encoding = fixed)]
+    value: i32,
+}
+
+#[derive(ForyStruct, Debug, PartialEq)]
+struct VarintI32Wire {
+    value: i32,
+}
+
+#[test]
+fn fixed_i32_field_writes_four_little_endian_bytes() {
+    let type_resolver = TypeResolver::default();
+    assert_eq!(
+        only_field_type::<FixedI32Wire>(&type_resolver).type_id,
+        TypeId::INT32 as u32
+    );
+    assert_eq!(
+        only_field_type::<VarintI32Wire>(&type_resolver).type_id,
+        TypeId::VARINT32 as u32
+    );
+
+    for value in [0, 1, -1, 300, i32::MIN, i32::MAX] {
+        let fixed = write_struct_data(&FixedI32Wire { value });
+        let varint = write_struct_data(&VarintI32Wire { value });
+        assert_eq!(fixed, value.to_le_bytes(), "value {value}");
+        let mut buffer = vec![];
+        let mut writer = Writer::from_buffer(&mut buffer);
+        writer.write_var_i32(value);
+        assert_eq!(varint, writer.dump(), "value {value}");
+    }
+
+    for compatible in [false, true] {
+        let mut fory = Fory::builder().xlang(false).compatible(compatible).build();
+        fory.register::<FixedI32Wire>(1).unwrap();
+        for value in [0, -7, i32::MIN, i32::MAX] {
+            let bytes = fory.serialize(&FixedI32Wire { value }).unwrap();
+            let decoded: FixedI32Wire = fory.deserialize(&bytes).unwrap();
+            assert_eq!(decoded, FixedI32Wire { value });
+        }
+    }
+}
@@ -19,6 +19,7 @@ use fory_core::buffer::Reader;
 use fory_core::error::Error;
 use fory_core::fory::Fory;
 use fory_derive::ForyStruct;
+use std::collections::HashMap;
 
 #[test]
 fn test_nested_struct_register_order() {
@@ -75,6 +76,300 @@ fn test_serialize_to_appends_bytes() {
     assert_eq!(&buf[len_first..], &expected_second);
 }
 
+#[test]
+fn test_serialize_to_writer() {
+    #[derive(ForyStruct, Debug, PartialEq)]
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    struct FailingWriter;
+
+    impl std::io::Write for FailingWriter {
+        fn write(&mut self, _buf: &[u8]) -> std::io::Result<usize> {
+            Err(std::io::Error::other("sink closed"))
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let mut fory = Fory::builder().xlang(false).compatible(false).build();
+    fory.register::<Point>(100).unwrap();
+    let p1 = Point { x: 1, y: 2 };
+    let p2 = Point { x: -3, y: 4 };
+
+    let mut sink = std::io::Cursor::new(Vec::new());
+    let len_first = fory.serialize_to_writer(&mut sink, &p1).unwrap();
+    let len_second = fory.serialize_to_writer(&mut sink, &p2).unwrap();
+    let bytes = sink.into_inner();
+    assert_eq!(&bytes[..len_first], &fory.serialize(&p1).unwrap());
+    assert_eq!(&bytes[len_first..], &fory.serialize(&p2).unwrap());
+    assert_eq!(bytes.len(), len_first + len_second);
+
+    let err = fory
+        .serialize_to_writer(&mut FailingWriter, &p1)
+        .unwrap_err();
+    assert!(matches!(err, Error::EncodeError(_)));
+    // The thread-local buffer is reset after a failed write.
+    assert_eq!(fory.serialize(&p1).unwrap(), &bytes[..len_first]);
+}
+
+#[test]
+fn test_serialize_into_reuses_writer() {
+    use fory_core::buffer::Writer;
+
+    #[derive(ForyStruct, Debug, PartialEq)]
+    struct Event {
+        id: u64,
+        name: String,
+    }
+
+    let mut fory = Fory::builder().xlang(false).compatible(false).build();
+    fory.register::<Event>(100).unwrap();
+
+    let mut buf = Vec::new();
+    let mut writer = Writer::from_buffer(&mut buf);
+    let mut frames = Vec::new();
+    for id in 0..10_000u64 {
+        let event = Event {
+            id,
+            name: format!("event-{}", id % 97),
+        };
+        let bytes = fory.serialize_into(&mut writer, &event).unwrap();
+        assert_eq!(bytes, fory.serialize(&event).unwrap().as_slice());
+        assert_eq!(event, fory.deserialize::<Event>(bytes).unwrap());
+        frames.push(bytes.to_vec());
+    }
+    let capacity = buf.capacity();
+    assert!(capacity < 1024, "writer buffer grew to {capacity}");
+
+    for (id, frame) in frames.iter().enumerate() {
+        let event: Event = fory.deserialize(frame).unwrap();
+        assert_eq!(event.id, id as u64);
+    }
+
+    // A failed call leaves the writer empty rather than holding a partial payload.
+    let mut buf = Vec::new();
+    let mut writer = Writer::from_buffer(&mut buf);
+    let unregistered = Fory::builder().xlang(false).build();
+    assert!(unregistered
+        .serialize_into(
+            &mut writer,
+            &Event {
+                id: 1,
+                name: String::new()
+            }
+        )
+        .is_err());
+    assert!(writer.is_empty());
+}
+
+#[test]
+fn test_deserialize_with_len() {
+    #[derive(ForyStruct, Debug, PartialEq)]
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    let mut fory = Fory::builder().xlang(false).compatible(true).build();
+    fory.register::<Point>(100).unwrap();
+    let p1 = Point { x: 1, y: 2 };
+    let p2 = Point { x: -3, y: 4 };
+
+    let mut buf = fory.serialize(&p1).unwrap();
+    let first_len = buf.len();
+    buf.extend(fory.serialize(&p2).unwrap());
+
+    let (first, len) = fory.deserialize_with_len::<Point>(&buf).unwrap();
+    assert_eq!(first, p1);
+    assert_eq!(len, first_len);
+    let (second, len) = fory
+        .deserialize_with_len::<Point>(&buf[first_len..])
+        .unwrap();
+    assert_eq!(second, p2);
+    assert_eq!(len, buf.len() - first_len);
+}
+
+#[test]
+fn test_deserialize_strict() {
+    #[derive(ForyStruct, Debug, PartialEq)]
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    let mut fory = Fory::builder().xlang(false).compatible(true).build();
+    fory.register::<Point>(100).unwrap();
+    let point = Point { x: 1, y: -2 };
+    let bytes = fory.serialize(&point).unwrap();
+
+    assert_eq!(point, fory.deserialize_strict::<Point>(&bytes).unwrap());
+
+    let mut padded = bytes.clone();
+    padded.extend_from_slice(&[0xde, 0xad, 0xbe]);
+    assert_eq!(point, fory.deserialize::<Point>(&padded).unwrap());
+    let err = fory.deserialize_strict::<Point>(&padded).unwrap_err();
+    assert!(matches!(err, Error::TrailingBytes(3)), "{err}");
+
+    let err = fory
+        .deserialize_strict::<Point>(&bytes[..bytes.len() - 1])
+        .unwrap_err();
+    assert!(matches!(err, Error::BufferOutOfBound(..)), "{err}");
+}
+
+#[derive(ForyStruct, Debug, PartialEq)]
+struct Frame {
+    seq: u32,
+    body: String,
+}
+
+fn framed_fory() -> Fory {
+    let mut fory = Fory::builder().xlang(false).compatible(true).build();
+    fory.register::<Frame>(100).unwrap();
+    fory
+}
+
+#[test]
+fn test_deserialize_framed() {
+    let fory = framed_fory();
+    let frame = Frame {
+        seq: 1,
+        body: "hello".to_string(),
+    };
+    let bytes = fory.serialize_framed(&frame).unwrap();
+    let payload = fory.serialize(&frame).unwrap();
+    assert_eq!(bytes[0] as usize, payload.len());
+    assert_eq!(&bytes[1..], &payload[..]);
+
+    let (decoded, consumed) = fory.deserialize_framed::<Frame>(&bytes).unwrap();
+    assert_eq!(decoded, frame);
+    assert_eq!(consumed, bytes.len());
+}
+
+#[test]
+fn test_deserialize_framed_incomplete() {
+    let fory = framed_fory();
+    let frame = Frame {
+        seq: 2,
+        body: "x".repeat(200),
+    };
+    let bytes = fory.serialize_framed(&frame).unwrap();
+    // 200-byte body pushes the length prefix to two bytes.
+    assert!(bytes[0] & 0x80 != 0);
+
+    let err = fory.deserialize_framed::<Frame>(&[]).unwrap_err();
+    assert!(matches!(err, Error::Incomplete(1)), "{err}");
+    let err = fory.deserialize_framed::<Frame>(&bytes[..1]).unwrap_err();
+    assert!(matches!(err, Error::Incomplete(1)), "{err}");
+    let err = fory.deserialize_framed::<Frame>(&bytes[..10]).unwrap_err();
+    assert!(
+        matches!(err, Error::Incomplete(n) if n == bytes.len() - 10),
+        "{err}"
+    );
+}
+
+#[test]
+fn test_deserialize_framed_oversized() {
+    let mut fory = Fory::builder()
+        .xlang(false)
+        .compatible(true)
+        .max_binary_size(1024)
+        .build();
+    fory.register::<Frame>(100).unwrap();
+    // A prefix announcing `u32::MAX` bytes fails at once instead of asking for more input.
+    let err = fory
+        .deserialize_framed::<Frame>(&[0xff, 0xff, 0xff, 0xff, 0x0f, 1, 2, 3])
+        .unwrap_err();
+    assert!(matches!(err, Error::SizeLimitExceeded(_)), "{err}");
+}
+
+#[test]
+fn test_deserialize_framed_back_to_back() {
+    let fory = framed_fory();
+    let first = Frame {
+        seq: 1,
+        body: "first".to_string(),
+    };
+    let second = Frame {
+        seq: 2,
+        body: "second".to_string(),
+    };
+    let mut stream = fory.serialize_framed(&first).unwrap();
+    let first_len = stream.len();
+    stream.extend(fory.serialize_framed(&second).unwrap());
+
+    let (decoded, consumed) = fory.deserialize_framed::<Frame>(&stream).unwrap();
+    assert_eq!(decoded, first);
+    assert_eq!(consumed, first_len);
+    let (decoded, consumed) = fory
+        .deserialize_framed::<Frame>(&stream[first_len..])
+        .unwrap();
+    assert_eq!(decoded, second);
+    assert_eq!(first_len + consumed, stream.len());
+}
+
+#[test]
+fn test_serialized_size_matches_serialize() {
+    #[derive(ForyStruct, Debug, PartialEq)]
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    #[derive(ForyStruct, Debug, PartialEq)]
+    struct Shape {
+        name: String,
+        points: Vec<Point>,
+        labels: HashMap<String, i64>,
+        parent: Option<Box<Shape>>,
+    }
+
+    let shape = Shape {
+        name: "triangle".to_string(),
+        points: vec![Point { x: 0, y: 0 }, Point { x: 3, y: -4 }],
+        labels: HashMap::from([("area".to_string(), 6), ("sides".to_string(), 3)]),
+        parent: Some(Box::new(Shape {
+            name: "root".to_string(),
+            points: vec![],
+            labels: HashMap::new(),
+            parent: None,
+        })),
+    };
+
+    for fory in [
+        Fory::builder().xlang(false).compatible(false).build(),
+        Fory::builder().xlang(false).compatible(true).build(),
+        Fory::builder().xlang(true).build(),
+        Fory::builder()
+            .xlang(false)
+            .checksum(fory_core::Checksum::Crc32)
+            .build(),
+    ] {
+        let mut fory = fory;
+        fory.register::<Point>(100).unwrap();
+        fory.register::<Shape>(101).unwrap();
+
+        let point = Point { x: 1, y: -2 };
+        assert_eq!(
+            fory.serialized_size(&point).unwrap(),
+            fory.serialize(&point).unwrap().len()
+        );
+        assert_eq!(
+            fory.serialized_size(&shape).unwrap(),
+            fory.serialize(&shape).unwrap().len()
+        );
+        let points = vec![point, Point { x: 5, y: 6 }];
+        assert_eq!(
+            fory.serialized_size(&points).unwrap(),
+            fory.serialize(&points).unwrap().len()
+        );
+    }
+}
+
 #[test]
 fn test_serialize_to_detailed() {
     #[derive(ForyStruct, Debug, PartialEq)]
@@ -329,6 +624,167 @@ fn test_type_mismatch_error_shows_type_name() {
     );
 }
 
+#[test]
+fn test_struct_slot_rejects_non_struct_type_id() {
+    #[derive(ForyStruct, Debug, PartialEq)]
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    for compatible in [false, true] {
+        for xlang in [false, true] {
+            let mut fory = Fory::builder().compatible(compatible).xlang(xlang).build();
+            fory.register::<Point>(100).unwrap();
+            for bytes in [
+                fory.serialize(&7i32).unwrap(),
+                fory.serialize(&"point".to_string()).unwrap(),
+                fory.serialize(&vec![1i64, 2]).unwrap(),
+            ] {
+                let err = fory.deserialize::<Point>(&bytes).unwrap_err();
+                assert!(
+                    matches!(err, Error::TypeMismatch(_)),
+                    "compatible={compatible} xlang={xlang}: {err}"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_register_alias() {
+    #[derive(ForyStruct, Debug, PartialEq)]
+    struct Order {
+        id: u64,
+        item: String,
+    }
+
+    let order = Order {
+        id: 7,
+        item: "book".to_string(),
+    };
+    for compatible in [false, true] {
+        for xlang in [false, true] {
+            let build = || Fory::builder().compatible(compatible).xlang(xlang).build();
+            let mut service_a = build();
+            service_a.register::<Order>(999).unwrap();
+            let mut service_b = build();
+            service_b.register::<Order>(1001).unwrap();
+            let mut gateway = build();
+            gateway.register::<Order>(999).unwrap();
+            gateway.register_alias::<Order>(1001).unwrap();
+
+            for bytes in [
+                service_a.serialize(&order).unwrap(),
+                service_b.serialize(&order).unwrap(),
+            ] {
+                let decoded: Order = gateway.deserialize(&bytes).unwrap();
+                assert_eq!(decoded, order, "compatible={compatible} xlang={xlang}");
+            }
+            // The gateway keeps writing the canonical id.
+            let bytes = gateway.serialize(&order).unwrap();
+            assert_eq!(service_a.deserialize::<Order>(&bytes).unwrap(), order);
+            if !compatible && !fory_core::error::should_panic_on_error() {
+                assert!(service_b.deserialize::<Order>(&bytes).is_err());
+            }
+        }
+    }
+}
+
+#[test]
+fn test_register_alias_rejects_conflicts() {
+    if fory_core::error::should_panic_on_error() {
+        return;
+    }
+    #[derive(ForyStruct, Debug, PartialEq)]
+    struct Order {
+        id: u64,
+    }
+
+    #[derive(ForyStruct, Debug, PartialEq)]
+    struct Refund {
+        id: u64,
+    }
+
+    let mut fory = Fory::default();
+    // The type must be registered by id before it can be aliased.
+    assert!(fory.register_alias::<Order>(1001).is_err());
+    fory.register::<Order>(999).unwrap();
+    fory.register::<Refund>(1000).unwrap();
+    assert!(fory.register_alias::<Order>(999).is_err());
+    assert!(fory.register_alias::<Order>(1000).is_err());
+    fory.register_alias::<Order>(1001).unwrap();
+    assert!(fory.register_alias::<Refund>(1001).is_err());
+    assert!(fory.register::<Refund>(1001).is_err());
+}
+
+mod type_hash_v1 {
+    use fory_derive::ForyStruct;
+
+    #[derive(ForyStruct)]
+    pub struct Account {
+        pub id: i64,
+        pub name: String,
+        pub tags: Vec<String>,
+    }
+}
+
+mod type_hash_v2 {
+    use fory_derive::ForyStruct;
+
+    // Same field names and types as v1 in a different order.
+    #[derive(ForyStruct)]
+    pub struct Account {
+        pub tags: Vec<String>,
+        pub id: i64,
+        pub name: String,
+    }
+
+    #[derive(ForyStruct)]
+    pub struct Customer {
+        pub id: i64,
+        pub name: String,
+        pub tags: Vec<String>,
+    }
+
+    #[derive(ForyStruct)]
+    pub struct FieldRenamed {
+        pub id: i64,
+        pub title: String,
+        pub tags: Vec<String>,
+    }
+
+    #[derive(ForyStruct)]
+    pub struct Retyped {
+        pub id: i32,
+        pub name: String,
+        pub tags: Vec<String>,
+    }
+}
+
+#[test]
+fn test_type_hash_fingerprints_fields() {
+    let fory = Fory::default();
+    let hash = fory.type_hash::<type_hash_v1::Account>();
+    assert_ne!(hash, 0);
+    assert_eq!(hash, Fory::default().type_hash::<type_hash_v1::Account>());
+    assert_eq!(hash, fory.type_hash::<type_hash_v2::Account>());
+    // The type name is not part of the hash; field names and types are.
+    assert_eq!(hash, fory.type_hash::<type_hash_v2::Customer>());
+    assert_ne!(hash, fory.type_hash::<type_hash_v2::FieldRenamed>());
+    assert_ne!(hash, fory.type_hash::<type_hash_v2::Retyped>());
+}
+
+#[test]
+fn test_type_hash_pinned() {
+    // Schema registries store this value, so it must not change between builds or
+    // releases for the same struct definition.
+    assert_eq!(
+        Fory::default().type_hash::<type_hash_v1::Account>(),
+        1556633564
+    );
+}
+
 #[test]
 fn test_size_guardrail_configuration_accessors() {
     let default_fory = Fory::builder().xlang(false).compatible(false).build();
@@ -367,3 +823,194 @@ fn test_size_limit_exceeded_error_display() {
     assert!(matches!(err, Error::SizeLimitExceeded(_)));
     assert_eq!(err.to_string(), "Collection size 3 exceeds limit 2");
 }
+
+#[test]
+fn test_serialize_reserves_buffer_once() {
+    use fory_core::buffer::Writer;
+
+    #[derive(ForyStruct, Debug, PartialEq)]
+    struct Wide {
+        a0: i32,
+        a1: i32,
+        a2: i32,
+        a3: i32,
+        a4: i32,
+        a5: i32,
+        a6: i32,
+        a7: i32,
+        b0: i64,
+        b1: i64,
+        b2: i64,
+        b3: i64,
+        b4: i64,
+        b5: i64,
+        b6: i64,
+        b7: i64,
+        c0: f64,
+        c1: f64,
+        c2: f64,
+        c3: f64,
+        c4: f64,
+        c5: f64,
+        c6: f64,
+        c7: f64,
+        d0: bool,
+        d1: bool,
+        d2: u8,
+        d3: u16,
+        e0: String,
+        e1: String,
+        f0: Option<i64>,
+        f1: Option<f64>,
+    }
+
+    let value = Wide {
+        a0: i32::MIN,
+        a1: i32::MAX,
+        a2: -1,
+        a3: 1 << 30,
+        a4: 7,
+        a5: -7,
+        a6: 1 << 20,
+        a7: -(1 << 20),
+        b0: i64::MIN,
+        b1: i64::MAX,
+        b2: -1,
+        b3: 1 << 62,
+        b4: 7,
+        b5: -7,
+        b6: 1 << 40,
+        b7: -(1 << 40),
+        c0: 0.1,
+        c1: -0.1,
+        c2: f64::MAX,
+        c3: f64::MIN,
+        c4: 1.5,
+        c5: -1.5,
+        c6: 1e300,
+        c7: -1e300,
+        d0: true,
+        d1: false,
+        d2: u8::MAX,
+        d3: u16::MAX,
+        e0: "short".to_string(),
+        e1: String::new(),
+        f0: Some(i64::MAX),
+        f1: None,
+    };
+
+    for compatible in [false, true] {
+        let mut fory = Fory::builder().xlang(false).compatible(compatible).build();
+        fory.register::<Wide>(100).unwrap();
+
+        // `write_head_for` performs the one up-front reservation sized from `fory_estimate_size`.
+        let mut head = Vec::new();
+        fory.write_head_for(&value, &mut Writer::from_buffer(&mut head));
+        let reserved = head.capacity();
+
+        let mut buf = Vec::new();
+        fory.serialize_to(&mut buf, &value).unwrap();
+        assert!(buf.len() <= reserved, "{} > {}", buf.len(), reserved);
+        assert_eq!(buf.capacity(), reserved);
+        assert_eq!(value, fory.deserialize::<Wide>(&buf).unwrap());
+    }
+}
+
+#[test]
+fn test_serialize_reserves_buffer_once_for_large_string() {
+    use fory_core::buffer::Writer;
+    use fory_core::Serializer;
+
+    #[derive(ForyStruct, Debug, PartialEq)]
+    struct Document {
+        id: i64,
+        body: String,
+        title: Option<String>,
+        checksum: Vec<u8>,
+        offsets: Vec<i64>,
+    }
+
+    let value = Document {
+        id: 42,
+        body: "x".repeat(1 << 20),
+        title: Some("t".repeat(1 << 21)),
+        checksum: vec![7; 1 << 16],
+        offsets: (0..4096).collect(),
+    };
+    assert!(value.fory_estimate_size() > value.body.len());
+
+    for compatible in [false, true] {
+        let mut fory = Fory::builder().xlang(false).compatible(compatible).build();
+        fory.register::<Document>(100).unwrap();
+
+        let mut head = Vec::new();
+        fory.write_head_for(&value, &mut Writer::from_buffer(&mut head));
+        let reserved = head.capacity();
+
+        // The buffer grows once, for the head, and never again while the strings are written.
+        let mut buf = Vec::new();
+        fory.serialize_to(&mut buf, &value).unwrap();
+        assert!(buf.len() <= reserved, "{} > {}", buf.len(), reserved);
+        assert_eq!(buf.capacity(), reserved);
+        assert_eq!(value, fory.deserialize::<Document>(&buf).unwrap());
+    }
+}
+
+#[test]
+fn test_schema_mode_none_skips_type_meta() {
+    use fory_core::SchemaMode;
+
+    #[derive(ForyStruct, Debug, PartialEq)]
+    struct Reading {
+        sensor: String,
+        value: f64,
+        samples: Vec<i32>,
+        tags: HashMap<String, String>,
+    }
+
+    let reading = Reading {
+        sensor: "s-1".to_string(),
+        value: 0.25,
+        samples: vec![1, 2, 3],
+        tags: HashMap::from([("unit".to_string(), "C".to_string())]),
+    };
+    for xlang in [false, true] {
+        let mut lean = Fory::builder()
+            .xlang(xlang)
+            .schema_mode(SchemaMode::None)
+            .build();
+        lean.register::<Reading>(100).unwrap();
+        let mut compatible = Fory::builder()
+            .xlang(xlang)
+            .schema_mode(SchemaMode::Compatible)
+            .build();
+        compatible.register::<Reading>(100).unwrap();
+        assert_eq!(lean.get_schema_mode(), SchemaMode::None);
+        assert!(!lean.is_compatible());
+        assert_eq!(compatible.get_schema_mode(), SchemaMode::Compatible);
+        assert_eq!(
+            Fory::builder().xlang(xlang).build().get_schema_mode(),
+            SchemaMode::Compatible
+        );
+
+        let lean_bytes = lean.serialize(&reading).unwrap();
+        let compatible_bytes = compatible.serialize(&reading).unwrap();
+        assert_eq!(lean.deserialize::<Reading>(&lean_bytes).unwrap(), reading);
+        assert!(
+            lean_bytes.len() < compatible_bytes.len(),
+            "{} >= {}",
+            lean_bytes.len(),
+            compatible_bytes.len()
+        );
+        // The payload carries no TypeMeta to inspect.
+        let err = lean.peek_type_meta(&lean_bytes).unwrap_err();
+        assert!(matches!(err, Error::TypeError(_)), "{err}");
+    }
+
+    // The later of schema_mode and compatible wins.
+    let fory = Fory::builder()
+        .schema_mode(SchemaMode::None)
+        .compatible(true)
+        .build();
+    assert_eq!(fory.get_schema_mode(), SchemaMode::Compatible);
+}
@@ -0,0 +1,70 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Tests for deriving `ForyStruct` on generic structs without spelling out bounds.
+
+use fory_core::fory::Fory;
+use fory_derive::ForyStruct;
+
+#[derive(Debug, PartialEq, ForyStruct)]
+struct Pair<A, B> {
+    a: A,
+    b: B,
+}
+
+#[derive(Debug, PartialEq, ForyStruct)]
+struct Labeled<T> {
+    label: String,
+    values: Vec<T>,
+    pair: Option<Pair<T, i64>>,
+}
+
+#[test]
+fn test_generic_pair() {
+    for compatible in [false, true] {
+        for xlang in [false, true] {
+            let mut fory = Fory::builder().xlang(xlang).compatible(compatible).build();
+            fory.register::<Pair<i32, String>>(100).unwrap();
+
+            let value = Pair {
+                a: 42,
+                b: "hello".to_string(),
+            };
+            let bytes = fory.serialize(&value).unwrap();
+            let result: Pair<i32, String> = fory.deserialize(&bytes).unwrap();
+            assert_eq!(result, value);
+        }
+    }
+}
+
+#[test]
+fn test_generic_params_in_nested_fields() {
+    for compatible in [false, true] {
+        let mut fory = Fory::builder().xlang(false).compatible(compatible).build();
+        fory.register::<Pair<f64, i64>>(100).unwrap();
+        fory.register::<Labeled<f64>>(101).unwrap();
+
+        let value = Labeled {
+            label: "scores".to_string(),
+            values: vec![1.5, 2.5],
+            pair: Some(Pair { a: 0.5, b: 7 }),
+        };
+        let bytes = fory.serialize(&value).unwrap();
+        let result: Labeled<f64> = fory.deserialize(&bytes).unwrap();
+        assert_eq!(result, value);
+    }
+}
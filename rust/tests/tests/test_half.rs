@@ -0,0 +1,100 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use fory_core::{Float16, Fory};
+use fory_derive::ForyStruct;
+use half::f16;
+
+fn values() -> Vec<f16> {
+    vec![
+        f16::ZERO,
+        f16::NEG_ZERO,
+        f16::ONE,
+        f16::from_f32(-3.5),
+        f16::MIN_POSITIVE_SUBNORMAL,
+        f16::from_bits(0x03ff), // largest subnormal
+        f16::MAX,
+        f16::INFINITY,
+        f16::NEG_INFINITY,
+    ]
+}
+
+#[test]
+fn test_half_round_trip() {
+    for xlang in [false, true] {
+        let fory = Fory::builder().xlang(xlang).build();
+        for value in values() {
+            let bytes = fory.serialize(&value).unwrap();
+            let decoded: f16 = fory.deserialize(&bytes).unwrap();
+            assert_eq!(value.to_bits(), decoded.to_bits());
+        }
+        let bytes = fory.serialize(&f16::NAN).unwrap();
+        assert!(fory.deserialize::<f16>(&bytes).unwrap().is_nan());
+    }
+}
+
+#[test]
+fn test_half_wire_compatible_with_float16() {
+    let fory = Fory::builder().xlang(true).build();
+    for value in values() {
+        let float16 = Float16::from_bits(value.to_bits());
+        let bytes = fory.serialize(&value).unwrap();
+        assert_eq!(bytes, fory.serialize(&float16).unwrap());
+        assert_eq!(
+            fory.deserialize::<Float16>(&bytes).unwrap().to_bits(),
+            value.to_bits()
+        );
+    }
+}
+
+#[test]
+fn test_half_vec() {
+    let fory = Fory::builder().xlang(false).build();
+    let value: Vec<f16> = (0..10_000).map(|i| f16::from_f32(i as f32 / 7.0)).collect();
+    let bytes = fory.serialize(&value).unwrap();
+    // Written as a float16 array: two bytes per element plus a small header.
+    assert!(bytes.len() < value.len() * 2 + 16);
+    assert_eq!(fory.deserialize::<Vec<f16>>(&bytes).unwrap(), value);
+
+    let float16s: Vec<Float16> = value
+        .iter()
+        .map(|v| Float16::from_bits(v.to_bits()))
+        .collect();
+    assert_eq!(bytes, fory.serialize(&float16s).unwrap());
+}
+
+#[derive(ForyStruct, Debug, PartialEq)]
+struct Features {
+    scale: f16,
+    bias: Option<f16>,
+    weights: Vec<f16>,
+}
+
+#[test]
+fn test_half_struct_fields() {
+    for compatible in [false, true] {
+        let mut fory = Fory::builder().xlang(false).compatible(compatible).build();
+        fory.register::<Features>(100).unwrap();
+        let value = Features {
+            scale: f16::from_f32(0.25),
+            bias: Some(f16::MIN_POSITIVE_SUBNORMAL),
+            weights: values(),
+        };
+        let bytes = fory.serialize(&value).unwrap();
+        assert_eq!(fory.deserialize::<Features>(&bytes).unwrap(), value);
+    }
+}
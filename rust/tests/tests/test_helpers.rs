@@ -15,6 +15,9 @@
 // specific language governing permissions and limitations
 // under the License.
 
+// Each test crate includes this module and uses only some of its helpers.
+#![allow(dead_code)]
+
 use fory_core::fory::Fory;
 use fory_core::{ForyDefault, Serializer};
 use std::any::Any;
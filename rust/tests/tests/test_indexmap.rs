@@ -0,0 +1,95 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use fory_core::Fory;
+use fory_derive::ForyStruct;
+use indexmap::IndexMap;
+use std::collections::HashMap;
+
+#[derive(ForyStruct, Debug, PartialEq)]
+struct Settings {
+    name: String,
+    values: IndexMap<String, i32>,
+    nested: IndexMap<i32, Option<String>>,
+}
+
+#[derive(ForyStruct, Debug, PartialEq)]
+struct PeerSettings {
+    name: String,
+    values: HashMap<String, i32>,
+}
+
+#[derive(ForyStruct, Debug, PartialEq)]
+struct LocalSettings {
+    name: String,
+    values: IndexMap<String, i32>,
+}
+
+fn unsorted() -> IndexMap<String, i32> {
+    ["zeta", "alpha", "mid", "beta", "omega"]
+        .iter()
+        .enumerate()
+        .map(|(i, key)| (key.to_string(), i as i32))
+        .collect()
+}
+
+#[test]
+fn test_indexmap_preserves_insertion_order() {
+    for compatible in [false, true] {
+        let mut fory = Fory::builder().xlang(false).compatible(compatible).build();
+        fory.register::<Settings>(100).unwrap();
+
+        let map = unsorted();
+        let bytes = fory.serialize(&map).unwrap();
+        let decoded: IndexMap<String, i32> = fory.deserialize(&bytes).unwrap();
+        assert!(decoded.iter().eq(map.iter()));
+
+        let settings = Settings {
+            name: "ordered".to_string(),
+            values: map,
+            nested: IndexMap::from([(3, None), (1, Some("one".to_string())), (2, None)]),
+        };
+        let bytes = fory.serialize(&settings).unwrap();
+        let decoded: Settings = fory.deserialize(&bytes).unwrap();
+        assert!(decoded.values.iter().eq(settings.values.iter()));
+        assert!(decoded.nested.keys().eq([3, 1, 2].iter()));
+        assert_eq!(decoded, settings);
+    }
+}
+
+#[test]
+fn test_indexmap_reads_hashmap_payload() {
+    let mut writer = Fory::builder().xlang(false).compatible(true).build();
+    writer.register::<PeerSettings>(101).unwrap();
+    let mut reader = Fory::builder().xlang(false).compatible(true).build();
+    reader.register::<LocalSettings>(101).unwrap();
+
+    let values: HashMap<String, i32> = unsorted().into_iter().collect();
+    let peer = PeerSettings {
+        name: "peer".to_string(),
+        values: values.clone(),
+    };
+    let bytes = writer.serialize(&peer).unwrap();
+    let decoded: LocalSettings = reader.deserialize(&bytes).unwrap();
+    assert_eq!(decoded.name, "peer");
+    // The IndexMap keeps the order the HashMap happened to write its entries in.
+    assert!(decoded.values.iter().eq(values.iter()));
+
+    let bytes = writer.serialize(&values).unwrap();
+    let decoded: IndexMap<String, i32> = reader.deserialize(&bytes).unwrap();
+    assert!(decoded.iter().eq(values.iter()));
+}
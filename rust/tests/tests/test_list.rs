@@ -139,6 +139,66 @@ fn test_struct_with_collections() {
     assert_eq!(data, obj);
 }
 
+#[test]
+fn test_option_linkedlist_roundtrip() {
+    let fory = Fory::builder().xlang(false).compatible(false).build();
+    let list: Option<LinkedList<String>> =
+        Some(["a", "b", "c"].iter().map(|s| s.to_string()).collect());
+    let bin = fory.serialize(&list).unwrap();
+    let obj: Option<LinkedList<String>> = fory.deserialize(&bin).expect("deserialize");
+    assert_eq!(list, obj);
+
+    let none: Option<LinkedList<String>> = None;
+    let bin = fory.serialize(&none).unwrap();
+    let obj: Option<LinkedList<String>> = fory.deserialize(&bin).expect("deserialize");
+    assert_eq!(none, obj);
+}
+
+#[derive(ForyStruct, PartialEq, Debug)]
+struct NullableLinkedLists {
+    levels: LinkedList<Option<i8>>,
+    history: Option<LinkedList<i32>>,
+}
+
+/// `NullableLinkedLists` with `Vec` fields, for reading the same payload.
+#[derive(ForyStruct, PartialEq, Debug)]
+struct NullableVecs {
+    levels: Vec<Option<i8>>,
+    history: Option<Vec<i32>>,
+}
+
+#[test]
+fn test_nullable_linkedlist_compatible() {
+    let mut fory = Fory::builder().xlang(false).compatible(true).build();
+    fory.register::<NullableLinkedLists>(100).unwrap();
+    let mut reader = Fory::builder().xlang(false).compatible(true).build();
+    reader.register::<NullableVecs>(100).unwrap();
+
+    let data = NullableLinkedLists {
+        levels: [Some(-3), None, Some(7), None].into_iter().collect(),
+        history: Some([1, 2, 3].into_iter().collect()),
+    };
+    let bin = fory.serialize(&data).unwrap();
+    let obj: NullableLinkedLists = fory.deserialize(&bin).expect("deserialize");
+    assert_eq!(data, obj);
+    let obj: NullableVecs = reader.deserialize(&bin).expect("deserialize");
+    assert_eq!(
+        obj,
+        NullableVecs {
+            levels: vec![Some(-3), None, Some(7), None],
+            history: Some(vec![1, 2, 3]),
+        }
+    );
+
+    let data = NullableLinkedLists {
+        levels: LinkedList::new(),
+        history: None,
+    };
+    let bin = fory.serialize(&data).unwrap();
+    let obj: NullableLinkedLists = fory.deserialize(&bin).expect("deserialize");
+    assert_eq!(data, obj);
+}
+
 #[test]
 fn test_vec_float16_basic() {
     use fory_core::types::float16::float16;
@@ -273,3 +333,209 @@ fn test_vec_max_collection_size_guardrail() {
         "unexpected error message: {err}"
     );
 }
+
+#[test]
+fn test_vec_field_bogus_length_rejected() {
+    #[derive(ForyStruct, Debug, PartialEq)]
+    struct Holder {
+        items: Vec<String>,
+    }
+
+    for compatible in [false, true] {
+        let mut fory = Fory::builder().xlang(false).compatible(compatible).build();
+        fory.register::<Holder>(100).unwrap();
+        let three = fory
+            .serialize(&Holder {
+                items: vec!["a".to_string(); 3],
+            })
+            .unwrap();
+        let four = fory
+            .serialize(&Holder {
+                items: vec!["a".to_string(); 4],
+            })
+            .unwrap();
+        // The first differing byte is the one-byte element count; declare i32::MAX instead.
+        let pos = three.iter().zip(&four).position(|(a, b)| a != b).unwrap();
+        assert_eq!(three[pos], 3);
+        let mut forged = three[..pos].to_vec();
+        forged.extend_from_slice(&[0xff, 0xff, 0xff, 0xff, 0x07]);
+        forged.extend_from_slice(&three[pos + 1..]);
+
+        let err = fory.deserialize::<Holder>(&forged).unwrap_err();
+        assert!(
+            matches!(err, fory_core::Error::SizeLimitExceeded(_)),
+            "expected SizeLimitExceeded, got: {err}"
+        );
+
+        // Without the cap, the length is still checked against the remaining bytes.
+        let mut unbounded = Fory::builder()
+            .xlang(false)
+            .compatible(compatible)
+            .max_collection_size(u32::MAX)
+            .build();
+        unbounded.register::<Holder>(100).unwrap();
+        let err = unbounded.deserialize::<Holder>(&forged).unwrap_err();
+        assert!(
+            matches!(err, fory_core::Error::BufferOutOfBound(..)),
+            "expected BufferOutOfBound, got: {err}"
+        );
+    }
+}
+
+#[test]
+fn test_vec_u8_large_binary() {
+    use fory_core::buffer::Reader;
+    use fory_core::TypeId;
+
+    let data: Vec<u8> = (0..1024 * 1024).map(|i| (i * 31 % 251) as u8).collect();
+    for xlang in [false, true] {
+        let fory = Fory::builder().xlang(xlang).compatible(false).build();
+        let bin = fory.serialize(&data).unwrap();
+        // Header, ref flag, BINARY type id and byte length, then the bytes as one block.
+        let mut reader = Reader::new(&bin);
+        reader.read_u8().unwrap();
+        reader.read_i8().unwrap();
+        assert_eq!(reader.read_u8().unwrap(), TypeId::BINARY as u8);
+        assert_eq!(reader.read_var_u32().unwrap() as usize, data.len());
+        assert_eq!(reader.slice_after_cursor(), data.as_slice());
+
+        let obj: Vec<u8> = fory.deserialize(&bin).unwrap();
+        assert_eq!(data, obj);
+    }
+}
+
+#[test]
+fn test_byte_vectors_compatible() {
+    #[derive(ForyStruct, PartialEq, Debug)]
+    struct Blobs {
+        unsigned: Vec<u8>,
+        signed: Vec<i8>,
+    }
+
+    let mut fory = Fory::builder().xlang(false).compatible(true).build();
+    fory.register::<Blobs>(100).unwrap();
+    let data = Blobs {
+        unsigned: vec![0, 127, 128, 255],
+        signed: vec![i8::MIN, -1, 0, i8::MAX],
+    };
+    let bin = fory.serialize(&data).unwrap();
+    let obj: Blobs = fory.deserialize(&bin).unwrap();
+    assert_eq!(data, obj);
+}
+
+#[test]
+fn test_primitive_vec_fixed_width_layout() {
+    use fory_core::buffer::Reader;
+    use fory_core::TypeId;
+
+    fn check<T>(fory: &Fory, data: Vec<T>, type_id: TypeId, to_le: fn(&T) -> Vec<u8>)
+    where
+        T: fory_core::Serializer + fory_core::ForyDefault + PartialEq + std::fmt::Debug,
+    {
+        let bin = fory.serialize(&data).unwrap();
+        let mut reader = Reader::new(&bin);
+        reader.read_u8().unwrap();
+        reader.read_i8().unwrap();
+        assert_eq!(reader.read_u8().unwrap(), type_id as u8);
+        let expected: Vec<u8> = data.iter().flat_map(to_le).collect();
+        assert_eq!(reader.read_var_u32().unwrap() as usize, expected.len());
+        assert_eq!(reader.slice_after_cursor(), expected.as_slice());
+        assert_eq!(data, fory.deserialize::<Vec<T>>(&bin).unwrap());
+    }
+
+    for xlang in [false, true] {
+        let fory = Fory::builder().xlang(xlang).compatible(false).build();
+        check(
+            &fory,
+            vec![i16::MIN, -1, 0, i16::MAX],
+            TypeId::INT16_ARRAY,
+            |v| v.to_le_bytes().to_vec(),
+        );
+        check(
+            &fory,
+            vec![i32::MIN, -1, 0, i32::MAX],
+            TypeId::INT32_ARRAY,
+            |v| v.to_le_bytes().to_vec(),
+        );
+        check(
+            &fory,
+            vec![i64::MIN, -1, 0, i64::MAX],
+            TypeId::INT64_ARRAY,
+            |v| v.to_le_bytes().to_vec(),
+        );
+        check(
+            &fory,
+            vec![f32::MIN, -0.0, 1.5, f32::MAX],
+            TypeId::FLOAT32_ARRAY,
+            |v| v.to_le_bytes().to_vec(),
+        );
+        check(
+            &fory,
+            vec![f64::MIN, -0.0, 1.5, f64::MAX],
+            TypeId::FLOAT64_ARRAY,
+            |v| v.to_le_bytes().to_vec(),
+        );
+    }
+}
+
+#[test]
+fn test_vec_f64_one_million() {
+    let fory = Fory::builder().xlang(false).compatible(false).build();
+    let data: Vec<f64> = (0..1_000_000).map(|i| i as f64 / 7.0).collect();
+    let bin = fory.serialize(&data).unwrap();
+    assert!(bin.len() < data.len() * 8 + 16);
+    let obj: Vec<f64> = fory.deserialize(&bin).unwrap();
+    assert_eq!(data, obj);
+}
+
+#[derive(ForyStruct, PartialEq, Debug, Clone)]
+struct SliceItem {
+    id: i32,
+    name: String,
+}
+
+#[test]
+fn test_serialize_slice_matches_vec() {
+    for (xlang, compatible, track_ref) in [
+        (false, false, false),
+        (false, true, true),
+        (true, false, false),
+        (true, true, true),
+    ] {
+        let mut fory = Fory::builder()
+            .xlang(xlang)
+            .compatible(compatible)
+            .track_ref(track_ref)
+            .build();
+        fory.register::<SliceItem>(100).unwrap();
+
+        let ints = vec![3i32, -1, 7];
+        assert_eq!(
+            fory.serialize_slice(&ints[..]).unwrap(),
+            fory.serialize(&ints).unwrap()
+        );
+        let strings = vec!["a".to_string(), "b".to_string()];
+        assert_eq!(
+            fory.serialize_slice(&strings[..]).unwrap(),
+            fory.serialize(&strings).unwrap()
+        );
+        let empty: Vec<u8> = Vec::new();
+        assert_eq!(
+            fory.serialize_slice(&empty[..]).unwrap(),
+            fory.serialize(&empty).unwrap()
+        );
+
+        let items: Vec<SliceItem> = (0..4)
+            .map(|id| SliceItem {
+                id,
+                name: format!("item{id}"),
+            })
+            .collect();
+        let bytes = fory.serialize_slice(&items[1..3]).unwrap();
+        assert_eq!(bytes, fory.serialize(&items[1..3].to_vec()).unwrap());
+        let decoded: Vec<SliceItem> = fory.deserialize(&bytes).unwrap();
+        assert_eq!(decoded, items[1..3]);
+        let boxed: Box<[SliceItem]> = fory.deserialize(&bytes).unwrap();
+        assert_eq!(&boxed[..], &items[1..3]);
+    }
+}
@@ -17,7 +17,7 @@
 
 use fory_core::fory::Fory;
 use fory_derive::ForyStruct;
-use std::collections::{BTreeMap, HashMap};
+use std::collections::{BTreeMap, HashMap, HashSet};
 
 #[test]
 fn test_hashmap_string() {
@@ -97,6 +97,40 @@ fn test_hashmap_max_collection_size_guardrail() {
     );
 }
 
+#[test]
+fn test_map_field_bogus_length_rejected() {
+    #[derive(ForyStruct, Debug, PartialEq)]
+    struct Holder {
+        entries: HashMap<String, i32>,
+    }
+
+    let holder = |n: i32| Holder {
+        entries: (0..n).map(|i| (i.to_string(), i)).collect(),
+    };
+    for compatible in [false, true] {
+        let mut fory = Fory::builder()
+            .xlang(false)
+            .compatible(compatible)
+            .max_collection_size(u32::MAX)
+            .build();
+        fory.register::<Holder>(100).unwrap();
+        let three = fory.serialize(&holder(3)).unwrap();
+        let four = fory.serialize(&holder(4)).unwrap();
+        // The first differing byte is the one-byte entry count; declare i32::MAX instead.
+        let pos = three.iter().zip(&four).position(|(a, b)| a != b).unwrap();
+        assert_eq!(three[pos], 3);
+        let mut forged = three[..pos].to_vec();
+        forged.extend_from_slice(&[0xff, 0xff, 0xff, 0xff, 0x07]);
+        forged.extend_from_slice(&three[pos + 1..]);
+
+        let err = fory.deserialize::<Holder>(&forged).unwrap_err();
+        assert!(
+            matches!(err, fory_core::Error::BufferOutOfBound(..)),
+            "expected BufferOutOfBound, got: {err}"
+        );
+    }
+}
+
 #[test]
 fn test_btreemap_max_collection_size_guardrail() {
     let fory = Fory::builder().xlang(false).compatible(false).build();
@@ -125,3 +159,45 @@ fn test_btreemap_max_collection_size_guardrail() {
         "unexpected error message: {err}"
     );
 }
+
+type AHashMap<K, V> = HashMap<K, V, ahash::RandomState>;
+type AHashSet<T> = HashSet<T, ahash::RandomState>;
+
+#[derive(ForyStruct, PartialEq, Debug)]
+struct CustomHasherContainer {
+    ids: HashMap<i32, i32, ahash::RandomState>,
+    names: HashSet<String, ahash::RandomState>,
+    #[fory(map(key(encoding = fixed), value(encoding = fixed)))]
+    fixed: HashMap<u32, u64, ahash::RandomState>,
+}
+
+#[test]
+fn test_custom_hasher_roundtrip() {
+    let map: AHashMap<i32, i32> = (0..100).map(|i| (i, i * i)).collect();
+    let set: AHashSet<String> = ["a", "b", "c"].iter().map(|s| s.to_string()).collect();
+    for compatible in [false, true] {
+        for xlang in [false, true] {
+            let mut fory = Fory::builder().xlang(xlang).compatible(compatible).build();
+            fory.register::<CustomHasherContainer>(100).unwrap();
+
+            let bytes = fory.serialize(&map).unwrap();
+            assert_eq!(fory.deserialize::<AHashMap<i32, i32>>(&bytes).unwrap(), map);
+            // The hasher is not part of the wire format.
+            let std_map: HashMap<i32, i32> = fory.deserialize(&bytes).unwrap();
+            assert_eq!(std_map.len(), map.len());
+            assert_eq!(fory.serialize(&std_map).unwrap().len(), bytes.len());
+
+            let bytes = fory.serialize(&set).unwrap();
+            assert_eq!(fory.deserialize::<AHashSet<String>>(&bytes).unwrap(), set);
+
+            let container = CustomHasherContainer {
+                ids: map.clone(),
+                names: set.clone(),
+                fixed: (0..10u32).map(|i| (i, u64::from(i) << 40)).collect(),
+            };
+            let bytes = fory.serialize(&container).unwrap();
+            let decoded: CustomHasherContainer = fory.deserialize(&bytes).unwrap();
+            assert_eq!(decoded, container);
+        }
+    }
+}
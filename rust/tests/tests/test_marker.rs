@@ -150,3 +150,41 @@ fn test_phantom_data_uses_none_type_id() {
 fn test_unit_type_uses_none_type_id() {
     assert_eq!(<() as Serializer>::fory_static_type_id(), TypeId::NONE);
 }
+
+/// Generic wrapper whose type parameter only appears in a marker field
+#[derive(Debug, PartialEq, ForyStruct)]
+struct Wrapper<T> {
+    val: i32,
+    _marker: PhantomData<T>,
+}
+
+#[derive(Debug, PartialEq, ForyStruct)]
+struct Unwrapped {
+    val: i32,
+}
+
+#[test]
+fn test_generic_wrapper_with_phantom_data() {
+    for compatible in [false, true] {
+        for xlang in [false, true] {
+            let mut fory = Fory::builder().xlang(xlang).compatible(compatible).build();
+            fory.register::<Wrapper<String>>(104).unwrap();
+            let value = Wrapper::<String> {
+                val: 7,
+                _marker: PhantomData,
+            };
+            let bytes = fory.serialize(&value).unwrap();
+            let result: Wrapper<String> = fory.deserialize(&bytes).unwrap();
+            assert_eq!(result, value);
+
+            // The marker takes no field slot, so the payload matches a struct without it.
+            let mut plain = Fory::builder().xlang(xlang).compatible(compatible).build();
+            plain.register::<Unwrapped>(104).unwrap();
+            assert_eq!(bytes, plain.serialize(&Unwrapped { val: 7 }).unwrap());
+            if compatible {
+                let meta = fory.peek_type_meta(&bytes).unwrap();
+                assert_eq!(meta.get_field_infos().len(), 1);
+            }
+        }
+    }
+}
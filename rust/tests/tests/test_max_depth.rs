@@ -0,0 +1,176 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use fory_core::{Error, Fory};
+use fory_derive::{ForyStruct, ForyUnion};
+
+#[derive(ForyStruct, Debug, PartialEq)]
+struct Node {
+    value: i32,
+    children: Vec<Node>,
+}
+
+#[derive(ForyUnion, Debug, PartialEq)]
+enum Expr {
+    #[fory(unknown)]
+    Unknown(fory_core::UnknownCase),
+    #[fory(id = 0, default)]
+    Lit(i32),
+    #[fory(id = 1)]
+    Neg(Box<Expr>),
+}
+
+#[derive(ForyStruct, Debug, PartialEq)]
+struct ListNode {
+    val: i32,
+    next: Option<Box<ListNode>>,
+}
+
+fn chain(depth: usize) -> Node {
+    let mut node = Node {
+        value: 0,
+        children: vec![],
+    };
+    for value in 1..depth as i32 {
+        node = Node {
+            value,
+            children: vec![node],
+        };
+    }
+    node
+}
+
+fn linked_list(len: usize) -> ListNode {
+    let mut node = ListNode { val: 0, next: None };
+    for val in 1..len as i32 {
+        node = ListNode {
+            val,
+            next: Some(Box::new(node)),
+        };
+    }
+    node
+}
+
+fn negations(depth: usize) -> Expr {
+    let mut expr = Expr::Lit(1);
+    for _ in 1..depth {
+        expr = Expr::Neg(Box::new(expr));
+    }
+    expr
+}
+
+#[test]
+fn test_max_depth_default() {
+    assert_eq!(Fory::builder().build().get_max_depth(), 64);
+    assert_eq!(Fory::builder().max_depth(8).build().get_max_depth(), 8);
+}
+
+#[test]
+fn test_recursive_struct_depth_limit() {
+    if fory_core::error::should_panic_on_error() {
+        return;
+    }
+    for compatible in [false, true] {
+        for xlang in [false, true] {
+            let mut fory = Fory::builder()
+                .compatible(compatible)
+                .xlang(xlang)
+                .max_depth(16)
+                .build();
+            fory.register::<Node>(100).unwrap();
+
+            let shallow = chain(16);
+            let bytes = fory.serialize(&shallow).unwrap();
+            assert_eq!(shallow, fory.deserialize::<Node>(&bytes).unwrap());
+
+            let bytes = fory.serialize(&chain(17)).unwrap();
+            let err = fory.deserialize::<Node>(&bytes).unwrap_err();
+            assert!(matches!(err, Error::DepthExceed(_)), "{err}");
+
+            // The counter is reset between calls.
+            let bytes = fory.serialize(&shallow).unwrap();
+            assert_eq!(shallow, fory.deserialize::<Node>(&bytes).unwrap());
+        }
+    }
+}
+
+#[test]
+fn test_deep_payload_errors_instead_of_overflowing() {
+    if fory_core::error::should_panic_on_error() {
+        return;
+    }
+    // Serialize on a large stack; reading it back with the default limit must fail cleanly.
+    let bytes = std::thread::Builder::new()
+        .stack_size(256 * 1024 * 1024)
+        .spawn(|| {
+            let mut fory = Fory::builder().xlang(false).max_depth(u32::MAX).build();
+            fory.register::<Node>(100).unwrap();
+            let value = chain(20_000);
+            let bytes = fory.serialize(&value).unwrap();
+            // Dropping a deep chain recurses too.
+            std::mem::forget(value);
+            bytes
+        })
+        .unwrap()
+        .join()
+        .unwrap();
+
+    let mut fory = Fory::builder().xlang(false).build();
+    fory.register::<Node>(100).unwrap();
+    let err = fory.deserialize::<Node>(&bytes).unwrap_err();
+    assert!(matches!(err, Error::DepthExceed(_)), "{err}");
+}
+
+#[test]
+fn test_recursive_enum_depth_limit() {
+    if fory_core::error::should_panic_on_error() {
+        return;
+    }
+    let mut fory = Fory::builder().xlang(false).max_depth(16).build();
+    fory.register_union::<Expr>(101).unwrap();
+
+    let shallow = negations(16);
+    let bytes = fory.serialize(&shallow).unwrap();
+    assert_eq!(shallow, fory.deserialize::<Expr>(&bytes).unwrap());
+
+    let bytes = fory.serialize(&negations(17)).unwrap();
+    let err = fory.deserialize::<Expr>(&bytes).unwrap_err();
+    assert!(matches!(err, Error::DepthExceed(_)), "{err}");
+}
+
+#[test]
+fn test_linked_list_depth_limit() {
+    if fory_core::error::should_panic_on_error() {
+        return;
+    }
+    for compatible in [false, true] {
+        let mut fory = Fory::builder()
+            .xlang(false)
+            .compatible(compatible)
+            .max_depth(16)
+            .build();
+        fory.register::<ListNode>(102).unwrap();
+
+        let shallow = linked_list(16);
+        let bytes = fory.serialize(&shallow).unwrap();
+        assert_eq!(shallow, fory.deserialize::<ListNode>(&bytes).unwrap());
+
+        let bytes = fory.serialize(&linked_list(17)).unwrap();
+        let err = fory.deserialize::<ListNode>(&bytes).unwrap_err();
+        assert!(matches!(err, Error::DepthExceed(_)), "{err}");
+    }
+}
@@ -35,3 +35,352 @@ fn test_meta_hash() {
     .unwrap();
     assert_ne!(meta.get_hash(), 0);
 }
+
+#[test]
+fn test_peek_type_meta() {
+    use fory_core::{Error, Fory};
+    use fory_derive::ForyStruct;
+    use std::collections::HashMap;
+
+    #[derive(ForyStruct, Debug, PartialEq)]
+    struct Order {
+        id: i64,
+        note: Option<String>,
+        lines: Vec<String>,
+        totals: HashMap<String, f64>,
+        paid: bool,
+    }
+
+    let order = Order {
+        id: 7,
+        note: None,
+        lines: vec!["a".to_string()],
+        totals: HashMap::new(),
+        paid: true,
+    };
+    for xlang in [false, true] {
+        let mut by_id = Fory::builder().xlang(xlang).compatible(true).build();
+        by_id.register::<Order>(100).unwrap();
+        let bytes = by_id.serialize(&order).unwrap();
+        let meta = by_id.peek_type_meta(&bytes).unwrap();
+        assert_eq!(meta.get_type_id(), TypeId::COMPATIBLE_STRUCT as u32);
+        assert_eq!(meta.get_user_type_id(), 100);
+
+        let mut fields: Vec<(&str, u32)> = meta
+            .get_field_infos()
+            .iter()
+            .map(|f| (f.field_name.as_str(), f.field_type.type_id))
+            .collect();
+        fields.sort();
+        assert_eq!(
+            fields,
+            [
+                ("id", TypeId::VARINT64 as u32),
+                ("lines", TypeId::LIST as u32),
+                ("note", TypeId::STRING as u32),
+                ("paid", TypeId::BOOL as u32),
+                ("totals", TypeId::MAP as u32),
+            ]
+        );
+        // Peeking leaves the payload readable as usual.
+        assert_eq!(order, by_id.deserialize::<Order>(&bytes).unwrap());
+
+        let mut by_name = Fory::builder().xlang(xlang).compatible(true).build();
+        by_name.register_by_name::<Order>("shop.Order").unwrap();
+        let bytes = by_name.serialize(&order).unwrap();
+        let meta = by_name.peek_type_meta(&bytes).unwrap();
+        assert_eq!(meta.get_type_id(), TypeId::NAMED_COMPATIBLE_STRUCT as u32);
+        assert_eq!(meta.get_type_name().original, "Order");
+        assert_eq!(meta.get_field_infos().len(), 5);
+
+        // Schema-consistent structs and non-struct roots carry no inline meta.
+        let mut strict = Fory::builder().xlang(xlang).compatible(false).build();
+        strict.register::<Order>(100).unwrap();
+        let err = strict
+            .peek_type_meta(&strict.serialize(&order).unwrap())
+            .unwrap_err();
+        assert!(matches!(err, Error::TypeError(_)), "{err}");
+        let err = by_id
+            .peek_type_meta(&by_id.serialize(&1i32).unwrap())
+            .unwrap_err();
+        assert!(matches!(err, Error::TypeError(_)), "{err}");
+    }
+}
+
+mod reordered_v1 {
+    use fory_derive::ForyStruct;
+
+    #[derive(ForyStruct, Debug, PartialEq)]
+    pub struct Event {
+        pub id: i64,
+        pub name: String,
+        pub tags: Vec<String>,
+        pub score: Option<f64>,
+    }
+}
+
+mod reordered_v2 {
+    use fory_derive::ForyStruct;
+
+    #[derive(ForyStruct, Debug, PartialEq)]
+    pub struct Event {
+        pub score: Option<f64>,
+        pub tags: Vec<String>,
+        pub id: i64,
+        pub name: String,
+    }
+}
+
+#[test]
+fn test_reordered_fields_share_hashes() {
+    use fory_core::Fory;
+
+    let v1 = reordered_v1::Event {
+        id: 1,
+        name: "a".to_string(),
+        tags: vec!["t".to_string()],
+        score: Some(0.5),
+    };
+    // The struct version hash sorts fields before hashing, so check_struct_version
+    // accepts a reordered definition.
+    let mut writer = Fory::builder()
+        .xlang(false)
+        .compatible(false)
+        .check_struct_version(true)
+        .build();
+    writer.register::<reordered_v1::Event>(100).unwrap();
+    let mut reader = Fory::builder()
+        .xlang(false)
+        .compatible(false)
+        .check_struct_version(true)
+        .build();
+    reader.register::<reordered_v2::Event>(100).unwrap();
+    assert_eq!(
+        writer.type_hash::<reordered_v1::Event>(),
+        reader.type_hash::<reordered_v2::Event>()
+    );
+    let v2: reordered_v2::Event = reader.deserialize(&writer.serialize(&v1).unwrap()).unwrap();
+    assert_eq!(
+        (v2.id, v2.name, v2.tags, v2.score),
+        (v1.id, v1.name, v1.tags, v1.score)
+    );
+
+    // The compatible-mode TypeMeta is built from sorted fields as well.
+    for xlang in [false, true] {
+        let mut first = Fory::builder().xlang(xlang).compatible(true).build();
+        first.register::<reordered_v1::Event>(100).unwrap();
+        let mut second = Fory::builder().xlang(xlang).compatible(true).build();
+        second.register::<reordered_v2::Event>(100).unwrap();
+        let first_bytes = first
+            .serialize(&reordered_v1::Event {
+                id: 2,
+                name: "b".to_string(),
+                tags: vec![],
+                score: None,
+            })
+            .unwrap();
+        let second_bytes = second
+            .serialize(&reordered_v2::Event {
+                score: None,
+                tags: vec![],
+                id: 2,
+                name: "b".to_string(),
+            })
+            .unwrap();
+        assert_eq!(
+            first.peek_type_meta(&first_bytes).unwrap().get_hash(),
+            second.peek_type_meta(&second_bytes).unwrap().get_hash()
+        );
+    }
+}
+
+#[test]
+fn test_type_meta_written_once_per_payload() {
+    use fory_core::Fory;
+    use fory_derive::ForyStruct;
+    use std::any::Any;
+
+    #[derive(ForyStruct, Debug, PartialEq)]
+    struct Reading {
+        sensor_identifier: i32,
+        measured_value: f64,
+    }
+
+    #[derive(ForyStruct, Debug, PartialEq)]
+    struct Annotation {
+        annotation_text: String,
+    }
+
+    fn mixed(count: usize) -> Vec<Box<dyn Any>> {
+        (0..count)
+            .map(|i| -> Box<dyn Any> {
+                if i % 2 == 0 {
+                    Box::new(Reading {
+                        sensor_identifier: i as i32,
+                        measured_value: 0.5,
+                    })
+                } else {
+                    Box::new(Annotation {
+                        annotation_text: "ok".to_string(),
+                    })
+                }
+            })
+            .collect()
+    }
+
+    // The schema overhead is the size difference between compatible and schema-consistent payloads.
+    let mut sizes = vec![];
+    for compatible in [false, true] {
+        let mut fory = Fory::builder().xlang(false).compatible(compatible).build();
+        fory.register::<Reading>(100).unwrap();
+        fory.register::<Annotation>(101).unwrap();
+        let homogeneous = |count: usize| {
+            (0..count)
+                .map(|i| Reading {
+                    sensor_identifier: i as i32,
+                    measured_value: 0.5,
+                })
+                .collect::<Vec<_>>()
+        };
+        sizes.push([
+            fory.serialize(&homogeneous(1)).unwrap().len(),
+            fory.serialize(&homogeneous(1000)).unwrap().len(),
+            fory.serialize(&mixed(2)).unwrap().len(),
+            fory.serialize(&mixed(1000)).unwrap().len(),
+        ]);
+
+        let bytes = fory.serialize(&mixed(1000)).unwrap();
+        let decoded: Vec<Box<dyn Any>> = fory.deserialize(&bytes).unwrap();
+        assert_eq!(decoded.len(), 1000);
+        assert_eq!(
+            decoded[998].downcast_ref::<Reading>(),
+            Some(&Reading {
+                sensor_identifier: 998,
+                measured_value: 0.5,
+            })
+        );
+        assert!(decoded[999].downcast_ref::<Annotation>().is_some());
+    }
+    let overhead = |i: usize| sizes[1][i] - sizes[0][i];
+    // Each type's meta is written once; later elements refer back to it by index.
+    assert!(overhead(0) > 0);
+    assert_eq!(overhead(1), overhead(0));
+    assert_eq!(overhead(3), overhead(2));
+}
+
+#[test]
+fn test_check_compatibility() {
+    use fory_core::Fory;
+    use fory_derive::ForyStruct;
+    use std::collections::HashMap;
+
+    // The two versions of the `simple` compatible-mode test.
+    #[derive(ForyStruct, Debug, Default)]
+    struct Animal1 {
+        f1: HashMap<i8, Vec<i8>>,
+        f2: String,
+        f3: Vec<i8>,
+        f5: String,
+        f6: Vec<i8>,
+        f7: i8,
+        last: i8,
+    }
+
+    #[derive(ForyStruct, Debug, Default)]
+    struct Animal2 {
+        f1: HashMap<i8, Vec<i8>>,
+        f3: Vec<i8>,
+        f4: String,
+        f5: i8,
+        f6: Vec<i8>,
+        f7: i16,
+        last: i8,
+    }
+
+    #[derive(ForyStruct, Debug, Default)]
+    struct Animal3 {
+        f1: HashMap<i8, Vec<i8>>,
+        f2: Option<String>,
+        f3: Vec<i8>,
+        f5: String,
+        f6: Vec<i8>,
+        f7: i64,
+        last: i8,
+    }
+
+    for xlang in [false, true] {
+        let mut fory1 = Fory::builder().xlang(xlang).compatible(true).build();
+        fory1.register::<Animal1>(999).unwrap();
+        let mut fory2 = Fory::builder().xlang(xlang).compatible(true).build();
+        fory2.register::<Animal2>(999).unwrap();
+        let mut fory3 = Fory::builder().xlang(xlang).compatible(true).build();
+        fory3.register::<Animal3>(999).unwrap();
+        let v1 = fory1
+            .peek_type_meta(&fory1.serialize(&Animal1::default()).unwrap())
+            .unwrap();
+        let v2 = fory2
+            .peek_type_meta(&fory2.serialize(&Animal2::default()).unwrap())
+            .unwrap();
+        let v3 = fory3
+            .peek_type_meta(&fory3.serialize(&Animal3::default()).unwrap())
+            .unwrap();
+
+        let report = Fory::check_compatibility(&v1, &v2);
+        let names = |fields: &[FieldInfo]| {
+            fields
+                .iter()
+                .map(|f| f.field_name.clone())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(names(&report.added), ["f4"]);
+        assert_eq!(names(&report.removed), ["f2"]);
+        let mut retyped: Vec<(&str, u32, u32, bool)> = report
+            .retyped
+            .iter()
+            .map(|f| {
+                (
+                    f.field_name.as_str(),
+                    f.old_type.type_id,
+                    f.new_type.type_id,
+                    f.breaking,
+                )
+            })
+            .collect();
+        retyped.sort();
+        assert_eq!(
+            retyped,
+            [
+                // Compatible mode parses the string, which fails for non-numeric text.
+                ("f5", TypeId::STRING as u32, TypeId::INT8 as u32, true),
+                ("f7", TypeId::INT8 as u32, TypeId::INT16 as u32, false),
+            ]
+        );
+        assert!(report.is_breaking());
+        let breaking: Vec<&str> = report
+            .breaking_fields()
+            .map(|f| f.field_name.as_str())
+            .collect();
+        assert_eq!(breaking, ["f5"]);
+
+        // Narrowing back is breaking in the other direction as well.
+        let report = Fory::check_compatibility(&v2, &v1);
+        assert_eq!(names(&report.added), ["f2"]);
+        assert_eq!(names(&report.removed), ["f4"]);
+        assert_eq!(report.breaking_fields().count(), 2);
+
+        // Widening an integer and making a field nullable keep every old value readable.
+        let report = Fory::check_compatibility(&v1, &v3);
+        assert!(report.added.is_empty() && report.removed.is_empty());
+        assert_eq!(report.retyped.len(), 2);
+        assert!(!report.is_breaking());
+        let report = Fory::check_compatibility(&v3, &v1);
+        let mut breaking: Vec<&str> = report
+            .breaking_fields()
+            .map(|f| f.field_name.as_str())
+            .collect();
+        breaking.sort();
+        assert_eq!(breaking, ["f2", "f7"]);
+
+        assert!(Fory::check_compatibility(&v1, &v1).is_unchanged());
+    }
+}
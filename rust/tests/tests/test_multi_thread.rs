@@ -162,3 +162,43 @@ fn test_multiple_threads_shared_fory() {
         });
     }
 }
+
+#[test]
+fn test_clone_config_multi_thread() {
+    #[derive(ForyStruct, Debug, PartialEq, Clone)]
+    struct Job {
+        id: i32,
+        name: String,
+    }
+
+    let mut fory = Fory::builder().xlang(false).compatible(true).build();
+    fory.register::<Job>(100).unwrap();
+
+    let handles: Vec<_> = (0..8)
+        .map(|i| {
+            let worker = fory.clone_config().unwrap();
+            thread::spawn(move || {
+                let job = Job {
+                    id: i,
+                    name: format!("job-{i}"),
+                };
+                let bytes = worker.serialize(&job).unwrap();
+                assert_eq!(worker.deserialize::<Job>(&bytes).unwrap(), job);
+                bytes
+            })
+        })
+        .collect();
+    for (i, handle) in handles.into_iter().enumerate() {
+        let job: Job = fory.deserialize(&handle.join().unwrap()).unwrap();
+        assert_eq!(job.id, i as i32);
+        assert_eq!(job.name, format!("job-{i}"));
+    }
+
+    if fory_core::error::should_panic_on_error() {
+        return;
+    }
+    // The snapshot ends the registration phase for both instances.
+    let mut worker = fory.clone_config().unwrap();
+    assert!(worker.register::<Job>(101).is_err());
+    assert!(fory.register::<Job>(101).is_err());
+}
@@ -0,0 +1,72 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use fory_core::fory::Fory;
+use fory_derive::ForyStruct;
+use std::collections::HashMap;
+
+fn values() -> [Option<Option<String>>; 3] {
+    [Some(Some("x".to_string())), Some(None), None]
+}
+
+#[test]
+fn test_nested_option_top_level() {
+    for compatible in [false, true] {
+        let fory = Fory::builder().xlang(false).compatible(compatible).build();
+        for value in values() {
+            let bin = fory.serialize(&value).unwrap();
+            let obj: Option<Option<String>> = fory.deserialize(&bin).unwrap();
+            assert_eq!(value, obj);
+        }
+        // `None` and `Some(None)` have distinct encodings.
+        assert_ne!(
+            fory.serialize(&Some(None::<String>)).unwrap(),
+            fory.serialize(&None::<Option<String>>).unwrap()
+        );
+    }
+}
+
+#[test]
+fn test_nested_option_fields() {
+    #[derive(ForyStruct, Debug, PartialEq)]
+    struct Patch {
+        name: Option<Option<String>>,
+        age: Option<Option<i32>>,
+        tags: Vec<Option<Option<String>>>,
+        attrs: HashMap<String, Option<Option<String>>>,
+    }
+
+    for compatible in [false, true] {
+        let mut fory = Fory::builder().xlang(false).compatible(compatible).build();
+        fory.register::<Patch>(100).unwrap();
+        for (name, age) in values().into_iter().zip([Some(None), None, Some(Some(30))]) {
+            let patch = Patch {
+                name,
+                age,
+                tags: values().to_vec(),
+                attrs: HashMap::from([
+                    ("set".to_string(), Some(Some("v".to_string()))),
+                    ("clear".to_string(), Some(None)),
+                    ("keep".to_string(), None),
+                ]),
+            };
+            let bin = fory.serialize(&patch).unwrap();
+            let obj: Patch = fory.deserialize(&bin).unwrap();
+            assert_eq!(patch, obj);
+        }
+    }
+}
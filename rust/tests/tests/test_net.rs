@@ -0,0 +1,113 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+mod test_helpers;
+
+use fory_core::fory::Fory;
+use fory_derive::ForyStruct;
+use std::collections::HashMap;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV6};
+use test_helpers::{test_arc_any, test_box_any, test_rc_any, test_roundtrip};
+
+const V4: Ipv4Addr = Ipv4Addr::new(192, 168, 1, 20);
+const V6: Ipv6Addr = Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0x1);
+
+#[test]
+fn test_ip_addr() {
+    let fory = Fory::builder().xlang(false).compatible(false).build();
+    test_roundtrip(&fory, V4);
+    test_roundtrip(&fory, V6);
+    test_roundtrip(&fory, Ipv6Addr::LOCALHOST);
+    for ip in [IpAddr::V4(V4), IpAddr::V6(V6)] {
+        test_roundtrip(&fory, ip);
+        test_box_any(&fory, ip);
+        test_rc_any(&fory, ip);
+        test_arc_any(&fory, ip);
+    }
+    test_roundtrip(&fory, Some(IpAddr::V6(V6)));
+    test_roundtrip(&fory, vec![IpAddr::V4(V4), IpAddr::V6(V6)]);
+
+    // The discriminator byte precedes the octets.
+    let v4_len = fory.serialize(&IpAddr::V4(V4)).unwrap().len();
+    let v6_len = fory.serialize(&IpAddr::V6(V6)).unwrap().len();
+    assert_eq!(v6_len - v4_len, 12);
+    assert_eq!(
+        fory.serialize(&V4).unwrap().len() + 1,
+        fory.serialize(&IpAddr::V4(V4)).unwrap().len()
+    );
+}
+
+#[test]
+fn test_socket_addr() {
+    let fory = Fory::builder().xlang(false).compatible(false).build();
+    let v4: SocketAddr = "10.0.0.1:8443".parse().unwrap();
+    let v6 = SocketAddr::V6(SocketAddrV6::new(V6, 65535, 7, 3));
+    for addr in [v4, v6] {
+        test_roundtrip(&fory, addr);
+        test_box_any(&fory, addr);
+    }
+    let obj: SocketAddr = fory.deserialize(&fory.serialize(&v6).unwrap()).unwrap();
+    let SocketAddr::V6(obj) = obj else {
+        panic!("expected a v6 socket address");
+    };
+    assert_eq!((obj.port(), obj.flowinfo(), obj.scope_id()), (65535, 7, 3));
+}
+
+#[test]
+fn test_net_struct() {
+    #[derive(ForyStruct, Debug, PartialEq)]
+    struct Listener {
+        bind: SocketAddr,
+        advertise: Option<IpAddr>,
+        gateway: Ipv4Addr,
+        dns: Vec<Ipv6Addr>,
+        routes: HashMap<IpAddr, u16>,
+    }
+
+    for compatible in [false, true] {
+        let mut fory = Fory::builder().xlang(false).compatible(compatible).build();
+        fory.register::<Listener>(100).unwrap();
+        let listener = Listener {
+            bind: "[::1]:9000".parse().unwrap(),
+            advertise: Some(IpAddr::V4(V4)),
+            gateway: Ipv4Addr::new(10, 0, 0, 1),
+            dns: vec![V6, Ipv6Addr::UNSPECIFIED],
+            routes: HashMap::from([(IpAddr::V4(V4), 1), (IpAddr::V6(V6), 2)]),
+        };
+        test_roundtrip(&fory, listener);
+    }
+}
+
+#[test]
+fn test_ip_addr_rejects_invalid_flag() {
+    let fory = Fory::builder().xlang(false).compatible(false).build();
+    let mut bytes = fory.serialize(&IpAddr::V4(V4)).unwrap();
+    let flag = bytes.len() - 5;
+    bytes[flag] = 5;
+    let err = fory.deserialize::<IpAddr>(&bytes).unwrap_err();
+    assert!(
+        err.to_string().contains("invalid IP address version flag"),
+        "unexpected error: {err}"
+    );
+}
+
+#[test]
+fn test_net_when_xlang() {
+    let fory = Fory::builder().xlang(true).compatible(false).build();
+    assert!(fory.serialize(&V4).is_err());
+    assert!(fory.serialize(&IpAddr::V6(V6)).is_err());
+}
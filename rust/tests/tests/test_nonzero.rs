@@ -0,0 +1,119 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+mod test_helpers;
+
+use fory_core::{Error, Fory};
+use fory_derive::ForyStruct;
+use std::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
+};
+use test_helpers::test_roundtrip;
+
+#[derive(ForyStruct, Debug, PartialEq)]
+struct Limits {
+    workers: NonZeroU32,
+    buffer: NonZeroU64,
+    offset: NonZeroI64,
+    retries: Option<NonZeroU8>,
+    ports: Vec<NonZeroU16>,
+}
+
+#[derive(ForyStruct, Debug, PartialEq)]
+struct PlainLimits {
+    workers: u32,
+}
+
+#[derive(ForyStruct, Debug, PartialEq)]
+struct StrictLimits {
+    workers: NonZeroU32,
+}
+
+#[test]
+fn test_nonzero_roundtrip() {
+    let fory = Fory::builder().xlang(false).build();
+    test_roundtrip(&fory, NonZeroI8::new(-8).unwrap());
+    test_roundtrip(&fory, NonZeroI16::new(i16::MIN).unwrap());
+    test_roundtrip(&fory, NonZeroI32::new(-32).unwrap());
+    test_roundtrip(&fory, NonZeroI64::new(i64::MAX).unwrap());
+    test_roundtrip(&fory, NonZeroI128::new(-128).unwrap());
+    test_roundtrip(&fory, NonZeroIsize::new(-1).unwrap());
+    test_roundtrip(&fory, NonZeroU8::new(u8::MAX).unwrap());
+    test_roundtrip(&fory, NonZeroU16::new(16).unwrap());
+    test_roundtrip(&fory, NonZeroU32::new(32).unwrap());
+    test_roundtrip(&fory, NonZeroU64::new(u64::MAX).unwrap());
+    test_roundtrip(&fory, NonZeroU128::new(128).unwrap());
+    test_roundtrip(&fory, NonZeroUsize::new(1).unwrap());
+    test_roundtrip(
+        &fory,
+        vec![NonZeroU32::new(1).unwrap(), NonZeroU32::new(2).unwrap()],
+    );
+
+    // A NonZero value is read back as its plain integer.
+    let bytes = fory.serialize(&NonZeroU32::new(7).unwrap()).unwrap();
+    assert_eq!(fory.deserialize::<u32>(&bytes).unwrap(), 7);
+}
+
+#[test]
+fn test_nonzero_struct_fields() {
+    for compatible in [false, true] {
+        for xlang in [false, true] {
+            let mut fory = Fory::builder().compatible(compatible).xlang(xlang).build();
+            fory.register::<Limits>(100).unwrap();
+            test_roundtrip(
+                &fory,
+                Limits {
+                    workers: NonZeroU32::new(8).unwrap(),
+                    buffer: NonZeroU64::new(4096).unwrap(),
+                    offset: NonZeroI64::new(-3).unwrap(),
+                    retries: None,
+                    ports: vec![NonZeroU16::new(80).unwrap(), NonZeroU16::new(443).unwrap()],
+                },
+            );
+        }
+    }
+}
+
+#[test]
+fn test_zero_on_wire_is_rejected() {
+    if fory_core::error::should_panic_on_error() {
+        return;
+    }
+    let fory = Fory::builder().xlang(false).build();
+    let bytes = fory.serialize(&0u32).unwrap();
+    let err = fory.deserialize::<NonZeroU32>(&bytes).unwrap_err();
+    assert!(matches!(err, Error::InvalidData(_)), "{err}");
+
+    let bytes = fory.serialize(&vec![1i64, 0]).unwrap();
+    assert!(fory.deserialize::<Vec<NonZeroI64>>(&bytes).is_err());
+
+    // Compatible readers accept plain integer fields but still reject zero.
+    let mut writer = Fory::builder().compatible(true).xlang(false).build();
+    writer.register::<PlainLimits>(100).unwrap();
+    let mut reader = Fory::builder().compatible(true).xlang(false).build();
+    reader.register::<StrictLimits>(100).unwrap();
+    let bytes = writer.serialize(&PlainLimits { workers: 4 }).unwrap();
+    assert_eq!(
+        reader.deserialize::<StrictLimits>(&bytes).unwrap(),
+        StrictLimits {
+            workers: NonZeroU32::new(4).unwrap()
+        }
+    );
+    let bytes = writer.serialize(&PlainLimits { workers: 0 }).unwrap();
+    assert!(reader.deserialize::<StrictLimits>(&bytes).is_err());
+}
@@ -0,0 +1,119 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+mod test_helpers;
+
+use fory_core::fory::Fory;
+use fory_derive::ForyStruct;
+use std::ffi::OsString;
+use test_helpers::{test_arc_any, test_box_any, test_rc_any, test_roundtrip};
+
+#[test]
+fn test_os_string() {
+    let fory = Fory::builder().xlang(false).compatible(false).build();
+    test_roundtrip(&fory, OsString::new());
+    test_roundtrip(&fory, OsString::from("backup-2024.tar"));
+    test_roundtrip(&fory, Some(OsString::from("é")));
+    test_roundtrip(&fory, vec![OsString::from("a"), OsString::from("b")]);
+    test_box_any(&fory, OsString::from("any"));
+    test_rc_any(&fory, OsString::from("any"));
+    test_arc_any(&fory, OsString::from("any"));
+}
+
+#[test]
+fn test_os_string_struct() {
+    #[derive(ForyStruct, Debug, PartialEq)]
+    struct Command {
+        program: OsString,
+        args: Vec<OsString>,
+        cwd: Option<OsString>,
+    }
+
+    for compatible in [false, true] {
+        let mut fory = Fory::builder().xlang(false).compatible(compatible).build();
+        fory.register::<Command>(100).unwrap();
+        let command = Command {
+            program: OsString::from("ls"),
+            args: vec![OsString::from("-la"), platform_non_utf8()],
+            cwd: Some(OsString::from("/tmp")),
+        };
+        test_roundtrip(&fory, command);
+    }
+}
+
+#[test]
+fn test_os_string_not_utf8() {
+    let fory = Fory::builder().xlang(false).compatible(false).build();
+    let value = platform_non_utf8();
+    assert!(value.to_str().is_none());
+    let bin = fory.serialize(&value).unwrap();
+    assert_eq!(value, fory.deserialize::<OsString>(&bin).unwrap());
+}
+
+#[test]
+fn test_os_string_xlang_rejected() {
+    if fory_core::error::should_panic_on_error() {
+        return;
+    }
+    let fory = Fory::builder().xlang(true).build();
+    assert!(fory.serialize(&OsString::from("x")).is_err());
+}
+
+/// Unix readers accept Windows payloads only if the UTF-16 units are valid.
+#[cfg(unix)]
+#[test]
+fn test_os_string_reads_wide_units() {
+    if fory_core::error::should_panic_on_error() {
+        return;
+    }
+    let fory = Fory::builder().xlang(false).compatible(false).build();
+    let wide_payload = |units: Vec<u16>| {
+        // Swap the flag and bytes of a one-byte value for the flag and `Vec<u16>` data.
+        let mut bin = fory.serialize(&OsString::from("x")).unwrap();
+        bin.truncate(bin.len() - 3);
+        bin.push(1);
+        let units_len = units.len() * 2 + 1;
+        let units_bin = fory.serialize(&units).unwrap();
+        bin.extend_from_slice(&units_bin[units_bin.len() - units_len..]);
+        bin
+    };
+
+    let bin = wide_payload("hé".encode_utf16().collect());
+    assert_eq!(
+        OsString::from("hé"),
+        fory.deserialize::<OsString>(&bin).unwrap()
+    );
+
+    let bin = wide_payload(vec![0x72, 0xD800]);
+    let err = fory.deserialize::<OsString>(&bin).unwrap_err();
+    assert!(
+        matches!(err, fory_core::Error::InvalidData(_)),
+        "unexpected error: {err}"
+    );
+}
+
+#[cfg(unix)]
+fn platform_non_utf8() -> OsString {
+    use std::os::unix::ffi::OsStringExt;
+    OsString::from_vec(b"report\xff\xfe.txt".to_vec())
+}
+
+#[cfg(windows)]
+fn platform_non_utf8() -> OsString {
+    use std::os::windows::ffi::OsStringExt;
+    // A lone surrogate has no UTF-8 encoding.
+    OsString::from_wide(&[0x72, 0xD800, 0x74])
+}
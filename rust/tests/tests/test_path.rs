@@ -0,0 +1,112 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+use fory_core::fory::Fory;
+use fory_core::Error;
+use fory_derive::ForyStruct;
+use std::borrow::Cow;
+use std::path::{Path, PathBuf};
+
+#[test]
+fn test_path_roundtrip() {
+    let fory = Fory::builder().xlang(false).build();
+    for path in ["src/lib.rs", "/var/log/fory.log"] {
+        let path_buf = PathBuf::from(path);
+        let bin = fory.serialize(&path_buf).unwrap();
+        assert_eq!(path_buf, fory.deserialize::<PathBuf>(&bin).unwrap());
+    }
+}
+
+#[test]
+fn test_path_wire_compatible() {
+    let fory = Fory::builder().xlang(false).build();
+
+    let path = PathBuf::from("/etc/hosts");
+    let bin = fory.serialize(&path).unwrap();
+    assert_eq!(bin, fory.serialize(&"/etc/hosts".to_string()).unwrap());
+    assert_eq!("/etc/hosts", fory.deserialize::<String>(&bin).unwrap());
+
+    let borrowed: Cow<'static, Path> = Cow::Borrowed(Path::new("/etc/hosts"));
+    assert_eq!(bin, fory.serialize(&borrowed).unwrap());
+    let obj: Cow<'static, Path> = fory.deserialize(&bin).unwrap();
+    assert!(matches!(obj, Cow::Owned(ref p) if p == &path));
+}
+
+#[test]
+fn test_path_fields() {
+    #[derive(ForyStruct, Debug, PartialEq)]
+    struct FileEntry {
+        path: PathBuf,
+        parent: Option<PathBuf>,
+        size: u64,
+    }
+
+    #[derive(ForyStruct, Debug, PartialEq)]
+    struct FileEntryNames {
+        path: String,
+        parent: Option<String>,
+        size: u64,
+    }
+
+    for compatible in [false, true] {
+        let mut fory = Fory::builder().xlang(false).compatible(compatible).build();
+        fory.register::<FileEntry>(100).unwrap();
+        let entry = FileEntry {
+            path: PathBuf::from("docs/guide.md"),
+            parent: Some(PathBuf::from("docs")),
+            size: 42,
+        };
+        let bin = fory.serialize(&entry).unwrap();
+        assert_eq!(entry, fory.deserialize::<FileEntry>(&bin).unwrap());
+
+        let mut reader = Fory::builder().xlang(false).compatible(compatible).build();
+        reader.register::<FileEntryNames>(100).unwrap();
+        let names: FileEntryNames = reader.deserialize(&bin).unwrap();
+        assert_eq!(names.path, "docs/guide.md");
+        assert_eq!(names.parent.as_deref(), Some("docs"));
+    }
+}
+
+#[cfg(unix)]
+#[test]
+fn test_non_utf8_path_errors() {
+    use std::ffi::OsStr;
+    use std::os::unix::ffi::OsStrExt;
+
+    if fory_core::error::should_panic_on_error() {
+        return;
+    }
+    let fory = Fory::builder().xlang(false).build();
+    let path = PathBuf::from(OsStr::from_bytes(b"bad\xffname"));
+    let err = fory.serialize(&path).unwrap_err();
+    assert!(matches!(err, Error::InvalidPath(_)), "{err}");
+}
+
+#[cfg(windows)]
+#[test]
+fn test_non_utf8_path_errors() {
+    use std::ffi::OsString;
+    use std::os::windows::ffi::OsStringExt;
+
+    if fory_core::error::should_panic_on_error() {
+        return;
+    }
+    let fory = Fory::builder().xlang(false).build();
+    // A lone surrogate has no UTF-8 encoding.
+    let path = PathBuf::from(OsString::from_wide(&[0x62, 0xD800, 0x64]));
+    let err = fory.serialize(&path).unwrap_err();
+    assert!(matches!(err, Error::InvalidPath(_)), "{err}");
+}
@@ -0,0 +1,190 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use fory_core::{Error, Fory};
+use fory_derive::{ForyStruct, ForyUnion};
+use std::collections::HashMap;
+
+#[derive(ForyStruct, Debug, Default, PartialEq, Clone)]
+struct Address {
+    street: String,
+    zip: i32,
+}
+
+#[derive(ForyStruct, Debug, Default, PartialEq, Clone)]
+struct Customer {
+    id: i64,
+    name: String,
+    email: Option<String>,
+    age: i32,
+    score: f64,
+    active: bool,
+    tags: Vec<String>,
+    history: Vec<i64>,
+    attributes: HashMap<String, String>,
+    address: Address,
+    previous: Option<Address>,
+    notes: String,
+}
+
+fn customer() -> Customer {
+    Customer {
+        id: 42,
+        name: "Ada".to_string(),
+        email: Some("ada@example.com".to_string()),
+        age: 36,
+        score: 98.5,
+        active: true,
+        tags: vec!["gold".to_string(), "early".to_string()],
+        history: (0..100).collect(),
+        attributes: HashMap::from([("tier".to_string(), "1".to_string())]),
+        address: Address {
+            street: "Main St".to_string(),
+            zip: 12345,
+        },
+        previous: Some(Address {
+            street: "Old Rd".to_string(),
+            zip: 54321,
+        }),
+        notes: "x".repeat(200),
+    }
+}
+
+fn fory() -> Fory {
+    let mut fory = Fory::builder().xlang(false).compatible(true).build();
+    fory.register::<Address>(100).unwrap();
+    fory.register::<Customer>(101).unwrap();
+    fory
+}
+
+#[test]
+fn test_projection_reads_only_requested_fields() {
+    let fory = fory();
+    let bytes = fory.serialize(&customer()).unwrap();
+
+    let projected: Customer = fory
+        .deserialize_projection(&bytes, &["name", "address"])
+        .unwrap();
+    assert_eq!(
+        projected,
+        Customer {
+            name: "Ada".to_string(),
+            address: customer().address,
+            ..Customer::default()
+        }
+    );
+
+    // The same instance still reads full payloads afterwards.
+    assert_eq!(fory.deserialize::<Customer>(&bytes).unwrap(), customer());
+    let projected: Customer = fory.deserialize_projection(&bytes, &[]).unwrap();
+    assert_eq!(projected, Customer::default());
+}
+
+/// A reader-side view of `Customer` with a field the writer doesn't have.
+#[derive(ForyStruct, Debug, Default, PartialEq)]
+struct Contact {
+    id: i64,
+    name: String,
+    email: Option<String>,
+    phone: String,
+}
+
+#[test]
+fn test_projection_with_evolved_schema() {
+    let writer = fory();
+    let bytes = writer.serialize(&customer()).unwrap();
+
+    let mut reader = Fory::builder().xlang(false).compatible(true).build();
+    reader.register::<Address>(100).unwrap();
+    reader.register::<Contact>(101).unwrap();
+    let projected: Contact = reader
+        .deserialize_projection(&bytes, &["email", "phone"])
+        .unwrap();
+    assert_eq!(
+        projected,
+        Contact {
+            email: Some("ada@example.com".to_string()),
+            ..Contact::default()
+        }
+    );
+}
+
+#[derive(ForyStruct, Debug, Default, PartialEq)]
+struct Renamed {
+    id: i64,
+    #[fory(rename = "alias")]
+    f: String,
+    #[fory(rename = "displayName")]
+    display_name: String,
+}
+
+#[test]
+fn test_projection_by_rust_field_name() {
+    let mut fory = Fory::builder().xlang(false).compatible(true).build();
+    fory.register::<Renamed>(103).unwrap();
+    let value = Renamed {
+        id: 1,
+        f: "renamed".to_string(),
+        display_name: "Ada".to_string(),
+    };
+    let bytes = fory.serialize(&value).unwrap();
+
+    // Fields are requested by their Rust names, not by the names in the type metadata.
+    let projected: Renamed = fory
+        .deserialize_projection(&bytes, &["f", "display_name"])
+        .unwrap();
+    assert_eq!(projected, Renamed { id: 0, ..value });
+}
+
+#[test]
+fn test_projection_errors() {
+    if fory_core::error::should_panic_on_error() {
+        return;
+    }
+    let fory = fory();
+    let bytes = fory.serialize(&customer()).unwrap();
+    let err = fory
+        .deserialize_projection::<Customer>(&bytes, &["id", "phone"])
+        .unwrap_err();
+    assert!(matches!(err, Error::TypeError(_)), "{err}");
+
+    #[derive(ForyUnion, Debug, PartialEq)]
+    enum Shape {
+        #[fory(unknown)]
+        Unknown(fory_core::UnknownCase),
+        #[fory(id = 0, default)]
+        Circle(f64),
+        #[fory(id = 1)]
+        Square(f64),
+    }
+    let mut shapes = Fory::builder().xlang(false).compatible(true).build();
+    shapes.register_union::<Shape>(102).unwrap();
+    let bytes = shapes.serialize(&Shape::Square(2.0)).unwrap();
+    let err = shapes
+        .deserialize_projection::<Shape>(&bytes, &["radius"])
+        .unwrap_err();
+    assert!(matches!(err, Error::TypeError(_)), "{err}");
+
+    let mut schema_consistent = Fory::builder().xlang(false).compatible(false).build();
+    schema_consistent.register::<Address>(100).unwrap();
+    schema_consistent.register::<Customer>(101).unwrap();
+    let bytes = schema_consistent.serialize(&customer()).unwrap();
+    let err = schema_consistent
+        .deserialize_projection::<Customer>(&bytes, &["id"])
+        .unwrap_err();
+    assert!(matches!(err, Error::NotAllowed(_)), "{err}");
+}
@@ -0,0 +1,108 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+mod test_helpers;
+
+use fory_core::{Error, Fory};
+use fory_derive::ForyStruct;
+use std::ops::{Range, RangeInclusive};
+use test_helpers::test_roundtrip;
+
+#[derive(ForyStruct, Debug, PartialEq)]
+struct Selection {
+    id: i32,
+    span: Range<usize>,
+    pages: RangeInclusive<u32>,
+    spans: Vec<Range<i32>>,
+    previous: Option<Range<i64>>,
+}
+
+#[derive(ForyStruct, Debug, PartialEq)]
+struct SelectionV2 {
+    id: i32,
+    span: Range<usize>,
+}
+
+fn selection() -> Selection {
+    Selection {
+        id: 7,
+        span: 3..42,
+        pages: 1..=12,
+        spans: vec![0..4, -3..-1, 9..9],
+        previous: Some(-5..5),
+    }
+}
+
+#[test]
+fn test_range_roundtrip() {
+    let fory = Fory::builder().xlang(false).build();
+    test_roundtrip::<Range<i32>>(&fory, 0..10);
+    test_roundtrip::<Range<usize>>(&fory, 100..usize::MAX);
+    test_roundtrip::<RangeInclusive<i32>>(&fory, 5..=5);
+    test_roundtrip::<RangeInclusive<u8>>(&fory, 0..=u8::MAX);
+    test_roundtrip::<Range<String>>(&fory, "a".to_string().."m".to_string());
+    test_roundtrip::<Vec<Range<i32>>>(&fory, vec![0..10, -4..2, 7..7]);
+}
+
+#[test]
+fn test_range_inclusive_exhausted() {
+    let fory = Fory::builder().xlang(false).build();
+    let mut range = 5..=5;
+    assert_eq!(range.next(), Some(5));
+    assert!(range.is_empty());
+    let bytes = fory.serialize(&range).unwrap();
+    // Only the bounds are written; the decoded range is built fresh from them.
+    let decoded = fory.deserialize::<RangeInclusive<i32>>(&bytes).unwrap();
+    assert_eq!(decoded, RangeInclusive::new(*range.start(), *range.end()));
+}
+
+#[test]
+fn test_range_struct_fields() {
+    for compatible in [false, true] {
+        let mut fory = Fory::builder().compatible(compatible).xlang(false).build();
+        fory.register::<Selection>(100).unwrap();
+        test_roundtrip(&fory, selection());
+    }
+}
+
+#[test]
+fn test_range_schema_evolution() {
+    let mut writer = Fory::builder().compatible(true).xlang(false).build();
+    writer.register::<Selection>(100).unwrap();
+    let mut reader = Fory::builder().compatible(true).xlang(false).build();
+    reader.register::<SelectionV2>(100).unwrap();
+
+    let value = selection();
+    let bytes = writer.serialize(&value).unwrap();
+    assert_eq!(
+        reader.deserialize::<SelectionV2>(&bytes).unwrap(),
+        SelectionV2 {
+            id: value.id,
+            span: value.span,
+        }
+    );
+}
+
+#[test]
+fn test_range_rejected_in_xlang() {
+    if fory_core::error::should_panic_on_error() {
+        return;
+    }
+    let fory = Fory::builder().xlang(true).build();
+    let err = fory.serialize(&(0..10)).unwrap_err();
+    assert!(matches!(err, Error::NotAllowed(_)), "{err}");
+}
@@ -0,0 +1,146 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+mod test_helpers;
+
+use fory_core::{Error, Fory};
+use fory_derive::ForyStruct;
+use std::collections::HashMap;
+use std::rc::Rc;
+use test_helpers::test_roundtrip;
+
+#[derive(ForyStruct, Debug, PartialEq)]
+struct Failure {
+    code: i32,
+    reason: String,
+}
+
+#[derive(ForyStruct, Debug, PartialEq)]
+struct Outcome {
+    id: i32,
+    status: Result<i32, String>,
+    samples: Result<Vec<i32>, String>,
+    detail: Result<String, Failure>,
+    previous: Option<Result<u8, i64>>,
+    results: Vec<Result<i32, String>>,
+    by_key: HashMap<String, Result<bool, String>>,
+}
+
+#[derive(ForyStruct, Debug, PartialEq)]
+struct OutcomeV2 {
+    id: i32,
+    status: Result<i32, String>,
+}
+
+#[test]
+fn test_result_roundtrip() {
+    let fory = Fory::builder().xlang(false).build();
+    test_roundtrip::<Result<i32, String>>(&fory, Ok(7));
+    test_roundtrip::<Result<i32, String>>(&fory, Err("boom".to_string()));
+    test_roundtrip::<Result<Vec<i32>, String>>(&fory, Ok(vec![1, 2, 3]));
+    test_roundtrip::<Result<Vec<i32>, String>>(&fory, Err(String::new()));
+    test_roundtrip::<Result<Option<String>, ()>>(&fory, Ok(None));
+    test_roundtrip::<Result<(), u64>>(&fory, Ok(()));
+    test_roundtrip::<Result<Result<i8, i16>, String>>(&fory, Ok(Err(-3)));
+    test_roundtrip::<Vec<Result<i32, String>>>(&fory, vec![Ok(1), Err("e".to_string()), Ok(3)]);
+}
+
+#[test]
+fn test_result_shared_refs() {
+    let fory = Fory::builder().xlang(false).track_ref(true).build();
+    let shared = Rc::new("shared".to_string());
+    let value: Vec<Result<Rc<String>, i32>> = vec![Ok(shared.clone()), Err(1), Ok(shared)];
+    let bytes = fory.serialize(&value).unwrap();
+    let decoded: Vec<Result<Rc<String>, i32>> = fory.deserialize(&bytes).unwrap();
+    assert_eq!(decoded, value);
+    match (&decoded[0], &decoded[2]) {
+        (Ok(first), Ok(second)) => assert!(Rc::ptr_eq(first, second)),
+        _ => unreachable!(),
+    }
+}
+
+fn outcome(ok: bool) -> Outcome {
+    Outcome {
+        id: 42,
+        status: if ok {
+            Ok(200)
+        } else {
+            Err("timeout".to_string())
+        },
+        samples: if ok {
+            Ok(vec![4, 5, 6])
+        } else {
+            Err("no samples".to_string())
+        },
+        detail: if ok {
+            Ok("done".to_string())
+        } else {
+            Err(Failure {
+                code: 503,
+                reason: "unavailable".to_string(),
+            })
+        },
+        previous: if ok { Some(Err(-1)) } else { None },
+        results: vec![Ok(1), Err("two".to_string())],
+        by_key: HashMap::from([
+            ("a".to_string(), Ok(true)),
+            ("b".to_string(), Err("bad".to_string())),
+        ]),
+    }
+}
+
+#[test]
+fn test_result_struct_fields() {
+    for compatible in [false, true] {
+        let mut fory = Fory::builder().compatible(compatible).xlang(false).build();
+        fory.register::<Failure>(100).unwrap();
+        fory.register::<Outcome>(101).unwrap();
+        test_roundtrip(&fory, outcome(true));
+        test_roundtrip(&fory, outcome(false));
+    }
+}
+
+#[test]
+fn test_result_schema_evolution() {
+    let mut writer = Fory::builder().compatible(true).xlang(false).build();
+    writer.register::<Failure>(100).unwrap();
+    writer.register::<Outcome>(101).unwrap();
+    let mut reader = Fory::builder().compatible(true).xlang(false).build();
+    reader.register::<OutcomeV2>(101).unwrap();
+
+    for ok in [true, false] {
+        let value = outcome(ok);
+        let bytes = writer.serialize(&value).unwrap();
+        assert_eq!(
+            reader.deserialize::<OutcomeV2>(&bytes).unwrap(),
+            OutcomeV2 {
+                id: value.id,
+                status: value.status,
+            }
+        );
+    }
+}
+
+#[test]
+fn test_result_rejected_in_xlang() {
+    if fory_core::error::should_panic_on_error() {
+        return;
+    }
+    let fory = Fory::builder().xlang(true).build();
+    let err = fory.serialize(&Ok::<i32, String>(1)).unwrap_err();
+    assert!(matches!(err, Error::NotAllowed(_)), "{err}");
+}
@@ -0,0 +1,148 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use fory_core::{Error, Fory};
+use fory_derive::ForyStruct;
+use num_bigint::BigInt;
+use rust_decimal::Decimal;
+
+fn values() -> Vec<Decimal> {
+    vec![
+        Decimal::ZERO,
+        Decimal::new(1, 7),
+        Decimal::new(-123_456, 2),
+        Decimal::new(1_000, 2),
+        Decimal::MAX,
+        Decimal::MIN,
+        Decimal::from_i128_with_scale(1, 28),
+    ]
+}
+
+#[test]
+fn test_rust_decimal_round_trip() {
+    for xlang in [false, true] {
+        let fory = Fory::builder().xlang(xlang).compatible(false).build();
+        for value in values() {
+            let bytes = fory.serialize(&value).unwrap();
+            let decoded: Decimal = fory.deserialize(&bytes).unwrap();
+            // Exact: same digits and the same scale, not only numeric equality.
+            assert_eq!(value.mantissa(), decoded.mantissa());
+            assert_eq!(value.scale(), decoded.scale());
+        }
+    }
+}
+
+#[test]
+fn test_rust_decimal_wire_compatible() {
+    let fory = Fory::builder().xlang(true).compatible(false).build();
+    for value in values() {
+        let fory_value =
+            fory_core::Decimal::new(BigInt::from(value.mantissa()), value.scale() as i32);
+        let bytes = fory.serialize(&value).unwrap();
+        assert_eq!(bytes, fory.serialize(&fory_value).unwrap());
+        assert_eq!(fory_value, fory.deserialize(&bytes).unwrap());
+    }
+
+    // Scales outside 0..=28 are normalized when the value stays exact.
+    let bytes = fory
+        .serialize(&fory_core::Decimal::new(BigInt::from(-15), -3))
+        .unwrap();
+    assert_eq!(
+        fory.deserialize::<Decimal>(&bytes).unwrap(),
+        Decimal::new(-15_000, 0)
+    );
+    let bytes = fory
+        .serialize(&fory_core::Decimal::new(BigInt::from(700), 30))
+        .unwrap();
+    assert_eq!(
+        fory.deserialize::<Decimal>(&bytes).unwrap(),
+        Decimal::from_i128_with_scale(7, 28)
+    );
+}
+
+#[test]
+fn test_rust_decimal_rejects_out_of_range() {
+    let fory = Fory::builder().xlang(true).compatible(false).build();
+    for value in [
+        fory_core::Decimal::new(BigInt::from(1), 29),
+        fory_core::Decimal::new(BigInt::from(1) << 96, 0),
+        fory_core::Decimal::new(BigInt::from(1), -29),
+    ] {
+        let bytes = fory.serialize(&value).unwrap();
+        let err = fory.deserialize::<Decimal>(&bytes).unwrap_err();
+        assert!(matches!(err, Error::InvalidData(_)), "{err}");
+    }
+}
+
+#[test]
+fn test_rust_decimal_rejects_min_scale() {
+    // Header, not-null flag, DECIMAL type id, scale `i32::MIN` as a zigzag varint and an
+    // unscaled value of 5.
+    let bytes = [0, 255, 40, 0xff, 0xff, 0xff, 0xff, 0x0f, 20];
+    let fory = Fory::builder().xlang(false).compatible(false).build();
+    let err = fory.deserialize::<Decimal>(&bytes).unwrap_err();
+    assert!(matches!(err, Error::InvalidData(_)), "{err}");
+}
+
+#[derive(ForyStruct, Debug, PartialEq)]
+struct Invoice {
+    amount: Decimal,
+    tax: Option<Decimal>,
+    lines: Vec<Decimal>,
+}
+
+#[derive(ForyStruct, Debug, PartialEq)]
+struct TextInvoice {
+    amount: String,
+    tax: Option<String>,
+    lines: Vec<Decimal>,
+}
+
+#[test]
+fn test_rust_decimal_struct() {
+    for compatible in [false, true] {
+        let mut fory = Fory::builder().xlang(false).compatible(compatible).build();
+        fory.register::<Invoice>(100).unwrap();
+        let invoice = Invoice {
+            amount: Decimal::new(-4_999, 2),
+            tax: Some(Decimal::new(1, 7)),
+            lines: values(),
+        };
+        let bytes = fory.serialize(&invoice).unwrap();
+        assert_eq!(invoice, fory.deserialize::<Invoice>(&bytes).unwrap());
+    }
+}
+
+#[test]
+fn test_rust_decimal_compatible_scalar_conversion() {
+    let mut writer = Fory::builder().xlang(false).compatible(true).build();
+    writer.register::<TextInvoice>(100).unwrap();
+    let mut reader = Fory::builder().xlang(false).compatible(true).build();
+    reader.register::<Invoice>(100).unwrap();
+
+    let bytes = writer
+        .serialize(&TextInvoice {
+            amount: "12.50".to_string(),
+            tax: Some("0.0000001".to_string()),
+            lines: vec![Decimal::ONE],
+        })
+        .unwrap();
+    let invoice: Invoice = reader.deserialize(&bytes).unwrap();
+    assert_eq!(invoice.amount, Decimal::new(1_250, 2));
+    assert_eq!(invoice.tax, Some(Decimal::new(1, 7)));
+    assert_eq!(invoice.lines, vec![Decimal::ONE]);
+}
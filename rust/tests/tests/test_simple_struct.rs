@@ -301,3 +301,35 @@ fn test_struct_with_bfloat16_fields() {
     assert_eq!(obj2.arr_field[1].to_bits(), bfloat16::MAX.to_bits());
     assert_eq!(obj2.arr_field[2].to_bits(), bfloat16::ZERO.to_bits());
 }
+
+#[test]
+fn test_unit_struct_and_unit_field() {
+    #[derive(ForyStruct, Debug, PartialEq)]
+    struct Marker;
+
+    #[derive(ForyStruct, Debug, PartialEq)]
+    struct Tagged {
+        id: i32,
+        unit: (),
+        marker: Marker,
+    }
+
+    for compatible in [false, true] {
+        for xlang in [false, true] {
+            let mut fory = Fory::builder().xlang(xlang).compatible(compatible).build();
+            fory.register::<Marker>(202).unwrap();
+            fory.register::<Tagged>(203).unwrap();
+
+            let bytes = fory.serialize(&Marker).unwrap();
+            assert_eq!(fory.deserialize::<Marker>(&bytes).unwrap(), Marker);
+
+            let tagged = Tagged {
+                id: 7,
+                unit: (),
+                marker: Marker,
+            };
+            let bytes = fory.serialize(&tagged).unwrap();
+            assert_eq!(fory.deserialize::<Tagged>(&bytes).unwrap(), tagged);
+        }
+    }
+}
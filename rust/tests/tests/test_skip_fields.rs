@@ -433,3 +433,52 @@ fn test_trait_object_serialization() {
     assert!(decoded_complex.animals_arc.is_empty());
     assert!(decoded_complex.registry.is_empty());
 }
+
+#[test]
+fn test_skip_field_compatible() {
+    #[derive(ForyStruct, Debug, PartialEq)]
+    struct WithCache {
+        id: i32,
+        #[fory(skip)]
+        cache: f64,
+        name: String,
+    }
+
+    #[derive(ForyStruct, Debug, PartialEq)]
+    struct WithoutCache {
+        id: i32,
+        name: String,
+    }
+
+    let mut fory1 = Fory::builder().xlang(false).compatible(true).build();
+    fory1.register::<WithCache>(20).unwrap();
+    let mut fory2 = Fory::builder().xlang(false).compatible(true).build();
+    fory2.register::<WithoutCache>(20).unwrap();
+
+    let original = WithCache {
+        id: 7,
+        cache: 3.5,
+        name: "cached".to_string(),
+    };
+    let bytes = fory1.serialize(&original).unwrap();
+    let decoded: WithCache = fory1.deserialize(&bytes).unwrap();
+    assert_eq!(decoded.id, 7);
+    assert_eq!(decoded.cache, 0.0);
+    assert_eq!(decoded.name, "cached");
+
+    // The skipped field is absent from the type meta, so a peer without it reads
+    // every other field in place, and vice versa.
+    let decoded: WithoutCache = fory2.deserialize(&bytes).unwrap();
+    assert_eq!(
+        decoded,
+        WithoutCache {
+            id: 7,
+            name: "cached".to_string(),
+        }
+    );
+    let bytes = fory2.serialize(&decoded).unwrap();
+    let decoded: WithCache = fory1.deserialize(&bytes).unwrap();
+    assert_eq!(decoded.id, 7);
+    assert_eq!(decoded.cache, 0.0);
+    assert_eq!(decoded.name, "cached");
+}
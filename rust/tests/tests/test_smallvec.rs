@@ -0,0 +1,118 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use fory_core::Fory;
+use fory_derive::ForyStruct;
+use smallvec::{smallvec, SmallVec};
+
+#[derive(ForyStruct, Debug, PartialEq)]
+struct Batch {
+    ids: SmallVec<[i32; 4]>,
+    tags: SmallVec<[String; 2]>,
+    maybe: SmallVec<[Option<i64>; 2]>,
+}
+
+#[derive(ForyStruct, Debug, PartialEq)]
+struct PeerBatch {
+    name: String,
+    ids: Vec<i32>,
+}
+
+#[derive(ForyStruct, Debug, PartialEq)]
+struct LocalBatch {
+    name: String,
+    ids: SmallVec<[i32; 4]>,
+}
+
+#[test]
+fn test_smallvec_inline_round_trip() {
+    for xlang in [false, true] {
+        let mut fory = Fory::builder().xlang(xlang).compatible(true).build();
+        fory.register::<Batch>(100).unwrap();
+
+        let ids: SmallVec<[i32; 4]> = smallvec![1, -2, 3];
+        let bytes = fory.serialize(&ids).unwrap();
+        let decoded: SmallVec<[i32; 4]> = fory.deserialize(&bytes).unwrap();
+        assert_eq!(decoded, ids);
+        assert!(!decoded.spilled());
+
+        let batch = Batch {
+            ids,
+            tags: smallvec!["a".to_string()],
+            maybe: smallvec![None, Some(7)],
+        };
+        let bytes = fory.serialize(&batch).unwrap();
+        let decoded: Batch = fory.deserialize(&bytes).unwrap();
+        assert_eq!(decoded, batch);
+        assert!(!decoded.ids.spilled());
+        assert!(!decoded.tags.spilled());
+    }
+}
+
+#[test]
+fn test_smallvec_spilled_round_trip() {
+    for xlang in [false, true] {
+        let mut fory = Fory::builder().xlang(xlang).compatible(true).build();
+        fory.register::<Batch>(100).unwrap();
+
+        let ids: SmallVec<[i32; 4]> = (0..10).collect();
+        assert!(ids.spilled());
+        let bytes = fory.serialize(&ids).unwrap();
+        let decoded: SmallVec<[i32; 4]> = fory.deserialize(&bytes).unwrap();
+        assert_eq!(decoded, ids);
+        assert!(decoded.spilled());
+
+        let batch = Batch {
+            ids,
+            tags: (0..5).map(|i| format!("tag{i}")).collect(),
+            maybe: smallvec![Some(1), None, Some(3)],
+        };
+        let bytes = fory.serialize(&batch).unwrap();
+        let decoded: Batch = fory.deserialize(&bytes).unwrap();
+        assert_eq!(decoded, batch);
+        assert!(decoded.ids.spilled());
+        assert!(decoded.tags.spilled());
+        assert!(decoded.maybe.spilled());
+    }
+}
+
+#[test]
+fn test_smallvec_reads_vec_field() {
+    for xlang in [false, true] {
+        let mut writer = Fory::builder().xlang(xlang).compatible(true).build();
+        writer.register::<PeerBatch>(101).unwrap();
+        let mut reader = Fory::builder().xlang(xlang).compatible(true).build();
+        reader.register::<LocalBatch>(101).unwrap();
+
+        for ids in [vec![5, 6], vec![1, 2, 3, 4, 5, 6]] {
+            let peer = PeerBatch {
+                name: "peer".to_string(),
+                ids: ids.clone(),
+            };
+            let bytes = writer.serialize(&peer).unwrap();
+            let decoded: LocalBatch = reader.deserialize(&bytes).unwrap();
+            assert_eq!(decoded.name, "peer");
+            assert_eq!(decoded.ids.as_slice(), ids.as_slice());
+            assert_eq!(decoded.ids.spilled(), ids.len() > 4);
+
+            let back: PeerBatch = writer
+                .deserialize(&reader.serialize(&decoded).unwrap())
+                .unwrap();
+            assert_eq!(back, peer);
+        }
+    }
+}
@@ -0,0 +1,216 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use fory_core::{Error, Fory};
+use fory_derive::ForyStruct;
+
+#[derive(ForyStruct, Debug, PartialEq, Default)]
+struct Sparse {
+    id: i32,
+    f01: Option<i32>,
+    f02: Option<i64>,
+    f03: Option<String>,
+    f04: Option<f64>,
+    f05: Option<bool>,
+    f06: Option<Vec<i32>>,
+    f07: Option<String>,
+    f08: Option<i32>,
+    f09: Option<i64>,
+    f10: Option<String>,
+    f11: Option<f32>,
+    f12: Option<u8>,
+    f13: Option<String>,
+    f14: Option<i32>,
+    f15: Option<i64>,
+    f16: Option<Vec<String>>,
+    f17: Option<String>,
+    f18: Option<i16>,
+    f19: Option<u32>,
+    f20: Option<String>,
+}
+
+/// Reads `Sparse` payloads with fields dropped, one made non-optional and one added.
+#[derive(ForyStruct, Debug, PartialEq, Default)]
+struct SparseV2 {
+    id: i32,
+    f03: Option<String>,
+    f07: Option<String>,
+    f08: i32,
+    f19: Option<u32>,
+    #[fory(default = Some("unset".to_string()))]
+    f20: Option<String>,
+    extra: Option<String>,
+}
+
+#[derive(ForyStruct, Debug, PartialEq, Default)]
+struct Batch {
+    items: Vec<Sparse>,
+    last: Option<Sparse>,
+}
+
+/// Reads `Batch` payloads without knowing `Sparse`, so the items are skipped.
+#[derive(ForyStruct, Debug, PartialEq, Default)]
+struct BatchHead {
+    tag: Option<String>,
+}
+
+fn sparse(id: i32) -> Sparse {
+    Sparse {
+        id,
+        f03: Some(format!("name-{id}")),
+        f19: Some(19),
+        ..Default::default()
+    }
+}
+
+fn fory(sparse_options: bool) -> Fory {
+    let mut fory = Fory::builder()
+        .xlang(false)
+        .compatible(true)
+        .sparse_options(sparse_options)
+        .build();
+    fory.register::<Sparse>(100).unwrap();
+    fory.register::<Batch>(101).unwrap();
+    fory
+}
+
+#[test]
+fn test_sparse_options_shrink_payload() {
+    let dense = fory(false);
+    let sparse_fory = fory(true);
+    let value = sparse(7);
+
+    let dense_bytes = dense.serialize(&value).unwrap();
+    let sparse_bytes = sparse_fory.serialize(&value).unwrap();
+    // 18 null flags are replaced by a 3-byte bitmap of the absent fields, and the header
+    // gains its native options byte. The rest of the payload, type metadata included, is
+    // unchanged.
+    assert_eq!(dense_bytes.len() - sparse_bytes.len(), 18 - 3 - 1);
+    assert_eq!(
+        sparse_fory.deserialize::<Sparse>(&sparse_bytes).unwrap(),
+        value
+    );
+    // The header marks sparse payloads, so readers don't need the option themselves.
+    assert_eq!(dense.deserialize::<Sparse>(&sparse_bytes).unwrap(), value);
+    assert_eq!(
+        sparse_fory.deserialize::<Sparse>(&dense_bytes).unwrap(),
+        value
+    );
+
+    let mut target = Sparse {
+        f01: Some(1),
+        f20: Some("stale".to_string()),
+        ..Default::default()
+    };
+    sparse_fory
+        .deserialize_into(&sparse_bytes, &mut target)
+        .unwrap();
+    assert_eq!(target, value);
+
+    // Without compatible mode there is no bitmap.
+    let same_schema = Fory::builder()
+        .xlang(false)
+        .compatible(false)
+        .sparse_options(true)
+        .build();
+    assert!(!same_schema.is_sparse_options());
+}
+
+#[test]
+fn test_sparse_options_nested_and_evolved() {
+    let writer = fory(true);
+    let batch = Batch {
+        items: vec![sparse(1), Sparse::default(), sparse(3)],
+        last: Some(Sparse {
+            f06: Some(vec![1, 2]),
+            f16: Some(vec!["x".to_string()]),
+            ..sparse(4)
+        }),
+    };
+    let bytes = writer.serialize(&batch).unwrap();
+    assert_eq!(writer.deserialize::<Batch>(&bytes).unwrap(), batch);
+    assert!(writer.debug_dump(&bytes).unwrap().contains("\"f01\": null"));
+
+    let mut head_reader = Fory::builder()
+        .xlang(false)
+        .compatible(true)
+        .sparse_options(true)
+        .build();
+    head_reader.register::<BatchHead>(101).unwrap();
+    assert_eq!(
+        head_reader.deserialize::<BatchHead>(&bytes).unwrap(),
+        BatchHead::default()
+    );
+
+    let mut reader = Fory::builder()
+        .xlang(false)
+        .compatible(true)
+        .sparse_options(true)
+        .build();
+    reader.register::<SparseV2>(100).unwrap();
+    let value = Sparse {
+        f07: Some("seven".to_string()),
+        f08: Some(8),
+        ..sparse(5)
+    };
+    assert_eq!(
+        reader
+            .deserialize::<SparseV2>(&writer.serialize(&value).unwrap())
+            .unwrap(),
+        SparseV2 {
+            id: 5,
+            f03: Some("name-5".to_string()),
+            f07: Some("seven".to_string()),
+            f08: 8,
+            f19: Some(19),
+            f20: None,
+            extra: None,
+        }
+    );
+}
+
+#[test]
+fn test_sparse_options_rejected_in_xlang() {
+    if fory_core::error::should_panic_on_error() {
+        return;
+    }
+    let mut fory = Fory::builder()
+        .xlang(true)
+        .compatible(true)
+        .sparse_options(true)
+        .build();
+    fory.register::<Sparse>(100).unwrap();
+    let err = fory.serialize(&sparse(1)).unwrap_err();
+    assert!(matches!(err, Error::NotAllowed(_)), "{err}");
+
+    // The native options byte is only defined for native-mode payloads.
+    let err = fory.deserialize::<i32>(&[0x41, 0x01, 0xff]).unwrap_err();
+    assert!(matches!(err, Error::InvalidData(_)), "{err}");
+}
+
+#[test]
+fn test_sparse_options_unknown_native_option_rejected() {
+    if fory_core::error::should_panic_on_error() {
+        return;
+    }
+    let fory = fory(true);
+    let mut bytes = fory.serialize(&sparse(1)).unwrap();
+    assert_eq!(bytes[1], 0x01);
+    bytes[1] |= 0x80;
+    let err = fory.deserialize::<Sparse>(&bytes).unwrap_err();
+    assert!(matches!(err, Error::InvalidData(_)), "{err}");
+}
@@ -0,0 +1,128 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use fory_core::error::Error;
+use fory_core::fory::Fory;
+use fory_core::types::{Duration as ForyDuration, Timestamp};
+use fory_derive::ForyStruct;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+// 2226-01-01T00:00:00Z
+const FAR_FUTURE_SECS: u64 = 8_078_832_000;
+
+#[test]
+fn test_std_duration_round_trip() {
+    for xlang in [false, true] {
+        let fory = Fory::builder().xlang(xlang).build();
+        for value in [
+            Duration::ZERO,
+            Duration::new(1, 500),
+            Duration::from_nanos(999_999_999),
+            Duration::new(i64::MAX as u64, 999_999_999),
+        ] {
+            let bin = fory.serialize(&value).unwrap();
+            assert_eq!(value, fory.deserialize::<Duration>(&bin).unwrap());
+            let fory_value = ForyDuration::try_from(value).unwrap();
+            assert_eq!(bin, fory.serialize(&fory_value).unwrap());
+        }
+        let err = fory.serialize(&Duration::MAX).unwrap_err();
+        assert!(matches!(err, Error::InvalidData(_)));
+    }
+}
+
+#[test]
+fn test_std_duration_rejects_negative() {
+    let fory = Fory::builder().xlang(false).build();
+    let bin = fory.serialize(&ForyDuration::from_millis(-1)).unwrap();
+    let err = fory.deserialize::<Duration>(&bin).unwrap_err();
+    assert!(matches!(err, Error::InvalidData(_)));
+}
+
+#[test]
+fn test_system_time_round_trip() {
+    for xlang in [false, true] {
+        let fory = Fory::builder().xlang(xlang).build();
+        for value in [
+            UNIX_EPOCH,
+            UNIX_EPOCH - Duration::new(86_400, 1),
+            SystemTime::now(),
+            UNIX_EPOCH + Duration::new(FAR_FUTURE_SECS, 123_456_789),
+        ] {
+            let bin = fory.serialize(&value).unwrap();
+            assert_eq!(value, fory.deserialize::<SystemTime>(&bin).unwrap());
+            let timestamp = Timestamp::try_from(value).unwrap();
+            assert_eq!(bin, fory.serialize(&timestamp).unwrap());
+            assert_eq!(timestamp, fory.deserialize::<Timestamp>(&bin).unwrap());
+        }
+    }
+}
+
+#[derive(ForyStruct, Debug, PartialEq)]
+struct Lease {
+    granted_at: SystemTime,
+    ttl: Duration,
+    renewed_at: Option<SystemTime>,
+    grace: Vec<Duration>,
+}
+
+#[derive(ForyStruct, Debug, PartialEq)]
+struct ForyLease {
+    granted_at: Timestamp,
+    ttl: ForyDuration,
+    renewed_at: Option<Timestamp>,
+    grace: Vec<ForyDuration>,
+}
+
+fn lease() -> Lease {
+    Lease {
+        granted_at: UNIX_EPOCH + Duration::new(FAR_FUTURE_SECS, 1),
+        ttl: Duration::ZERO,
+        renewed_at: Some(UNIX_EPOCH),
+        grace: vec![Duration::from_millis(250), Duration::from_secs(60)],
+    }
+}
+
+#[test]
+fn test_std_time_struct_fields() {
+    for compatible in [false, true] {
+        let mut fory = Fory::builder().xlang(false).compatible(compatible).build();
+        fory.register::<Lease>(100).unwrap();
+        let value = lease();
+        let bin = fory.serialize(&value).unwrap();
+        assert_eq!(value, fory.deserialize::<Lease>(&bin).unwrap());
+    }
+}
+
+#[test]
+fn test_std_time_reads_as_fory_types() {
+    let mut writer = Fory::builder().xlang(false).compatible(true).build();
+    writer.register::<Lease>(100).unwrap();
+    let mut reader = Fory::builder().xlang(false).compatible(true).build();
+    reader.register::<ForyLease>(100).unwrap();
+
+    let value = lease();
+    let obj: ForyLease = reader
+        .deserialize(&writer.serialize(&value).unwrap())
+        .unwrap();
+    assert_eq!(
+        obj.granted_at,
+        Timestamp::try_from(value.granted_at).unwrap()
+    );
+    assert_eq!(obj.ttl, ForyDuration::default());
+    assert_eq!(obj.renewed_at, Some(Timestamp::default()));
+    assert_eq!(obj.grace[0], ForyDuration::from_millis(250));
+}
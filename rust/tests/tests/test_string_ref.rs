@@ -0,0 +1,189 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use fory_core::{Error, Fory};
+use fory_derive::ForyStruct;
+use std::collections::HashMap;
+
+fn records(count: usize) -> Vec<HashMap<String, i32>> {
+    (0..count as i32)
+        .map(|i| {
+            HashMap::from([
+                ("request_identifier".to_string(), i),
+                ("response_status_code".to_string(), 200),
+                ("elapsed_milliseconds".to_string(), i % 7),
+            ])
+        })
+        .collect()
+}
+
+#[test]
+fn test_repeated_map_keys() {
+    let plain = Fory::builder().xlang(false).build();
+    let interned = Fory::builder().xlang(false).string_ref(true).build();
+    assert!(interned.is_string_ref());
+    assert!(!plain.is_string_ref());
+
+    let value = records(1000);
+    let plain_bytes = plain.serialize(&value).unwrap();
+    let interned_bytes = interned.serialize(&value).unwrap();
+    assert!(
+        interned_bytes.len() * 4 < plain_bytes.len(),
+        "{} bytes with string_ref, {} without",
+        interned_bytes.len(),
+        plain_bytes.len()
+    );
+    let decoded: Vec<HashMap<String, i32>> = interned.deserialize(&interned_bytes).unwrap();
+    assert_eq!(decoded, value);
+
+    // References are scoped to one payload, so distinct strings are written as usual. Only
+    // the header differs, as its native options byte marks the option.
+    let distinct = vec!["first".to_string(), "second".to_string()];
+    interned.serialize(&distinct).unwrap();
+    let bytes = interned.serialize(&distinct).unwrap();
+    assert_eq!(bytes[2..], plain.serialize(&distinct).unwrap()[1..]);
+    assert_eq!(
+        interned.deserialize::<Vec<String>>(&bytes).unwrap(),
+        distinct
+    );
+}
+
+mod writer {
+    use fory_derive::ForyStruct;
+
+    #[derive(ForyStruct, Debug, PartialEq)]
+    pub struct Message {
+        pub dropped: String,
+        pub sender: String,
+        pub recipient: String,
+        pub cc: Vec<String>,
+    }
+}
+
+#[derive(ForyStruct, Debug, PartialEq)]
+struct Message {
+    sender: String,
+    recipient: String,
+    cc: Vec<String>,
+}
+
+#[test]
+fn test_string_refs_in_struct_fields() {
+    for compatible in [false, true] {
+        let mut fory = Fory::builder()
+            .xlang(false)
+            .compatible(compatible)
+            .string_ref(true)
+            .build();
+        fory.register::<Message>(100).unwrap();
+        let value = Message {
+            sender: "alice@example.com".to_string(),
+            recipient: "alice@example.com".to_string(),
+            cc: vec![
+                String::new(),
+                "alice@example.com".to_string(),
+                String::new(),
+                "bob@example.com".to_string(),
+            ],
+        };
+        let bytes = fory.serialize(&value).unwrap();
+        assert_eq!(fory.deserialize::<Message>(&bytes).unwrap(), value);
+    }
+}
+
+#[test]
+fn test_string_refs_into_skipped_fields() {
+    // A field the reader skips can hold the first occurrence of a string that a later field
+    // refers back to.
+    let mut writer = Fory::builder()
+        .xlang(false)
+        .compatible(true)
+        .string_ref(true)
+        .build();
+    writer.register::<writer::Message>(100).unwrap();
+    let mut reader = Fory::builder()
+        .xlang(false)
+        .compatible(true)
+        .string_ref(true)
+        .build();
+    reader.register::<Message>(100).unwrap();
+
+    let bytes = writer
+        .serialize(&writer::Message {
+            dropped: "carol@example.com".to_string(),
+            sender: "carol@example.com".to_string(),
+            recipient: "dave@example.com".to_string(),
+            cc: vec!["carol@example.com".to_string()],
+        })
+        .unwrap();
+    let message: Message = reader.deserialize(&bytes).unwrap();
+    assert_eq!(
+        message,
+        Message {
+            sender: "carol@example.com".to_string(),
+            recipient: "dave@example.com".to_string(),
+            cc: vec!["carol@example.com".to_string()],
+        }
+    );
+}
+
+#[test]
+fn test_string_ref_recorded_in_header() {
+    let writer = Fory::builder().xlang(false).string_ref(true).build();
+    let value = vec!["repeated".to_string(), "repeated".to_string()];
+    let mut bytes = writer.serialize(&value).unwrap();
+
+    // Readers follow the header rather than their own setting.
+    let reader = Fory::builder().xlang(false).build();
+    assert_eq!(reader.deserialize::<Vec<String>>(&bytes).unwrap(), value);
+    let plain_bytes = reader.serialize(&value).unwrap();
+    assert_eq!(
+        writer.deserialize::<Vec<String>>(&plain_bytes).unwrap(),
+        value
+    );
+
+    if fory_core::error::should_panic_on_error() {
+        return;
+    }
+    // A reference in a payload whose header doesn't mark string references is invalid.
+    assert_eq!(bytes[1], 0x02);
+    bytes[1] = 0;
+    let err = reader.deserialize::<Vec<String>>(&bytes).unwrap_err();
+    assert!(matches!(err, Error::InvalidData(_)), "{err}");
+}
+
+#[test]
+fn test_string_ref_requires_native_mode() {
+    if fory_core::error::should_panic_on_error() {
+        return;
+    }
+    let xlang = Fory::builder().xlang(true).string_ref(true).build();
+    let err = xlang.serialize(&"a".to_string()).unwrap_err();
+    assert!(matches!(err, Error::NotAllowed(_)), "{err}");
+
+    // The native options byte is only defined for native-mode payloads.
+    let err = xlang
+        .deserialize::<String>(&[0x41, 0x02, 0xff, 21, 0x06, b'a'])
+        .unwrap_err();
+    assert!(matches!(err, Error::InvalidData(_)), "{err}");
+
+    // Header bit 7 is reserved.
+    let err = xlang
+        .deserialize::<String>(&[0x81, 0xff, 21, 0x06, b'a'])
+        .unwrap_err();
+    assert!(matches!(err, Error::InvalidData(_)), "{err}");
+}
@@ -0,0 +1,167 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use fory_core::{Error, Fory, ForyDefault, Serializer};
+use fory_derive::{ForyEnum, ForyStruct};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::rc::Rc;
+use std::sync::Arc;
+
+#[derive(ForyEnum, Debug, PartialEq, Default)]
+enum Kind {
+    #[default]
+    A,
+    B,
+}
+
+#[derive(ForyStruct, Debug, PartialEq)]
+struct Inner {
+    id: i64,
+    tags: Vec<String>,
+}
+
+#[derive(ForyStruct, Debug, PartialEq)]
+struct Outer {
+    flag: bool,
+    small: i8,
+    var: i32,
+    wide: u64,
+    ratio: f64,
+    name: String,
+    unicode: String,
+    bytes: Vec<u8>,
+    ints: Vec<i32>,
+    opt: Option<Inner>,
+    inner: Inner,
+    list: Vec<Inner>,
+    map: HashMap<String, Vec<i64>>,
+    sorted: BTreeMap<i32, Option<String>>,
+    set: HashSet<i16>,
+    kind: Kind,
+    shared: Rc<Inner>,
+    shared_again: Rc<Inner>,
+    arc: Arc<String>,
+}
+
+fn outer() -> Outer {
+    let shared = Rc::new(Inner {
+        id: -9,
+        tags: vec!["s".to_string()],
+    });
+    Outer {
+        flag: true,
+        small: -3,
+        var: i32::MIN,
+        wide: u64::MAX,
+        ratio: 0.25,
+        name: "latin1 name".to_string(),
+        unicode: "中文 😀".to_string(),
+        bytes: vec![1, 2, 3, 4, 5],
+        ints: vec![i32::MAX, 0, -1],
+        opt: Some(Inner {
+            id: 1,
+            tags: vec![],
+        }),
+        inner: Inner {
+            id: 2,
+            tags: vec!["a".to_string(), "bc".to_string()],
+        },
+        list: vec![
+            Inner {
+                id: 3,
+                tags: vec!["x".to_string()],
+            },
+            Inner {
+                id: 4,
+                tags: vec![],
+            },
+        ],
+        map: HashMap::from([("k".to_string(), vec![1, -1, i64::MAX])]),
+        sorted: BTreeMap::from([(1, Some("one".to_string())), (2, None)]),
+        set: HashSet::from([7, -7]),
+        kind: Kind::B,
+        shared: shared.clone(),
+        shared_again: shared,
+        arc: Arc::new("arc".to_string()),
+    }
+}
+
+/// Feeds every proper prefix of `bytes` to `deserialize` and requires an `Err`,
+/// never a panic or a bogus success.
+fn assert_prefixes_fail<T: Serializer + ForyDefault>(fory: &Fory, bytes: &[u8]) {
+    for len in 0..bytes.len() {
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            fory.deserialize::<T>(&bytes[..len])
+        }));
+        match result {
+            Ok(Err(_)) => {}
+            Ok(Ok(_)) => panic!("prefix of length {len}/{} decoded", bytes.len()),
+            Err(_) => panic!("prefix of length {len}/{} panicked", bytes.len()),
+        }
+    }
+}
+
+#[test]
+fn test_truncated_scalars() {
+    for xlang in [false, true] {
+        let fory = Fory::builder().xlang(xlang).build();
+        assert_prefixes_fail::<bool>(&fory, &fory.serialize(&true).unwrap());
+        assert_prefixes_fail::<i32>(&fory, &fory.serialize(&i32::MIN).unwrap());
+        assert_prefixes_fail::<u64>(&fory, &fory.serialize(&u64::MAX).unwrap());
+        assert_prefixes_fail::<f64>(&fory, &fory.serialize(&1.5f64).unwrap());
+        assert_prefixes_fail::<String>(&fory, &fory.serialize(&"中文".to_string()).unwrap());
+        assert_prefixes_fail::<Vec<u8>>(&fory, &fory.serialize(&vec![1u8, 2, 3]).unwrap());
+        assert_prefixes_fail::<Vec<String>>(
+            &fory,
+            &fory
+                .serialize(&vec!["a".to_string(), "b".to_string()])
+                .unwrap(),
+        );
+        assert_prefixes_fail::<HashMap<i32, String>>(
+            &fory,
+            &fory
+                .serialize(&HashMap::from([(1, "x".to_string())]))
+                .unwrap(),
+        );
+    }
+}
+
+#[test]
+fn test_truncated_struct() {
+    for compatible in [false, true] {
+        for xlang in [false, true] {
+            let mut fory = Fory::builder()
+                .xlang(xlang)
+                .compatible(compatible)
+                .track_ref(true)
+                .build();
+            fory.register::<Kind>(100).unwrap();
+            fory.register::<Inner>(101).unwrap();
+            fory.register::<Outer>(102).unwrap();
+            let bytes = fory.serialize(&outer()).unwrap();
+            assert_eq!(outer(), fory.deserialize::<Outer>(&bytes).unwrap());
+            assert_prefixes_fail::<Outer>(&fory, &bytes);
+        }
+    }
+}
+
+#[test]
+fn test_empty_input() {
+    let fory = Fory::default();
+    let err = fory.deserialize::<i32>(&[]).unwrap_err();
+    assert!(matches!(err, Error::BufferOutOfBound(..)), "{err}");
+}
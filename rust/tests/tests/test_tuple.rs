@@ -217,6 +217,32 @@ fn test_homogeneous_tuple_unsigned() {
     assert_eq!(tuple_u64, obj);
 }
 
+#[test]
+fn test_vec_of_tuples() {
+    for compatible in [false, true] {
+        let fory = Fory::builder().xlang(false).compatible(compatible).build();
+        let pairs = vec![(1i32, "one".to_string()), (2i32, "two".to_string())];
+        let bin = fory.serialize(&pairs).unwrap();
+        let obj: Vec<(i32, String)> = fory.deserialize(&bin).expect("deserialize");
+        assert_eq!(pairs, obj);
+
+        let maybe_pair = Some((-1i8, "neg".to_string()));
+        let bin = fory.serialize(&maybe_pair).unwrap();
+        let obj: Option<(i8, String)> = fory.deserialize(&bin).expect("deserialize");
+        assert_eq!(maybe_pair, obj);
+    }
+}
+
+#[test]
+fn test_tuple_reserved_space() {
+    use fory_core::serializer::Serializer;
+    assert_eq!(
+        <(i32, i64, f64)>::fory_reserved_space(),
+        4 + i32::fory_reserved_space() + i64::fory_reserved_space() + f64::fory_reserved_space()
+    );
+    assert_eq!(<(u8,)>::fory_reserved_space(), 4 + 1);
+}
+
 // Test that tuples are serialized with LIST type ID
 #[test]
 fn test_tuple_type_id() {
@@ -84,6 +84,25 @@ fn test_triple_tuple_struct() {
     assert_eq!(result, triple);
 }
 
+#[test]
+fn test_vec_of_tuple_structs() {
+    for compatible in [false, true] {
+        let mut fory = Fory::builder().xlang(false).compatible(compatible).build();
+        fory.register::<Single>(101).unwrap();
+        fory.register::<Triple>(103).unwrap();
+
+        let singles = vec![Single(1), Single(-2), Single(i32::MAX)];
+        let bytes = fory.serialize(&singles).unwrap();
+        let result: Vec<Single> = fory.deserialize(&bytes).unwrap();
+        assert_eq!(result, singles);
+
+        let triples = vec![Triple(1, 2, 3), Triple(-4, i64::MIN, u32::MAX)];
+        let bytes = fory.serialize(&triples).unwrap();
+        let result: Vec<Triple> = fory.deserialize(&bytes).unwrap();
+        assert_eq!(result, triples);
+    }
+}
+
 // Tuple Structs with Complex Types
 
 #[derive(ForyStruct, Debug, PartialEq, Clone)]
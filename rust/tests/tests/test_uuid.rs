@@ -0,0 +1,112 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+mod test_helpers;
+
+use fory_core::fory::Fory;
+use fory_derive::ForyStruct;
+use std::collections::HashMap;
+use test_helpers::{test_arc_any, test_box_any, test_rc_any, test_roundtrip};
+use uuid::Uuid;
+
+const ID: Uuid = Uuid::from_u128(0x0123_4567_89ab_cdef_fedc_ba98_7654_3210);
+
+#[test]
+fn test_uuid() {
+    let fory = Fory::builder().xlang(false).compatible(false).build();
+    for id in [Uuid::nil(), Uuid::max(), ID] {
+        test_roundtrip(&fory, id);
+        test_box_any(&fory, id);
+        test_rc_any(&fory, id);
+        test_arc_any(&fory, id);
+    }
+    test_roundtrip(&fory, Some(ID));
+    test_roundtrip(&fory, None::<Uuid>);
+    test_roundtrip(&fory, vec![ID, Uuid::nil()]);
+    test_roundtrip(&fory, HashMap::from([(ID, 1), (Uuid::max(), 2)]));
+}
+
+#[test]
+fn test_uuid_wire_layout() {
+    let fory = Fory::builder().xlang(false).compatible(false).build();
+    // `u8` and `Uuid` both have a one-byte type id, so the rest is the shared header.
+    let header_len = fory.serialize(&0u8).unwrap().len() - 1;
+    let bytes = fory.serialize(&ID).unwrap();
+    assert_eq!(bytes.len(), header_len + 16);
+    assert_eq!(
+        &bytes[header_len..],
+        &[
+            0xef, 0xcd, 0xab, 0x89, 0x67, 0x45, 0x23, 0x01, 0x10, 0x32, 0x54, 0x76, 0x98, 0xba,
+            0xdc, 0xfe,
+        ]
+    );
+}
+
+#[test]
+fn test_uuid_struct() {
+    #[derive(ForyStruct, Debug, PartialEq)]
+    struct Session {
+        id: Uuid,
+        parent: Option<Uuid>,
+        members: HashMap<Uuid, String>,
+    }
+
+    for compatible in [false, true] {
+        let mut fory = Fory::builder().xlang(false).compatible(compatible).build();
+        fory.register::<Session>(100).unwrap();
+        let session = Session {
+            id: ID,
+            parent: None,
+            members: HashMap::from([(Uuid::max(), "root".to_string())]),
+        };
+        test_roundtrip(&fory, session);
+    }
+}
+
+#[test]
+fn test_uuid_when_xlang() {
+    let fory = Fory::builder().xlang(true).compatible(false).build();
+    assert!(fory.serialize(&ID).is_err());
+}
+
+#[test]
+fn test_uuid_skipped_in_compatible_mode() {
+    #[derive(ForyStruct, Debug, PartialEq)]
+    struct Writer {
+        id: Uuid,
+        name: String,
+    }
+
+    #[derive(ForyStruct, Debug, PartialEq)]
+    struct Reader {
+        name: String,
+    }
+
+    let mut writer = Fory::builder().xlang(false).compatible(true).build();
+    writer.register::<Writer>(100).unwrap();
+    let mut reader = Fory::builder().xlang(false).compatible(true).build();
+    reader.register::<Reader>(100).unwrap();
+
+    let bytes = writer
+        .serialize(&Writer {
+            id: ID,
+            name: "fory".to_string(),
+        })
+        .unwrap();
+    let obj: Reader = reader.deserialize(&bytes).unwrap();
+    assert_eq!(obj.name, "fory");
+}
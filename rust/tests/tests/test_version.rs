@@ -0,0 +1,158 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use fory_core::{Error, Fory};
+use fory_derive::ForyStruct;
+
+#[derive(ForyStruct, Debug, PartialEq)]
+#[fory(version = 1)]
+struct SettingsV1 {
+    retries: u32,
+}
+
+#[derive(ForyStruct, Debug, PartialEq)]
+#[fory(version = 3, min_version = 2)]
+struct SettingsV3 {
+    retries: u32,
+    timeout_ms: u64,
+}
+
+#[derive(ForyStruct, Debug, PartialEq)]
+#[fory(version = 4)]
+struct SettingsV4 {
+    retries: u32,
+    timeout_ms: u64,
+    endpoint: String,
+}
+
+#[derive(ForyStruct, Debug, PartialEq)]
+struct Unversioned {
+    retries: u32,
+}
+
+fn fory_with<T>() -> Fory
+where
+    T: fory_core::StructSerializer + fory_core::Serializer + fory_core::ForyDefault,
+{
+    let mut fory = Fory::builder().xlang(false).compatible(true).build();
+    fory.register::<T>(100).unwrap();
+    fory
+}
+
+#[test]
+fn test_matching_version() {
+    for compatible in [false, true] {
+        let mut fory = Fory::builder().xlang(false).compatible(compatible).build();
+        fory.register::<SettingsV3>(100).unwrap();
+        let settings = SettingsV3 {
+            retries: 3,
+            timeout_ms: 250,
+        };
+        let bytes = fory.serialize(&settings).unwrap();
+        assert_eq!(
+            fory.deserialize_versioned::<SettingsV3>(&bytes).unwrap(),
+            (settings, 3)
+        );
+        let settings: SettingsV3 = fory.deserialize(&bytes).unwrap();
+        assert_eq!(settings.timeout_ms, 250);
+    }
+}
+
+#[test]
+fn test_newer_version_accepted() {
+    let writer = fory_with::<SettingsV4>();
+    let reader = fory_with::<SettingsV3>();
+    let bytes = writer
+        .serialize(&SettingsV4 {
+            retries: 5,
+            timeout_ms: 100,
+            endpoint: "primary".to_string(),
+        })
+        .unwrap();
+    let (settings, version) = reader.deserialize_versioned::<SettingsV3>(&bytes).unwrap();
+    assert_eq!(version, 4);
+    assert_eq!(
+        settings,
+        SettingsV3 {
+            retries: 5,
+            timeout_ms: 100
+        }
+    );
+
+    // Versions only travel in the root header, so nested values are unaffected.
+    let bytes = writer
+        .serialize(&vec![SettingsV4 {
+            retries: 1,
+            timeout_ms: 2,
+            endpoint: String::new(),
+        }])
+        .unwrap();
+    assert_eq!(
+        reader.deserialize::<Vec<SettingsV3>>(&bytes).unwrap().len(),
+        1
+    );
+}
+
+#[test]
+fn test_too_old_version_rejected() {
+    if fory_core::error::should_panic_on_error() {
+        return;
+    }
+    let reader = fory_with::<SettingsV3>();
+
+    let bytes = fory_with::<SettingsV1>()
+        .serialize(&SettingsV1 { retries: 2 })
+        .unwrap();
+    let err = reader.deserialize::<SettingsV3>(&bytes).unwrap_err();
+    assert!(matches!(err, Error::VersionTooOld(1, 2)), "{err}");
+    let err = reader
+        .deserialize_versioned::<SettingsV3>(&bytes)
+        .unwrap_err();
+    assert!(matches!(err, Error::VersionTooOld(1, 2)), "{err}");
+
+    // A payload without a version counts as version zero.
+    let bytes = fory_with::<Unversioned>()
+        .serialize(&Unversioned { retries: 2 })
+        .unwrap();
+    let err = reader.deserialize::<SettingsV3>(&bytes).unwrap_err();
+    assert!(matches!(err, Error::VersionTooOld(0, 2)), "{err}");
+
+    // Readers without a minimum accept any version, but need one to report it.
+    let unversioned = fory_with::<Unversioned>();
+    let err = unversioned
+        .deserialize_versioned::<Unversioned>(&bytes)
+        .unwrap_err();
+    assert!(matches!(err, Error::InvalidData(_)), "{err}");
+    let bytes = fory_with::<SettingsV1>()
+        .serialize(&SettingsV1 { retries: 2 })
+        .unwrap();
+    assert_eq!(
+        unversioned.deserialize::<Unversioned>(&bytes).unwrap(),
+        Unversioned { retries: 2 }
+    );
+}
+
+#[test]
+fn test_versioned_struct_requires_native_mode() {
+    if fory_core::error::should_panic_on_error() {
+        return;
+    }
+    let mut fory = Fory::builder().xlang(true).build();
+    fory.register::<SettingsV1>(100).unwrap();
+    let err = fory.serialize(&SettingsV1 { retries: 1 }).unwrap_err();
+    assert!(matches!(err, Error::NotAllowed(_)), "{err}");
+}
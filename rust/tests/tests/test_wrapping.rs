@@ -0,0 +1,113 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use fory_core::Fory;
+use fory_derive::ForyStruct;
+use std::num::Wrapping;
+
+#[derive(ForyStruct, Debug, PartialEq)]
+struct Packet {
+    checksum: Wrapping<u32>,
+    sequence: Wrapping<i64>,
+    parity: Option<Wrapping<u8>>,
+    blocks: Vec<Wrapping<u32>>,
+}
+
+#[derive(ForyStruct, Debug, PartialEq)]
+struct PlainPacket {
+    checksum: u32,
+    blocks: Vec<u32>,
+}
+
+#[derive(ForyStruct, Debug, PartialEq)]
+struct WrappingPacket {
+    checksum: Wrapping<u32>,
+    blocks: Vec<Wrapping<u32>>,
+}
+
+#[test]
+fn test_wrapping_roundtrip() {
+    let fory = Fory::builder().xlang(false).build();
+    let checksum = Wrapping(u32::MAX) + Wrapping(2);
+    let bytes = fory.serialize(&checksum).unwrap();
+    assert_eq!(fory.deserialize::<Wrapping<u32>>(&bytes).unwrap(), checksum);
+    // The payload is the one written for the inner integer.
+    assert_eq!(bytes, fory.serialize(&1u32).unwrap());
+
+    let blocks = vec![Wrapping(1u32), Wrapping(u32::MAX)];
+    let bytes = fory.serialize(&blocks).unwrap();
+    assert_eq!(
+        fory.deserialize::<Vec<Wrapping<u32>>>(&bytes).unwrap(),
+        blocks
+    );
+    assert_eq!(bytes, fory.serialize(&vec![1u32, u32::MAX]).unwrap());
+}
+
+#[test]
+fn test_wrapping_struct_fields() {
+    for compatible in [false, true] {
+        for xlang in [false, true] {
+            let mut fory = Fory::builder().compatible(compatible).xlang(xlang).build();
+            fory.register::<Packet>(100).unwrap();
+            let packet = Packet {
+                checksum: Wrapping(0xdead_beef),
+                sequence: Wrapping(i64::MIN) - Wrapping(1),
+                parity: Some(Wrapping(1)),
+                blocks: vec![Wrapping(7), Wrapping(0)],
+            };
+            let bytes = fory.serialize(&packet).unwrap();
+            assert_eq!(fory.deserialize::<Packet>(&bytes).unwrap(), packet);
+        }
+    }
+}
+
+#[test]
+fn test_plain_fields_read_into_wrapping() {
+    let mut writer = Fory::builder().compatible(true).xlang(false).build();
+    writer.register::<PlainPacket>(100).unwrap();
+    let mut reader = Fory::builder().compatible(true).xlang(false).build();
+    reader.register::<WrappingPacket>(100).unwrap();
+
+    let bytes = writer
+        .serialize(&PlainPacket {
+            checksum: 42,
+            blocks: vec![1, 2, 3],
+        })
+        .unwrap();
+    assert_eq!(
+        reader.deserialize::<WrappingPacket>(&bytes).unwrap(),
+        WrappingPacket {
+            checksum: Wrapping(42),
+            blocks: vec![Wrapping(1), Wrapping(2), Wrapping(3)],
+        }
+    );
+
+    // And back: a wrapping field reads into a plain one.
+    let bytes = reader
+        .serialize(&WrappingPacket {
+            checksum: Wrapping(9),
+            blocks: vec![Wrapping(4)],
+        })
+        .unwrap();
+    assert_eq!(
+        writer.deserialize::<PlainPacket>(&bytes).unwrap(),
+        PlainPacket {
+            checksum: 9,
+            blocks: vec![4],
+        }
+    );
+}